@@ -0,0 +1,120 @@
+// Compile resources/dicom_dict.tsv into static perfect hash maps so the
+// dictionary costs nothing at runtime. The tsv is regenerated by dictgen.
+extern crate phf_codegen;
+
+use std::collections::HashSet;
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+fn entry(fields: &[&str]) -> String {
+    format!("DicomDictElt {{ vr : {:?}, vm: {:?}, name: {:?}, retired: {:?}, keyword: {:?} }}",
+            fields[1], fields[2], fields[3], fields[4], fields[5])
+}
+
+/// "SOPInstanceUID" -> "SOP_INSTANCE_UID"
+fn const_name(keyword: &str) -> String {
+    let chars: Vec<char> = keyword.chars().collect();
+    let mut name = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower) {
+                name.push('_');
+            }
+        }
+        name.extend(c.to_uppercase());
+    }
+    name
+}
+
+fn main() {
+    dictionary();
+    uids();
+}
+
+fn dictionary() {
+    let src = "resources/dicom_dict.tsv";
+    println!("cargo:rerun-if-changed={}", src);
+    let input = BufReader::new(File::open(src).unwrap());
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("dicom_dict.rs");
+    let mut out = BufWriter::new(File::create(&out).unwrap());
+    let tags_out = Path::new(&env::var("OUT_DIR").unwrap()).join("tags.rs");
+    let mut tags_out = BufWriter::new(File::create(&tags_out).unwrap());
+
+    let mut tags = phf_codegen::Map::new();
+    let mut keywords = phf_codegen::Map::new();
+    let mut seen = HashSet::new();
+    let mut repeaters = Vec::new();
+    for line in input.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') || line.is_empty() { continue; }
+        let fields: Vec<&str> = line.split('\t').collect();
+        assert!(fields.len() == 6, "bad dictionary line: {}", line);
+        if fields[0].contains('x') {
+            // repeating groups/elements are matched as tag & mask == value
+            let mask: String = fields[0].chars().map(|c| if c == 'x' { '0' } else { 'F' }).collect();
+            let value = fields[0].replace("x", "0");
+            repeaters.push(format!("(0x{}, 0x{}, {})", mask, value, entry(&fields)));
+            continue;
+        }
+        let tag = u32::from_str_radix(fields[0], 16).unwrap();
+        tags.entry(tag, &entry(&fields));
+        let keyword = fields[5].to_string();
+        if !keyword.is_empty() && seen.insert(keyword.clone()) {
+            write!(tags_out, "/// ({},{}) {}{}\npub const {}: DicomTag = DicomTag {{ tag: 0x{}, vr: {:?}, vm: {:?}, keyword: {:?} }};\n",
+                   &fields[0][..4], &fields[0][4..], fields[3], if fields[4].is_empty() { "" } else { " (retired)" },
+                   const_name(&keyword), fields[0], fields[1], fields[2], keyword).unwrap();
+            keywords.entry(keyword, &format!("0x{}", fields[0]));
+        }
+    }
+
+    write!(out, "static DICOM_DICTIONARY: ::phf::Map<u32, DicomDictElt<'static>> = ").unwrap();
+    tags.build(&mut out).unwrap();
+    write!(out, ";\n\nstatic DICOM_KEYWORDS: ::phf::Map<&'static str, u32> = ").unwrap();
+    keywords.build(&mut out).unwrap();
+    writeln!(out, ";\n\nstatic REPEATERS_DICTIONARY: &'static [(u32, u32, DicomDictElt<'static>)] = &[").unwrap();
+    for r in repeaters.iter() {
+        writeln!(out, "    {},", r).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn uid_type(name: &str) -> &'static str {
+    match name {
+        "Transfer Syntax" => "TransferSyntax",
+        "SOP Class" => "SopClass",
+        "Meta SOP Class" => "MetaSopClass",
+        "Well-known SOP Instance" => "WellKnownSopInstance",
+        "Well-known frame of reference" => "WellKnownFrameOfReference",
+        "Application Context Name" => "ApplicationContextName",
+        "Coding Scheme" | "DICOM UIDs as a Coding Scheme" => "CodingScheme",
+        _ => "Other",
+    }
+}
+
+fn uids() {
+    let src = "resources/uid_registry.tsv";
+    println!("cargo:rerun-if-changed={}", src);
+    let input = BufReader::new(File::open(src).unwrap());
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("uids.rs");
+    let mut out = BufWriter::new(File::create(&out).unwrap());
+
+    let mut uids = phf_codegen::Map::new();
+    for line in input.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') || line.is_empty() { continue; }
+        let fields: Vec<&str> = line.split('\t').collect();
+        assert!(fields.len() == 5, "bad uid registry line: {}", line);
+        let retired = fields[1].ends_with("(Retired)");
+        let name = if retired { &fields[1][..fields[1].len() - "(Retired)".len()] } else { fields[1] }.trim();
+        uids.entry(fields[0].to_string(),
+                   &format!("DicomUid {{ uid: {:?}, name: {:?}, keyword: {:?}, uid_type: UidType::{}, retired: {}, part: {:?} }}",
+                            fields[0], name, fields[2], uid_type(fields[3]), retired, fields[4]));
+    }
+    write!(out, "static UID_REGISTRY: ::phf::Map<&'static str, DicomUid> = ").unwrap();
+    uids.build(&mut out).unwrap();
+    writeln!(out, ";").unwrap();
+}
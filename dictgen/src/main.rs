@@ -0,0 +1,326 @@
+//! Regenerate resources/dicom_dict.tsv and resources/uid_registry.tsv from the
+//! docbook sources of the DICOM standard:
+//!
+//!     cargo run -p dictgen -- fetch [--pin]
+//!     cargo run -p dictgen -- [part06.xml part07.xml]
+//!
+//! `fetch` downloads PS3.6 and PS3.7 of the pinned edition into resources/standard/
+//! and checks them against resources/standard/SHA256SUMS; `--pin` records the hash
+//! of a file with no entry yet. Without arguments the vendored copies are verified
+//! the same way and used. build.rs compiles the tables into the crate.
+extern crate sha2;
+extern crate xml;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use sha2::{Digest, Sha256};
+
+use xml::reader::{EventReader, XmlEvent};
+
+const DICT_FILENAME: &'static str = "resources/dicom_dict.tsv";
+const UID_FILENAME: &'static str = "resources/uid_registry.tsv";
+const STANDARD_DIR: &'static str = "resources/standard";
+const SUMS_FILENAME: &'static str = "resources/standard/SHA256SUMS";
+
+/// The edition of the standard the tables are generated from. Moving to a new one means
+/// changing this, running `fetch --pin` and reviewing the diff of the generated tables.
+const EDITION: &'static str = "2024c";
+/// Overridden by DICTGEN_BASE_URL, e.g. for a mirror.
+const BASE_URL: &'static str = "https://dicom.nema.org/medical/dicom";
+const PARTS: [&'static str; 2] = ["part06", "part07"];
+
+/// A docbook table: its label (e.g. "6-1"), caption, column headers and the text of each cell.
+struct Table {
+    label: String,
+    caption: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Rows keyed by column header. The unlabelled last column of the PS3.6
+    /// registries holds the retired status and is keyed "Retired".
+    fn records(&self) -> Vec<HashMap<String, String>> {
+        self.rows.iter().map(|row| {
+            self.headers.iter().zip(row.iter()).map(|(h, v)| {
+                let h = if h.is_empty() { "Retired".to_string() } else { h.clone() };
+                (h, v.clone())
+            }).collect()
+        }).collect()
+    }
+}
+
+/// A row of the data dictionary, in the column order of dicom_dict.tsv.
+struct DictEntry {
+    tag: String,
+    vr: String,
+    vm: String,
+    name: String,
+    retired: String,
+    keyword: String,
+}
+
+fn field(rec: &HashMap<String, String>, key: &str) -> String {
+    rec.get(key).cloned().unwrap_or_default()
+}
+
+/// Cell text without the zero width spaces the standard uses as line break hints.
+fn clean(text: &str) -> String {
+    text.replace('\u{200b}', "").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_tables(path: &Path) -> Result<Vec<Table>, String> {
+    let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut tables = Vec::new();
+    let mut table: Option<Table> = None;
+    let mut row = Vec::new();
+    let mut cell: Option<String> = None;
+    let (mut in_caption, mut in_thead) = (false, false);
+    for event in EventReader::new(BufReader::new(file)) {
+        match event.map_err(|e| format!("{}: {}", path.display(), e))? {
+            XmlEvent::StartElement { name, attributes, .. } => match &name.local_name[..] {
+                "table" => {
+                    let label = attributes.iter().find(|a| a.name.local_name == "label").map(|a| a.value.clone());
+                    table = Some(Table { label: label.unwrap_or_default(), caption: String::new(),
+                                         headers: Vec::new(), rows: Vec::new() });
+                },
+                "caption" => in_caption = true,
+                "thead" => in_thead = true,
+                "tr" => row.clear(),
+                "th" | "td" => cell = Some(String::new()),
+                "para" => if let Some(ref mut cell) = cell { cell.push(' '); },
+                _ => (),
+            },
+            XmlEvent::Characters(text) | XmlEvent::CData(text) | XmlEvent::Whitespace(text) => {
+                if in_caption {
+                    if let Some(ref mut table) = table { table.caption.push_str(&text); }
+                } else if let Some(ref mut cell) = cell {
+                    cell.push_str(&text);
+                }
+            },
+            XmlEvent::EndElement { name } => match &name.local_name[..] {
+                "caption" => in_caption = false,
+                "thead" => in_thead = false,
+                "th" | "td" => if let Some(text) = cell.take() { row.push(clean(&text)); },
+                "tr" => if let Some(ref mut table) = table {
+                    if in_thead { table.headers = row.clone(); } else { table.rows.push(row.clone()); }
+                },
+                "table" => if let Some(mut t) = table.take() {
+                    t.caption = clean(&t.caption);
+                    tables.push(t);
+                },
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+    Ok(tables)
+}
+
+/// The table labelled `label`, checked against its caption so a renumbering between
+/// editions is an error rather than the wrong table.
+fn table<'a>(tables: &'a [Table], label: &str, caption: &str) -> Result<&'a Table, String> {
+    match tables.iter().find(|t| t.label == label) {
+        Some(t) if t.caption == caption => Ok(t),
+        Some(t) => Err(format!("table {} is captioned {:?}, expected {:?}", label, t.caption, caption)),
+        None => Err(format!("no table {} ({:?})", label, caption)),
+    }
+}
+
+/// Apply the same clean ups the Python generator did, so the output is stable across editions.
+fn normalize(mut e: DictEntry) -> DictEntry {
+    // e.g. (FFFE,E000)
+    if e.vr == "See Note" { e.vr = "NONE".to_string(); }
+    // e.g. (0018,1153)
+    e.name = e.name.replace('\u{b5}', "u").replace('\u{3bc}', "u");
+    // e.g. (0014,0023) and (0018,9445)
+    if e.retired == "RET" || e.retired == "RET - See Note" { e.retired = "Retired".to_string(); }
+    // e.g. (0008,0102), (0014,0025), (0040,A170)
+    if e.retired == "DICOS" || e.retired == "DICONDE" || e.retired == "See Note" { e.retired = String::new(); }
+    // e.g. (0028,1200)
+    e.vm = e.vm.replace(" or ", " ");
+    // e.g. (0018,9445) and (0028,0020)
+    if e.vr.is_empty() && e.vm.is_empty() {
+        e.vr = "OB".to_string();
+        e.vm = "1".to_string();
+        e.name = "Retired-blank".to_string();
+    }
+    // (gggg,eeee) -> ggggeeee, keeping the x digits of repeating groups
+    e.tag = e.tag.trim_matches(|c| c == '(' || c == ')').replace(",", "");
+    e
+}
+
+fn dictionary(part06: &[Table], part07: &[Table]) -> Result<Vec<DictEntry>, String> {
+    let mut entries = Vec::new();
+    for &(label, caption) in [("6-1", "Registry of DICOM Data Elements"),
+                              ("7-1", "Registry of DICOM File Meta Elements"),
+                              ("8-1", "Registry of DICOM Directory Structuring Elements")].iter() {
+        for rec in table(part06, label, caption)?.records() {
+            entries.push(DictEntry { tag: field(&rec, "Tag"), vr: field(&rec, "VR"), vm: field(&rec, "VM"),
+                                     name: field(&rec, "Name"), retired: field(&rec, "Retired"),
+                                     keyword: field(&rec, "Keyword") });
+        }
+    }
+    for &(label, caption, retired) in [("E.1-1", "Command Fields", ""),
+                                       ("E.2-1", "Retired Command Fields", "Retired")].iter() {
+        for rec in table(part07, label, caption)?.records() {
+            entries.push(DictEntry { tag: field(&rec, "Tag"), vr: field(&rec, "VR"), vm: field(&rec, "VM"),
+                                     name: field(&rec, "Message Field"), retired: retired.to_string(),
+                                     keyword: field(&rec, "Keyword") });
+        }
+    }
+    entries.sort_by(|a, b| a.tag.cmp(&b.tag));
+    let entries: Vec<DictEntry> = entries.into_iter().map(normalize).collect();
+    // repeating groups/elements go after the plain tags
+    let (mut main, masks): (Vec<DictEntry>, Vec<DictEntry>) = entries.into_iter().partition(|e| !e.tag.contains('x'));
+    main.extend(masks);
+    Ok(main)
+}
+
+/// (UID, Name, Keyword, Type, Part) from PS3.6 Annex A.
+fn uids(part06: &[Table]) -> Result<Vec<[String; 5]>, String> {
+    let mut uids = Vec::new();
+    for rec in table(part06, "A-1", "UID Values")?.records() {
+        uids.push([field(&rec, "UID Value"), field(&rec, "UID Name"), field(&rec, "UID Keyword"),
+                   field(&rec, "UID Type"), field(&rec, "Part")]);
+    }
+    for rec in table(part06, "A-2", "Well-known Frames of Reference")?.records() {
+        uids.push([field(&rec, "UID Value"), field(&rec, "UID Name"), field(&rec, "UID Keyword"),
+                   "Well-known frame of reference".to_string(), field(&rec, "Normative Reference")]);
+    }
+    Ok(uids)
+}
+
+fn write_tsv(path: &Path, header: &str, rows: &[Vec<&str>]) -> Result<(), String> {
+    let err = |e: ::std::io::Error| format!("{}: {}", path.display(), e);
+    let mut fp = BufWriter::new(File::create(path).map_err(&err)?);
+    writeln!(fp, "# {}", header).map_err(&err)?;
+    for row in rows {
+        writeln!(fp, "{}", row.join("\t")).map_err(&err)?;
+    }
+    Ok(())
+}
+
+fn sha256(path: &Path) -> Result<String, String> {
+    let mut data = Vec::new();
+    File::open(path).and_then(|mut f| f.read_to_end(&mut data)).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(Sha256::digest(&data).iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// File name -> hash, in the `sha256sum` format. A missing file has no entries.
+fn read_sums(path: &Path) -> Result<BTreeMap<String, String>, String> {
+    let mut sums = BTreeMap::new();
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Ok(sums),
+    };
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut fields = line.split_whitespace();
+        if let (Some(hash), Some(name)) = (fields.next(), fields.next()) {
+            sums.insert(name.to_string(), hash.to_string());
+        }
+    }
+    Ok(sums)
+}
+
+fn write_sums(path: &Path, sums: &BTreeMap<String, String>) -> Result<(), String> {
+    let err = |e: ::std::io::Error| format!("{}: {}", path.display(), e);
+    let mut fp = BufWriter::new(File::create(path).map_err(&err)?);
+    for (name, hash) in sums {
+        writeln!(fp, "{}  {}", hash, name).map_err(&err)?;
+    }
+    Ok(())
+}
+
+/// Check `path` against its pinned hash, or pin it if `pin` is set and it has none.
+fn verify(path: &Path, name: &str, sums: &mut BTreeMap<String, String>, pin: bool) -> Result<(), String> {
+    let hash = sha256(path)?;
+    match sums.get(name).cloned() {
+        Some(ref pinned) if *pinned == hash => Ok(()),
+        Some(pinned) => Err(format!("{}: sha256 {} does not match the pinned {}", path.display(), hash, pinned)),
+        None if pin => {
+            println!("pinned {} {}", name, hash);
+            sums.insert(name.to_string(), hash);
+            Ok(())
+        },
+        None => Err(format!("{}: no hash pinned in {}; review the file and run `dictgen fetch --pin`",
+                            path.display(), SUMS_FILENAME)),
+    }
+}
+
+/// Download the pinned edition's docbook sources, keeping only files that verify.
+fn fetch(root: &Path, pin: bool) -> Result<(), String> {
+    let base = env::var("DICTGEN_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+    let mut sums = read_sums(&root.join(SUMS_FILENAME))?;
+    for part in PARTS.iter() {
+        let name = format!("{}.xml", part);
+        let url = format!("{}/{}/source/docbook/{}/{}", base, EDITION, part, name);
+        let dest = root.join(STANDARD_DIR).join(&name);
+        let download = dest.with_extension("xml.download");
+        println!("fetching {}", url);
+        let status = Command::new("curl").arg("-fsSL").arg("-o").arg(&download).arg(&url).status()
+            .map_err(|e| format!("curl: {}", e))?;
+        if !status.success() {
+            let _ = fs::remove_file(&download);
+            return Err(format!("{}: download failed ({})", url, status));
+        }
+        if let Err(e) = verify(&download, &name, &mut sums, pin) {
+            let _ = fs::remove_file(&download);
+            return Err(e);
+        }
+        fs::rename(&download, &dest).map_err(|e| format!("{}: {}", dest.display(), e))?;
+    }
+    if pin { write_sums(&root.join(SUMS_FILENAME), &sums)?; }
+    Ok(())
+}
+
+fn run() -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let usage = "usage: dictgen fetch [--pin] | dictgen [part06.xml part07.xml]".to_string();
+    let (part06, part07) = match args.len() {
+        0 => {
+            let mut sums = read_sums(&root.join(SUMS_FILENAME))?;
+            let paths: Vec<PathBuf> = PARTS.iter().map(|part| root.join(STANDARD_DIR).join(format!("{}.xml", part))).collect();
+            for (part, path) in PARTS.iter().zip(paths.iter()) {
+                verify(path, &format!("{}.xml", part), &mut sums, false)?;
+            }
+            (paths[0].clone(), paths[1].clone())
+        },
+        _ if args[0] == "fetch" => return match args.len() {
+            1 => fetch(&root, false),
+            2 if args[1] == "--pin" => fetch(&root, true),
+            _ => Err(usage),
+        },
+        2 => (PathBuf::from(&args[0]), PathBuf::from(&args[1])),
+        _ => return Err(usage),
+    };
+    let part06 = parse_tables(&part06)?;
+    let part07 = parse_tables(&part07)?;
+
+    let entries = dictionary(&part06, &part07)?;
+    let rows: Vec<Vec<&str>> = entries.iter()
+        .map(|e| vec![&e.tag[..], &e.vr, &e.vm, &e.name, &e.retired, &e.keyword]).collect();
+    write_tsv(&root.join(DICT_FILENAME), "Tag\tVR\tVM\tName\tRetired\tKeyword", &rows)?;
+
+    let uids = uids(&part06)?;
+    let rows: Vec<Vec<&str>> = uids.iter().map(|u| u.iter().map(|s| &s[..]).collect()).collect();
+    write_tsv(&root.join(UID_FILENAME), "UID\tName\tKeyword\tType\tPart", &rows)?;
+
+    println!("Finished, wrote {} tags and {} UIDs", entries.len(), uids.len());
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        writeln!(::std::io::stderr(), "dictgen: {}", e).unwrap();
+        process::exit(1);
+    }
+}
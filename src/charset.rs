@@ -0,0 +1,168 @@
+// Decoding of text values according to Specific Character Set (0008,0005),
+// PS3.3 C.12.1.1.2 and PS3.5 section 6.1, including ISO 2022 code extensions.
+use encoding::{Encoding, EncodingRef, DecoderTrap};
+use encoding::all::{ISO_8859_1, ISO_8859_2, ISO_8859_3, ISO_8859_4, ISO_8859_5, ISO_8859_6, ISO_8859_7,
+                    ISO_8859_8, ISO_8859_15, WINDOWS_1254, WINDOWS_874, WINDOWS_31J, WINDOWS_949,
+                    ISO_2022_JP, GBK, GB18030, UTF_8};
+
+/// The code element invoked in G0 (the 7-bit half).
+#[derive(Clone, Copy, PartialEq)]
+enum G0 {
+    Ascii,
+    JisRoman,
+    Jis0208,
+    Jis0212,
+}
+
+/// The character set of a dataset or sequence item.
+#[derive(Clone)]
+pub struct CharacterSet {
+    iso2022: bool,
+    /// G0 and G1 at the start of each value and after each delimiter
+    initial: (G0, Option<EncodingRef>),
+}
+
+impl Default for CharacterSet {
+    fn default() -> CharacterSet {
+        CharacterSet { iso2022: false, initial: (G0::Ascii, None) }
+    }
+}
+
+/// Code elements for a defined term. Terms that only make sense after an escape
+/// (e.g. ISO 2022 IR 87) leave G1 unset.
+fn code_elements(term: &str) -> Option<(G0, Option<EncodingRef>)> {
+    let g1: EncodingRef = match term {
+        "" | "ISO_IR 6" | "ISO 2022 IR 6" => return Some((G0::Ascii, None)),
+        "ISO_IR 13" | "ISO 2022 IR 13" => return Some((G0::JisRoman, Some(WINDOWS_31J))),
+        "ISO 2022 IR 87" => return Some((G0::Jis0208, None)),
+        "ISO 2022 IR 159" => return Some((G0::Jis0212, None)),
+        "ISO_IR 100" | "ISO 2022 IR 100" => ISO_8859_1,
+        "ISO_IR 101" | "ISO 2022 IR 101" => ISO_8859_2,
+        "ISO_IR 109" | "ISO 2022 IR 109" => ISO_8859_3,
+        "ISO_IR 110" | "ISO 2022 IR 110" => ISO_8859_4,
+        "ISO_IR 144" | "ISO 2022 IR 144" => ISO_8859_5,
+        "ISO_IR 127" | "ISO 2022 IR 127" => ISO_8859_6,
+        "ISO_IR 126" | "ISO 2022 IR 126" => ISO_8859_7,
+        "ISO_IR 138" | "ISO 2022 IR 138" => ISO_8859_8,
+        "ISO_IR 148" | "ISO 2022 IR 148" => WINDOWS_1254,
+        "ISO_IR 203" | "ISO 2022 IR 203" => ISO_8859_15,
+        "ISO_IR 166" | "ISO 2022 IR 166" => WINDOWS_874,
+        "ISO 2022 IR 149" => WINDOWS_949,
+        "ISO 2022 IR 58" => GBK,
+        "ISO_IR 192" => UTF_8,
+        "GB18030" => GB18030,
+        "GBK" => GBK,
+        _ => return None,
+    };
+    Some((G0::Ascii, Some(g1)))
+}
+
+#[derive(Clone, Copy)]
+enum Designation {
+    G0(G0),
+    G1(EncodingRef),
+}
+
+/// The escape sequence at the start of `seq` (after the ESC), and its length.
+fn escape(seq: &[u8]) -> Option<(usize, Designation)> {
+    let table: [(&[u8], Designation); 18] = [
+        (b"(B", Designation::G0(G0::Ascii)),
+        (b"(J", Designation::G0(G0::JisRoman)),
+        (b"$B", Designation::G0(G0::Jis0208)),
+        (b"$(D", Designation::G0(G0::Jis0212)),
+        (b")I", Designation::G1(WINDOWS_31J)),
+        (b"$)C", Designation::G1(WINDOWS_949)),
+        (b"$)A", Designation::G1(GBK)),
+        (b"-A", Designation::G1(ISO_8859_1)),
+        (b"-B", Designation::G1(ISO_8859_2)),
+        (b"-C", Designation::G1(ISO_8859_3)),
+        (b"-D", Designation::G1(ISO_8859_4)),
+        (b"-L", Designation::G1(ISO_8859_5)),
+        (b"-G", Designation::G1(ISO_8859_6)),
+        (b"-F", Designation::G1(ISO_8859_7)),
+        (b"-H", Designation::G1(ISO_8859_8)),
+        (b"-M", Designation::G1(WINDOWS_1254)),
+        (b"-T", Designation::G1(WINDOWS_874)),
+        (b"-b", Designation::G1(ISO_8859_15)),
+    ];
+    table.iter().find(|&&(esc, _)| seq.starts_with(esc)).map(|&(esc, d)| (esc.len(), d))
+}
+
+/// Text without a character set: UTF-8 if it is valid, Latin-1 otherwise.
+fn decode_default(bytes: &[u8]) -> String {
+    match String::from_utf8(bytes.to_vec()) {
+        Ok(s) => s,
+        Err(_) => ISO_8859_1.decode(bytes, DecoderTrap::Replace).unwrap_or_default(),
+    }
+}
+
+fn decode_run(run: &[u8], g0: G0, g1: Option<EncodingRef>, out: &mut String) {
+    if run.is_empty() { return; }
+    let decoded = match g0 {
+        G0::Jis0208 | G0::Jis0212 => {
+            let mut seq = if g0 == G0::Jis0208 { b"\x1b$B".to_vec() } else { b"\x1b$(D".to_vec() };
+            seq.extend_from_slice(run);
+            ISO_2022_JP.decode(&seq, DecoderTrap::Replace)
+        },
+        G0::Ascii | G0::JisRoman => match g1 {
+            Some(g1) => g1.decode(run, DecoderTrap::Replace),
+            None => Ok(decode_default(run)),
+        },
+    };
+    out.push_str(&decoded.unwrap_or_default());
+}
+
+impl CharacterSet {
+    /// From the value of Specific Character Set, e.g. "ISO_IR 100" or "\ISO 2022 IR 87".
+    /// Unknown terms fall back to the default repertoire.
+    pub fn new(value: &str) -> CharacterSet {
+        let terms: Vec<&str> = value.split('\\').map(|t| t.trim_matches(|c| c == ' ' || c == '\0')).collect();
+        CharacterSet {
+            iso2022: terms.iter().any(|t| t.starts_with("ISO 2022")),
+            initial: code_elements(terms[0]).unwrap_or((G0::Ascii, None)),
+        }
+    }
+
+    /// Decode a text value. `pn` makes '^' and '=' reset the code extensions too, as
+    /// they delimit Person Name components.
+    pub fn decode(&self, bytes: &[u8], pn: bool) -> String {
+        let (mut g0, mut g1) = self.initial;
+        if !self.iso2022 {
+            let mut out = String::new();
+            decode_run(bytes, g0, g1, &mut out);
+            return out;
+        }
+        let mut out = String::new();
+        let (mut run, mut i) = (0, 0);
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b == 0x1b {
+                decode_run(&bytes[run..i], g0, g1, &mut out);
+                i += 1;
+                match escape(&bytes[i..]) {
+                    Some((len, Designation::G0(set))) => { g0 = set; i += len; },
+                    Some((len, Designation::G1(set))) => { g1 = Some(set); i += len; },
+                    None => (),
+                }
+                run = i;
+                continue;
+            }
+            let single_byte = g0 == G0::Ascii || g0 == G0::JisRoman;
+            let delimiter = match b {
+                b'\\' | b'\r' | b'\n' | b'\t' | 0x0c => true,
+                b'^' | b'=' => pn,
+                _ => false,
+            };
+            if single_byte && delimiter {
+                decode_run(&bytes[run..i], g0, g1, &mut out);
+                out.push(b as char);
+                g0 = self.initial.0;
+                g1 = self.initial.1;
+                run = i + 1;
+            }
+            i += 1;
+        }
+        decode_run(&bytes[run..], g0, g1, &mut out);
+        out
+    }
+}
@@ -0,0 +1,108 @@
+// Color pixel data, PS3.3 C.7.6.3.1.2 and C.7.6.3.1.3, and its conversion to RGB.
+use dicom_types::{DcmImg, DcmImg8};
+
+/// The color models of PhotometricInterpretation that can be converted to RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorSpace {
+    Rgb,
+    YbrFull,
+    /// YBR_FULL with Cb and Cr shared by each horizontal pair of pixels: Y1 Y2 Cb Cr
+    YbrFull422,
+    /// Partial range YBR, as decoded from MPEG: a Y plane, then Cb and Cr planes of half
+    /// the width and height
+    YbrPartial420,
+}
+
+impl ColorSpace {
+    pub fn from_photometric(photometric: &str) -> Option<ColorSpace> {
+        match photometric.trim_matches(|c| c == ' ' || c == '\0') {
+            "RGB" => Some(ColorSpace::Rgb),
+            "YBR_FULL" => Some(ColorSpace::YbrFull),
+            "YBR_FULL_422" => Some(ColorSpace::YbrFull422),
+            "YBR_PARTIAL_420" => Some(ColorSpace::YbrPartial420),
+            _ => None,
+        }
+    }
+
+    /// Samples in an 8 bit frame of `xr` by `yr` pixels.
+    pub fn frame_len(&self, xr: usize, yr: usize) -> usize {
+        let (cw, ch) = ((xr + 1) / 2, (yr + 1) / 2);
+        match *self {
+            ColorSpace::Rgb | ColorSpace::YbrFull => xr * yr * 3,
+            ColorSpace::YbrFull422 => cw * 4 * yr,
+            ColorSpace::YbrPartial420 => xr * yr + 2 * cw * ch,
+        }
+    }
+}
+
+/// An 8 bit color image as stored in PixelData.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DcmColorImg {
+    pub xr : usize,
+    pub yr : usize,
+    pub zr : usize,
+    pub color : ColorSpace,
+    /// PlanarConfiguration 1: each frame is all the first samples, then the second, then the
+    /// third. Only RGB and YBR_FULL can be planar.
+    pub planar : bool,
+    pub data : Vec<u8>,
+}
+
+fn clamp(v: f64) -> u8 {
+    v.round().max(0.0).min(255.0) as u8
+}
+
+fn ybr_full(y: u8, cb: u8, cr: u8) -> [u8; 3] {
+    let (y, cb, cr) = (y as f64, cb as f64 - 128.0, cr as f64 - 128.0);
+    [clamp(y + 1.402 * cr), clamp(y - 0.344136 * cb - 0.714136 * cr), clamp(y + 1.772 * cb)]
+}
+
+fn ybr_partial(y: u8, cb: u8, cr: u8) -> [u8; 3] {
+    let (y, cb, cr) = (1.1644 * (y as f64 - 16.0), cb as f64 - 128.0, cr as f64 - 128.0);
+    [clamp(y + 1.596 * cr), clamp(y - 0.3918 * cb - 0.8130 * cr), clamp(y + 2.0172 * cb)]
+}
+
+impl DcmColorImg {
+    /// Bytes per frame.
+    pub fn frame_len(&self) -> usize {
+        self.color.frame_len(self.xr, self.yr)
+    }
+
+    /// The three samples of pixel (x, y) of a frame.
+    fn samples(&self, frame: &[u8], x: usize, y: usize) -> (u8, u8, u8) {
+        let (pixels, i) = (self.xr * self.yr, y * self.xr + x);
+        let (cw, ch) = ((self.xr + 1) / 2, (self.yr + 1) / 2);
+        match self.color {
+            ColorSpace::Rgb | ColorSpace::YbrFull if self.planar => (frame[i], frame[pixels + i], frame[2 * pixels + i]),
+            ColorSpace::Rgb | ColorSpace::YbrFull => (frame[3 * i], frame[3 * i + 1], frame[3 * i + 2]),
+            ColorSpace::YbrFull422 => {
+                let pair = y * cw * 4 + (x / 2) * 4;
+                (frame[pair + x % 2], frame[pair + 2], frame[pair + 3])
+            },
+            ColorSpace::YbrPartial420 => {
+                let c = (y / 2) * cw + x / 2;
+                (frame[i], frame[pixels + c], frame[pixels + cw * ch + c])
+            },
+        }
+    }
+
+    /// Every frame as interleaved RGB, or `None` if `data` is too short for the dimensions.
+    pub fn to_rgb(&self) -> Option<DcmImg8> {
+        let frame_len = self.frame_len();
+        if frame_len == 0 || self.data.len() < frame_len * self.zr { return None; }
+        let mut rgb = Vec::with_capacity(self.xr * self.yr * self.zr * 3);
+        for frame in self.data.chunks(frame_len).take(self.zr) {
+            for y in 0..self.yr {
+                for x in 0..self.xr {
+                    let (a, b, c) = self.samples(frame, x, y);
+                    rgb.extend_from_slice(&match self.color {
+                        ColorSpace::Rgb => [a, b, c],
+                        ColorSpace::YbrFull | ColorSpace::YbrFull422 => ybr_full(a, b, c),
+                        ColorSpace::YbrPartial420 => ybr_partial(a, b, c),
+                    });
+                }
+            }
+        }
+        Some(DcmImg { xr: self.xr, yr: self.yr, zr: self.zr, samples: 3, data: rgb })
+    }
+}
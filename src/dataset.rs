@@ -1,20 +1,27 @@
 
-use std::io::Result;
 use byteorder::{ReadBytesExt, BigEndian, LittleEndian};
+use flate2::read::DeflateDecoder;
 use std::io::Cursor;
-use std::collections::HashMap;
+use std::io::prelude::*;
 use std::str;
 
-use dicom_types::{DicomDict, DicomSlice, DicomGeltEltDict, DicomElt, DicomKwEltDict, DcmImg16, DcmImg8};
+use dicom_dict::lookup_tag;
+use tags;
+use dicom_types::{PrivateDict, DicomSlice, Dataset, DicomDataElt, DicomElt, DicomTag, DcmFragments, TransferSyntax};
+use charset::CharacterSet;
+use color::ColorSpace;
+use pixels::{PixelFormat, DcmPixels};
+use error::{DicomError, Result};
 
+#[derive(Clone, Copy)]
 enum Endian {
     Big,
     Little
 }
 
-const EXTRA_LENGTH_VRS:[&'static str; 6] = ["OB", "OW", "OF", "SQ", "UN", "UT"];
-const VR_NAMES:[&'static str; 27] = [ "AE","AS","AT","CS","DA","DS","DT","FL","FD","IS","LO","LT","OB","OF",
-       "OW","PN","SH","SL","SQ","SS","ST","TM","UI","UL","UN","US","UT" ];
+const EXTRA_LENGTH_VRS:[&'static str; 13] = ["OB", "OD", "OF", "OL", "OV", "OW", "SQ", "SV", "UC", "UN", "UR", "UT", "UV"];
+const VR_NAMES:[&'static str; 34] = [ "AE","AS","AT","CS","DA","DS","DT","FL","FD","IS","LO","LT","OB","OD",
+       "OF","OL","OV","OW","PN","SH","SL","SQ","SS","ST","SV","TM","UC","UI","UL","UN","UR","US","UT","UV" ];
 
 fn u8tou16(bytes: &[u8]) -> u16 { (bytes[1]  as u16) << 8 | bytes[0] as u16 }
 
@@ -23,9 +30,35 @@ fn u8tou32(bytes: &[u8]) -> u32 {
     (bytes[1] as u32) << 8 | bytes[0] as u32
 }
 
+fn get_u16(bytes: &[u8], order: Endian) -> u16 {
+    match order {
+        Endian::Little => u8tou16(bytes),
+        Endian::Big => (bytes[0] as u16) << 8 | bytes[1] as u16,
+    }
+}
+
+fn get_u32(bytes: &[u8], order: Endian) -> u32 {
+    match order {
+        Endian::Little => u8tou32(bytes),
+        Endian::Big => (bytes[0] as u32) << 24 | (bytes[1] as u32) << 16 |
+                       (bytes[2] as u32) << 8 | bytes[3] as u32,
+    }
+}
+
 fn u16tou32(bytes: &[u16]) -> u32 { (bytes[1]  as u32) << 16 | bytes[0] as u32 }
 
-fn u8tostr(bytes: &[u8]) -> &str { str::from_utf8(bytes).unwrap() }
+fn u8tostr(bytes: &[u8], tag: u32, off: usize) -> Result<&str> {
+    str::from_utf8(bytes).map_err(|e| DicomError::BadValue { tag: tag, offset: off, reason: e.to_string() })
+}
+
+/// Bounds-checked `&data[off..off+n]`; `tag` is the element being read, for the diagnostic.
+fn bytes(data: &[u8], off: usize, n: usize, tag: u32) -> Result<&[u8]> {
+    if off <= data.len() && n <= data.len() - off {
+        Ok(&data[off..off+n])
+    } else {
+        Err(DicomError::Truncated { tag: tag, offset: off, needed: n })
+    }
+}
 
 fn isodd(x : usize) -> bool { x % 2 == 1 }
 
@@ -33,303 +66,489 @@ fn always_implicit(grp: u16, elt: u16) -> bool {
     grp == 0xFFFE && (elt == 0xE0DD || elt == 0xE000 || elt == 0xE00D)
 }
 
-fn pixeldata_parse<'a>(data: &[u8], sz: usize, vr: &str, elementsopt: Option<&DicomGeltEltDict>) -> (DicomElt, usize) {
-    let (xr, wsize) = if vr == "OB" {(sz, 1)} else { (sz/2, 2) };
-
-    let (xr, yr, zr) = match elementsopt {
-        Some(elements) => {
-            let (xa, ya, za) = (0x00280010, 0x00280011, 0x00280012);
-            let xr = match elements.get(&xa) {
-                Some(&DicomElt::UInt16s(ref val)) => val[0] as usize,
-                Some(_) | None => xr,
-            };
-            let yr = match elements.get(&ya) {
-                Some(&DicomElt::UInt16s(ref val)) => val[0] as usize,
-                Some(_) | None => 1 as usize,
-            };
-            let zr = match elements.get(&za) {
-                Some(&DicomElt::UInt16s(ref val)) => val[0] as usize,
-                Some(_) | None => 1 as usize,
-            };
-            (xr, yr, zr)
-        },
-        None => (xr, 1 as usize, 1 as usize),
+/// A US or IS attribute of the Image Pixel module, e.g. Rows or NumberOfFrames.
+fn dimension(elements: Option<&Dataset>, tag: DicomTag) -> Option<usize> {
+    match elements.and_then(|elements| elements.get(tag)).map(|e| &e.value) {
+        Some(&DicomElt::UInt16s(ref v)) if !v.is_empty() => Some(v[0] as usize),
+        Some(&DicomElt::Int32s(ref v)) if !v.is_empty() && v[0] > 0 => Some(v[0] as usize),
+        _ => None,
+    }
+}
+
+/// The sample layout of native PixelData. Missing attributes default to whole samples of
+/// the VR's width; unusable ones are only reported when the pixels are decoded.
+fn pixel_format(elements: Option<&Dataset>, vr: &str) -> PixelFormat {
+    let allocated = dimension(elements, tags::BITS_ALLOCATED).unwrap_or(if vr == "OB" {8} else {16});
+    let stored = dimension(elements, tags::BITS_STORED).unwrap_or(allocated);
+    PixelFormat { allocated: allocated, stored: stored,
+                  high_bit: dimension(elements, tags::HIGH_BIT).unwrap_or(stored.saturating_sub(1)),
+                  signed: dimension(elements, tags::PIXEL_REPRESENTATION) == Some(1) }
+}
+
+fn pixeldata_parse<'a>(data: &[u8], start: usize, sz: usize, vr: &str, order: Endian,
+                       elementsopt: Option<&Dataset>) -> Result<(DicomElt, usize)> {
+    let format = pixel_format(elementsopt, vr);
+    let len = if sz == 0xffffffff { sz } else { sz * 8 / format.allocated.max(1) };
+    let zr = dimension(elementsopt, tags::NUMBER_OF_FRAMES).unwrap_or(1);
+    let samples = dimension(elementsopt, tags::SAMPLES_PER_PIXEL).unwrap_or(1);
+    // without Rows and Columns, each frame is one row
+    let (columns, rows) = (dimension(elementsopt, tags::COLUMNS), dimension(elementsopt, tags::ROWS));
+    let dims = match (columns, rows) {
+        (Some(xr), Some(yr)) => (xr, yr, zr, samples),
+        // a zero is kept for decoding to report
+        (Some(0), _) | (_, Some(0)) => (columns.unwrap_or(1), rows.unwrap_or(1), zr, samples),
+        _ => (len / (zr * samples).max(1), 1, zr, samples),
     };
     let (result, newoff) = if sz != 0xffffffff {
-        let dp : &[u8]= &data[0..sz];
-        let v = match wsize {
-            2 => {
-                let mut r = Cursor::new(dp);
-                let mut resvec16 : Vec<i16> = Vec::new();
-                for _ in 0..(sz/2) { resvec16.push(r.read_i16::<LittleEndian>().unwrap()); }
-                DicomElt::Image16( DcmImg16 { xr : xr, yr : yr, zr : zr, data : resvec16 } )
-            },
-            1 => {
-                DicomElt::Image8( DcmImg8 { xr : xr, yr : yr, zr : zr, data : dp.to_owned() } )
-            },
-            _ => panic!("bad wsize"),
-
+        let dp : &[u8]= bytes(data, start, sz, 0x7FE00010)?;
+        let (xr, yr, zr, samples) = dims;
+        let photometric = match elementsopt.and_then(|elements| elements.get(tags::PHOTOMETRIC_INTERPRETATION)) {
+            Some(&DicomDataElt { value: DicomElt::String(ref s), .. }) => ColorSpace::from_photometric(s),
+            _ => None,
+        };
+        // subsampled YBR has fewer chroma samples than pixels
+        let frame_values = match photometric {
+            Some(color) if samples == 3 => color.frame_len(xr, yr),
+            _ => xr * yr * samples,
         };
-        (v, sz)
+        (DicomElt::Pixels(DcmPixels { xr: xr, yr: yr, zr: zr, samples: samples, frame_values: frame_values,
+                                      format: format, big_endian: match order { Endian::Big => true, Endian::Little => false },
+                                      offset: start, data: dp.to_vec() }), sz)
     } else {
-        let mut off = 0;
-        let mut resvec8 = Vec::new();
-        let mut resvec16 = Vec::new();
+        // encapsulated: the Basic Offset Table item, then the compressed fragments
+        let mut off = start;
+        let mut offsets = None;
+        let mut fragments = Vec::new();
         loop {
-            let (grp, elt) = (u8tou16(&data[off..off+2]), u8tou16(&data[off+2..off+4]));
-            let xr = u8tou32(&data[off+4..off+8]) as usize;
+            let hdr = bytes(data, off, 8, 0x7FE00010)?;
+            let (grp, elt) = (get_u16(&hdr[0..2], order), get_u16(&hdr[2..4], order));
+            let fraglen = get_u32(&hdr[4..8], order) as usize;
             off += 8;
             if grp == 0xFFFE && elt == 0xE0DD { break; }
-            if grp != 0xFFFE || elt != 0xE000 { panic!("dicom: expected item tag in encapsulated pixel data"); }
-            let dp = &data[off..off+xr];
-            match wsize {
-                2 =>  {
-                    let mut r = Cursor::new(dp);
-                    for _ in 0..(xr/2) {
-                        resvec16.push(r.read_i16::<LittleEndian>().unwrap())
-                    };
-                }
-                1 => resvec8.extend_from_slice(dp),
-                _ => panic!("bad wsize"),
-            };
-            off += xr;
+            if grp != 0xFFFE || elt != 0xE000 {
+                return Err(DicomError::ExpectedItem { tag: 0x7FE00010, offset: off - 8,
+                                                      found: u16tou32(&[elt, grp]) });
+            }
+            let fragment = bytes(data, off, fraglen, 0x7FE00010)?;
+            if offsets.is_none() {
+                offsets = Some(fragment.chunks(4).filter(|c| c.len() == 4).map(|c| get_u32(c, order)).collect());
+            } else {
+                fragments.push(fragment.to_vec());
+            }
+            off += fraglen;
         };
-        match wsize {
-            2 => (DicomElt::Image16( DcmImg16 { xr : xr, yr : yr, zr : zr, data : resvec16 } ), off),
-            1 => (DicomElt::Image8( DcmImg8 { xr : xr, yr : yr, zr : zr, data : resvec8 } ), off) ,
-            _ => panic!("bad wsize"),
-        }
+        let (xr, yr, zr, samples) = dims;
+        (DicomElt::Fragments( DcmFragments { xr : xr, yr : yr, zr : zr, samples : samples,
+                                             offsets : offsets.unwrap_or_default(), fragments : fragments } ), off - start)
     };
-    (result, newoff)
+    Ok((result, newoff))
 }
 
-fn sequence_item<'a>(dict: &DicomDict<'a>, bytes : &[u8], off : &mut usize, evr: bool, sz : usize, items : &mut Vec<DicomElt>) {
-
-    while *off < sz {
-        let (gelt, elt) = element(dict, bytes, off, evr, None);
-        if gelt == (0xFFFE, 0xE00D) {break}
-        items.push(elt);
+fn sequence_item<'a>(private: &PrivateDict<'a>, cs: &CharacterSet, data : &[u8], off : &mut usize, evr: bool, order: Endian, end : usize)
+                     -> Result<Dataset> {
+    let mut item = Dataset::new();
+    let mut cs = cs.clone();
+    while *off < end {
+        let eltoff = *off;
+        let elt = element(private, &cs, data, off, evr, order, Some(&item))?;
+        if elt.tag == 0xFFFEE00D {break}
+        update_charset(&elt, &mut cs);
+        insert_element(&mut item, elt, eltoff)?;
     }
+    Ok(item)
 }
 
-fn undefined_length(data : &[u8]) -> (usize, Vec<u16>) {
-    let mut v = Vec::new();
-    let (mut w1, mut w2, mut off);
-    off = 0;
-    w2 = 0;
-    loop {
-        w1 = w2;
-        w2 = u8tou16(&data[off..off+2]);
-        off += 2;
-        if w1 == 0xFFFE {
-            if w2 == 0xE0DD { break }
-            v.push(w1);
-        }
-        if w2 != 0xFFFE { v.push(w2); }
-    }
-    off += 4;
-    (off, v)
-}
-
-fn sequence_parse<'a>(dict: &DicomDict<'a>, data : &[u8], evr: bool) -> (usize, DicomElt) {
+fn sequence_parse<'a>(private: &PrivateDict<'a>, cs: &CharacterSet, data : &[u8], start: usize, end: usize, tag: u32, evr: bool, order: Endian)
+                      -> Result<(usize, DicomElt)> {
     let mut sq  = Vec::new();
-    let mut off = 0;
-    let len = data.len();
-    while off < len {
-        let (grp, elt) = (u8tou16(&data[off..off+2]), u8tou16(&data[off+2..off+4]));
-        let itemlen = u8tou32(&data[off+4..off+8]) as usize;
+    let mut off = start;
+    while off < end {
+        let hdr = bytes(data, off, 8, tag)?;
+        let (grp, elt) = (get_u16(&hdr[0..2], order), get_u16(&hdr[2..4], order));
+        let itemlen = get_u32(&hdr[4..8], order) as usize;
         off += 8;
         if grp == 0xFFFE && elt == 0xE0DD { break }
-        if grp != 0xFFFE && elt != 0xE000 { panic!("dicom: expected item tag in sequence") }
-        sequence_item(dict, data, &mut off, evr, itemlen, &mut sq);
+        if grp != 0xFFFE || elt != 0xE000 {
+            return Err(DicomError::ExpectedItem { tag: tag, offset: off - 8, found: u16tou32(&[elt, grp]) });
+        }
+        let itemend = if itemlen == 0xffffffff { end } else { off + itemlen };
+        sq.push(sequence_item(private, cs, data, &mut off, evr, order, itemend)?);
     }
-    (off, DicomElt::Seq(sq))
+    Ok((off - start, DicomElt::Seq(sq)))
 }
 
-fn numeric_parse_little<'a>(mut c : Cursor<&[u8]>, elt : DicomElt, count : usize) -> DicomElt {
-    let (mut i32s, mut u32s, mut u16s, mut i16s, mut f32s, mut f64s);
+fn numeric_parse_little<'a>(mut c : Cursor<&[u8]>, elt : DicomElt, count : usize) -> Result<DicomElt> {
+    let (mut i64s, mut u64s, mut i32s, mut u32s, mut u16s, mut i16s, mut f32s, mut f64s);
 
     match elt {
         DicomElt::UInt16s(_) => {
             u16s = Vec::new();
-            for _ in 0..count {u16s.push(c.read_u16::<LittleEndian>().unwrap())}
-            DicomElt::UInt16s(u16s)
+            for _ in 0..count {u16s.push(c.read_u16::<LittleEndian>()?)}
+            Ok(DicomElt::UInt16s(u16s))
         },
         DicomElt::Int16s(_) => {
             i16s = Vec::new();
-            for _ in 0..count {i16s.push(c.read_i16::<LittleEndian>().unwrap())}
-            DicomElt::Int16s(i16s)
+            for _ in 0..count {i16s.push(c.read_i16::<LittleEndian>()?)}
+            Ok(DicomElt::Int16s(i16s))
         },
         DicomElt::UInt32s(_) => {
             u32s = Vec::new();
-            for _ in 0..count {u32s.push(c.read_u32::<LittleEndian>().unwrap())}
-            DicomElt::UInt32s(u32s)
+            for _ in 0..count {u32s.push(c.read_u32::<LittleEndian>()?)}
+            Ok(DicomElt::UInt32s(u32s))
         },
         DicomElt::Int32s(_) => {
             i32s = Vec::new();
-            for _ in 0..count {i32s.push(c.read_i32::<LittleEndian>().unwrap())}
-            DicomElt::Int32s(i32s)
+            for _ in 0..count {i32s.push(c.read_i32::<LittleEndian>()?)}
+            Ok(DicomElt::Int32s(i32s))
         },
         DicomElt::Float32s(_) => {
             f32s = Vec::new();
-            for _ in 0..count {f32s.push(c.read_f32::<LittleEndian>().unwrap())}
-            DicomElt::Float32s(f32s)
+            for _ in 0..count {f32s.push(c.read_f32::<LittleEndian>()?)}
+            Ok(DicomElt::Float32s(f32s))
         },
         DicomElt::Float64s(_) => {
             f64s = Vec::new();
-            for _ in 0..count {f64s.push(c.read_f64::<LittleEndian>().unwrap())}
-            DicomElt::Float64s(f64s)
+            for _ in 0..count {f64s.push(c.read_f64::<LittleEndian>()?)}
+            Ok(DicomElt::Float64s(f64s))
+        },
+        DicomElt::Int64s(_) => {
+            i64s = Vec::new();
+            for _ in 0..count {i64s.push(c.read_i64::<LittleEndian>()?)}
+            Ok(DicomElt::Int64s(i64s))
+        },
+        DicomElt::UInt64s(_) => {
+            u64s = Vec::new();
+            for _ in 0..count {u64s.push(c.read_u64::<LittleEndian>()?)}
+            Ok(DicomElt::UInt64s(u64s))
         },
-        _ => panic!("bad juju"),
+        _ => Err(DicomError::UnexpectedType(format!("{:?}", elt))),
     }
 }
-fn numeric_parse_big<'a>(mut c : Cursor<&[u8]>, elt : DicomElt, count : usize) -> DicomElt {
-    let mut uv = Vec::new();
-    let mut f32v = Vec::new();
-    let mut f64v = Vec::new();
-    for _ in 0..count {
-        match elt {
-            DicomElt::UInt16s(_) => uv.push(c.read_u16::<BigEndian>().unwrap()),
-            DicomElt::Float32s(_) => f32v.push(c.read_f32::<BigEndian>().unwrap()),
-            DicomElt::Float64s(_) => f64v.push(c.read_f64::<BigEndian>().unwrap()),
-            _ => panic!("bad type: {:?}", elt)
-        }
-    }
+fn numeric_parse_big<'a>(mut c : Cursor<&[u8]>, elt : DicomElt, count : usize) -> Result<DicomElt> {
+    let (mut i64s, mut u64s, mut i32s, mut u32s, mut u16s, mut i16s, mut f32s, mut f64s);
+
     match elt {
-        DicomElt::UInt16s(_) => DicomElt::UInt16s(uv),
-        DicomElt::Float32s(_) => DicomElt::Float32s(f32v),
-        DicomElt::Float64s(_) => DicomElt::Float64s(f64v),
-        _ => panic!("bad type: {:?}", elt)
+        DicomElt::UInt16s(_) => {
+            u16s = Vec::new();
+            for _ in 0..count {u16s.push(c.read_u16::<BigEndian>()?)}
+            Ok(DicomElt::UInt16s(u16s))
+        },
+        DicomElt::Int16s(_) => {
+            i16s = Vec::new();
+            for _ in 0..count {i16s.push(c.read_i16::<BigEndian>()?)}
+            Ok(DicomElt::Int16s(i16s))
+        },
+        DicomElt::UInt32s(_) => {
+            u32s = Vec::new();
+            for _ in 0..count {u32s.push(c.read_u32::<BigEndian>()?)}
+            Ok(DicomElt::UInt32s(u32s))
+        },
+        DicomElt::Int32s(_) => {
+            i32s = Vec::new();
+            for _ in 0..count {i32s.push(c.read_i32::<BigEndian>()?)}
+            Ok(DicomElt::Int32s(i32s))
+        },
+        DicomElt::Float32s(_) => {
+            f32s = Vec::new();
+            for _ in 0..count {f32s.push(c.read_f32::<BigEndian>()?)}
+            Ok(DicomElt::Float32s(f32s))
+        },
+        DicomElt::Float64s(_) => {
+            f64s = Vec::new();
+            for _ in 0..count {f64s.push(c.read_f64::<BigEndian>()?)}
+            Ok(DicomElt::Float64s(f64s))
+        },
+        DicomElt::Int64s(_) => {
+            i64s = Vec::new();
+            for _ in 0..count {i64s.push(c.read_i64::<BigEndian>()?)}
+            Ok(DicomElt::Int64s(i64s))
+        },
+        DicomElt::UInt64s(_) => {
+            u64s = Vec::new();
+            for _ in 0..count {u64s.push(c.read_u64::<BigEndian>()?)}
+            Ok(DicomElt::UInt64s(u64s))
+        },
+        _ => Err(DicomError::UnexpectedType(format!("{:?}", elt))),
     }
 }
 
-fn string_parse(data: &[u8]) -> DicomElt {
-    let dsstr = u8tostr(data);
-    let vstr : Vec<&str> = dsstr.split('\\').collect();
+/// The components of an IS or DS value, without padding. Empty components are skipped.
+fn number_strs<'a>(data: &'a [u8], tag: u32, off: usize) -> Result<Vec<&'a str>> {
+    Ok(u8tostr(data, tag, off)?.split('\\')
+        .map(|s| s.trim_matches(|c: char| c.is_whitespace() || c == '\0'))
+        .filter(|s| !s.is_empty()).collect())
+}
+
+fn ds_parse(data: &[u8], tag: u32, off: usize) -> Result<DicomElt> {
     let mut v : Vec<f64> = Vec::new();
-    for &s in &vstr {
-        v.push(s.trim().parse().unwrap());
+    for s in number_strs(data, tag, off)? {
+        match s.parse() {
+            Ok(val) => v.push(val),
+            Err(e) => return Err(DicomError::BadValue { tag: tag, offset: off, reason: format!("{:?}: {}", s, e) }),
+        }
+    };
+    Ok(if v.is_empty() { DicomElt::Empty } else { DicomElt::Float64s(v) })
+}
+
+/// IS is a 32 bit integer; integral values written with a decimal point, e.g. "2.0", are accepted too.
+fn is_parse(data: &[u8], tag: u32, off: usize) -> Result<DicomElt> {
+    let mut v : Vec<i32> = Vec::new();
+    for s in number_strs(data, tag, off)? {
+        let val = s.parse::<i32>().ok().or_else(|| match s.parse::<f64>() {
+            Ok(f) if f.fract() == 0.0 && f >= i32::MIN as f64 && f <= i32::MAX as f64 => Some(f as i32),
+            _ => None,
+        });
+        match val {
+            Some(val) => v.push(val),
+            None => return Err(DicomError::BadValue { tag: tag, offset: off, reason: format!("{:?} is not an integer string", s) }),
+        }
     };
-    DicomElt::Float64s(v)
+    Ok(if v.is_empty() { DicomElt::Empty } else { DicomElt::Int32s(v) })
 }
 
-fn numeric_parse(c : Cursor<&[u8]>, elt : DicomElt, count : usize, order: Endian) -> DicomElt {
+fn numeric_parse(c : Cursor<&[u8]>, elt : DicomElt, count : usize, order: Endian) -> Result<DicomElt> {
     match order {
         Endian::Big => numeric_parse_big(c, elt, count),
         Endian::Little => numeric_parse_little(c, elt, count),
     }
 }
 
-fn lookup_vr<'a>(dict: &DicomDict<'a>, gelt: (u16, u16)) -> Option<&'a str> {
-    let key = if gelt.0 & 0xff00 == 0x5000 {
-        [0x5000, gelt.1]
-    } else if gelt.0 & 0xff00 == 0x6000 {
-        [0x6000, gelt.1]
-    } else {
-        [gelt.0, gelt.1]
+/// VR of a public element in an implicit VR dataset. Where the dictionary allows
+/// more than one VR, pick the one PS3.5 Annex A prescribes for implicit VR.
+fn lookup_vr(tag: u32, elements: Option<&Dataset>) -> Option<&'static str> {
+    let vr = match lookup_tag(tag) {
+        Some(elt) => elt.vr,
+        None => return None,
     };
-    let vr_key = u16tou32(&key);
-    let result = dict.get(&vr_key);
-    match result {
-        Some(elt) => Some(elt.vr),
+    let signed = match elements.and_then(|elements| elements.get(0x00280103)).map(|elt| &elt.value) {
+        Some(&DicomElt::UInt16s(ref v)) => v.first() == Some(&1),
+        _ => false,
+    };
+    Some(match vr {
+        "OB or OW" => "OW",
+        "US or OW" => "US",
+        "US or SS" | "US or SS or OW" => if signed { "SS" } else { "US" },
+        vr => vr,
+    })
+}
+
+/// VR of a private element in an implicit VR dataset, where only the creator
+/// elements and anything in the private dictionary can be resolved.
+fn lookup_private_vr<'a>(private: &PrivateDict<'a>, elements: Option<&Dataset>, tag: u32) -> Option<&'a str> {
+    let creator = match elements.and_then(|elements| elements.private_creator(tag)) {
+        Some(creator) => creator,
         None => return None,
-    }
+    };
+    private.get(creator).and_then(|block| block.get(&(tag & 0xFFFF00FF))).map(|elt| elt.vr)
 }
 
-fn element<'a>(dict: &DicomDict<'a>, data: &[u8], start: &mut usize, evr: bool, elements: Option<&DicomGeltEltDict>)
-               -> ((u16, u16), DicomElt) {
+fn element<'a>(private: &PrivateDict<'a>, cs: &CharacterSet, data: &[u8], start: &mut usize, evr: bool, order: Endian,
+               elements: Option<&Dataset>) -> Result<DicomDataElt> {
     let mut off = *start;
-    let (grp, elt) = (u8tou16(&data[off..off+2]), u8tou16(&data[off+2..off+4]));
+    let hdr = bytes(data, off, 4, 0)?;
+    let (grp, elt) = (get_u16(&hdr[0..2], order), get_u16(&hdr[2..4], order));
     off += 4;
     let gelt = (grp, elt);
+    let tag = u16tou32(&[elt, grp]);
+    let is_private = isodd(grp as usize) && grp > 0x0008;
     let (mut vr, lenbytes) = if evr && !always_implicit(grp, elt) {
-        let vr = u8tostr(&data[off..off+2]);
+        let vrbytes = bytes(data, off, 2, tag)?;
+        let vr = match str::from_utf8(vrbytes) {
+            Ok(vr) => vr,
+            Err(_) => return Err(DicomError::BadVR { tag: tag, offset: off,
+                                                     vr: String::from_utf8_lossy(vrbytes).into_owned() }),
+        };
         let lenbytes = if EXTRA_LENGTH_VRS.contains(&vr) { off += 4; 4} else { off += 2; 2 };
         (vr, lenbytes)
-    } else {
-        let vr = match lookup_vr(dict, gelt) {
-            Some(vr) => vr,
-            None => panic!("bad vr"),
+    } else if is_private {
+        let vr = if elt == 0x0000 {
+            "UL"
+        } else if 0x0010 <= elt && elt <= 0x00FF {
+            "LO"
+        } else {
+            lookup_private_vr(private, elements, tag).unwrap_or("UN")
         };
         (vr, 4)
+    } else {
+        // group lengths aren't all in the dictionary, and newer tags aren't in this edition of it
+        let vr = if elt == 0x0000 { "UL" } else { lookup_vr(tag, elements).unwrap_or("UN") };
+        (vr, 4)
     };
 
-    if isodd(grp as usize) && grp > 0x0008 && 0x0010 <= elt && elt < 0x00FF {
-        vr = "LO";
-    } else if isodd(grp as usize) && grp > 0x0008 {
-        vr = "UN";
-    }
 //    println!("grp: {} elt: {} diffvr: {} vr: {} lenbytes: {} data[0]: {} data[1]: {}",
 //             grp, elt, diffvr, vr, lenbytes, data[off], data[off+1]);
     let mut sz = if lenbytes == 4 {
-        u8tou32(&data[off..off+4]) as usize }
+        get_u32(bytes(data, off, 4, tag)?, order) as usize }
     else {
-        let val = u8tou16(&data[off..off+2]) as usize;
+        let val = get_u16(bytes(data, off, 2, tag)?, order) as usize;
         //println!("data[0]: {} data[1]: {}, val: {}", data[off], data[off+1], val);
         val
     };
     off += lenbytes;
-    let end = off + sz;
-    let entry = if sz == 0 || vr == "XX" {
+    // explicit VR writers that don't know a private element fall back to UN
+    if is_private && vr == "UN" && elt > 0x00FF && sz != 0xffffffff {
+        if let Some(privvr) = lookup_private_vr(private, elements, tag) {
+            vr = privvr;
+        }
+    }
+    let entry = if sz == 0 && vr == "SQ" {
+        DicomElt::Seq(vec![])
+    } else if sz == 0 || vr == "XX" {
         DicomElt::Empty
-    } else if sz == 0xffffffff {
-        let (len, v) = undefined_length(&data[off..]);
-        sz = len;
-        DicomElt::UInt16s(v)
     } else if gelt == (0x7FE0, 0x0010) {
-        let (elt, len) = pixeldata_parse(&data[off..], sz, vr, elements);
+        let (elt, len) = pixeldata_parse(data, off, sz, vr, order, elements)?;
         sz = len;
         elt
+    } else if sz == 0xffffffff {
+        // Only sequences can have undefined length; an undefined length UN is a sequence
+        // encoded as implicit VR little endian regardless of the transfer syntax (PS3.5 6.2.2).
+        let (len, seq) = if vr == "UN" {
+            sequence_parse(private, cs, data, off, data.len(), tag, false, Endian::Little)?
+        } else {
+            sequence_parse(private, cs, data, off, data.len(), tag, evr, order)?
+        };
+        sz = len;
+        seq
     } else {
-        let mut r = Cursor::new(&data[off..off+sz]);
+        let value = bytes(data, off, sz, tag)?;
+        let r = Cursor::new(value);
         match vr {
-            "AT" => DicomElt::UInt16s(vec![r.read_u16::<LittleEndian>().unwrap(),
-                                           r.read_u16::<LittleEndian>().unwrap()]),
-            "AE" | "AS" | "CS" | "DA" | "DT" | "LO" | "PN" | "SH" | "TM" | "UI" =>
-                DicomElt::String(u8tostr(&data[off..off+sz]).to_string()),
-            "IS" | "DS" => string_parse(&data[off..off+sz]),
-            "ST" | "LT" | "UT" => DicomElt::String(u8tostr(&data[off..off+sz]).to_string()),
-            "FL" => numeric_parse(r, DicomElt::Float32s(vec![]), sz/8, Endian::Little),
-            "FD" => numeric_parse(r, DicomElt::Float64s(vec![]), sz/4, Endian::Little),
-            "SL" => numeric_parse(r, DicomElt::Int32s(vec![]), sz/4, Endian::Little),
-            "SS" => numeric_parse(r, DicomElt::Int16s(vec![]), sz/2, Endian::Little),
-            "UL" => numeric_parse(r, DicomElt::UInt32s(vec![]), sz/4, Endian::Little),
-            "US" => numeric_parse(r, DicomElt::UInt16s(vec![]), sz/2, Endian::Little),
-            "OB" | "UN" => { DicomElt::Bytes(data[off..end].to_owned())},
-            "OD" => numeric_parse(r, DicomElt::Float64s(vec![]), sz/8, Endian::Big),
-            "OF" => numeric_parse(r, DicomElt::Float32s(vec![]), sz/4, Endian::Big),
-            "OW" => numeric_parse(r, DicomElt::UInt16s(vec![]), sz/2, Endian::Big),
-            "SQ" => {let (newoff, newelt) = sequence_parse(dict, &data[off..end], evr);
-                     assert!(newoff <= sz); sz -= sz - newoff; newelt} ,
-             _ => panic!("bad vr: {}", vr),
+            "AT" => numeric_parse(r, DicomElt::UInt16s(vec![]), sz/2, order)?,
+            "AE" | "AS" | "CS" | "DA" | "DT" | "TM" | "UI" | "UR" =>
+                DicomElt::String(u8tostr(value, tag, off)?.to_string()),
+            "IS" => is_parse(value, tag, off)?,
+            "DS" => ds_parse(value, tag, off)?,
+            "SH" | "LO" | "ST" | "LT" | "UT" | "UC" | "PN" => DicomElt::String(cs.decode(value, vr == "PN")),
+            "FL" => numeric_parse(r, DicomElt::Float32s(vec![]), sz/4, order)?,
+            "FD" => numeric_parse(r, DicomElt::Float64s(vec![]), sz/8, order)?,
+            "SL" => numeric_parse(r, DicomElt::Int32s(vec![]), sz/4, order)?,
+            "SS" => numeric_parse(r, DicomElt::Int16s(vec![]), sz/2, order)?,
+            "SV" => numeric_parse(r, DicomElt::Int64s(vec![]), sz/8, order)?,
+            "UL" => numeric_parse(r, DicomElt::UInt32s(vec![]), sz/4, order)?,
+            "US" => numeric_parse(r, DicomElt::UInt16s(vec![]), sz/2, order)?,
+            "UV" => numeric_parse(r, DicomElt::UInt64s(vec![]), sz/8, order)?,
+            "OB" | "UN" => { DicomElt::Bytes(value.to_owned())},
+            "OD" => numeric_parse(r, DicomElt::Float64s(vec![]), sz/8, order)?,
+            "OF" => numeric_parse(r, DicomElt::Float32s(vec![]), sz/4, order)?,
+            "OL" => numeric_parse(r, DicomElt::UInt32s(vec![]), sz/4, order)?,
+            "OV" => numeric_parse(r, DicomElt::UInt64s(vec![]), sz/8, order)?,
+            "OW" => numeric_parse(r, DicomElt::UInt16s(vec![]), sz/2, order)?,
+            "SQ" => {let (newoff, newelt) = sequence_parse(private, cs, data, off, off + sz, tag, evr, order)?;
+                     if newoff > sz {
+                         return Err(DicomError::BadValue { tag: tag, offset: off,
+                                                           reason: "sequence overruns its length".to_string() });
+                     }
+                     sz = newoff; newelt} ,
+             _ => return Err(DicomError::BadVR { tag: tag, offset: *start, vr: vr.to_string() }),
         }
     };
     off += sz as usize;
     if isodd(sz) {off += 1;}
     *start = off;
-    (gelt, entry)
+    Ok(DicomDataElt { tag: tag, vr: vr.to_string(), value: entry })
+}
+
+/// Specific Character Set applies to the rest of its dataset and to the items nested in it.
+fn update_charset(elt: &DicomDataElt, cs: &mut CharacterSet) {
+    if elt.tag == 0x00080005 {
+        if let DicomElt::String(ref value) = elt.value {
+            *cs = CharacterSet::new(value);
+        }
+    }
+}
+
+fn insert_element(elements: &mut Dataset, elt: DicomDataElt, off: usize) -> Result<()> {
+    let tag = elt.tag;
+    if elements.contains(tag) { return Err(DicomError::DuplicateTag { tag: tag, offset: off }); }
+    elements.insert(elt);
+    Ok(())
 }
 
-pub fn read_dataset<'a>(dict: &DicomDict<'a>, data: &[u8], start: usize) -> Result<DicomSlice> {
+fn read_file_meta<'a>(private: &PrivateDict<'a>, data: &[u8], off: &mut usize, elements: &mut Dataset) -> Result<()> {
+    // File Meta Information (group 0002) is always explicit VR little endian,
+    // whatever the transfer syntax of the rest of the dataset is.
+    while *off + 2 <= data.len() && u8tou16(&data[*off..*off+2]) == 0x0002 {
+        let eltoff = *off;
+        let elt = element(private, &CharacterSet::default(), data, off, true, Endian::Little, None)?;
+        insert_element(elements, elt, eltoff)?;
+    }
+    Ok(())
+}
+
+fn read_elements<'a>(private: &PrivateDict<'a>, data: &[u8], start: usize, evr: bool, order: Endian,
+                     elements: &mut Dataset) -> Result<()> {
     let mut off = start;
-    let sig = u8tostr(&data[off+4..off+6]);
-    let evr = VR_NAMES.contains(&sig);
-    let mut elements : DicomGeltEltDict = HashMap::new();
-    let mut state : DicomKwEltDict = HashMap::new();
-    while off < data.len() - 2 {
-        let (gelt, elt) = element(dict, data, &mut off, evr, Some(&elements));
-        let tag = u16tou32(&[gelt.1, gelt.0] );
-        if dict.contains_key(&tag) {
-            let ref dictelt = dict[&tag];
-            let keyword = dictelt.keyword;
-            assert!(!state.contains_key(keyword));
-            state.insert(keyword.to_string(), elt.to_owned());
+    let mut cs = CharacterSet::default();
+    while off + 2 < data.len() {
+        let eltoff = off;
+        let elt = element(private, &cs, data, &mut off, evr, order, Some(elements))?;
+        //println!("tag: {:08X} off: {}", elt.tag, off);
+        update_charset(&elt, &mut cs);
+        insert_element(elements, elt, eltoff)?;
+    }
+    Ok(())
+}
+
+/// In tolerant mode a truncation stops the parse but isn't fatal.
+fn salvage(res: Result<()>, tolerant: bool) -> Result<Option<DicomError>> {
+    match res {
+        Ok(()) => Ok(None),
+        Err(e) => match e {
+            DicomError::Truncated { .. } if tolerant => Ok(Some(e)),
+            _ => Err(e),
+        },
+    }
+}
+
+fn read_dataset_opt<'a>(private: &PrivateDict<'a>, data: &[u8], start: usize, tolerant: bool)
+                        -> Result<(DicomSlice, Option<DicomError>)> {
+    let mut off = start;
+    let mut elements = Dataset::new();
+
+    let meta = read_file_meta(private, data, &mut off, &mut elements);
+    let mut diag = salvage(meta, tolerant)?;
+    let transfer_syntax = match elements.get(0x00020010).map(|e| &e.value) {
+        Some(&DicomElt::String(ref uid)) => TransferSyntax::from_uid(uid),
+        Some(_) | None => {
+            // no meta header to go by, guess from whether a VR follows the first tag
+            let sig = bytes(data, off+4, 2, 0).ok().and_then(|b| str::from_utf8(b).ok()).unwrap_or("");
+            if VR_NAMES.contains(&sig) {
+                TransferSyntax::ExplicitVRLittleEndian
+            } else {
+                TransferSyntax::ImplicitVRLittleEndian
+            }
+        },
+    };
+    if diag.is_none() {
+        let evr = transfer_syntax.is_explicit_vr();
+        let order = if transfer_syntax.is_big_endian() { Endian::Big } else { Endian::Little };
+        let body = if transfer_syntax.is_deflated() {
+            // everything after the meta group is a raw deflate stream (no zlib header)
+            let mut inflated = Vec::new();
+            let rest = bytes(data, off, data.len().saturating_sub(off), 0)?;
+            let inflate = DeflateDecoder::new(rest).read_to_end(&mut inflated);
+            let body = read_elements(private, &inflated, 0, evr, order, &mut elements);
+            // a cut off stream still yields what was inflated before the break; offsets in
+            // the diagnostic are into the inflated data
+            match inflate {
+                Ok(_) => body,
+                Err(_) => body.and(Err(DicomError::Truncated { tag: 0, offset: inflated.len(), needed: 0 })),
+            }
         } else {
-            //println!("tag: {:08X} - {:04X} {:04X} not found in dict", tag, gelt.0, gelt.1);
-        }
-        assert!(!elements.contains_key(&tag));
-        //println!("tag: {:08X} off: {}", tag, off);
-        elements.insert(tag, elt);
+            read_elements(private, data, off, evr, order, &mut elements)
+        };
+        diag = salvage(body, tolerant)?;
     }
-    Ok(DicomSlice { keydict : state } )
+    Ok((DicomSlice { dataset : elements, transfer_syntax : transfer_syntax }, diag))
+}
+
+pub fn read_dataset<'a>(private: &PrivateDict<'a>, data: &[u8], start: usize) -> Result<DicomSlice> {
+    read_dataset_opt(private, data, start, false).map(|(slice, _)| slice)
+}
+
+/// Like `read_dataset`, but if the data is truncated returns the elements read before the
+/// truncation point along with the `DicomError::Truncated` describing where it stopped.
+pub fn read_dataset_tolerant<'a>(private: &PrivateDict<'a>, data: &[u8], start: usize)
+                                 -> Result<(DicomSlice, Option<DicomError>)> {
+    read_dataset_opt(private, data, start, true)
 }
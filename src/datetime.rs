@@ -0,0 +1,247 @@
+// Date (DA), time (TM) and date time (DT) values, PS3.5 6.2, and the range
+// matching form of PS3.4 C.2.2.2.5. Any trailing components may be omitted,
+// so every value covers an interval rather than an instant.
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveTime, NaiveDateTime, DateTime, FixedOffset, TimeZone};
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// A DA value, e.g. "20200131". Month and day may be missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomDate {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+/// A TM value, e.g. "103015.25".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomTime {
+    pub hour: u8,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    /// Microseconds and the number of digits given, e.g. (250000, 2) for ".25"
+    pub fraction: Option<(u32, u8)>,
+}
+
+/// A DT value, e.g. "20200131103015.25-0500".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomDateTime {
+    pub date: DicomDate,
+    pub time: Option<DicomTime>,
+    /// Minutes east of UTC, when the value carries its own offset
+    pub offset: Option<i16>,
+}
+
+/// A range query such as "20200101-20201231"; either end may be open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomRange<T> {
+    pub start: Option<T>,
+    pub end: Option<T>,
+}
+
+fn digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) { return None; }
+    s.parse().ok()
+}
+
+fn trim(s: &str) -> &str {
+    s.trim_matches(|c| c == ' ' || c == '\0')
+}
+
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 => if is_leap(year) { 29 } else { 28 },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468
+}
+
+/// Split "start-end" using `parse` for each side. A value that parses on its own is a
+/// single value range, which keeps DT offsets like "-0500" from being taken as a separator.
+fn parse_range<T: Copy, F: Fn(&str) -> Option<T>>(value: &str, parse: F) -> Option<DicomRange<T>> {
+    let value = trim(value);
+    if let Some(v) = parse(value) {
+        return Some(DicomRange { start: Some(v), end: Some(v) });
+    }
+    for (i, _) in value.match_indices('-') {
+        let (start, end) = (trim(&value[..i]), trim(&value[i + 1..]));
+        if start.is_empty() && end.is_empty() { continue; }
+        let start = if start.is_empty() { None } else { match parse(start) { Some(v) => Some(v), None => continue } };
+        let end = if end.is_empty() { None } else { match parse(end) { Some(v) => Some(v), None => continue } };
+        return Some(DicomRange { start: start, end: end });
+    }
+    None
+}
+
+impl DicomDate {
+    /// Parse "YYYY", "YYYYMM" or "YYYYMMDD", and the old "YYYY.MM.DD" form.
+    pub fn parse(value: &str) -> Option<DicomDate> {
+        let value = trim(value);
+        if !value.is_ascii() { return None; }
+        let value = if value.len() == 10 && value.as_bytes()[4] == b'.' && value.as_bytes()[7] == b'.' {
+            value.replace('.', "")
+        } else {
+            value.to_string()
+        };
+        if value.len() != 4 && value.len() != 6 && value.len() != 8 { return None; }
+        let year = digits(&value[..4])?;
+        let month = if value.len() >= 6 { Some(digits(&value[4..6])?) } else { None };
+        let day = if value.len() == 8 { Some(digits(&value[6..8])?) } else { None };
+        if let Some(m) = month {
+            if m < 1 || m > 12 { return None; }
+            if let Some(d) = day {
+                if d < 1 || d > days_in_month(year as i64, m) { return None; }
+            }
+        }
+        Some(DicomDate { year: year as u16, month: month.map(|m| m as u8), day: day.map(|d| d as u8) })
+    }
+
+    pub fn parse_range(value: &str) -> Option<DicomRange<DicomDate>> {
+        parse_range(value, DicomDate::parse)
+    }
+
+    /// The first and last day covered, in days since 1970-01-01.
+    fn bounds(&self) -> (i64, i64) {
+        let year = self.year as i64;
+        let (first_month, last_month) = self.month.map_or((1, 12), |m| (m as u32, m as u32));
+        let (first_day, last_day) = self.day.map_or((1, days_in_month(year, last_month)), |d| (d as u32, d as u32));
+        (days_from_civil(year, first_month, first_day), days_from_civil(year, last_month, last_day))
+    }
+
+    /// The first day covered by the value.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year as i32, self.month.unwrap_or(1) as u32, self.day.unwrap_or(1) as u32)
+    }
+}
+
+impl DicomTime {
+    /// Parse "HH", "HHMM", "HHMMSS" or "HHMMSS.F" to "HHMMSS.FFFFFF", and the old "HH:MM:SS" form.
+    pub fn parse(value: &str) -> Option<DicomTime> {
+        let value = trim(value);
+        if !value.is_ascii() { return None; }
+        let value = value.replace(':', "");
+        let mut parts = value.splitn(2, '.');
+        let main = parts.next().unwrap_or("");
+        let frac = parts.next();
+        if main.len() != 2 && main.len() != 4 && main.len() != 6 { return None; }
+        let hour = digits(&main[..2])?;
+        let minute = if main.len() >= 4 { Some(digits(&main[2..4])?) } else { None };
+        let second = if main.len() == 6 { Some(digits(&main[4..6])?) } else { None };
+        let fraction = match frac {
+            Some(f) if main.len() == 6 && f.len() <= 6 => {
+                let n = f.len() as u32;
+                Some((digits(f)? * 10u32.pow(6 - n), n as u8))
+            },
+            Some(_) => return None,
+            None => None,
+        };
+        if hour > 23 || minute.map_or(false, |m| m > 59) || second.map_or(false, |s| s > 60) { return None; }
+        Some(DicomTime { hour: hour as u8, minute: minute.map(|m| m as u8), second: second.map(|s| s as u8),
+                         fraction: fraction })
+    }
+
+    pub fn parse_range(value: &str) -> Option<DicomRange<DicomTime>> {
+        parse_range(value, DicomTime::parse)
+    }
+
+    /// The first and last microsecond of the day covered.
+    fn bounds(&self) -> (i64, i64) {
+        let hour = self.hour as i64 * 3_600_000_000;
+        let (m0, m1) = self.minute.map_or((0, 59), |m| (m as i64, m as i64));
+        let (s0, s1) = self.second.map_or((0, 59), |s| (s as i64, s as i64));
+        let (f0, f1) = self.fraction.map_or((0, 999_999), |(f, n)| (f as i64, f as i64 + 10i64.pow(6 - n as u32) - 1));
+        (hour + m0 * 60_000_000 + s0 * 1_000_000 + f0, hour + m1 * 60_000_000 + s1 * 1_000_000 + f1)
+    }
+
+    /// The first instant covered by the value.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_time(&self) -> Option<NaiveTime> {
+        NaiveTime::from_hms_micro_opt(self.hour as u32, self.minute.unwrap_or(0) as u32,
+                                      self.second.unwrap_or(0) as u32, self.fraction.map_or(0, |f| f.0))
+    }
+}
+
+impl DicomDateTime {
+    /// Parse "YYYY[MM[DD[HH[MM[SS[.F{1-6}]]]]]][&ZZXX]".
+    pub fn parse(value: &str) -> Option<DicomDateTime> {
+        let value = trim(value);
+        if !value.is_ascii() { return None; }
+        let (value, offset) = match value.len().checked_sub(5).map(|i| (i, value.as_bytes()[i])) {
+            Some((i, sign)) if sign == b'+' || sign == b'-' => {
+                let (hours, minutes) = (digits(&value[i + 1..i + 3])?, digits(&value[i + 3..])?);
+                if hours > 14 || minutes > 59 { return None; }
+                let offset = (hours * 60 + minutes) as i16;
+                (&value[..i], Some(if sign == b'-' { -offset } else { offset }))
+            },
+            _ => (value, None),
+        };
+        let main = value.split('.').next().unwrap_or("");
+        if main.len() % 2 != 0 || main.len() < 4 || main.len() > 14 || (main.len() < 14 && main.len() != value.len()) {
+            return None;
+        }
+        let date = DicomDate::parse(&value[..main.len().min(8)])?;
+        let time = if main.len() > 8 { Some(DicomTime::parse(&value[8..])?) } else { None };
+        Some(DicomDateTime { date: date, time: time, offset: offset })
+    }
+
+    pub fn parse_range(value: &str) -> Option<DicomRange<DicomDateTime>> {
+        parse_range(value, DicomDateTime::parse)
+    }
+
+    /// The first and last microsecond covered, since 1970-01-01 UTC. Values without
+    /// an offset are taken to be UTC.
+    fn bounds(&self) -> (i64, i64) {
+        let (d0, d1) = self.date.bounds();
+        let (t0, t1) = self.time.map_or((0, MICROS_PER_DAY - 1), |t| t.bounds());
+        let offset = self.offset.unwrap_or(0) as i64 * 60_000_000;
+        (d0 * MICROS_PER_DAY + t0 - offset, d1 * MICROS_PER_DAY + t1 - offset)
+    }
+
+    /// The first instant covered by the value, as written.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_datetime(&self) -> Option<NaiveDateTime> {
+        let time = match self.time {
+            Some(t) => t.to_naive_time()?,
+            None => NaiveTime::from_hms_opt(0, 0, 0)?,
+        };
+        Some(self.date.to_naive_date()?.and_time(time))
+    }
+
+    /// The first instant covered by the value; `None` if it has no UTC offset.
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime(&self) -> Option<DateTime<FixedOffset>> {
+        let offset = FixedOffset::east_opt(self.offset? as i32 * 60)?;
+        offset.from_local_datetime(&self.to_naive_datetime()?).single()
+    }
+}
+
+macro_rules! range_contains {
+    ($t:ty) => {
+        impl DicomRange<$t> {
+            /// Whether the whole interval covered by `value` lies within the range.
+            pub fn contains(&self, value: &$t) -> bool {
+                let (first, last) = value.bounds();
+                self.start.map_or(true, |s| s.bounds().0 <= first) && self.end.map_or(true, |e| last <= e.bounds().1)
+            }
+        }
+    }
+}
+
+range_contains!(DicomDate);
+range_contains!(DicomTime);
+range_contains!(DicomDateTime);
@@ -1,4170 +1,24 @@
-// DICOM data dictionary auto-generated by generate_dicom_dict.py
-use std::collections::HashMap;
-use dicom_types::DicomDict;
+// DICOM data dictionary. The tables are compiled by build.rs from
+// resources/dicom_dict.tsv, which is generated by dictgen from the PS3.6/PS3.7 XML.
 use dicom_types::DicomDictElt;
 
-pub fn dicom_dictionary_init<'a>() -> DicomDict<'a> {
-	let mut dicom_dictionary : DicomDict = HashMap::new();
+include!(concat!(env!("OUT_DIR"), "/dicom_dict.rs"));
 
-	dicom_dictionary.insert(0x00000000, DicomDictElt { vr : "UL", vm: "1", name: "Command Group Length", retired: "", keyword: "CommandGroupLength" });
-	dicom_dictionary.insert(0x00000001, DicomDictElt { vr : "UL", vm: "1", name: "Command Length to End", retired: "Retired", keyword: "CommandLengthToEnd" });
-	dicom_dictionary.insert(0x00000002, DicomDictElt { vr : "UI", vm: "1", name: "Affected SOP Class UID", retired: "", keyword: "AffectedSOPClassUID" });
-	dicom_dictionary.insert(0x00000003, DicomDictElt { vr : "UI", vm: "1", name: "Requested SOP Class UID", retired: "", keyword: "RequestedSOPClassUID" });
-	dicom_dictionary.insert(0x00000010, DicomDictElt { vr : "SH", vm: "1", name: "Command Recognition Code", retired: "Retired", keyword: "CommandRecognitionCode" });
-	dicom_dictionary.insert(0x00000100, DicomDictElt { vr : "US", vm: "1", name: "Command Field", retired: "", keyword: "CommandField" });
-	dicom_dictionary.insert(0x00000110, DicomDictElt { vr : "US", vm: "1", name: "Message ID", retired: "", keyword: "MessageID" });
-	dicom_dictionary.insert(0x00000120, DicomDictElt { vr : "US", vm: "1", name: "Message ID Being Responded To", retired: "", keyword: "MessageIDBeingRespondedTo" });
-	dicom_dictionary.insert(0x00000200, DicomDictElt { vr : "AE", vm: "1", name: "Initiator", retired: "Retired", keyword: "Initiator" });
-	dicom_dictionary.insert(0x00000300, DicomDictElt { vr : "AE", vm: "1", name: "Receiver", retired: "Retired", keyword: "Receiver" });
-	dicom_dictionary.insert(0x00000400, DicomDictElt { vr : "AE", vm: "1", name: "Find Location", retired: "Retired", keyword: "FindLocation" });
-	dicom_dictionary.insert(0x00000600, DicomDictElt { vr : "AE", vm: "1", name: "Move Destination", retired: "", keyword: "MoveDestination" });
-	dicom_dictionary.insert(0x00000700, DicomDictElt { vr : "US", vm: "1", name: "Priority", retired: "", keyword: "Priority" });
-	dicom_dictionary.insert(0x00000800, DicomDictElt { vr : "US", vm: "1", name: "Command Data Set Type", retired: "", keyword: "CommandDataSetType" });
-	dicom_dictionary.insert(0x00000850, DicomDictElt { vr : "US", vm: "1", name: "Number of Matches", retired: "Retired", keyword: "NumberOfMatches" });
-	dicom_dictionary.insert(0x00000860, DicomDictElt { vr : "US", vm: "1", name: "Response Sequence Number", retired: "Retired", keyword: "ResponseSequenceNumber" });
-	dicom_dictionary.insert(0x00000900, DicomDictElt { vr : "US", vm: "1", name: "Status", retired: "", keyword: "Status" });
-	dicom_dictionary.insert(0x00000901, DicomDictElt { vr : "AT", vm: "1-n", name: "Offending Element", retired: "", keyword: "OffendingElement" });
-	dicom_dictionary.insert(0x00000902, DicomDictElt { vr : "LO", vm: "1", name: "Error Comment", retired: "", keyword: "ErrorComment" });
-	dicom_dictionary.insert(0x00000903, DicomDictElt { vr : "US", vm: "1", name: "Error ID", retired: "", keyword: "ErrorID" });
-	dicom_dictionary.insert(0x00001000, DicomDictElt { vr : "UI", vm: "1", name: "Affected SOP Instance UID", retired: "", keyword: "AffectedSOPInstanceUID" });
-	dicom_dictionary.insert(0x00001001, DicomDictElt { vr : "UI", vm: "1", name: "Requested SOP Instance UID", retired: "", keyword: "RequestedSOPInstanceUID" });
-	dicom_dictionary.insert(0x00001002, DicomDictElt { vr : "US", vm: "1", name: "Event Type ID", retired: "", keyword: "EventTypeID" });
-	dicom_dictionary.insert(0x00001005, DicomDictElt { vr : "AT", vm: "1-n", name: "Attribute Identifier List", retired: "", keyword: "AttributeIdentifierList" });
-	dicom_dictionary.insert(0x00001008, DicomDictElt { vr : "US", vm: "1", name: "Action Type ID", retired: "", keyword: "ActionTypeID" });
-	dicom_dictionary.insert(0x00001020, DicomDictElt { vr : "US", vm: "1", name: "Number of Remaining Sub-operations", retired: "", keyword: "NumberOfRemainingSuboperations" });
-	dicom_dictionary.insert(0x00001021, DicomDictElt { vr : "US", vm: "1", name: "Number of Completed Sub-operations", retired: "", keyword: "NumberOfCompletedSuboperations" });
-	dicom_dictionary.insert(0x00001022, DicomDictElt { vr : "US", vm: "1", name: "Number of Failed Sub-operations", retired: "", keyword: "NumberOfFailedSuboperations" });
-	dicom_dictionary.insert(0x00001023, DicomDictElt { vr : "US", vm: "1", name: "Number of Warning Sub-operations", retired: "", keyword: "NumberOfWarningSuboperations" });
-	dicom_dictionary.insert(0x00001030, DicomDictElt { vr : "AE", vm: "1", name: "Move Originator Application Entity Title", retired: "", keyword: "MoveOriginatorApplicationEntityTitle" });
-	dicom_dictionary.insert(0x00001031, DicomDictElt { vr : "US", vm: "1", name: "Move Originator Message ID", retired: "", keyword: "MoveOriginatorMessageID" });
-	dicom_dictionary.insert(0x00004000, DicomDictElt { vr : "LT", vm: "1", name: "Dialog Receiver", retired: "Retired", keyword: "DialogReceiver" });
-	dicom_dictionary.insert(0x00004010, DicomDictElt { vr : "LT", vm: "1", name: "Terminal Type", retired: "Retired", keyword: "TerminalType" });
-	dicom_dictionary.insert(0x00005010, DicomDictElt { vr : "SH", vm: "1", name: "Message Set ID", retired: "Retired", keyword: "MessageSetID" });
-	dicom_dictionary.insert(0x00005020, DicomDictElt { vr : "SH", vm: "1", name: "End Message ID", retired: "Retired", keyword: "EndMessageID" });
-	dicom_dictionary.insert(0x00005110, DicomDictElt { vr : "LT", vm: "1", name: "Display Format", retired: "Retired", keyword: "DisplayFormat" });
-	dicom_dictionary.insert(0x00005120, DicomDictElt { vr : "LT", vm: "1", name: "Page Position ID", retired: "Retired", keyword: "PagePositionID" });
-	dicom_dictionary.insert(0x00005130, DicomDictElt { vr : "CS", vm: "1", name: "Text Format ID", retired: "Retired", keyword: "TextFormatID" });
-	dicom_dictionary.insert(0x00005140, DicomDictElt { vr : "CS", vm: "1", name: "Normal/Reverse", retired: "Retired", keyword: "NormalReverse" });
-	dicom_dictionary.insert(0x00005150, DicomDictElt { vr : "CS", vm: "1", name: "Add Gray Scale", retired: "Retired", keyword: "AddGrayScale" });
-	dicom_dictionary.insert(0x00005160, DicomDictElt { vr : "CS", vm: "1", name: "Borders", retired: "Retired", keyword: "Borders" });
-	dicom_dictionary.insert(0x00005170, DicomDictElt { vr : "IS", vm: "1", name: "Copies", retired: "Retired", keyword: "Copies" });
-	dicom_dictionary.insert(0x00005180, DicomDictElt { vr : "CS", vm: "1", name: "Command Magnification Type", retired: "Retired", keyword: "CommandMagnificationType" });
-	dicom_dictionary.insert(0x00005190, DicomDictElt { vr : "CS", vm: "1", name: "Erase", retired: "Retired", keyword: "Erase" });
-	dicom_dictionary.insert(0x000051A0, DicomDictElt { vr : "CS", vm: "1", name: "Print", retired: "Retired", keyword: "Print" });
-	dicom_dictionary.insert(0x000051B0, DicomDictElt { vr : "US", vm: "1-n", name: "Overlays", retired: "Retired", keyword: "Overlays" });
-	dicom_dictionary.insert(0x00020000, DicomDictElt { vr : "UL", vm: "1", name: "File Meta Information Group Length", retired: "", keyword: "FileMetaInformationGroupLength" });
-	dicom_dictionary.insert(0x00020001, DicomDictElt { vr : "OB", vm: "1", name: "File Meta Information Version", retired: "", keyword: "FileMetaInformationVersion" });
-	dicom_dictionary.insert(0x00020002, DicomDictElt { vr : "UI", vm: "1", name: "Media Storage SOP Class UID", retired: "", keyword: "MediaStorageSOPClassUID" });
-	dicom_dictionary.insert(0x00020003, DicomDictElt { vr : "UI", vm: "1", name: "Media Storage SOP Instance UID", retired: "", keyword: "MediaStorageSOPInstanceUID" });
-	dicom_dictionary.insert(0x00020010, DicomDictElt { vr : "UI", vm: "1", name: "Transfer Syntax UID", retired: "", keyword: "TransferSyntaxUID" });
-	dicom_dictionary.insert(0x00020012, DicomDictElt { vr : "UI", vm: "1", name: "Implementation Class UID", retired: "", keyword: "ImplementationClassUID" });
-	dicom_dictionary.insert(0x00020013, DicomDictElt { vr : "SH", vm: "1", name: "Implementation Version Name", retired: "", keyword: "ImplementationVersionName" });
-	dicom_dictionary.insert(0x00020016, DicomDictElt { vr : "AE", vm: "1", name: "Source Application Entity Title", retired: "", keyword: "SourceApplicationEntityTitle" });
-	dicom_dictionary.insert(0x00020017, DicomDictElt { vr : "AE", vm: "1", name: "Sending Application Entity Title", retired: "", keyword: "SendingApplicationEntityTitle" });
-	dicom_dictionary.insert(0x00020018, DicomDictElt { vr : "AE", vm: "1", name: "Receiving Application Entity Title", retired: "", keyword: "ReceivingApplicationEntityTitle" });
-	dicom_dictionary.insert(0x00020100, DicomDictElt { vr : "UI", vm: "1", name: "Private Information Creator UID", retired: "", keyword: "PrivateInformationCreatorUID" });
-	dicom_dictionary.insert(0x00020102, DicomDictElt { vr : "OB", vm: "1", name: "Private Information", retired: "", keyword: "PrivateInformation" });
-	dicom_dictionary.insert(0x00041130, DicomDictElt { vr : "CS", vm: "1", name: "File-set ID", retired: "", keyword: "FileSetID" });
-	dicom_dictionary.insert(0x00041141, DicomDictElt { vr : "CS", vm: "1-8", name: "File-set Descriptor File ID", retired: "", keyword: "FileSetDescriptorFileID" });
-	dicom_dictionary.insert(0x00041142, DicomDictElt { vr : "CS", vm: "1", name: "Specific Character Set of File-set Descriptor File", retired: "", keyword: "SpecificCharacterSetOfFileSetDescriptorFile" });
-	dicom_dictionary.insert(0x00041200, DicomDictElt { vr : "UL", vm: "1", name: "Offset of the First Directory Record of the Root Directory Entity", retired: "", keyword: "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity" });
-	dicom_dictionary.insert(0x00041202, DicomDictElt { vr : "UL", vm: "1", name: "Offset of the Last Directory Record of the Root Directory Entity", retired: "", keyword: "OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity" });
-	dicom_dictionary.insert(0x00041212, DicomDictElt { vr : "US", vm: "1", name: "File-set Consistency Flag", retired: "", keyword: "FileSetConsistencyFlag" });
-	dicom_dictionary.insert(0x00041220, DicomDictElt { vr : "SQ", vm: "1", name: "Directory Record Sequence", retired: "", keyword: "DirectoryRecordSequence" });
-	dicom_dictionary.insert(0x00041400, DicomDictElt { vr : "UL", vm: "1", name: "Offset of the Next Directory Record", retired: "", keyword: "OffsetOfTheNextDirectoryRecord" });
-	dicom_dictionary.insert(0x00041410, DicomDictElt { vr : "US", vm: "1", name: "Record In-use Flag", retired: "", keyword: "RecordInUseFlag" });
-	dicom_dictionary.insert(0x00041420, DicomDictElt { vr : "UL", vm: "1", name: "Offset of Referenced Lower-Level Directory Entity", retired: "", keyword: "OffsetOfReferencedLowerLevelDirectoryEntity" });
-	dicom_dictionary.insert(0x00041430, DicomDictElt { vr : "CS", vm: "1", name: "Directory Record Type", retired: "", keyword: "DirectoryRecordType" });
-	dicom_dictionary.insert(0x00041432, DicomDictElt { vr : "UI", vm: "1", name: "Private Record UID", retired: "", keyword: "PrivateRecordUID" });
-	dicom_dictionary.insert(0x00041500, DicomDictElt { vr : "CS", vm: "1-8", name: "Referenced File ID", retired: "", keyword: "ReferencedFileID" });
-	dicom_dictionary.insert(0x00041504, DicomDictElt { vr : "UL", vm: "1", name: "MRDR Directory Record Offset", retired: "Retired", keyword: "MRDRDirectoryRecordOffset" });
-	dicom_dictionary.insert(0x00041510, DicomDictElt { vr : "UI", vm: "1", name: "Referenced SOP Class UID in File", retired: "", keyword: "ReferencedSOPClassUIDInFile" });
-	dicom_dictionary.insert(0x00041511, DicomDictElt { vr : "UI", vm: "1", name: "Referenced SOP Instance UID in File", retired: "", keyword: "ReferencedSOPInstanceUIDInFile" });
-	dicom_dictionary.insert(0x00041512, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Transfer Syntax UID in File", retired: "", keyword: "ReferencedTransferSyntaxUIDInFile" });
-	dicom_dictionary.insert(0x0004151A, DicomDictElt { vr : "UI", vm: "1-n", name: "Referenced Related General SOP Class UID in File", retired: "", keyword: "ReferencedRelatedGeneralSOPClassUIDInFile" });
-	dicom_dictionary.insert(0x00041600, DicomDictElt { vr : "UL", vm: "1", name: "Number of References", retired: "Retired", keyword: "NumberOfReferences" });
-	dicom_dictionary.insert(0x00080001, DicomDictElt { vr : "UL", vm: "1", name: "Length to End", retired: "Retired", keyword: "LengthToEnd" });
-	dicom_dictionary.insert(0x00080005, DicomDictElt { vr : "CS", vm: "1-n", name: "Specific Character Set", retired: "", keyword: "SpecificCharacterSet" });
-	dicom_dictionary.insert(0x00080006, DicomDictElt { vr : "SQ", vm: "1", name: "Language Code Sequence", retired: "", keyword: "LanguageCodeSequence" });
-	dicom_dictionary.insert(0x00080008, DicomDictElt { vr : "CS", vm: "2-n", name: "Image Type", retired: "", keyword: "ImageType" });
-	dicom_dictionary.insert(0x00080010, DicomDictElt { vr : "SH", vm: "1", name: "Recognition Code", retired: "Retired", keyword: "RecognitionCode" });
-	dicom_dictionary.insert(0x00080012, DicomDictElt { vr : "DA", vm: "1", name: "Instance Creation Date", retired: "", keyword: "InstanceCreationDate" });
-	dicom_dictionary.insert(0x00080013, DicomDictElt { vr : "TM", vm: "1", name: "Instance Creation Time", retired: "", keyword: "InstanceCreationTime" });
-	dicom_dictionary.insert(0x00080014, DicomDictElt { vr : "UI", vm: "1", name: "Instance Creator UID", retired: "", keyword: "InstanceCreatorUID" });
-	dicom_dictionary.insert(0x00080015, DicomDictElt { vr : "DT", vm: "1", name: "Instance Coercion DateTime", retired: "", keyword: "InstanceCoercionDateTime" });
-	dicom_dictionary.insert(0x00080016, DicomDictElt { vr : "UI", vm: "1", name: "SOP Class UID", retired: "", keyword: "SOPClassUID" });
-	dicom_dictionary.insert(0x00080018, DicomDictElt { vr : "UI", vm: "1", name: "SOP Instance UID", retired: "", keyword: "SOPInstanceUID" });
-	dicom_dictionary.insert(0x0008001A, DicomDictElt { vr : "UI", vm: "1-n", name: "Related General SOP Class UID", retired: "", keyword: "RelatedGeneralSOPClassUID" });
-	dicom_dictionary.insert(0x0008001B, DicomDictElt { vr : "UI", vm: "1", name: "Original Specialized SOP Class UID", retired: "", keyword: "OriginalSpecializedSOPClassUID" });
-	dicom_dictionary.insert(0x00080020, DicomDictElt { vr : "DA", vm: "1", name: "Study Date", retired: "", keyword: "StudyDate" });
-	dicom_dictionary.insert(0x00080021, DicomDictElt { vr : "DA", vm: "1", name: "Series Date", retired: "", keyword: "SeriesDate" });
-	dicom_dictionary.insert(0x00080022, DicomDictElt { vr : "DA", vm: "1", name: "Acquisition Date", retired: "", keyword: "AcquisitionDate" });
-	dicom_dictionary.insert(0x00080023, DicomDictElt { vr : "DA", vm: "1", name: "Content Date", retired: "", keyword: "ContentDate" });
-	dicom_dictionary.insert(0x00080024, DicomDictElt { vr : "DA", vm: "1", name: "Overlay Date", retired: "Retired", keyword: "OverlayDate" });
-	dicom_dictionary.insert(0x00080025, DicomDictElt { vr : "DA", vm: "1", name: "Curve Date", retired: "Retired", keyword: "CurveDate" });
-	dicom_dictionary.insert(0x0008002A, DicomDictElt { vr : "DT", vm: "1", name: "Acquisition DateTime", retired: "", keyword: "AcquisitionDateTime" });
-	dicom_dictionary.insert(0x00080030, DicomDictElt { vr : "TM", vm: "1", name: "Study Time", retired: "", keyword: "StudyTime" });
-	dicom_dictionary.insert(0x00080031, DicomDictElt { vr : "TM", vm: "1", name: "Series Time", retired: "", keyword: "SeriesTime" });
-	dicom_dictionary.insert(0x00080032, DicomDictElt { vr : "TM", vm: "1", name: "Acquisition Time", retired: "", keyword: "AcquisitionTime" });
-	dicom_dictionary.insert(0x00080033, DicomDictElt { vr : "TM", vm: "1", name: "Content Time", retired: "", keyword: "ContentTime" });
-	dicom_dictionary.insert(0x00080034, DicomDictElt { vr : "TM", vm: "1", name: "Overlay Time", retired: "Retired", keyword: "OverlayTime" });
-	dicom_dictionary.insert(0x00080035, DicomDictElt { vr : "TM", vm: "1", name: "Curve Time", retired: "Retired", keyword: "CurveTime" });
-	dicom_dictionary.insert(0x00080040, DicomDictElt { vr : "US", vm: "1", name: "Data Set Type", retired: "Retired", keyword: "DataSetType" });
-	dicom_dictionary.insert(0x00080041, DicomDictElt { vr : "LO", vm: "1", name: "Data Set Subtype", retired: "Retired", keyword: "DataSetSubtype" });
-	dicom_dictionary.insert(0x00080042, DicomDictElt { vr : "CS", vm: "1", name: "Nuclear Medicine Series Type", retired: "Retired", keyword: "NuclearMedicineSeriesType" });
-	dicom_dictionary.insert(0x00080050, DicomDictElt { vr : "SH", vm: "1", name: "Accession Number", retired: "", keyword: "AccessionNumber" });
-	dicom_dictionary.insert(0x00080051, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of Accession Number Sequence", retired: "", keyword: "IssuerOfAccessionNumberSequence" });
-	dicom_dictionary.insert(0x00080052, DicomDictElt { vr : "CS", vm: "1", name: "Query/Retrieve Level", retired: "", keyword: "QueryRetrieveLevel" });
-	dicom_dictionary.insert(0x00080053, DicomDictElt { vr : "CS", vm: "1", name: "Query/Retrieve View", retired: "", keyword: "QueryRetrieveView" });
-	dicom_dictionary.insert(0x00080054, DicomDictElt { vr : "AE", vm: "1-n", name: "Retrieve AE Title", retired: "", keyword: "RetrieveAETitle" });
-	dicom_dictionary.insert(0x00080055, DicomDictElt { vr : "AE", vm: "1", name: "Station  AE Title", retired: "", keyword: "StationAETitle" });
-	dicom_dictionary.insert(0x00080056, DicomDictElt { vr : "CS", vm: "1", name: "Instance Availability", retired: "", keyword: "InstanceAvailability" });
-	dicom_dictionary.insert(0x00080058, DicomDictElt { vr : "UI", vm: "1-n", name: "Failed SOP Instance UID List", retired: "", keyword: "FailedSOPInstanceUIDList" });
-	dicom_dictionary.insert(0x00080060, DicomDictElt { vr : "CS", vm: "1", name: "Modality", retired: "", keyword: "Modality" });
-	dicom_dictionary.insert(0x00080061, DicomDictElt { vr : "CS", vm: "1-n", name: "Modalities in Study", retired: "", keyword: "ModalitiesInStudy" });
-	dicom_dictionary.insert(0x00080062, DicomDictElt { vr : "UI", vm: "1-n", name: "SOP Classes in Study", retired: "", keyword: "SOPClassesInStudy" });
-	dicom_dictionary.insert(0x00080064, DicomDictElt { vr : "CS", vm: "1", name: "Conversion Type", retired: "", keyword: "ConversionType" });
-	dicom_dictionary.insert(0x00080068, DicomDictElt { vr : "CS", vm: "1", name: "Presentation Intent Type", retired: "", keyword: "PresentationIntentType" });
-	dicom_dictionary.insert(0x00080070, DicomDictElt { vr : "LO", vm: "1", name: "Manufacturer", retired: "", keyword: "Manufacturer" });
-	dicom_dictionary.insert(0x00080080, DicomDictElt { vr : "LO", vm: "1", name: "Institution Name", retired: "", keyword: "InstitutionName" });
-	dicom_dictionary.insert(0x00080081, DicomDictElt { vr : "ST", vm: "1", name: "Institution Address", retired: "", keyword: "InstitutionAddress" });
-	dicom_dictionary.insert(0x00080082, DicomDictElt { vr : "SQ", vm: "1", name: "Institution Code Sequence", retired: "", keyword: "InstitutionCodeSequence" });
-	dicom_dictionary.insert(0x00080090, DicomDictElt { vr : "PN", vm: "1", name: "Referring Physician's Name", retired: "", keyword: "ReferringPhysicianName" });
-	dicom_dictionary.insert(0x00080092, DicomDictElt { vr : "ST", vm: "1", name: "Referring Physician's Address", retired: "", keyword: "ReferringPhysicianAddress" });
-	dicom_dictionary.insert(0x00080094, DicomDictElt { vr : "SH", vm: "1-n", name: "Referring Physician's Telephone Numbers", retired: "", keyword: "ReferringPhysicianTelephoneNumbers" });
-	dicom_dictionary.insert(0x00080096, DicomDictElt { vr : "SQ", vm: "1", name: "Referring Physician Identification Sequence", retired: "", keyword: "ReferringPhysicianIdentificationSequence" });
-	dicom_dictionary.insert(0x0008009C, DicomDictElt { vr : "PN", vm: "1-n", name: "Consulting Physician's Name", retired: "", keyword: "ConsultingPhysicianName" });
-	dicom_dictionary.insert(0x0008009D, DicomDictElt { vr : "SQ", vm: "1", name: "Consulting Physician Identification Sequence", retired: "", keyword: "ConsultingPhysicianIdentificationSequence" });
-	dicom_dictionary.insert(0x00080100, DicomDictElt { vr : "SH", vm: "1", name: "Code Value", retired: "", keyword: "CodeValue" });
-	dicom_dictionary.insert(0x00080101, DicomDictElt { vr : "LO", vm: "1", name: "Extended Code Value", retired: "", keyword: "ExtendedCodeValue" });
-	dicom_dictionary.insert(0x00080102, DicomDictElt { vr : "SH", vm: "1", name: "Coding Scheme Designator", retired: "", keyword: "CodingSchemeDesignator" });
-	dicom_dictionary.insert(0x00080103, DicomDictElt { vr : "SH", vm: "1", name: "Coding Scheme Version", retired: "", keyword: "CodingSchemeVersion" });
-	dicom_dictionary.insert(0x00080104, DicomDictElt { vr : "LO", vm: "1", name: "Code Meaning", retired: "", keyword: "CodeMeaning" });
-	dicom_dictionary.insert(0x00080105, DicomDictElt { vr : "CS", vm: "1", name: "Mapping Resource", retired: "", keyword: "MappingResource" });
-	dicom_dictionary.insert(0x00080106, DicomDictElt { vr : "DT", vm: "1", name: "Context Group Version", retired: "", keyword: "ContextGroupVersion" });
-	dicom_dictionary.insert(0x00080107, DicomDictElt { vr : "DT", vm: "1", name: "Context Group Local Version", retired: "", keyword: "ContextGroupLocalVersion" });
-	dicom_dictionary.insert(0x00080108, DicomDictElt { vr : "LT", vm: "1", name: "Extended Code Meaning", retired: "", keyword: "ExtendedCodeMeaning" });
-	dicom_dictionary.insert(0x0008010B, DicomDictElt { vr : "CS", vm: "1", name: "Context Group Extension Flag", retired: "", keyword: "ContextGroupExtensionFlag" });
-	dicom_dictionary.insert(0x0008010C, DicomDictElt { vr : "UI", vm: "1", name: "Coding Scheme UID", retired: "", keyword: "CodingSchemeUID" });
-	dicom_dictionary.insert(0x0008010D, DicomDictElt { vr : "UI", vm: "1", name: "Context Group Extension Creator UID", retired: "", keyword: "ContextGroupExtensionCreatorUID" });
-	dicom_dictionary.insert(0x0008010F, DicomDictElt { vr : "CS", vm: "1", name: "Context Identifier", retired: "", keyword: "ContextIdentifier" });
-	dicom_dictionary.insert(0x00080110, DicomDictElt { vr : "SQ", vm: "1", name: "Coding Scheme Identification Sequence", retired: "", keyword: "CodingSchemeIdentificationSequence" });
-	dicom_dictionary.insert(0x00080112, DicomDictElt { vr : "LO", vm: "1", name: "Coding Scheme Registry", retired: "", keyword: "CodingSchemeRegistry" });
-	dicom_dictionary.insert(0x00080114, DicomDictElt { vr : "ST", vm: "1", name: "Coding Scheme External ID", retired: "", keyword: "CodingSchemeExternalID" });
-	dicom_dictionary.insert(0x00080115, DicomDictElt { vr : "ST", vm: "1", name: "Coding Scheme Name", retired: "", keyword: "CodingSchemeName" });
-	dicom_dictionary.insert(0x00080116, DicomDictElt { vr : "ST", vm: "1", name: "Coding Scheme Responsible Organization", retired: "", keyword: "CodingSchemeResponsibleOrganization" });
-	dicom_dictionary.insert(0x00080117, DicomDictElt { vr : "UI", vm: "1", name: "Context UID", retired: "", keyword: "ContextUID" });
-	dicom_dictionary.insert(0x00080118, DicomDictElt { vr : "UI", vm: "1", name: "Mapping Resource UID", retired: "", keyword: "MappingResourceUID" });
-	dicom_dictionary.insert(0x00080119, DicomDictElt { vr : "UC", vm: "1", name: "Long Code Value", retired: "", keyword: "LongCodeValue" });
-	dicom_dictionary.insert(0x00080120, DicomDictElt { vr : "UR", vm: "1", name: "URN Code Value", retired: "", keyword: "URNCodeValue" });
-	dicom_dictionary.insert(0x00080121, DicomDictElt { vr : "SQ", vm: "1", name: "Equivalent Code Sequence", retired: "", keyword: "EquivalentCodeSequence" });
-	dicom_dictionary.insert(0x00080122, DicomDictElt { vr : "LO", vm: "1", name: "Mapping Resource Name", retired: "", keyword: "MappingResourceName" });
-	dicom_dictionary.insert(0x00080123, DicomDictElt { vr : "SQ", vm: "1", name: "Context Group Identification Sequence", retired: "", keyword: "ContextGroupIdentificationSequence" });
-	dicom_dictionary.insert(0x00080124, DicomDictElt { vr : "SQ", vm: "1", name: "Mapping Resource Identification Sequence", retired: "", keyword: "MappingResourceIdentificationSequence" });
-	dicom_dictionary.insert(0x00080201, DicomDictElt { vr : "SH", vm: "1", name: "Timezone Offset From UTC", retired: "", keyword: "TimezoneOffsetFromUTC" });
-	dicom_dictionary.insert(0x00080220, DicomDictElt { vr : "SQ", vm: "1", name: "Responsible Group Code Sequence", retired: "", keyword: "ResponsibleGroupCodeSequence" });
-	dicom_dictionary.insert(0x00080221, DicomDictElt { vr : "CS", vm: "1", name: "Equipment Modality", retired: "", keyword: "EquipmentModality" });
-	dicom_dictionary.insert(0x00080222, DicomDictElt { vr : "LO", vm: "1", name: "Manufacturer's Related Model Group", retired: "", keyword: "ManufacturerRelatedModelGroup" });
-	dicom_dictionary.insert(0x00080300, DicomDictElt { vr : "SQ", vm: "1", name: "Private Data Element Characteristics Sequence", retired: "", keyword: "PrivateDataElementCharacteristicsSequence" });
-	dicom_dictionary.insert(0x00080301, DicomDictElt { vr : "US", vm: "1", name: "Private Group Reference", retired: "", keyword: "PrivateGroupReference" });
-	dicom_dictionary.insert(0x00080302, DicomDictElt { vr : "LO", vm: "1", name: "Private Creator Reference", retired: "", keyword: "PrivateCreatorReference" });
-	dicom_dictionary.insert(0x00080303, DicomDictElt { vr : "CS", vm: "1", name: "Block Identifying Information Status", retired: "", keyword: "BlockIdentifyingInformationStatus" });
-	dicom_dictionary.insert(0x00080304, DicomDictElt { vr : "US", vm: "1-n", name: "Nonidentifying Private Elements", retired: "", keyword: "NonidentifyingPrivateElements" });
-	dicom_dictionary.insert(0x00080305, DicomDictElt { vr : "SQ", vm: "1", name: "Deidentification Action Sequence", retired: "", keyword: "DeidentificationActionSequence" });
-	dicom_dictionary.insert(0x00080306, DicomDictElt { vr : "US", vm: "1-n", name: "Identifying Private Elements", retired: "", keyword: "IdentifyingPrivateElements" });
-	dicom_dictionary.insert(0x00080307, DicomDictElt { vr : "CS", vm: "1", name: "Deidentification Action", retired: "", keyword: "DeidentificationAction" });
-	dicom_dictionary.insert(0x00080308, DicomDictElt { vr : "US", vm: "1", name: "Private Data Element", retired: "", keyword: "PrivateDataElement" });
-	dicom_dictionary.insert(0x00080309, DicomDictElt { vr : "UL", vm: "1-3", name: "Private Data Element Value Multiplicity", retired: "", keyword: "PrivateDataElementValueMultiplicity" });
-	dicom_dictionary.insert(0x0008030A, DicomDictElt { vr : "CS", vm: "1", name: "Private Data Element Value Representation", retired: "", keyword: "PrivateDataElementValueRepresentation" });
-	dicom_dictionary.insert(0x0008030B, DicomDictElt { vr : "UL", vm: "1-2", name: "Private Data Element Number of Items", retired: "", keyword: "PrivateDataElementNumberOfItems" });
-	dicom_dictionary.insert(0x0008030C, DicomDictElt { vr : "UC", vm: "1", name: "Private Data Element Name", retired: "", keyword: "PrivateDataElementName" });
-	dicom_dictionary.insert(0x0008030D, DicomDictElt { vr : "UC", vm: "1", name: "Private Data Element Keyword", retired: "", keyword: "PrivateDataElementKeyword" });
-	dicom_dictionary.insert(0x0008030E, DicomDictElt { vr : "UT", vm: "1", name: "Private Data Element Description", retired: "", keyword: "PrivateDataElementDescription" });
-	dicom_dictionary.insert(0x0008030F, DicomDictElt { vr : "UT", vm: "1", name: "Private Data Element Encoding", retired: "", keyword: "PrivateDataElementEncoding" });
-	dicom_dictionary.insert(0x00080310, DicomDictElt { vr : "SQ", vm: "1", name: "Private Data Element Definition Sequence", retired: "", keyword: "PrivateDataElementDefinitionSequence" });
-	dicom_dictionary.insert(0x00081000, DicomDictElt { vr : "AE", vm: "1", name: "Network ID", retired: "Retired", keyword: "NetworkID" });
-	dicom_dictionary.insert(0x00081010, DicomDictElt { vr : "SH", vm: "1", name: "Station Name", retired: "", keyword: "StationName" });
-	dicom_dictionary.insert(0x00081030, DicomDictElt { vr : "LO", vm: "1", name: "Study Description", retired: "", keyword: "StudyDescription" });
-	dicom_dictionary.insert(0x00081032, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Code Sequence", retired: "", keyword: "ProcedureCodeSequence" });
-	dicom_dictionary.insert(0x0008103E, DicomDictElt { vr : "LO", vm: "1", name: "Series Description", retired: "", keyword: "SeriesDescription" });
-	dicom_dictionary.insert(0x0008103F, DicomDictElt { vr : "SQ", vm: "1", name: "Series Description Code Sequence", retired: "", keyword: "SeriesDescriptionCodeSequence" });
-	dicom_dictionary.insert(0x00081040, DicomDictElt { vr : "LO", vm: "1", name: "Institutional Department Name", retired: "", keyword: "InstitutionalDepartmentName" });
-	dicom_dictionary.insert(0x00081048, DicomDictElt { vr : "PN", vm: "1-n", name: "Physician(s) of Record", retired: "", keyword: "PhysiciansOfRecord" });
-	dicom_dictionary.insert(0x00081049, DicomDictElt { vr : "SQ", vm: "1", name: "Physician(s) of Record Identification Sequence", retired: "", keyword: "PhysiciansOfRecordIdentificationSequence" });
-	dicom_dictionary.insert(0x00081050, DicomDictElt { vr : "PN", vm: "1-n", name: "Performing Physician's Name", retired: "", keyword: "PerformingPhysicianName" });
-	dicom_dictionary.insert(0x00081052, DicomDictElt { vr : "SQ", vm: "1", name: "Performing Physician Identification Sequence", retired: "", keyword: "PerformingPhysicianIdentificationSequence" });
-	dicom_dictionary.insert(0x00081060, DicomDictElt { vr : "PN", vm: "1-n", name: "Name of Physician(s) Reading Study", retired: "", keyword: "NameOfPhysiciansReadingStudy" });
-	dicom_dictionary.insert(0x00081062, DicomDictElt { vr : "SQ", vm: "1", name: "Physician(s) Reading Study Identification Sequence", retired: "", keyword: "PhysiciansReadingStudyIdentificationSequence" });
-	dicom_dictionary.insert(0x00081070, DicomDictElt { vr : "PN", vm: "1-n", name: "Operators' Name", retired: "", keyword: "OperatorsName" });
-	dicom_dictionary.insert(0x00081072, DicomDictElt { vr : "SQ", vm: "1", name: "Operator Identification Sequence", retired: "", keyword: "OperatorIdentificationSequence" });
-	dicom_dictionary.insert(0x00081080, DicomDictElt { vr : "LO", vm: "1-n", name: "Admitting Diagnoses Description", retired: "", keyword: "AdmittingDiagnosesDescription" });
-	dicom_dictionary.insert(0x00081084, DicomDictElt { vr : "SQ", vm: "1", name: "Admitting Diagnoses Code Sequence", retired: "", keyword: "AdmittingDiagnosesCodeSequence" });
-	dicom_dictionary.insert(0x00081090, DicomDictElt { vr : "LO", vm: "1", name: "Manufacturer's Model Name", retired: "", keyword: "ManufacturerModelName" });
-	dicom_dictionary.insert(0x00081100, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Results Sequence", retired: "Retired", keyword: "ReferencedResultsSequence" });
-	dicom_dictionary.insert(0x00081110, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Study Sequence", retired: "", keyword: "ReferencedStudySequence" });
-	dicom_dictionary.insert(0x00081111, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Performed Procedure Step Sequence", retired: "", keyword: "ReferencedPerformedProcedureStepSequence" });
-	dicom_dictionary.insert(0x00081115, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Series Sequence", retired: "", keyword: "ReferencedSeriesSequence" });
-	dicom_dictionary.insert(0x00081120, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Patient Sequence", retired: "", keyword: "ReferencedPatientSequence" });
-	dicom_dictionary.insert(0x00081125, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Visit Sequence", retired: "", keyword: "ReferencedVisitSequence" });
-	dicom_dictionary.insert(0x00081130, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Overlay Sequence", retired: "Retired", keyword: "ReferencedOverlaySequence" });
-	dicom_dictionary.insert(0x00081134, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Stereometric Instance Sequence", retired: "", keyword: "ReferencedStereometricInstanceSequence" });
-	dicom_dictionary.insert(0x0008113A, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Waveform Sequence", retired: "", keyword: "ReferencedWaveformSequence" });
-	dicom_dictionary.insert(0x00081140, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Sequence", retired: "", keyword: "ReferencedImageSequence" });
-	dicom_dictionary.insert(0x00081145, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Curve Sequence", retired: "Retired", keyword: "ReferencedCurveSequence" });
-	dicom_dictionary.insert(0x0008114A, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Instance Sequence", retired: "", keyword: "ReferencedInstanceSequence" });
-	dicom_dictionary.insert(0x0008114B, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Real World Value Mapping Instance Sequence", retired: "", keyword: "ReferencedRealWorldValueMappingInstanceSequence" });
-	dicom_dictionary.insert(0x00081150, DicomDictElt { vr : "UI", vm: "1", name: "Referenced SOP Class UID", retired: "", keyword: "ReferencedSOPClassUID" });
-	dicom_dictionary.insert(0x00081155, DicomDictElt { vr : "UI", vm: "1", name: "Referenced SOP Instance UID", retired: "", keyword: "ReferencedSOPInstanceUID" });
-	dicom_dictionary.insert(0x0008115A, DicomDictElt { vr : "UI", vm: "1-n", name: "SOP Classes Supported", retired: "", keyword: "SOPClassesSupported" });
-	dicom_dictionary.insert(0x00081160, DicomDictElt { vr : "IS", vm: "1-n", name: "Referenced Frame Number", retired: "", keyword: "ReferencedFrameNumber" });
-	dicom_dictionary.insert(0x00081161, DicomDictElt { vr : "UL", vm: "1-n", name: "Simple Frame List", retired: "", keyword: "SimpleFrameList" });
-	dicom_dictionary.insert(0x00081162, DicomDictElt { vr : "UL", vm: "3-3n", name: "Calculated Frame List", retired: "", keyword: "CalculatedFrameList" });
-	dicom_dictionary.insert(0x00081163, DicomDictElt { vr : "FD", vm: "2", name: "Time Range", retired: "", keyword: "TimeRange" });
-	dicom_dictionary.insert(0x00081164, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Extraction Sequence", retired: "", keyword: "FrameExtractionSequence" });
-	dicom_dictionary.insert(0x00081167, DicomDictElt { vr : "UI", vm: "1", name: "Multi-frame Source SOP Instance UID", retired: "", keyword: "MultiFrameSourceSOPInstanceUID" });
-	dicom_dictionary.insert(0x00081190, DicomDictElt { vr : "UR", vm: "1", name: "Retrieve URL", retired: "", keyword: "RetrieveURL" });
-	dicom_dictionary.insert(0x00081195, DicomDictElt { vr : "UI", vm: "1", name: "Transaction UID", retired: "", keyword: "TransactionUID" });
-	dicom_dictionary.insert(0x00081196, DicomDictElt { vr : "US", vm: "1", name: "Warning Reason", retired: "", keyword: "WarningReason" });
-	dicom_dictionary.insert(0x00081197, DicomDictElt { vr : "US", vm: "1", name: "Failure Reason", retired: "", keyword: "FailureReason" });
-	dicom_dictionary.insert(0x00081198, DicomDictElt { vr : "SQ", vm: "1", name: "Failed SOP Sequence", retired: "", keyword: "FailedSOPSequence" });
-	dicom_dictionary.insert(0x00081199, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced SOP Sequence", retired: "", keyword: "ReferencedSOPSequence" });
-	dicom_dictionary.insert(0x0008119A, DicomDictElt { vr : "SQ", vm: "1", name: "Other Failures Sequence", retired: "", keyword: "OtherFailuresSequence" });
-	dicom_dictionary.insert(0x00081200, DicomDictElt { vr : "SQ", vm: "1", name: "Studies Containing Other Referenced Instances Sequence", retired: "", keyword: "StudiesContainingOtherReferencedInstancesSequence" });
-	dicom_dictionary.insert(0x00081250, DicomDictElt { vr : "SQ", vm: "1", name: "Related Series Sequence", retired: "", keyword: "RelatedSeriesSequence" });
-	dicom_dictionary.insert(0x00082110, DicomDictElt { vr : "CS", vm: "1", name: "Lossy Image Compression (Retired)", retired: "Retired", keyword: "LossyImageCompressionRetired" });
-	dicom_dictionary.insert(0x00082111, DicomDictElt { vr : "ST", vm: "1", name: "Derivation Description", retired: "", keyword: "DerivationDescription" });
-	dicom_dictionary.insert(0x00082112, DicomDictElt { vr : "SQ", vm: "1", name: "Source Image Sequence", retired: "", keyword: "SourceImageSequence" });
-	dicom_dictionary.insert(0x00082120, DicomDictElt { vr : "SH", vm: "1", name: "Stage Name", retired: "", keyword: "StageName" });
-	dicom_dictionary.insert(0x00082122, DicomDictElt { vr : "IS", vm: "1", name: "Stage Number", retired: "", keyword: "StageNumber" });
-	dicom_dictionary.insert(0x00082124, DicomDictElt { vr : "IS", vm: "1", name: "Number of Stages", retired: "", keyword: "NumberOfStages" });
-	dicom_dictionary.insert(0x00082127, DicomDictElt { vr : "SH", vm: "1", name: "View Name", retired: "", keyword: "ViewName" });
-	dicom_dictionary.insert(0x00082128, DicomDictElt { vr : "IS", vm: "1", name: "View Number", retired: "", keyword: "ViewNumber" });
-	dicom_dictionary.insert(0x00082129, DicomDictElt { vr : "IS", vm: "1", name: "Number of Event Timers", retired: "", keyword: "NumberOfEventTimers" });
-	dicom_dictionary.insert(0x0008212A, DicomDictElt { vr : "IS", vm: "1", name: "Number of Views in Stage", retired: "", keyword: "NumberOfViewsInStage" });
-	dicom_dictionary.insert(0x00082130, DicomDictElt { vr : "DS", vm: "1-n", name: "Event Elapsed Time(s)", retired: "", keyword: "EventElapsedTimes" });
-	dicom_dictionary.insert(0x00082132, DicomDictElt { vr : "LO", vm: "1-n", name: "Event Timer Name(s)", retired: "", keyword: "EventTimerNames" });
-	dicom_dictionary.insert(0x00082133, DicomDictElt { vr : "SQ", vm: "1", name: "Event Timer Sequence", retired: "", keyword: "EventTimerSequence" });
-	dicom_dictionary.insert(0x00082134, DicomDictElt { vr : "FD", vm: "1", name: "Event Time Offset", retired: "", keyword: "EventTimeOffset" });
-	dicom_dictionary.insert(0x00082135, DicomDictElt { vr : "SQ", vm: "1", name: "Event Code Sequence", retired: "", keyword: "EventCodeSequence" });
-	dicom_dictionary.insert(0x00082142, DicomDictElt { vr : "IS", vm: "1", name: "Start Trim", retired: "", keyword: "StartTrim" });
-	dicom_dictionary.insert(0x00082143, DicomDictElt { vr : "IS", vm: "1", name: "Stop Trim", retired: "", keyword: "StopTrim" });
-	dicom_dictionary.insert(0x00082144, DicomDictElt { vr : "IS", vm: "1", name: "Recommended Display Frame Rate", retired: "", keyword: "RecommendedDisplayFrameRate" });
-	dicom_dictionary.insert(0x00082200, DicomDictElt { vr : "CS", vm: "1", name: "Transducer Position", retired: "Retired", keyword: "TransducerPosition" });
-	dicom_dictionary.insert(0x00082204, DicomDictElt { vr : "CS", vm: "1", name: "Transducer Orientation", retired: "Retired", keyword: "TransducerOrientation" });
-	dicom_dictionary.insert(0x00082208, DicomDictElt { vr : "CS", vm: "1", name: "Anatomic Structure", retired: "Retired", keyword: "AnatomicStructure" });
-	dicom_dictionary.insert(0x00082218, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Region Sequence", retired: "", keyword: "AnatomicRegionSequence" });
-	dicom_dictionary.insert(0x00082220, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Region Modifier Sequence", retired: "", keyword: "AnatomicRegionModifierSequence" });
-	dicom_dictionary.insert(0x00082228, DicomDictElt { vr : "SQ", vm: "1", name: "Primary Anatomic Structure Sequence", retired: "", keyword: "PrimaryAnatomicStructureSequence" });
-	dicom_dictionary.insert(0x00082229, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Structure, Space or Region Sequence", retired: "", keyword: "AnatomicStructureSpaceOrRegionSequence" });
-	dicom_dictionary.insert(0x00082230, DicomDictElt { vr : "SQ", vm: "1", name: "Primary Anatomic Structure Modifier Sequence", retired: "", keyword: "PrimaryAnatomicStructureModifierSequence" });
-	dicom_dictionary.insert(0x00082240, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Position Sequence", retired: "Retired", keyword: "TransducerPositionSequence" });
-	dicom_dictionary.insert(0x00082242, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Position Modifier Sequence", retired: "Retired", keyword: "TransducerPositionModifierSequence" });
-	dicom_dictionary.insert(0x00082244, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Orientation Sequence", retired: "Retired", keyword: "TransducerOrientationSequence" });
-	dicom_dictionary.insert(0x00082246, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Orientation Modifier Sequence", retired: "Retired", keyword: "TransducerOrientationModifierSequence" });
-	dicom_dictionary.insert(0x00082251, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Structure Space Or Region Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicStructureSpaceOrRegionCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00082253, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Portal Of Entrance Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicPortalOfEntranceCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00082255, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Approach Direction Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicApproachDirectionCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00082256, DicomDictElt { vr : "ST", vm: "1", name: "Anatomic Perspective Description (Trial)", retired: "Retired", keyword: "AnatomicPerspectiveDescriptionTrial" });
-	dicom_dictionary.insert(0x00082257, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Perspective Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicPerspectiveCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00082258, DicomDictElt { vr : "ST", vm: "1", name: "Anatomic Location Of Examining Instrument Description (Trial)", retired: "Retired", keyword: "AnatomicLocationOfExaminingInstrumentDescriptionTrial" });
-	dicom_dictionary.insert(0x00082259, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Location Of Examining Instrument Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicLocationOfExaminingInstrumentCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0008225A, DicomDictElt { vr : "SQ", vm: "1", name: "Anatomic Structure Space Or Region Modifier Code Sequence (Trial)", retired: "Retired", keyword: "AnatomicStructureSpaceOrRegionModifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0008225C, DicomDictElt { vr : "SQ", vm: "1", name: "On Axis Background Anatomic Structure Code Sequence (Trial)", retired: "Retired", keyword: "OnAxisBackgroundAnatomicStructureCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00083001, DicomDictElt { vr : "SQ", vm: "1", name: "Alternate Representation Sequence", retired: "", keyword: "AlternateRepresentationSequence" });
-	dicom_dictionary.insert(0x00083010, DicomDictElt { vr : "UI", vm: "1-n", name: "Irradiation Event UID", retired: "", keyword: "IrradiationEventUID" });
-	dicom_dictionary.insert(0x00083011, DicomDictElt { vr : "SQ", vm: "1", name: "Source Irradiation Event Sequence", retired: "", keyword: "SourceIrradiationEventSequence" });
-	dicom_dictionary.insert(0x00083012, DicomDictElt { vr : "UI", vm: "1", name: "Radiopharmaceutical Administration Event UID", retired: "", keyword: "RadiopharmaceuticalAdministrationEventUID" });
-	dicom_dictionary.insert(0x00084000, DicomDictElt { vr : "LT", vm: "1", name: "Identifying Comments", retired: "Retired", keyword: "IdentifyingComments" });
-	dicom_dictionary.insert(0x00089007, DicomDictElt { vr : "CS", vm: "4", name: "Frame Type", retired: "", keyword: "FrameType" });
-	dicom_dictionary.insert(0x00089092, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Evidence Sequence", retired: "", keyword: "ReferencedImageEvidenceSequence" });
-	dicom_dictionary.insert(0x00089121, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Raw Data Sequence", retired: "", keyword: "ReferencedRawDataSequence" });
-	dicom_dictionary.insert(0x00089123, DicomDictElt { vr : "UI", vm: "1", name: "Creator-Version UID", retired: "", keyword: "CreatorVersionUID" });
-	dicom_dictionary.insert(0x00089124, DicomDictElt { vr : "SQ", vm: "1", name: "Derivation Image Sequence", retired: "", keyword: "DerivationImageSequence" });
-	dicom_dictionary.insert(0x00089154, DicomDictElt { vr : "SQ", vm: "1", name: "Source Image Evidence Sequence", retired: "", keyword: "SourceImageEvidenceSequence" });
-	dicom_dictionary.insert(0x00089205, DicomDictElt { vr : "CS", vm: "1", name: "Pixel Presentation", retired: "", keyword: "PixelPresentation" });
-	dicom_dictionary.insert(0x00089206, DicomDictElt { vr : "CS", vm: "1", name: "Volumetric Properties", retired: "", keyword: "VolumetricProperties" });
-	dicom_dictionary.insert(0x00089207, DicomDictElt { vr : "CS", vm: "1", name: "Volume Based Calculation Technique", retired: "", keyword: "VolumeBasedCalculationTechnique" });
-	dicom_dictionary.insert(0x00089208, DicomDictElt { vr : "CS", vm: "1", name: "Complex Image Component", retired: "", keyword: "ComplexImageComponent" });
-	dicom_dictionary.insert(0x00089209, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Contrast", retired: "", keyword: "AcquisitionContrast" });
-	dicom_dictionary.insert(0x00089215, DicomDictElt { vr : "SQ", vm: "1", name: "Derivation Code Sequence", retired: "", keyword: "DerivationCodeSequence" });
-	dicom_dictionary.insert(0x00089237, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Presentation State Sequence", retired: "", keyword: "ReferencedPresentationStateSequence" });
-	dicom_dictionary.insert(0x00089410, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Other Plane Sequence", retired: "", keyword: "ReferencedOtherPlaneSequence" });
-	dicom_dictionary.insert(0x00089458, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Display Sequence", retired: "", keyword: "FrameDisplaySequence" });
-	dicom_dictionary.insert(0x00089459, DicomDictElt { vr : "FL", vm: "1", name: "Recommended Display Frame Rate in Float", retired: "", keyword: "RecommendedDisplayFrameRateInFloat" });
-	dicom_dictionary.insert(0x00089460, DicomDictElt { vr : "CS", vm: "1", name: "Skip Frame Range Flag", retired: "", keyword: "SkipFrameRangeFlag" });
-	dicom_dictionary.insert(0x00100010, DicomDictElt { vr : "PN", vm: "1", name: "Patient's Name", retired: "", keyword: "PatientName" });
-	dicom_dictionary.insert(0x00100020, DicomDictElt { vr : "LO", vm: "1", name: "Patient ID", retired: "", keyword: "PatientID" });
-	dicom_dictionary.insert(0x00100021, DicomDictElt { vr : "LO", vm: "1", name: "Issuer of Patient ID", retired: "", keyword: "IssuerOfPatientID" });
-	dicom_dictionary.insert(0x00100022, DicomDictElt { vr : "CS", vm: "1", name: "Type of Patient ID", retired: "", keyword: "TypeOfPatientID" });
-	dicom_dictionary.insert(0x00100024, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of Patient ID Qualifiers Sequence", retired: "", keyword: "IssuerOfPatientIDQualifiersSequence" });
-	dicom_dictionary.insert(0x00100026, DicomDictElt { vr : "SQ", vm: "1", name: "Source Patient Group Identification Sequence", retired: "", keyword: "SourcePatientGroupIdentificationSequence" });
-	dicom_dictionary.insert(0x00100027, DicomDictElt { vr : "SQ", vm: "1", name: "Group of Patients Identification Sequence", retired: "", keyword: "GroupOfPatientsIdentificationSequence" });
-	dicom_dictionary.insert(0x00100028, DicomDictElt { vr : "US", vm: "3", name: "Subject Relative Position in Image", retired: "", keyword: "SubjectRelativePositionInImage" });
-	dicom_dictionary.insert(0x00100030, DicomDictElt { vr : "DA", vm: "1", name: "Patient's Birth Date", retired: "", keyword: "PatientBirthDate" });
-	dicom_dictionary.insert(0x00100032, DicomDictElt { vr : "TM", vm: "1", name: "Patient's Birth Time", retired: "", keyword: "PatientBirthTime" });
-	dicom_dictionary.insert(0x00100033, DicomDictElt { vr : "LO", vm: "1", name: "Patient's Birth Date in Alternative Calendar", retired: "", keyword: "PatientBirthDateInAlternativeCalendar" });
-	dicom_dictionary.insert(0x00100034, DicomDictElt { vr : "LO", vm: "1", name: "Patient's Death Date in Alternative Calendar", retired: "", keyword: "PatientDeathDateInAlternativeCalendar" });
-	dicom_dictionary.insert(0x00100035, DicomDictElt { vr : "CS", vm: "1", name: "Patient's Alternative Calendar", retired: "", keyword: "PatientAlternativeCalendar" });
-	dicom_dictionary.insert(0x00100040, DicomDictElt { vr : "CS", vm: "1", name: "Patient's Sex", retired: "", keyword: "PatientSex" });
-	dicom_dictionary.insert(0x00100050, DicomDictElt { vr : "SQ", vm: "1", name: "Patient's Insurance Plan Code Sequence", retired: "", keyword: "PatientInsurancePlanCodeSequence" });
-	dicom_dictionary.insert(0x00100101, DicomDictElt { vr : "SQ", vm: "1", name: "Patient's Primary Language Code Sequence", retired: "", keyword: "PatientPrimaryLanguageCodeSequence" });
-	dicom_dictionary.insert(0x00100102, DicomDictElt { vr : "SQ", vm: "1", name: "Patient's Primary Language Modifier Code Sequence", retired: "", keyword: "PatientPrimaryLanguageModifierCodeSequence" });
-	dicom_dictionary.insert(0x00100200, DicomDictElt { vr : "CS", vm: "1", name: "Quality Control Subject", retired: "", keyword: "QualityControlSubject" });
-	dicom_dictionary.insert(0x00100201, DicomDictElt { vr : "SQ", vm: "1", name: "Quality Control Subject Type Code Sequence", retired: "", keyword: "QualityControlSubjectTypeCodeSequence" });
-	dicom_dictionary.insert(0x00100212, DicomDictElt { vr : "UC", vm: "1", name: "Strain Description", retired: "", keyword: "StrainDescription" });
-	dicom_dictionary.insert(0x00100213, DicomDictElt { vr : "LO", vm: "1", name: "Strain Nomenclature", retired: "", keyword: "StrainNomenclature" });
-	dicom_dictionary.insert(0x00100214, DicomDictElt { vr : "LO", vm: "1", name: "Strain Stock Number", retired: "", keyword: "StrainStockNumber" });
-	dicom_dictionary.insert(0x00100215, DicomDictElt { vr : "SQ", vm: "1", name: "Strain Source Registry Code Sequence", retired: "", keyword: "StrainSourceRegistryCodeSequence" });
-	dicom_dictionary.insert(0x00100216, DicomDictElt { vr : "SQ", vm: "1", name: "Strain Stock Sequence", retired: "", keyword: "StrainStockSequence" });
-	dicom_dictionary.insert(0x00100217, DicomDictElt { vr : "LO", vm: "1", name: "Strain Source", retired: "", keyword: "StrainSource" });
-	dicom_dictionary.insert(0x00100218, DicomDictElt { vr : "UT", vm: "1", name: "Strain Additional Information", retired: "", keyword: "StrainAdditionalInformation" });
-	dicom_dictionary.insert(0x00100219, DicomDictElt { vr : "SQ", vm: "1", name: "Strain Code Sequence", retired: "", keyword: "StrainCodeSequence" });
-	dicom_dictionary.insert(0x00101000, DicomDictElt { vr : "LO", vm: "1-n", name: "Other Patient IDs", retired: "", keyword: "OtherPatientIDs" });
-	dicom_dictionary.insert(0x00101001, DicomDictElt { vr : "PN", vm: "1-n", name: "Other Patient Names", retired: "", keyword: "OtherPatientNames" });
-	dicom_dictionary.insert(0x00101002, DicomDictElt { vr : "SQ", vm: "1", name: "Other Patient IDs Sequence", retired: "", keyword: "OtherPatientIDsSequence" });
-	dicom_dictionary.insert(0x00101005, DicomDictElt { vr : "PN", vm: "1", name: "Patient's Birth Name", retired: "", keyword: "PatientBirthName" });
-	dicom_dictionary.insert(0x00101010, DicomDictElt { vr : "AS", vm: "1", name: "Patient's Age", retired: "", keyword: "PatientAge" });
-	dicom_dictionary.insert(0x00101020, DicomDictElt { vr : "DS", vm: "1", name: "Patient's Size", retired: "", keyword: "PatientSize" });
-	dicom_dictionary.insert(0x00101021, DicomDictElt { vr : "SQ", vm: "1", name: "Patient's Size Code Sequence", retired: "", keyword: "PatientSizeCodeSequence" });
-	dicom_dictionary.insert(0x00101022, DicomDictElt { vr : "DS", vm: "1", name: "Patient's Body Mass Index", retired: "", keyword: "PatientBodyMassIndex" });
-	dicom_dictionary.insert(0x00101023, DicomDictElt { vr : "DS", vm: "1", name: "Measured AP Dimension", retired: "", keyword: "MeasuredAPDimension" });
-	dicom_dictionary.insert(0x00101024, DicomDictElt { vr : "DS", vm: "1", name: "Measured Lateral Dimension", retired: "", keyword: "MeasuredLateralDimension" });
-	dicom_dictionary.insert(0x00101030, DicomDictElt { vr : "DS", vm: "1", name: "Patient's Weight", retired: "", keyword: "PatientWeight" });
-	dicom_dictionary.insert(0x00101040, DicomDictElt { vr : "LO", vm: "1", name: "Patient's Address", retired: "", keyword: "PatientAddress" });
-	dicom_dictionary.insert(0x00101050, DicomDictElt { vr : "LO", vm: "1-n", name: "Insurance Plan Identification", retired: "Retired", keyword: "InsurancePlanIdentification" });
-	dicom_dictionary.insert(0x00101060, DicomDictElt { vr : "PN", vm: "1", name: "Patient's Mother's Birth Name", retired: "", keyword: "PatientMotherBirthName" });
-	dicom_dictionary.insert(0x00101080, DicomDictElt { vr : "LO", vm: "1", name: "Military Rank", retired: "", keyword: "MilitaryRank" });
-	dicom_dictionary.insert(0x00101081, DicomDictElt { vr : "LO", vm: "1", name: "Branch of Service", retired: "", keyword: "BranchOfService" });
-	dicom_dictionary.insert(0x00101090, DicomDictElt { vr : "LO", vm: "1", name: "Medical Record Locator", retired: "", keyword: "MedicalRecordLocator" });
-	dicom_dictionary.insert(0x00101100, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Patient Photo Sequence", retired: "", keyword: "ReferencedPatientPhotoSequence" });
-	dicom_dictionary.insert(0x00102000, DicomDictElt { vr : "LO", vm: "1-n", name: "Medical Alerts", retired: "", keyword: "MedicalAlerts" });
-	dicom_dictionary.insert(0x00102110, DicomDictElt { vr : "LO", vm: "1-n", name: "Allergies", retired: "", keyword: "Allergies" });
-	dicom_dictionary.insert(0x00102150, DicomDictElt { vr : "LO", vm: "1", name: "Country of Residence", retired: "", keyword: "CountryOfResidence" });
-	dicom_dictionary.insert(0x00102152, DicomDictElt { vr : "LO", vm: "1", name: "Region of Residence", retired: "", keyword: "RegionOfResidence" });
-	dicom_dictionary.insert(0x00102154, DicomDictElt { vr : "SH", vm: "1-n", name: "Patient's Telephone Numbers", retired: "", keyword: "PatientTelephoneNumbers" });
-	dicom_dictionary.insert(0x00102155, DicomDictElt { vr : "LT", vm: "1", name: "Patient's Telecom Information", retired: "", keyword: "PatientTelecomInformation" });
-	dicom_dictionary.insert(0x00102160, DicomDictElt { vr : "SH", vm: "1", name: "Ethnic Group", retired: "", keyword: "EthnicGroup" });
-	dicom_dictionary.insert(0x00102180, DicomDictElt { vr : "SH", vm: "1", name: "Occupation", retired: "", keyword: "Occupation" });
-	dicom_dictionary.insert(0x001021A0, DicomDictElt { vr : "CS", vm: "1", name: "Smoking Status", retired: "", keyword: "SmokingStatus" });
-	dicom_dictionary.insert(0x001021B0, DicomDictElt { vr : "LT", vm: "1", name: "Additional Patient History", retired: "", keyword: "AdditionalPatientHistory" });
-	dicom_dictionary.insert(0x001021C0, DicomDictElt { vr : "US", vm: "1", name: "Pregnancy Status", retired: "", keyword: "PregnancyStatus" });
-	dicom_dictionary.insert(0x001021D0, DicomDictElt { vr : "DA", vm: "1", name: "Last Menstrual Date", retired: "", keyword: "LastMenstrualDate" });
-	dicom_dictionary.insert(0x001021F0, DicomDictElt { vr : "LO", vm: "1", name: "Patient's Religious Preference", retired: "", keyword: "PatientReligiousPreference" });
-	dicom_dictionary.insert(0x00102201, DicomDictElt { vr : "LO", vm: "1", name: "Patient Species Description", retired: "", keyword: "PatientSpeciesDescription" });
-	dicom_dictionary.insert(0x00102202, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Species Code Sequence", retired: "", keyword: "PatientSpeciesCodeSequence" });
-	dicom_dictionary.insert(0x00102203, DicomDictElt { vr : "CS", vm: "1", name: "Patient's Sex Neutered", retired: "", keyword: "PatientSexNeutered" });
-	dicom_dictionary.insert(0x00102210, DicomDictElt { vr : "CS", vm: "1", name: "Anatomical Orientation Type", retired: "", keyword: "AnatomicalOrientationType" });
-	dicom_dictionary.insert(0x00102292, DicomDictElt { vr : "LO", vm: "1", name: "Patient Breed Description", retired: "", keyword: "PatientBreedDescription" });
-	dicom_dictionary.insert(0x00102293, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Breed Code Sequence", retired: "", keyword: "PatientBreedCodeSequence" });
-	dicom_dictionary.insert(0x00102294, DicomDictElt { vr : "SQ", vm: "1", name: "Breed Registration Sequence", retired: "", keyword: "BreedRegistrationSequence" });
-	dicom_dictionary.insert(0x00102295, DicomDictElt { vr : "LO", vm: "1", name: "Breed Registration Number", retired: "", keyword: "BreedRegistrationNumber" });
-	dicom_dictionary.insert(0x00102296, DicomDictElt { vr : "SQ", vm: "1", name: "Breed Registry Code Sequence", retired: "", keyword: "BreedRegistryCodeSequence" });
-	dicom_dictionary.insert(0x00102297, DicomDictElt { vr : "PN", vm: "1", name: "Responsible Person", retired: "", keyword: "ResponsiblePerson" });
-	dicom_dictionary.insert(0x00102298, DicomDictElt { vr : "CS", vm: "1", name: "Responsible Person Role", retired: "", keyword: "ResponsiblePersonRole" });
-	dicom_dictionary.insert(0x00102299, DicomDictElt { vr : "LO", vm: "1", name: "Responsible Organization", retired: "", keyword: "ResponsibleOrganization" });
-	dicom_dictionary.insert(0x00104000, DicomDictElt { vr : "LT", vm: "1", name: "Patient Comments", retired: "", keyword: "PatientComments" });
-	dicom_dictionary.insert(0x00109431, DicomDictElt { vr : "FL", vm: "1", name: "Examined Body Thickness", retired: "", keyword: "ExaminedBodyThickness" });
-	dicom_dictionary.insert(0x00120010, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Sponsor Name", retired: "", keyword: "ClinicalTrialSponsorName" });
-	dicom_dictionary.insert(0x00120020, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Protocol ID", retired: "", keyword: "ClinicalTrialProtocolID" });
-	dicom_dictionary.insert(0x00120021, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Protocol Name", retired: "", keyword: "ClinicalTrialProtocolName" });
-	dicom_dictionary.insert(0x00120030, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Site ID", retired: "", keyword: "ClinicalTrialSiteID" });
-	dicom_dictionary.insert(0x00120031, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Site Name", retired: "", keyword: "ClinicalTrialSiteName" });
-	dicom_dictionary.insert(0x00120040, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Subject ID", retired: "", keyword: "ClinicalTrialSubjectID" });
-	dicom_dictionary.insert(0x00120042, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Subject Reading ID", retired: "", keyword: "ClinicalTrialSubjectReadingID" });
-	dicom_dictionary.insert(0x00120050, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Time Point ID", retired: "", keyword: "ClinicalTrialTimePointID" });
-	dicom_dictionary.insert(0x00120051, DicomDictElt { vr : "ST", vm: "1", name: "Clinical Trial Time Point Description", retired: "", keyword: "ClinicalTrialTimePointDescription" });
-	dicom_dictionary.insert(0x00120060, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Coordinating Center Name", retired: "", keyword: "ClinicalTrialCoordinatingCenterName" });
-	dicom_dictionary.insert(0x00120062, DicomDictElt { vr : "CS", vm: "1", name: "Patient Identity Removed", retired: "", keyword: "PatientIdentityRemoved" });
-	dicom_dictionary.insert(0x00120063, DicomDictElt { vr : "LO", vm: "1-n", name: "De-identification Method", retired: "", keyword: "DeidentificationMethod" });
-	dicom_dictionary.insert(0x00120064, DicomDictElt { vr : "SQ", vm: "1", name: "De-identification Method Code Sequence", retired: "", keyword: "DeidentificationMethodCodeSequence" });
-	dicom_dictionary.insert(0x00120071, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Series ID", retired: "", keyword: "ClinicalTrialSeriesID" });
-	dicom_dictionary.insert(0x00120072, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Series Description", retired: "", keyword: "ClinicalTrialSeriesDescription" });
-	dicom_dictionary.insert(0x00120081, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Protocol Ethics Committee Name", retired: "", keyword: "ClinicalTrialProtocolEthicsCommitteeName" });
-	dicom_dictionary.insert(0x00120082, DicomDictElt { vr : "LO", vm: "1", name: "Clinical Trial Protocol Ethics Committee Approval Number", retired: "", keyword: "ClinicalTrialProtocolEthicsCommitteeApprovalNumber" });
-	dicom_dictionary.insert(0x00120083, DicomDictElt { vr : "SQ", vm: "1", name: "Consent for Clinical Trial Use Sequence", retired: "", keyword: "ConsentForClinicalTrialUseSequence" });
-	dicom_dictionary.insert(0x00120084, DicomDictElt { vr : "CS", vm: "1", name: "Distribution Type", retired: "", keyword: "DistributionType" });
-	dicom_dictionary.insert(0x00120085, DicomDictElt { vr : "CS", vm: "1", name: "Consent for Distribution Flag", retired: "", keyword: "ConsentForDistributionFlag" });
-	dicom_dictionary.insert(0x00120086, DicomDictElt { vr : "DA", vm: "1", name: "Ethics Committee Approval Effectiveness Start Date", retired: "", keyword: "EthicsCommitteeApprovalEffectivenessStartDate" });
-	dicom_dictionary.insert(0x00120087, DicomDictElt { vr : "DA", vm: "1", name: "Ethics Committee Approval Effectiveness End Date", retired: "", keyword: "EthicsCommitteeApprovalEffectivenessEndDate" });
-	dicom_dictionary.insert(0x00140023, DicomDictElt { vr : "ST", vm: "1", name: "CAD File Format", retired: "Retired", keyword: "CADFileFormat" });
-	dicom_dictionary.insert(0x00140024, DicomDictElt { vr : "ST", vm: "1", name: "Component Reference System", retired: "Retired", keyword: "ComponentReferenceSystem" });
-	dicom_dictionary.insert(0x00140025, DicomDictElt { vr : "ST", vm: "1", name: "Component Manufacturing Procedure", retired: "", keyword: "ComponentManufacturingProcedure" });
-	dicom_dictionary.insert(0x00140028, DicomDictElt { vr : "ST", vm: "1", name: "Component Manufacturer", retired: "", keyword: "ComponentManufacturer" });
-	dicom_dictionary.insert(0x00140030, DicomDictElt { vr : "DS", vm: "1-n", name: "Material Thickness", retired: "", keyword: "MaterialThickness" });
-	dicom_dictionary.insert(0x00140032, DicomDictElt { vr : "DS", vm: "1-n", name: "Material Pipe Diameter", retired: "", keyword: "MaterialPipeDiameter" });
-	dicom_dictionary.insert(0x00140034, DicomDictElt { vr : "DS", vm: "1-n", name: "Material Isolation Diameter", retired: "", keyword: "MaterialIsolationDiameter" });
-	dicom_dictionary.insert(0x00140042, DicomDictElt { vr : "ST", vm: "1", name: "Material Grade", retired: "", keyword: "MaterialGrade" });
-	dicom_dictionary.insert(0x00140044, DicomDictElt { vr : "ST", vm: "1", name: "Material Properties Description", retired: "", keyword: "MaterialPropertiesDescription" });
-	dicom_dictionary.insert(0x00140045, DicomDictElt { vr : "ST", vm: "1", name: "Material Properties File Format (Retired)", retired: "Retired", keyword: "MaterialPropertiesFileFormatRetired" });
-	dicom_dictionary.insert(0x00140046, DicomDictElt { vr : "LT", vm: "1", name: "Material Notes", retired: "", keyword: "MaterialNotes" });
-	dicom_dictionary.insert(0x00140050, DicomDictElt { vr : "CS", vm: "1", name: "Component Shape", retired: "", keyword: "ComponentShape" });
-	dicom_dictionary.insert(0x00140052, DicomDictElt { vr : "CS", vm: "1", name: "Curvature Type", retired: "", keyword: "CurvatureType" });
-	dicom_dictionary.insert(0x00140054, DicomDictElt { vr : "DS", vm: "1", name: "Outer Diameter", retired: "", keyword: "OuterDiameter" });
-	dicom_dictionary.insert(0x00140056, DicomDictElt { vr : "DS", vm: "1", name: "Inner Diameter", retired: "", keyword: "InnerDiameter" });
-	dicom_dictionary.insert(0x00140100, DicomDictElt { vr : "LO", vm: "1-n", name: "Component Welder IDs", retired: "", keyword: "ComponentWelderIDs" });
-	dicom_dictionary.insert(0x00140101, DicomDictElt { vr : "CS", vm: "1", name: "Secondary Approval Status", retired: "", keyword: "SecondaryApprovalStatus" });
-	dicom_dictionary.insert(0x00140102, DicomDictElt { vr : "DA", vm: "1", name: "Secondary Review Date", retired: "", keyword: "SecondaryReviewDate" });
-	dicom_dictionary.insert(0x00140103, DicomDictElt { vr : "TM", vm: "1", name: "Secondary Review Time", retired: "", keyword: "SecondaryReviewTime" });
-	dicom_dictionary.insert(0x00140104, DicomDictElt { vr : "PN", vm: "1", name: "Secondary Reviewer Name", retired: "", keyword: "SecondaryReviewerName" });
-	dicom_dictionary.insert(0x00140105, DicomDictElt { vr : "ST", vm: "1", name: "Repair ID", retired: "", keyword: "RepairID" });
-	dicom_dictionary.insert(0x00140106, DicomDictElt { vr : "SQ", vm: "1", name: "Multiple Component Approval Sequence", retired: "", keyword: "MultipleComponentApprovalSequence" });
-	dicom_dictionary.insert(0x00140107, DicomDictElt { vr : "CS", vm: "1-n", name: "Other Approval Status", retired: "", keyword: "OtherApprovalStatus" });
-	dicom_dictionary.insert(0x00140108, DicomDictElt { vr : "CS", vm: "1-n", name: "Other Secondary Approval Status", retired: "", keyword: "OtherSecondaryApprovalStatus" });
-	dicom_dictionary.insert(0x00141010, DicomDictElt { vr : "ST", vm: "1", name: "Actual Environmental Conditions", retired: "", keyword: "ActualEnvironmentalConditions" });
-	dicom_dictionary.insert(0x00141020, DicomDictElt { vr : "DA", vm: "1", name: "Expiry Date", retired: "", keyword: "ExpiryDate" });
-	dicom_dictionary.insert(0x00141040, DicomDictElt { vr : "ST", vm: "1", name: "Environmental Conditions", retired: "", keyword: "EnvironmentalConditions" });
-	dicom_dictionary.insert(0x00142002, DicomDictElt { vr : "SQ", vm: "1", name: "Evaluator Sequence", retired: "", keyword: "EvaluatorSequence" });
-	dicom_dictionary.insert(0x00142004, DicomDictElt { vr : "IS", vm: "1", name: "Evaluator Number", retired: "", keyword: "EvaluatorNumber" });
-	dicom_dictionary.insert(0x00142006, DicomDictElt { vr : "PN", vm: "1", name: "Evaluator Name", retired: "", keyword: "EvaluatorName" });
-	dicom_dictionary.insert(0x00142008, DicomDictElt { vr : "IS", vm: "1", name: "Evaluation Attempt", retired: "", keyword: "EvaluationAttempt" });
-	dicom_dictionary.insert(0x00142012, DicomDictElt { vr : "SQ", vm: "1", name: "Indication Sequence", retired: "", keyword: "IndicationSequence" });
-	dicom_dictionary.insert(0x00142014, DicomDictElt { vr : "IS", vm: "1", name: "Indication Number", retired: "", keyword: "IndicationNumber" });
-	dicom_dictionary.insert(0x00142016, DicomDictElt { vr : "SH", vm: "1", name: "Indication Label", retired: "", keyword: "IndicationLabel" });
-	dicom_dictionary.insert(0x00142018, DicomDictElt { vr : "ST", vm: "1", name: "Indication Description", retired: "", keyword: "IndicationDescription" });
-	dicom_dictionary.insert(0x0014201A, DicomDictElt { vr : "CS", vm: "1-n", name: "Indication Type", retired: "", keyword: "IndicationType" });
-	dicom_dictionary.insert(0x0014201C, DicomDictElt { vr : "CS", vm: "1", name: "Indication Disposition", retired: "", keyword: "IndicationDisposition" });
-	dicom_dictionary.insert(0x0014201E, DicomDictElt { vr : "SQ", vm: "1", name: "Indication ROI Sequence", retired: "", keyword: "IndicationROISequence" });
-	dicom_dictionary.insert(0x00142030, DicomDictElt { vr : "SQ", vm: "1", name: "Indication Physical Property Sequence", retired: "", keyword: "IndicationPhysicalPropertySequence" });
-	dicom_dictionary.insert(0x00142032, DicomDictElt { vr : "SH", vm: "1", name: "Property Label", retired: "", keyword: "PropertyLabel" });
-	dicom_dictionary.insert(0x00142202, DicomDictElt { vr : "IS", vm: "1", name: "Coordinate System Number of Axes", retired: "", keyword: "CoordinateSystemNumberOfAxes" });
-	dicom_dictionary.insert(0x00142204, DicomDictElt { vr : "SQ", vm: "1", name: "Coordinate System Axes Sequence", retired: "", keyword: "CoordinateSystemAxesSequence" });
-	dicom_dictionary.insert(0x00142206, DicomDictElt { vr : "ST", vm: "1", name: "Coordinate System Axis Description", retired: "", keyword: "CoordinateSystemAxisDescription" });
-	dicom_dictionary.insert(0x00142208, DicomDictElt { vr : "CS", vm: "1", name: "Coordinate System Data Set Mapping", retired: "", keyword: "CoordinateSystemDataSetMapping" });
-	dicom_dictionary.insert(0x0014220A, DicomDictElt { vr : "IS", vm: "1", name: "Coordinate System Axis Number", retired: "", keyword: "CoordinateSystemAxisNumber" });
-	dicom_dictionary.insert(0x0014220C, DicomDictElt { vr : "CS", vm: "1", name: "Coordinate System Axis Type", retired: "", keyword: "CoordinateSystemAxisType" });
-	dicom_dictionary.insert(0x0014220E, DicomDictElt { vr : "CS", vm: "1", name: "Coordinate System Axis Units", retired: "", keyword: "CoordinateSystemAxisUnits" });
-	dicom_dictionary.insert(0x00142210, DicomDictElt { vr : "OB", vm: "1", name: "Coordinate System Axis Values", retired: "", keyword: "CoordinateSystemAxisValues" });
-	dicom_dictionary.insert(0x00142220, DicomDictElt { vr : "SQ", vm: "1", name: "Coordinate System Transform Sequence", retired: "", keyword: "CoordinateSystemTransformSequence" });
-	dicom_dictionary.insert(0x00142222, DicomDictElt { vr : "ST", vm: "1", name: "Transform Description", retired: "", keyword: "TransformDescription" });
-	dicom_dictionary.insert(0x00142224, DicomDictElt { vr : "IS", vm: "1", name: "Transform Number of Axes", retired: "", keyword: "TransformNumberOfAxes" });
-	dicom_dictionary.insert(0x00142226, DicomDictElt { vr : "IS", vm: "1-n", name: "Transform Order of Axes", retired: "", keyword: "TransformOrderOfAxes" });
-	dicom_dictionary.insert(0x00142228, DicomDictElt { vr : "CS", vm: "1", name: "Transformed Axis Units", retired: "", keyword: "TransformedAxisUnits" });
-	dicom_dictionary.insert(0x0014222A, DicomDictElt { vr : "DS", vm: "1-n", name: "Coordinate System Transform Rotation and Scale Matrix", retired: "", keyword: "CoordinateSystemTransformRotationAndScaleMatrix" });
-	dicom_dictionary.insert(0x0014222C, DicomDictElt { vr : "DS", vm: "1-n", name: "Coordinate System Transform Translation Matrix", retired: "", keyword: "CoordinateSystemTransformTranslationMatrix" });
-	dicom_dictionary.insert(0x00143011, DicomDictElt { vr : "DS", vm: "1", name: "Internal Detector Frame Time", retired: "", keyword: "InternalDetectorFrameTime" });
-	dicom_dictionary.insert(0x00143012, DicomDictElt { vr : "DS", vm: "1", name: "Number of Frames Integrated", retired: "", keyword: "NumberOfFramesIntegrated" });
-	dicom_dictionary.insert(0x00143020, DicomDictElt { vr : "SQ", vm: "1", name: "Detector Temperature Sequence", retired: "", keyword: "DetectorTemperatureSequence" });
-	dicom_dictionary.insert(0x00143022, DicomDictElt { vr : "ST", vm: "1", name: "Sensor Name", retired: "", keyword: "SensorName" });
-	dicom_dictionary.insert(0x00143024, DicomDictElt { vr : "DS", vm: "1", name: "Horizontal Offset of Sensor", retired: "", keyword: "HorizontalOffsetOfSensor" });
-	dicom_dictionary.insert(0x00143026, DicomDictElt { vr : "DS", vm: "1", name: "Vertical Offset of Sensor", retired: "", keyword: "VerticalOffsetOfSensor" });
-	dicom_dictionary.insert(0x00143028, DicomDictElt { vr : "DS", vm: "1", name: "Sensor Temperature", retired: "", keyword: "SensorTemperature" });
-	dicom_dictionary.insert(0x00143040, DicomDictElt { vr : "SQ", vm: "1", name: "Dark Current Sequence", retired: "", keyword: "DarkCurrentSequence" });
-	dicom_dictionary.insert(0x00143050, DicomDictElt { vr : "OB or OW", vm: "1", name: "Dark Current Counts", retired: "", keyword: "DarkCurrentCounts" });
-	dicom_dictionary.insert(0x00143060, DicomDictElt { vr : "SQ", vm: "1", name: "Gain Correction Reference Sequence", retired: "", keyword: "GainCorrectionReferenceSequence" });
-	dicom_dictionary.insert(0x00143070, DicomDictElt { vr : "OB or OW", vm: "1", name: "Air Counts", retired: "", keyword: "AirCounts" });
-	dicom_dictionary.insert(0x00143071, DicomDictElt { vr : "DS", vm: "1", name: "KV Used in Gain Calibration", retired: "", keyword: "KVUsedInGainCalibration" });
-	dicom_dictionary.insert(0x00143072, DicomDictElt { vr : "DS", vm: "1", name: "MA Used in Gain Calibration", retired: "", keyword: "MAUsedInGainCalibration" });
-	dicom_dictionary.insert(0x00143073, DicomDictElt { vr : "DS", vm: "1", name: "Number of Frames Used for Integration", retired: "", keyword: "NumberOfFramesUsedForIntegration" });
-	dicom_dictionary.insert(0x00143074, DicomDictElt { vr : "LO", vm: "1", name: "Filter Material Used in Gain Calibration", retired: "", keyword: "FilterMaterialUsedInGainCalibration" });
-	dicom_dictionary.insert(0x00143075, DicomDictElt { vr : "DS", vm: "1", name: "Filter Thickness Used in Gain Calibration", retired: "", keyword: "FilterThicknessUsedInGainCalibration" });
-	dicom_dictionary.insert(0x00143076, DicomDictElt { vr : "DA", vm: "1", name: "Date of Gain Calibration", retired: "", keyword: "DateOfGainCalibration" });
-	dicom_dictionary.insert(0x00143077, DicomDictElt { vr : "TM", vm: "1", name: "Time of Gain Calibration", retired: "", keyword: "TimeOfGainCalibration" });
-	dicom_dictionary.insert(0x00143080, DicomDictElt { vr : "OB", vm: "1", name: "Bad Pixel Image", retired: "", keyword: "BadPixelImage" });
-	dicom_dictionary.insert(0x00143099, DicomDictElt { vr : "LT", vm: "1", name: "Calibration Notes", retired: "", keyword: "CalibrationNotes" });
-	dicom_dictionary.insert(0x00144002, DicomDictElt { vr : "SQ", vm: "1", name: "Pulser Equipment Sequence", retired: "", keyword: "PulserEquipmentSequence" });
-	dicom_dictionary.insert(0x00144004, DicomDictElt { vr : "CS", vm: "1", name: "Pulser Type", retired: "", keyword: "PulserType" });
-	dicom_dictionary.insert(0x00144006, DicomDictElt { vr : "LT", vm: "1", name: "Pulser Notes", retired: "", keyword: "PulserNotes" });
-	dicom_dictionary.insert(0x00144008, DicomDictElt { vr : "SQ", vm: "1", name: "Receiver Equipment Sequence", retired: "", keyword: "ReceiverEquipmentSequence" });
-	dicom_dictionary.insert(0x0014400A, DicomDictElt { vr : "CS", vm: "1", name: "Amplifier Type", retired: "", keyword: "AmplifierType" });
-	dicom_dictionary.insert(0x0014400C, DicomDictElt { vr : "LT", vm: "1", name: "Receiver Notes", retired: "", keyword: "ReceiverNotes" });
-	dicom_dictionary.insert(0x0014400E, DicomDictElt { vr : "SQ", vm: "1", name: "Pre-Amplifier Equipment Sequence", retired: "", keyword: "PreAmplifierEquipmentSequence" });
-	dicom_dictionary.insert(0x0014400F, DicomDictElt { vr : "LT", vm: "1", name: "Pre-Amplifier Notes", retired: "", keyword: "PreAmplifierNotes" });
-	dicom_dictionary.insert(0x00144010, DicomDictElt { vr : "SQ", vm: "1", name: "Transmit Transducer Sequence", retired: "", keyword: "TransmitTransducerSequence" });
-	dicom_dictionary.insert(0x00144011, DicomDictElt { vr : "SQ", vm: "1", name: "Receive Transducer Sequence", retired: "", keyword: "ReceiveTransducerSequence" });
-	dicom_dictionary.insert(0x00144012, DicomDictElt { vr : "US", vm: "1", name: "Number of Elements", retired: "", keyword: "NumberOfElements" });
-	dicom_dictionary.insert(0x00144013, DicomDictElt { vr : "CS", vm: "1", name: "Element Shape", retired: "", keyword: "ElementShape" });
-	dicom_dictionary.insert(0x00144014, DicomDictElt { vr : "DS", vm: "1", name: "Element Dimension A", retired: "", keyword: "ElementDimensionA" });
-	dicom_dictionary.insert(0x00144015, DicomDictElt { vr : "DS", vm: "1", name: "Element Dimension B", retired: "", keyword: "ElementDimensionB" });
-	dicom_dictionary.insert(0x00144016, DicomDictElt { vr : "DS", vm: "1", name: "Element Pitch A", retired: "", keyword: "ElementPitchA" });
-	dicom_dictionary.insert(0x00144017, DicomDictElt { vr : "DS", vm: "1", name: "Measured Beam Dimension A", retired: "", keyword: "MeasuredBeamDimensionA" });
-	dicom_dictionary.insert(0x00144018, DicomDictElt { vr : "DS", vm: "1", name: "Measured Beam Dimension B", retired: "", keyword: "MeasuredBeamDimensionB" });
-	dicom_dictionary.insert(0x00144019, DicomDictElt { vr : "DS", vm: "1", name: "Location of Measured Beam Diameter", retired: "", keyword: "LocationOfMeasuredBeamDiameter" });
-	dicom_dictionary.insert(0x0014401A, DicomDictElt { vr : "DS", vm: "1", name: "Nominal Frequency", retired: "", keyword: "NominalFrequency" });
-	dicom_dictionary.insert(0x0014401B, DicomDictElt { vr : "DS", vm: "1", name: "Measured Center Frequency", retired: "", keyword: "MeasuredCenterFrequency" });
-	dicom_dictionary.insert(0x0014401C, DicomDictElt { vr : "DS", vm: "1", name: "Measured Bandwidth", retired: "", keyword: "MeasuredBandwidth" });
-	dicom_dictionary.insert(0x0014401D, DicomDictElt { vr : "DS", vm: "1", name: "Element Pitch B", retired: "", keyword: "ElementPitchB" });
-	dicom_dictionary.insert(0x00144020, DicomDictElt { vr : "SQ", vm: "1", name: "Pulser Settings Sequence", retired: "", keyword: "PulserSettingsSequence" });
-	dicom_dictionary.insert(0x00144022, DicomDictElt { vr : "DS", vm: "1", name: "Pulse Width", retired: "", keyword: "PulseWidth" });
-	dicom_dictionary.insert(0x00144024, DicomDictElt { vr : "DS", vm: "1", name: "Excitation Frequency", retired: "", keyword: "ExcitationFrequency" });
-	dicom_dictionary.insert(0x00144026, DicomDictElt { vr : "CS", vm: "1", name: "Modulation Type", retired: "", keyword: "ModulationType" });
-	dicom_dictionary.insert(0x00144028, DicomDictElt { vr : "DS", vm: "1", name: "Damping", retired: "", keyword: "Damping" });
-	dicom_dictionary.insert(0x00144030, DicomDictElt { vr : "SQ", vm: "1", name: "Receiver Settings Sequence", retired: "", keyword: "ReceiverSettingsSequence" });
-	dicom_dictionary.insert(0x00144031, DicomDictElt { vr : "DS", vm: "1", name: "Acquired Soundpath Length", retired: "", keyword: "AcquiredSoundpathLength" });
-	dicom_dictionary.insert(0x00144032, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Compression Type", retired: "", keyword: "AcquisitionCompressionType" });
-	dicom_dictionary.insert(0x00144033, DicomDictElt { vr : "IS", vm: "1", name: "Acquisition Sample Size", retired: "", keyword: "AcquisitionSampleSize" });
-	dicom_dictionary.insert(0x00144034, DicomDictElt { vr : "DS", vm: "1", name: "Rectifier Smoothing", retired: "", keyword: "RectifierSmoothing" });
-	dicom_dictionary.insert(0x00144035, DicomDictElt { vr : "SQ", vm: "1", name: "DAC Sequence", retired: "", keyword: "DACSequence" });
-	dicom_dictionary.insert(0x00144036, DicomDictElt { vr : "CS", vm: "1", name: "DAC Type", retired: "", keyword: "DACType" });
-	dicom_dictionary.insert(0x00144038, DicomDictElt { vr : "DS", vm: "1-n", name: "DAC Gain Points", retired: "", keyword: "DACGainPoints" });
-	dicom_dictionary.insert(0x0014403A, DicomDictElt { vr : "DS", vm: "1-n", name: "DAC Time Points", retired: "", keyword: "DACTimePoints" });
-	dicom_dictionary.insert(0x0014403C, DicomDictElt { vr : "DS", vm: "1-n", name: "DAC Amplitude", retired: "", keyword: "DACAmplitude" });
-	dicom_dictionary.insert(0x00144040, DicomDictElt { vr : "SQ", vm: "1", name: "Pre-Amplifier Settings Sequence", retired: "", keyword: "PreAmplifierSettingsSequence" });
-	dicom_dictionary.insert(0x00144050, DicomDictElt { vr : "SQ", vm: "1", name: "Transmit Transducer Settings Sequence", retired: "", keyword: "TransmitTransducerSettingsSequence" });
-	dicom_dictionary.insert(0x00144051, DicomDictElt { vr : "SQ", vm: "1", name: "Receive Transducer Settings Sequence", retired: "", keyword: "ReceiveTransducerSettingsSequence" });
-	dicom_dictionary.insert(0x00144052, DicomDictElt { vr : "DS", vm: "1", name: "Incident Angle", retired: "", keyword: "IncidentAngle" });
-	dicom_dictionary.insert(0x00144054, DicomDictElt { vr : "ST", vm: "1", name: "Coupling Technique", retired: "", keyword: "CouplingTechnique" });
-	dicom_dictionary.insert(0x00144056, DicomDictElt { vr : "ST", vm: "1", name: "Coupling Medium", retired: "", keyword: "CouplingMedium" });
-	dicom_dictionary.insert(0x00144057, DicomDictElt { vr : "DS", vm: "1", name: "Coupling Velocity", retired: "", keyword: "CouplingVelocity" });
-	dicom_dictionary.insert(0x00144058, DicomDictElt { vr : "DS", vm: "1", name: "Probe Center Location X", retired: "", keyword: "ProbeCenterLocationX" });
-	dicom_dictionary.insert(0x00144059, DicomDictElt { vr : "DS", vm: "1", name: "Probe Center Location Z", retired: "", keyword: "ProbeCenterLocationZ" });
-	dicom_dictionary.insert(0x0014405A, DicomDictElt { vr : "DS", vm: "1", name: "Sound Path Length", retired: "", keyword: "SoundPathLength" });
-	dicom_dictionary.insert(0x0014405C, DicomDictElt { vr : "ST", vm: "1", name: "Delay Law Identifier", retired: "", keyword: "DelayLawIdentifier" });
-	dicom_dictionary.insert(0x00144060, DicomDictElt { vr : "SQ", vm: "1", name: "Gate Settings Sequence", retired: "", keyword: "GateSettingsSequence" });
-	dicom_dictionary.insert(0x00144062, DicomDictElt { vr : "DS", vm: "1", name: "Gate Threshold", retired: "", keyword: "GateThreshold" });
-	dicom_dictionary.insert(0x00144064, DicomDictElt { vr : "DS", vm: "1", name: "Velocity of Sound", retired: "", keyword: "VelocityOfSound" });
-	dicom_dictionary.insert(0x00144070, DicomDictElt { vr : "SQ", vm: "1", name: "Calibration Settings Sequence", retired: "", keyword: "CalibrationSettingsSequence" });
-	dicom_dictionary.insert(0x00144072, DicomDictElt { vr : "ST", vm: "1", name: "Calibration Procedure", retired: "", keyword: "CalibrationProcedure" });
-	dicom_dictionary.insert(0x00144074, DicomDictElt { vr : "SH", vm: "1", name: "Procedure Version", retired: "", keyword: "ProcedureVersion" });
-	dicom_dictionary.insert(0x00144076, DicomDictElt { vr : "DA", vm: "1", name: "Procedure Creation Date", retired: "", keyword: "ProcedureCreationDate" });
-	dicom_dictionary.insert(0x00144078, DicomDictElt { vr : "DA", vm: "1", name: "Procedure Expiration Date", retired: "", keyword: "ProcedureExpirationDate" });
-	dicom_dictionary.insert(0x0014407A, DicomDictElt { vr : "DA", vm: "1", name: "Procedure Last Modified Date", retired: "", keyword: "ProcedureLastModifiedDate" });
-	dicom_dictionary.insert(0x0014407C, DicomDictElt { vr : "TM", vm: "1-n", name: "Calibration Time", retired: "", keyword: "CalibrationTime" });
-	dicom_dictionary.insert(0x0014407E, DicomDictElt { vr : "DA", vm: "1-n", name: "Calibration Date", retired: "", keyword: "CalibrationDate" });
-	dicom_dictionary.insert(0x00144080, DicomDictElt { vr : "SQ", vm: "1", name: "Probe Drive Equipment Sequence", retired: "", keyword: "ProbeDriveEquipmentSequence" });
-	dicom_dictionary.insert(0x00144081, DicomDictElt { vr : "CS", vm: "1", name: "Drive Type", retired: "", keyword: "DriveType" });
-	dicom_dictionary.insert(0x00144082, DicomDictElt { vr : "LT", vm: "1", name: "Probe Drive Notes", retired: "", keyword: "ProbeDriveNotes" });
-	dicom_dictionary.insert(0x00144083, DicomDictElt { vr : "SQ", vm: "1", name: "Drive Probe Sequence", retired: "", keyword: "DriveProbeSequence" });
-	dicom_dictionary.insert(0x00144084, DicomDictElt { vr : "DS", vm: "1", name: "Probe Inductance", retired: "", keyword: "ProbeInductance" });
-	dicom_dictionary.insert(0x00144085, DicomDictElt { vr : "DS", vm: "1", name: "Probe Resistance", retired: "", keyword: "ProbeResistance" });
-	dicom_dictionary.insert(0x00144086, DicomDictElt { vr : "SQ", vm: "1", name: "Receive Probe Sequence", retired: "", keyword: "ReceiveProbeSequence" });
-	dicom_dictionary.insert(0x00144087, DicomDictElt { vr : "SQ", vm: "1", name: "Probe Drive Settings Sequence", retired: "", keyword: "ProbeDriveSettingsSequence" });
-	dicom_dictionary.insert(0x00144088, DicomDictElt { vr : "DS", vm: "1", name: "Bridge Resistors", retired: "", keyword: "BridgeResistors" });
-	dicom_dictionary.insert(0x00144089, DicomDictElt { vr : "DS", vm: "1", name: "Probe Orientation Angle", retired: "", keyword: "ProbeOrientationAngle" });
-	dicom_dictionary.insert(0x0014408B, DicomDictElt { vr : "DS", vm: "1", name: "User Selected Gain Y", retired: "", keyword: "UserSelectedGainY" });
-	dicom_dictionary.insert(0x0014408C, DicomDictElt { vr : "DS", vm: "1", name: "User Selected Phase", retired: "", keyword: "UserSelectedPhase" });
-	dicom_dictionary.insert(0x0014408D, DicomDictElt { vr : "DS", vm: "1", name: "User Selected Offset X", retired: "", keyword: "UserSelectedOffsetX" });
-	dicom_dictionary.insert(0x0014408E, DicomDictElt { vr : "DS", vm: "1", name: "User Selected Offset Y", retired: "", keyword: "UserSelectedOffsetY" });
-	dicom_dictionary.insert(0x00144091, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Settings Sequence", retired: "", keyword: "ChannelSettingsSequence" });
-	dicom_dictionary.insert(0x00144092, DicomDictElt { vr : "DS", vm: "1", name: "Channel Threshold", retired: "", keyword: "ChannelThreshold" });
-	dicom_dictionary.insert(0x0014409A, DicomDictElt { vr : "SQ", vm: "1", name: "Scanner Settings Sequence", retired: "", keyword: "ScannerSettingsSequence" });
-	dicom_dictionary.insert(0x0014409B, DicomDictElt { vr : "ST", vm: "1", name: "Scan Procedure", retired: "", keyword: "ScanProcedure" });
-	dicom_dictionary.insert(0x0014409C, DicomDictElt { vr : "DS", vm: "1", name: "Translation Rate X", retired: "", keyword: "TranslationRateX" });
-	dicom_dictionary.insert(0x0014409D, DicomDictElt { vr : "DS", vm: "1", name: "Translation Rate Y", retired: "", keyword: "TranslationRateY" });
-	dicom_dictionary.insert(0x0014409F, DicomDictElt { vr : "DS", vm: "1", name: "Channel Overlap", retired: "", keyword: "ChannelOverlap" });
-	dicom_dictionary.insert(0x001440A0, DicomDictElt { vr : "LO", vm: "1", name: "Image Quality Indicator Type", retired: "", keyword: "ImageQualityIndicatorType" });
-	dicom_dictionary.insert(0x001440A1, DicomDictElt { vr : "LO", vm: "1", name: "Image Quality Indicator Material", retired: "", keyword: "ImageQualityIndicatorMaterial" });
-	dicom_dictionary.insert(0x001440A2, DicomDictElt { vr : "LO", vm: "1", name: "Image Quality Indicator Size", retired: "", keyword: "ImageQualityIndicatorSize" });
-	dicom_dictionary.insert(0x00145002, DicomDictElt { vr : "IS", vm: "1", name: "LINAC Energy", retired: "", keyword: "LINACEnergy" });
-	dicom_dictionary.insert(0x00145004, DicomDictElt { vr : "IS", vm: "1", name: "LINAC Output", retired: "", keyword: "LINACOutput" });
-	dicom_dictionary.insert(0x00145100, DicomDictElt { vr : "US", vm: "1", name: "Active Aperture", retired: "", keyword: "ActiveAperture" });
-	dicom_dictionary.insert(0x00145101, DicomDictElt { vr : "DS", vm: "1", name: "Total Aperture", retired: "", keyword: "TotalAperture" });
-	dicom_dictionary.insert(0x00145102, DicomDictElt { vr : "DS", vm: "1", name: "Aperture Elevation", retired: "", keyword: "ApertureElevation" });
-	dicom_dictionary.insert(0x00145103, DicomDictElt { vr : "DS", vm: "1", name: "Main Lobe Angle", retired: "", keyword: "MainLobeAngle" });
-	dicom_dictionary.insert(0x00145104, DicomDictElt { vr : "DS", vm: "1", name: "Main Roof Angle", retired: "", keyword: "MainRoofAngle" });
-	dicom_dictionary.insert(0x00145105, DicomDictElt { vr : "CS", vm: "1", name: "Connector Type", retired: "", keyword: "ConnectorType" });
-	dicom_dictionary.insert(0x00145106, DicomDictElt { vr : "SH", vm: "1", name: "Wedge Model Number", retired: "", keyword: "WedgeModelNumber" });
-	dicom_dictionary.insert(0x00145107, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Angle Float", retired: "", keyword: "WedgeAngleFloat" });
-	dicom_dictionary.insert(0x00145108, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Roof Angle", retired: "", keyword: "WedgeRoofAngle" });
-	dicom_dictionary.insert(0x00145109, DicomDictElt { vr : "CS", vm: "1", name: "Wedge Element 1 Position", retired: "", keyword: "WedgeElement1Position" });
-	dicom_dictionary.insert(0x0014510A, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Material Velocity", retired: "", keyword: "WedgeMaterialVelocity" });
-	dicom_dictionary.insert(0x0014510B, DicomDictElt { vr : "SH", vm: "1", name: "Wedge Material", retired: "", keyword: "WedgeMaterial" });
-	dicom_dictionary.insert(0x0014510C, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Offset Z", retired: "", keyword: "WedgeOffsetZ" });
-	dicom_dictionary.insert(0x0014510D, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Origin Offset X", retired: "", keyword: "WedgeOriginOffsetX" });
-	dicom_dictionary.insert(0x0014510E, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Time Delay", retired: "", keyword: "WedgeTimeDelay" });
-	dicom_dictionary.insert(0x0014510F, DicomDictElt { vr : "SH", vm: "1", name: "Wedge Name", retired: "", keyword: "WedgeName" });
-	dicom_dictionary.insert(0x00145110, DicomDictElt { vr : "SH", vm: "1", name: "Wedge Manufacturer Name", retired: "", keyword: "WedgeManufacturerName" });
-	dicom_dictionary.insert(0x00145111, DicomDictElt { vr : "LO", vm: "1", name: "Wedge Description", retired: "", keyword: "WedgeDescription" });
-	dicom_dictionary.insert(0x00145112, DicomDictElt { vr : "DS", vm: "1", name: "Nominal Beam Angle", retired: "", keyword: "NominalBeamAngle" });
-	dicom_dictionary.insert(0x00145113, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Offset X", retired: "", keyword: "WedgeOffsetX" });
-	dicom_dictionary.insert(0x00145114, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Offset Y", retired: "", keyword: "WedgeOffsetY" });
-	dicom_dictionary.insert(0x00145115, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Total Length", retired: "", keyword: "WedgeTotalLength" });
-	dicom_dictionary.insert(0x00145116, DicomDictElt { vr : "DS", vm: "1", name: "Wedge In Contact Length", retired: "", keyword: "WedgeInContactLength" });
-	dicom_dictionary.insert(0x00145117, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Front Gap", retired: "", keyword: "WedgeFrontGap" });
-	dicom_dictionary.insert(0x00145118, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Total Height", retired: "", keyword: "WedgeTotalHeight" });
-	dicom_dictionary.insert(0x00145119, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Front Height", retired: "", keyword: "WedgeFrontHeight" });
-	dicom_dictionary.insert(0x0014511A, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Rear Height", retired: "", keyword: "WedgeRearHeight" });
-	dicom_dictionary.insert(0x0014511B, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Total Width", retired: "", keyword: "WedgeTotalWidth" });
-	dicom_dictionary.insert(0x0014511C, DicomDictElt { vr : "DS", vm: "1", name: "Wedge In Contact Width", retired: "", keyword: "WedgeInContactWidth" });
-	dicom_dictionary.insert(0x0014511D, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Chamfer Height", retired: "", keyword: "WedgeChamferHeight" });
-	dicom_dictionary.insert(0x0014511E, DicomDictElt { vr : "CS", vm: "1", name: "Wedge Curve", retired: "", keyword: "WedgeCurve" });
-	dicom_dictionary.insert(0x0014511F, DicomDictElt { vr : "DS", vm: "1", name: "Radius Along the Wedge", retired: "", keyword: "RadiusAlongWedge" });
-	dicom_dictionary.insert(0x00180010, DicomDictElt { vr : "LO", vm: "1", name: "Contrast/Bolus Agent", retired: "", keyword: "ContrastBolusAgent" });
-	dicom_dictionary.insert(0x00180012, DicomDictElt { vr : "SQ", vm: "1", name: "Contrast/Bolus Agent Sequence", retired: "", keyword: "ContrastBolusAgentSequence" });
-	dicom_dictionary.insert(0x00180013, DicomDictElt { vr : "FL", vm: "1", name: "Contrast/Bolus T1 Relaxivity", retired: "", keyword: "ContrastBolusT1Relaxivity" });
-	dicom_dictionary.insert(0x00180014, DicomDictElt { vr : "SQ", vm: "1", name: "Contrast/Bolus Administration Route Sequence", retired: "", keyword: "ContrastBolusAdministrationRouteSequence" });
-	dicom_dictionary.insert(0x00180015, DicomDictElt { vr : "CS", vm: "1", name: "Body Part Examined", retired: "", keyword: "BodyPartExamined" });
-	dicom_dictionary.insert(0x00180020, DicomDictElt { vr : "CS", vm: "1-n", name: "Scanning Sequence", retired: "", keyword: "ScanningSequence" });
-	dicom_dictionary.insert(0x00180021, DicomDictElt { vr : "CS", vm: "1-n", name: "Sequence Variant", retired: "", keyword: "SequenceVariant" });
-	dicom_dictionary.insert(0x00180022, DicomDictElt { vr : "CS", vm: "1-n", name: "Scan Options", retired: "", keyword: "ScanOptions" });
-	dicom_dictionary.insert(0x00180023, DicomDictElt { vr : "CS", vm: "1", name: "MR Acquisition Type", retired: "", keyword: "MRAcquisitionType" });
-	dicom_dictionary.insert(0x00180024, DicomDictElt { vr : "SH", vm: "1", name: "Sequence Name", retired: "", keyword: "SequenceName" });
-	dicom_dictionary.insert(0x00180025, DicomDictElt { vr : "CS", vm: "1", name: "Angio Flag", retired: "", keyword: "AngioFlag" });
-	dicom_dictionary.insert(0x00180026, DicomDictElt { vr : "SQ", vm: "1", name: "Intervention Drug Information Sequence", retired: "", keyword: "InterventionDrugInformationSequence" });
-	dicom_dictionary.insert(0x00180027, DicomDictElt { vr : "TM", vm: "1", name: "Intervention Drug Stop Time", retired: "", keyword: "InterventionDrugStopTime" });
-	dicom_dictionary.insert(0x00180028, DicomDictElt { vr : "DS", vm: "1", name: "Intervention Drug Dose", retired: "", keyword: "InterventionDrugDose" });
-	dicom_dictionary.insert(0x00180029, DicomDictElt { vr : "SQ", vm: "1", name: "Intervention Drug Code Sequence", retired: "", keyword: "InterventionDrugCodeSequence" });
-	dicom_dictionary.insert(0x0018002A, DicomDictElt { vr : "SQ", vm: "1", name: "Additional Drug Sequence", retired: "", keyword: "AdditionalDrugSequence" });
-	dicom_dictionary.insert(0x00180030, DicomDictElt { vr : "LO", vm: "1-n", name: "Radionuclide", retired: "Retired", keyword: "Radionuclide" });
-	dicom_dictionary.insert(0x00180031, DicomDictElt { vr : "LO", vm: "1", name: "Radiopharmaceutical", retired: "", keyword: "Radiopharmaceutical" });
-	dicom_dictionary.insert(0x00180032, DicomDictElt { vr : "DS", vm: "1", name: "Energy Window Centerline", retired: "Retired", keyword: "EnergyWindowCenterline" });
-	dicom_dictionary.insert(0x00180033, DicomDictElt { vr : "DS", vm: "1-n", name: "Energy Window Total Width", retired: "Retired", keyword: "EnergyWindowTotalWidth" });
-	dicom_dictionary.insert(0x00180034, DicomDictElt { vr : "LO", vm: "1", name: "Intervention Drug Name", retired: "", keyword: "InterventionDrugName" });
-	dicom_dictionary.insert(0x00180035, DicomDictElt { vr : "TM", vm: "1", name: "Intervention Drug Start Time", retired: "", keyword: "InterventionDrugStartTime" });
-	dicom_dictionary.insert(0x00180036, DicomDictElt { vr : "SQ", vm: "1", name: "Intervention Sequence", retired: "", keyword: "InterventionSequence" });
-	dicom_dictionary.insert(0x00180037, DicomDictElt { vr : "CS", vm: "1", name: "Therapy Type", retired: "Retired", keyword: "TherapyType" });
-	dicom_dictionary.insert(0x00180038, DicomDictElt { vr : "CS", vm: "1", name: "Intervention Status", retired: "", keyword: "InterventionStatus" });
-	dicom_dictionary.insert(0x00180039, DicomDictElt { vr : "CS", vm: "1", name: "Therapy Description", retired: "Retired", keyword: "TherapyDescription" });
-	dicom_dictionary.insert(0x0018003A, DicomDictElt { vr : "ST", vm: "1", name: "Intervention Description", retired: "", keyword: "InterventionDescription" });
-	dicom_dictionary.insert(0x00180040, DicomDictElt { vr : "IS", vm: "1", name: "Cine Rate", retired: "", keyword: "CineRate" });
-	dicom_dictionary.insert(0x00180042, DicomDictElt { vr : "CS", vm: "1", name: "Initial Cine Run State", retired: "", keyword: "InitialCineRunState" });
-	dicom_dictionary.insert(0x00180050, DicomDictElt { vr : "DS", vm: "1", name: "Slice Thickness", retired: "", keyword: "SliceThickness" });
-	dicom_dictionary.insert(0x00180060, DicomDictElt { vr : "DS", vm: "1", name: "KVP", retired: "", keyword: "KVP" });
-	dicom_dictionary.insert(0x00180061, DicomDictElt { vr : "DS", vm: "1", name: "", retired: "Retired", keyword: "" });
-	dicom_dictionary.insert(0x00180070, DicomDictElt { vr : "IS", vm: "1", name: "Counts Accumulated", retired: "", keyword: "CountsAccumulated" });
-	dicom_dictionary.insert(0x00180071, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Termination Condition", retired: "", keyword: "AcquisitionTerminationCondition" });
-	dicom_dictionary.insert(0x00180072, DicomDictElt { vr : "DS", vm: "1", name: "Effective Duration", retired: "", keyword: "EffectiveDuration" });
-	dicom_dictionary.insert(0x00180073, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Start Condition", retired: "", keyword: "AcquisitionStartCondition" });
-	dicom_dictionary.insert(0x00180074, DicomDictElt { vr : "IS", vm: "1", name: "Acquisition Start Condition Data", retired: "", keyword: "AcquisitionStartConditionData" });
-	dicom_dictionary.insert(0x00180075, DicomDictElt { vr : "IS", vm: "1", name: "Acquisition Termination Condition Data", retired: "", keyword: "AcquisitionTerminationConditionData" });
-	dicom_dictionary.insert(0x00180080, DicomDictElt { vr : "DS", vm: "1", name: "Repetition Time", retired: "", keyword: "RepetitionTime" });
-	dicom_dictionary.insert(0x00180081, DicomDictElt { vr : "DS", vm: "1", name: "Echo Time", retired: "", keyword: "EchoTime" });
-	dicom_dictionary.insert(0x00180082, DicomDictElt { vr : "DS", vm: "1", name: "Inversion Time", retired: "", keyword: "InversionTime" });
-	dicom_dictionary.insert(0x00180083, DicomDictElt { vr : "DS", vm: "1", name: "Number of Averages", retired: "", keyword: "NumberOfAverages" });
-	dicom_dictionary.insert(0x00180084, DicomDictElt { vr : "DS", vm: "1", name: "Imaging Frequency", retired: "", keyword: "ImagingFrequency" });
-	dicom_dictionary.insert(0x00180085, DicomDictElt { vr : "SH", vm: "1", name: "Imaged Nucleus", retired: "", keyword: "ImagedNucleus" });
-	dicom_dictionary.insert(0x00180086, DicomDictElt { vr : "IS", vm: "1-n", name: "Echo Number(s)", retired: "", keyword: "EchoNumbers" });
-	dicom_dictionary.insert(0x00180087, DicomDictElt { vr : "DS", vm: "1", name: "Magnetic Field Strength", retired: "", keyword: "MagneticFieldStrength" });
-	dicom_dictionary.insert(0x00180088, DicomDictElt { vr : "DS", vm: "1", name: "Spacing Between Slices", retired: "", keyword: "SpacingBetweenSlices" });
-	dicom_dictionary.insert(0x00180089, DicomDictElt { vr : "IS", vm: "1", name: "Number of Phase Encoding Steps", retired: "", keyword: "NumberOfPhaseEncodingSteps" });
-	dicom_dictionary.insert(0x00180090, DicomDictElt { vr : "DS", vm: "1", name: "Data Collection Diameter", retired: "", keyword: "DataCollectionDiameter" });
-	dicom_dictionary.insert(0x00180091, DicomDictElt { vr : "IS", vm: "1", name: "Echo Train Length", retired: "", keyword: "EchoTrainLength" });
-	dicom_dictionary.insert(0x00180093, DicomDictElt { vr : "DS", vm: "1", name: "Percent Sampling", retired: "", keyword: "PercentSampling" });
-	dicom_dictionary.insert(0x00180094, DicomDictElt { vr : "DS", vm: "1", name: "Percent Phase Field of View", retired: "", keyword: "PercentPhaseFieldOfView" });
-	dicom_dictionary.insert(0x00180095, DicomDictElt { vr : "DS", vm: "1", name: "Pixel Bandwidth", retired: "", keyword: "PixelBandwidth" });
-	dicom_dictionary.insert(0x00181000, DicomDictElt { vr : "LO", vm: "1", name: "Device Serial Number", retired: "", keyword: "DeviceSerialNumber" });
-	dicom_dictionary.insert(0x00181002, DicomDictElt { vr : "UI", vm: "1", name: "Device UID", retired: "", keyword: "DeviceUID" });
-	dicom_dictionary.insert(0x00181003, DicomDictElt { vr : "LO", vm: "1", name: "Device ID", retired: "", keyword: "DeviceID" });
-	dicom_dictionary.insert(0x00181004, DicomDictElt { vr : "LO", vm: "1", name: "Plate ID", retired: "", keyword: "PlateID" });
-	dicom_dictionary.insert(0x00181005, DicomDictElt { vr : "LO", vm: "1", name: "Generator ID", retired: "", keyword: "GeneratorID" });
-	dicom_dictionary.insert(0x00181006, DicomDictElt { vr : "LO", vm: "1", name: "Grid ID", retired: "", keyword: "GridID" });
-	dicom_dictionary.insert(0x00181007, DicomDictElt { vr : "LO", vm: "1", name: "Cassette ID", retired: "", keyword: "CassetteID" });
-	dicom_dictionary.insert(0x00181008, DicomDictElt { vr : "LO", vm: "1", name: "Gantry ID", retired: "", keyword: "GantryID" });
-	dicom_dictionary.insert(0x00181009, DicomDictElt { vr : "UT", vm: "1", name: "Unique Device Identifier", retired: "", keyword: "UniqueDeviceIdentifier" });
-	dicom_dictionary.insert(0x0018100A, DicomDictElt { vr : "SQ", vm: "1", name: "UDI Sequence", retired: "", keyword: "UDISequence" });
-	dicom_dictionary.insert(0x00181010, DicomDictElt { vr : "LO", vm: "1", name: "Secondary Capture Device ID", retired: "", keyword: "SecondaryCaptureDeviceID" });
-	dicom_dictionary.insert(0x00181011, DicomDictElt { vr : "LO", vm: "1", name: "Hardcopy Creation Device ID", retired: "Retired", keyword: "HardcopyCreationDeviceID" });
-	dicom_dictionary.insert(0x00181012, DicomDictElt { vr : "DA", vm: "1", name: "Date of Secondary Capture", retired: "", keyword: "DateOfSecondaryCapture" });
-	dicom_dictionary.insert(0x00181014, DicomDictElt { vr : "TM", vm: "1", name: "Time of Secondary Capture", retired: "", keyword: "TimeOfSecondaryCapture" });
-	dicom_dictionary.insert(0x00181016, DicomDictElt { vr : "LO", vm: "1", name: "Secondary Capture Device Manufacturer", retired: "", keyword: "SecondaryCaptureDeviceManufacturer" });
-	dicom_dictionary.insert(0x00181017, DicomDictElt { vr : "LO", vm: "1", name: "Hardcopy Device Manufacturer", retired: "Retired", keyword: "HardcopyDeviceManufacturer" });
-	dicom_dictionary.insert(0x00181018, DicomDictElt { vr : "LO", vm: "1", name: "Secondary Capture Device Manufacturer's Model Name", retired: "", keyword: "SecondaryCaptureDeviceManufacturerModelName" });
-	dicom_dictionary.insert(0x00181019, DicomDictElt { vr : "LO", vm: "1-n", name: "Secondary Capture Device Software Versions", retired: "", keyword: "SecondaryCaptureDeviceSoftwareVersions" });
-	dicom_dictionary.insert(0x0018101A, DicomDictElt { vr : "LO", vm: "1-n", name: "Hardcopy Device Software Version", retired: "Retired", keyword: "HardcopyDeviceSoftwareVersion" });
-	dicom_dictionary.insert(0x0018101B, DicomDictElt { vr : "LO", vm: "1", name: "Hardcopy Device Manufacturer's Model Name", retired: "Retired", keyword: "HardcopyDeviceManufacturerModelName" });
-	dicom_dictionary.insert(0x00181020, DicomDictElt { vr : "LO", vm: "1-n", name: "Software Version(s)", retired: "", keyword: "SoftwareVersions" });
-	dicom_dictionary.insert(0x00181022, DicomDictElt { vr : "SH", vm: "1", name: "Video Image Format Acquired", retired: "", keyword: "VideoImageFormatAcquired" });
-	dicom_dictionary.insert(0x00181023, DicomDictElt { vr : "LO", vm: "1", name: "Digital Image Format Acquired", retired: "", keyword: "DigitalImageFormatAcquired" });
-	dicom_dictionary.insert(0x00181030, DicomDictElt { vr : "LO", vm: "1", name: "Protocol Name", retired: "", keyword: "ProtocolName" });
-	dicom_dictionary.insert(0x00181040, DicomDictElt { vr : "LO", vm: "1", name: "Contrast/Bolus Route", retired: "", keyword: "ContrastBolusRoute" });
-	dicom_dictionary.insert(0x00181041, DicomDictElt { vr : "DS", vm: "1", name: "Contrast/Bolus Volume", retired: "", keyword: "ContrastBolusVolume" });
-	dicom_dictionary.insert(0x00181042, DicomDictElt { vr : "TM", vm: "1", name: "Contrast/Bolus Start Time", retired: "", keyword: "ContrastBolusStartTime" });
-	dicom_dictionary.insert(0x00181043, DicomDictElt { vr : "TM", vm: "1", name: "Contrast/Bolus Stop Time", retired: "", keyword: "ContrastBolusStopTime" });
-	dicom_dictionary.insert(0x00181044, DicomDictElt { vr : "DS", vm: "1", name: "Contrast/Bolus Total Dose", retired: "", keyword: "ContrastBolusTotalDose" });
-	dicom_dictionary.insert(0x00181045, DicomDictElt { vr : "IS", vm: "1", name: "Syringe Counts", retired: "", keyword: "SyringeCounts" });
-	dicom_dictionary.insert(0x00181046, DicomDictElt { vr : "DS", vm: "1-n", name: "Contrast Flow Rate", retired: "", keyword: "ContrastFlowRate" });
-	dicom_dictionary.insert(0x00181047, DicomDictElt { vr : "DS", vm: "1-n", name: "Contrast Flow Duration", retired: "", keyword: "ContrastFlowDuration" });
-	dicom_dictionary.insert(0x00181048, DicomDictElt { vr : "CS", vm: "1", name: "Contrast/Bolus Ingredient", retired: "", keyword: "ContrastBolusIngredient" });
-	dicom_dictionary.insert(0x00181049, DicomDictElt { vr : "DS", vm: "1", name: "Contrast/Bolus Ingredient Concentration", retired: "", keyword: "ContrastBolusIngredientConcentration" });
-	dicom_dictionary.insert(0x00181050, DicomDictElt { vr : "DS", vm: "1", name: "Spatial Resolution", retired: "", keyword: "SpatialResolution" });
-	dicom_dictionary.insert(0x00181060, DicomDictElt { vr : "DS", vm: "1", name: "Trigger Time", retired: "", keyword: "TriggerTime" });
-	dicom_dictionary.insert(0x00181061, DicomDictElt { vr : "LO", vm: "1", name: "Trigger Source or Type", retired: "", keyword: "TriggerSourceOrType" });
-	dicom_dictionary.insert(0x00181062, DicomDictElt { vr : "IS", vm: "1", name: "Nominal Interval", retired: "", keyword: "NominalInterval" });
-	dicom_dictionary.insert(0x00181063, DicomDictElt { vr : "DS", vm: "1", name: "Frame Time", retired: "", keyword: "FrameTime" });
-	dicom_dictionary.insert(0x00181064, DicomDictElt { vr : "LO", vm: "1", name: "Cardiac Framing Type", retired: "", keyword: "CardiacFramingType" });
-	dicom_dictionary.insert(0x00181065, DicomDictElt { vr : "DS", vm: "1-n", name: "Frame Time Vector", retired: "", keyword: "FrameTimeVector" });
-	dicom_dictionary.insert(0x00181066, DicomDictElt { vr : "DS", vm: "1", name: "Frame Delay", retired: "", keyword: "FrameDelay" });
-	dicom_dictionary.insert(0x00181067, DicomDictElt { vr : "DS", vm: "1", name: "Image Trigger Delay", retired: "", keyword: "ImageTriggerDelay" });
-	dicom_dictionary.insert(0x00181068, DicomDictElt { vr : "DS", vm: "1", name: "Multiplex Group Time Offset", retired: "", keyword: "MultiplexGroupTimeOffset" });
-	dicom_dictionary.insert(0x00181069, DicomDictElt { vr : "DS", vm: "1", name: "Trigger Time Offset", retired: "", keyword: "TriggerTimeOffset" });
-	dicom_dictionary.insert(0x0018106A, DicomDictElt { vr : "CS", vm: "1", name: "Synchronization Trigger", retired: "", keyword: "SynchronizationTrigger" });
-	dicom_dictionary.insert(0x0018106C, DicomDictElt { vr : "US", vm: "2", name: "Synchronization Channel", retired: "", keyword: "SynchronizationChannel" });
-	dicom_dictionary.insert(0x0018106E, DicomDictElt { vr : "UL", vm: "1", name: "Trigger Sample Position", retired: "", keyword: "TriggerSamplePosition" });
-	dicom_dictionary.insert(0x00181070, DicomDictElt { vr : "LO", vm: "1", name: "Radiopharmaceutical Route", retired: "", keyword: "RadiopharmaceuticalRoute" });
-	dicom_dictionary.insert(0x00181071, DicomDictElt { vr : "DS", vm: "1", name: "Radiopharmaceutical Volume", retired: "", keyword: "RadiopharmaceuticalVolume" });
-	dicom_dictionary.insert(0x00181072, DicomDictElt { vr : "TM", vm: "1", name: "Radiopharmaceutical Start Time", retired: "", keyword: "RadiopharmaceuticalStartTime" });
-	dicom_dictionary.insert(0x00181073, DicomDictElt { vr : "TM", vm: "1", name: "Radiopharmaceutical Stop Time", retired: "", keyword: "RadiopharmaceuticalStopTime" });
-	dicom_dictionary.insert(0x00181074, DicomDictElt { vr : "DS", vm: "1", name: "Radionuclide Total Dose", retired: "", keyword: "RadionuclideTotalDose" });
-	dicom_dictionary.insert(0x00181075, DicomDictElt { vr : "DS", vm: "1", name: "Radionuclide Half Life", retired: "", keyword: "RadionuclideHalfLife" });
-	dicom_dictionary.insert(0x00181076, DicomDictElt { vr : "DS", vm: "1", name: "Radionuclide Positron Fraction", retired: "", keyword: "RadionuclidePositronFraction" });
-	dicom_dictionary.insert(0x00181077, DicomDictElt { vr : "DS", vm: "1", name: "Radiopharmaceutical Specific Activity", retired: "", keyword: "RadiopharmaceuticalSpecificActivity" });
-	dicom_dictionary.insert(0x00181078, DicomDictElt { vr : "DT", vm: "1", name: "Radiopharmaceutical Start DateTime", retired: "", keyword: "RadiopharmaceuticalStartDateTime" });
-	dicom_dictionary.insert(0x00181079, DicomDictElt { vr : "DT", vm: "1", name: "Radiopharmaceutical Stop DateTime", retired: "", keyword: "RadiopharmaceuticalStopDateTime" });
-	dicom_dictionary.insert(0x00181080, DicomDictElt { vr : "CS", vm: "1", name: "Beat Rejection Flag", retired: "", keyword: "BeatRejectionFlag" });
-	dicom_dictionary.insert(0x00181081, DicomDictElt { vr : "IS", vm: "1", name: "Low R-R Value", retired: "", keyword: "LowRRValue" });
-	dicom_dictionary.insert(0x00181082, DicomDictElt { vr : "IS", vm: "1", name: "High R-R Value", retired: "", keyword: "HighRRValue" });
-	dicom_dictionary.insert(0x00181083, DicomDictElt { vr : "IS", vm: "1", name: "Intervals Acquired", retired: "", keyword: "IntervalsAcquired" });
-	dicom_dictionary.insert(0x00181084, DicomDictElt { vr : "IS", vm: "1", name: "Intervals Rejected", retired: "", keyword: "IntervalsRejected" });
-	dicom_dictionary.insert(0x00181085, DicomDictElt { vr : "LO", vm: "1", name: "PVC Rejection", retired: "", keyword: "PVCRejection" });
-	dicom_dictionary.insert(0x00181086, DicomDictElt { vr : "IS", vm: "1", name: "Skip Beats", retired: "", keyword: "SkipBeats" });
-	dicom_dictionary.insert(0x00181088, DicomDictElt { vr : "IS", vm: "1", name: "Heart Rate", retired: "", keyword: "HeartRate" });
-	dicom_dictionary.insert(0x00181090, DicomDictElt { vr : "IS", vm: "1", name: "Cardiac Number of Images", retired: "", keyword: "CardiacNumberOfImages" });
-	dicom_dictionary.insert(0x00181094, DicomDictElt { vr : "IS", vm: "1", name: "Trigger Window", retired: "", keyword: "TriggerWindow" });
-	dicom_dictionary.insert(0x00181100, DicomDictElt { vr : "DS", vm: "1", name: "Reconstruction Diameter", retired: "", keyword: "ReconstructionDiameter" });
-	dicom_dictionary.insert(0x00181110, DicomDictElt { vr : "DS", vm: "1", name: "Distance Source to Detector", retired: "", keyword: "DistanceSourceToDetector" });
-	dicom_dictionary.insert(0x00181111, DicomDictElt { vr : "DS", vm: "1", name: "Distance Source to Patient", retired: "", keyword: "DistanceSourceToPatient" });
-	dicom_dictionary.insert(0x00181114, DicomDictElt { vr : "DS", vm: "1", name: "Estimated Radiographic Magnification Factor", retired: "", keyword: "EstimatedRadiographicMagnificationFactor" });
-	dicom_dictionary.insert(0x00181120, DicomDictElt { vr : "DS", vm: "1", name: "Gantry/Detector Tilt", retired: "", keyword: "GantryDetectorTilt" });
-	dicom_dictionary.insert(0x00181121, DicomDictElt { vr : "DS", vm: "1", name: "Gantry/Detector Slew", retired: "", keyword: "GantryDetectorSlew" });
-	dicom_dictionary.insert(0x00181130, DicomDictElt { vr : "DS", vm: "1", name: "Table Height", retired: "", keyword: "TableHeight" });
-	dicom_dictionary.insert(0x00181131, DicomDictElt { vr : "DS", vm: "1", name: "Table Traverse", retired: "", keyword: "TableTraverse" });
-	dicom_dictionary.insert(0x00181134, DicomDictElt { vr : "CS", vm: "1", name: "Table Motion", retired: "", keyword: "TableMotion" });
-	dicom_dictionary.insert(0x00181135, DicomDictElt { vr : "DS", vm: "1-n", name: "Table Vertical Increment", retired: "", keyword: "TableVerticalIncrement" });
-	dicom_dictionary.insert(0x00181136, DicomDictElt { vr : "DS", vm: "1-n", name: "Table Lateral Increment", retired: "", keyword: "TableLateralIncrement" });
-	dicom_dictionary.insert(0x00181137, DicomDictElt { vr : "DS", vm: "1-n", name: "Table Longitudinal Increment", retired: "", keyword: "TableLongitudinalIncrement" });
-	dicom_dictionary.insert(0x00181138, DicomDictElt { vr : "DS", vm: "1", name: "Table Angle", retired: "", keyword: "TableAngle" });
-	dicom_dictionary.insert(0x0018113A, DicomDictElt { vr : "CS", vm: "1", name: "Table Type", retired: "", keyword: "TableType" });
-	dicom_dictionary.insert(0x00181140, DicomDictElt { vr : "CS", vm: "1", name: "Rotation Direction", retired: "", keyword: "RotationDirection" });
-	dicom_dictionary.insert(0x00181141, DicomDictElt { vr : "DS", vm: "1", name: "Angular Position", retired: "Retired", keyword: "AngularPosition" });
-	dicom_dictionary.insert(0x00181142, DicomDictElt { vr : "DS", vm: "1-n", name: "Radial Position", retired: "", keyword: "RadialPosition" });
-	dicom_dictionary.insert(0x00181143, DicomDictElt { vr : "DS", vm: "1", name: "Scan Arc", retired: "", keyword: "ScanArc" });
-	dicom_dictionary.insert(0x00181144, DicomDictElt { vr : "DS", vm: "1", name: "Angular Step", retired: "", keyword: "AngularStep" });
-	dicom_dictionary.insert(0x00181145, DicomDictElt { vr : "DS", vm: "1", name: "Center of Rotation Offset", retired: "", keyword: "CenterOfRotationOffset" });
-	dicom_dictionary.insert(0x00181146, DicomDictElt { vr : "DS", vm: "1-n", name: "Rotation Offset", retired: "Retired", keyword: "RotationOffset" });
-	dicom_dictionary.insert(0x00181147, DicomDictElt { vr : "CS", vm: "1", name: "Field of View Shape", retired: "", keyword: "FieldOfViewShape" });
-	dicom_dictionary.insert(0x00181149, DicomDictElt { vr : "IS", vm: "1-2", name: "Field of View Dimension(s)", retired: "", keyword: "FieldOfViewDimensions" });
-	dicom_dictionary.insert(0x00181150, DicomDictElt { vr : "IS", vm: "1", name: "Exposure Time", retired: "", keyword: "ExposureTime" });
-	dicom_dictionary.insert(0x00181151, DicomDictElt { vr : "IS", vm: "1", name: "X-Ray Tube Current", retired: "", keyword: "XRayTubeCurrent" });
-	dicom_dictionary.insert(0x00181152, DicomDictElt { vr : "IS", vm: "1", name: "Exposure", retired: "", keyword: "Exposure" });
-	dicom_dictionary.insert(0x00181153, DicomDictElt { vr : "IS", vm: "1", name: "Exposure in uAs", retired: "", keyword: "ExposureInuAs" });
-	dicom_dictionary.insert(0x00181154, DicomDictElt { vr : "DS", vm: "1", name: "Average Pulse Width", retired: "", keyword: "AveragePulseWidth" });
-	dicom_dictionary.insert(0x00181155, DicomDictElt { vr : "CS", vm: "1", name: "Radiation Setting", retired: "", keyword: "RadiationSetting" });
-	dicom_dictionary.insert(0x00181156, DicomDictElt { vr : "CS", vm: "1", name: "Rectification Type", retired: "", keyword: "RectificationType" });
-	dicom_dictionary.insert(0x0018115A, DicomDictElt { vr : "CS", vm: "1", name: "Radiation Mode", retired: "", keyword: "RadiationMode" });
-	dicom_dictionary.insert(0x0018115E, DicomDictElt { vr : "DS", vm: "1", name: "Image and Fluoroscopy Area Dose Product", retired: "", keyword: "ImageAndFluoroscopyAreaDoseProduct" });
-	dicom_dictionary.insert(0x00181160, DicomDictElt { vr : "SH", vm: "1", name: "Filter Type", retired: "", keyword: "FilterType" });
-	dicom_dictionary.insert(0x00181161, DicomDictElt { vr : "LO", vm: "1-n", name: "Type of Filters", retired: "", keyword: "TypeOfFilters" });
-	dicom_dictionary.insert(0x00181162, DicomDictElt { vr : "DS", vm: "1", name: "Intensifier Size", retired: "", keyword: "IntensifierSize" });
-	dicom_dictionary.insert(0x00181164, DicomDictElt { vr : "DS", vm: "2", name: "Imager Pixel Spacing", retired: "", keyword: "ImagerPixelSpacing" });
-	dicom_dictionary.insert(0x00181166, DicomDictElt { vr : "CS", vm: "1-n", name: "Grid", retired: "", keyword: "Grid" });
-	dicom_dictionary.insert(0x00181170, DicomDictElt { vr : "IS", vm: "1", name: "Generator Power", retired: "", keyword: "GeneratorPower" });
-	dicom_dictionary.insert(0x00181180, DicomDictElt { vr : "SH", vm: "1", name: "Collimator/grid Name", retired: "", keyword: "CollimatorGridName" });
-	dicom_dictionary.insert(0x00181181, DicomDictElt { vr : "CS", vm: "1", name: "Collimator Type", retired: "", keyword: "CollimatorType" });
-	dicom_dictionary.insert(0x00181182, DicomDictElt { vr : "IS", vm: "1-2", name: "Focal Distance", retired: "", keyword: "FocalDistance" });
-	dicom_dictionary.insert(0x00181183, DicomDictElt { vr : "DS", vm: "1-2", name: "X Focus Center", retired: "", keyword: "XFocusCenter" });
-	dicom_dictionary.insert(0x00181184, DicomDictElt { vr : "DS", vm: "1-2", name: "Y Focus Center", retired: "", keyword: "YFocusCenter" });
-	dicom_dictionary.insert(0x00181190, DicomDictElt { vr : "DS", vm: "1-n", name: "Focal Spot(s)", retired: "", keyword: "FocalSpots" });
-	dicom_dictionary.insert(0x00181191, DicomDictElt { vr : "CS", vm: "1", name: "Anode Target Material", retired: "", keyword: "AnodeTargetMaterial" });
-	dicom_dictionary.insert(0x001811A0, DicomDictElt { vr : "DS", vm: "1", name: "Body Part Thickness", retired: "", keyword: "BodyPartThickness" });
-	dicom_dictionary.insert(0x001811A2, DicomDictElt { vr : "DS", vm: "1", name: "Compression Force", retired: "", keyword: "CompressionForce" });
-	dicom_dictionary.insert(0x001811A4, DicomDictElt { vr : "LO", vm: "1", name: "Paddle Description", retired: "", keyword: "PaddleDescription" });
-	dicom_dictionary.insert(0x00181200, DicomDictElt { vr : "DA", vm: "1-n", name: "Date of Last Calibration", retired: "", keyword: "DateOfLastCalibration" });
-	dicom_dictionary.insert(0x00181201, DicomDictElt { vr : "TM", vm: "1-n", name: "Time of Last Calibration", retired: "", keyword: "TimeOfLastCalibration" });
-	dicom_dictionary.insert(0x00181202, DicomDictElt { vr : "DT", vm: "1", name: "DateTime of Last Calibration", retired: "", keyword: "DateTimeOfLastCalibration" });
-	dicom_dictionary.insert(0x00181210, DicomDictElt { vr : "SH", vm: "1-n", name: "Convolution Kernel", retired: "", keyword: "ConvolutionKernel" });
-	dicom_dictionary.insert(0x00181240, DicomDictElt { vr : "IS", vm: "1-n", name: "Upper/Lower Pixel Values", retired: "Retired", keyword: "UpperLowerPixelValues" });
-	dicom_dictionary.insert(0x00181242, DicomDictElt { vr : "IS", vm: "1", name: "Actual Frame Duration", retired: "", keyword: "ActualFrameDuration" });
-	dicom_dictionary.insert(0x00181243, DicomDictElt { vr : "IS", vm: "1", name: "Count Rate", retired: "", keyword: "CountRate" });
-	dicom_dictionary.insert(0x00181244, DicomDictElt { vr : "US", vm: "1", name: "Preferred Playback Sequencing", retired: "", keyword: "PreferredPlaybackSequencing" });
-	dicom_dictionary.insert(0x00181250, DicomDictElt { vr : "SH", vm: "1", name: "Receive Coil Name", retired: "", keyword: "ReceiveCoilName" });
-	dicom_dictionary.insert(0x00181251, DicomDictElt { vr : "SH", vm: "1", name: "Transmit Coil Name", retired: "", keyword: "TransmitCoilName" });
-	dicom_dictionary.insert(0x00181260, DicomDictElt { vr : "SH", vm: "1", name: "Plate Type", retired: "", keyword: "PlateType" });
-	dicom_dictionary.insert(0x00181261, DicomDictElt { vr : "LO", vm: "1", name: "Phosphor Type", retired: "", keyword: "PhosphorType" });
-	dicom_dictionary.insert(0x00181271, DicomDictElt { vr : "FD", vm: "1", name: "Water Equivalent Diameter", retired: "", keyword: "WaterEquivalentDiameter" });
-	dicom_dictionary.insert(0x00181272, DicomDictElt { vr : "SQ", vm: "1", name: "Water Equivalent Diameter Calculation Method Code Sequence", retired: "", keyword: "WaterEquivalentDiameterCalculationMethodCodeSequence" });
-	dicom_dictionary.insert(0x00181300, DicomDictElt { vr : "DS", vm: "1", name: "Scan Velocity", retired: "", keyword: "ScanVelocity" });
-	dicom_dictionary.insert(0x00181301, DicomDictElt { vr : "CS", vm: "1-n", name: "Whole Body Technique", retired: "", keyword: "WholeBodyTechnique" });
-	dicom_dictionary.insert(0x00181302, DicomDictElt { vr : "IS", vm: "1", name: "Scan Length", retired: "", keyword: "ScanLength" });
-	dicom_dictionary.insert(0x00181310, DicomDictElt { vr : "US", vm: "4", name: "Acquisition Matrix", retired: "", keyword: "AcquisitionMatrix" });
-	dicom_dictionary.insert(0x00181312, DicomDictElt { vr : "CS", vm: "1", name: "In-plane Phase Encoding Direction", retired: "", keyword: "InPlanePhaseEncodingDirection" });
-	dicom_dictionary.insert(0x00181314, DicomDictElt { vr : "DS", vm: "1", name: "Flip Angle", retired: "", keyword: "FlipAngle" });
-	dicom_dictionary.insert(0x00181315, DicomDictElt { vr : "CS", vm: "1", name: "Variable Flip Angle Flag", retired: "", keyword: "VariableFlipAngleFlag" });
-	dicom_dictionary.insert(0x00181316, DicomDictElt { vr : "DS", vm: "1", name: "SAR", retired: "", keyword: "SAR" });
-	dicom_dictionary.insert(0x00181318, DicomDictElt { vr : "DS", vm: "1", name: "dB/dt", retired: "", keyword: "dBdt" });
-	dicom_dictionary.insert(0x00181320, DicomDictElt { vr : "FL", vm: "1", name: "B1rms", retired: "", keyword: "B1rms" });
-	dicom_dictionary.insert(0x00181400, DicomDictElt { vr : "LO", vm: "1", name: "Acquisition Device Processing Description", retired: "", keyword: "AcquisitionDeviceProcessingDescription" });
-	dicom_dictionary.insert(0x00181401, DicomDictElt { vr : "LO", vm: "1", name: "Acquisition Device Processing Code", retired: "", keyword: "AcquisitionDeviceProcessingCode" });
-	dicom_dictionary.insert(0x00181402, DicomDictElt { vr : "CS", vm: "1", name: "Cassette Orientation", retired: "", keyword: "CassetteOrientation" });
-	dicom_dictionary.insert(0x00181403, DicomDictElt { vr : "CS", vm: "1", name: "Cassette Size", retired: "", keyword: "CassetteSize" });
-	dicom_dictionary.insert(0x00181404, DicomDictElt { vr : "US", vm: "1", name: "Exposures on Plate", retired: "", keyword: "ExposuresOnPlate" });
-	dicom_dictionary.insert(0x00181405, DicomDictElt { vr : "IS", vm: "1", name: "Relative X-Ray Exposure", retired: "", keyword: "RelativeXRayExposure" });
-	dicom_dictionary.insert(0x00181411, DicomDictElt { vr : "DS", vm: "1", name: "Exposure Index", retired: "", keyword: "ExposureIndex" });
-	dicom_dictionary.insert(0x00181412, DicomDictElt { vr : "DS", vm: "1", name: "Target Exposure Index", retired: "", keyword: "TargetExposureIndex" });
-	dicom_dictionary.insert(0x00181413, DicomDictElt { vr : "DS", vm: "1", name: "Deviation Index", retired: "", keyword: "DeviationIndex" });
-	dicom_dictionary.insert(0x00181450, DicomDictElt { vr : "DS", vm: "1", name: "Column Angulation", retired: "", keyword: "ColumnAngulation" });
-	dicom_dictionary.insert(0x00181460, DicomDictElt { vr : "DS", vm: "1", name: "Tomo Layer Height", retired: "", keyword: "TomoLayerHeight" });
-	dicom_dictionary.insert(0x00181470, DicomDictElt { vr : "DS", vm: "1", name: "Tomo Angle", retired: "", keyword: "TomoAngle" });
-	dicom_dictionary.insert(0x00181480, DicomDictElt { vr : "DS", vm: "1", name: "Tomo Time", retired: "", keyword: "TomoTime" });
-	dicom_dictionary.insert(0x00181490, DicomDictElt { vr : "CS", vm: "1", name: "Tomo Type", retired: "", keyword: "TomoType" });
-	dicom_dictionary.insert(0x00181491, DicomDictElt { vr : "CS", vm: "1", name: "Tomo Class", retired: "", keyword: "TomoClass" });
-	dicom_dictionary.insert(0x00181495, DicomDictElt { vr : "IS", vm: "1", name: "Number of Tomosynthesis Source Images", retired: "", keyword: "NumberOfTomosynthesisSourceImages" });
-	dicom_dictionary.insert(0x00181500, DicomDictElt { vr : "CS", vm: "1", name: "Positioner Motion", retired: "", keyword: "PositionerMotion" });
-	dicom_dictionary.insert(0x00181508, DicomDictElt { vr : "CS", vm: "1", name: "Positioner Type", retired: "", keyword: "PositionerType" });
-	dicom_dictionary.insert(0x00181510, DicomDictElt { vr : "DS", vm: "1", name: "Positioner Primary Angle", retired: "", keyword: "PositionerPrimaryAngle" });
-	dicom_dictionary.insert(0x00181511, DicomDictElt { vr : "DS", vm: "1", name: "Positioner Secondary Angle", retired: "", keyword: "PositionerSecondaryAngle" });
-	dicom_dictionary.insert(0x00181520, DicomDictElt { vr : "DS", vm: "1-n", name: "Positioner Primary Angle Increment", retired: "", keyword: "PositionerPrimaryAngleIncrement" });
-	dicom_dictionary.insert(0x00181521, DicomDictElt { vr : "DS", vm: "1-n", name: "Positioner Secondary Angle Increment", retired: "", keyword: "PositionerSecondaryAngleIncrement" });
-	dicom_dictionary.insert(0x00181530, DicomDictElt { vr : "DS", vm: "1", name: "Detector Primary Angle", retired: "", keyword: "DetectorPrimaryAngle" });
-	dicom_dictionary.insert(0x00181531, DicomDictElt { vr : "DS", vm: "1", name: "Detector Secondary Angle", retired: "", keyword: "DetectorSecondaryAngle" });
-	dicom_dictionary.insert(0x00181600, DicomDictElt { vr : "CS", vm: "1-3", name: "Shutter Shape", retired: "", keyword: "ShutterShape" });
-	dicom_dictionary.insert(0x00181602, DicomDictElt { vr : "IS", vm: "1", name: "Shutter Left Vertical Edge", retired: "", keyword: "ShutterLeftVerticalEdge" });
-	dicom_dictionary.insert(0x00181604, DicomDictElt { vr : "IS", vm: "1", name: "Shutter Right Vertical Edge", retired: "", keyword: "ShutterRightVerticalEdge" });
-	dicom_dictionary.insert(0x00181606, DicomDictElt { vr : "IS", vm: "1", name: "Shutter Upper Horizontal Edge", retired: "", keyword: "ShutterUpperHorizontalEdge" });
-	dicom_dictionary.insert(0x00181608, DicomDictElt { vr : "IS", vm: "1", name: "Shutter Lower Horizontal Edge", retired: "", keyword: "ShutterLowerHorizontalEdge" });
-	dicom_dictionary.insert(0x00181610, DicomDictElt { vr : "IS", vm: "2", name: "Center of Circular Shutter", retired: "", keyword: "CenterOfCircularShutter" });
-	dicom_dictionary.insert(0x00181612, DicomDictElt { vr : "IS", vm: "1", name: "Radius of Circular Shutter", retired: "", keyword: "RadiusOfCircularShutter" });
-	dicom_dictionary.insert(0x00181620, DicomDictElt { vr : "IS", vm: "2-2n", name: "Vertices of the Polygonal Shutter", retired: "", keyword: "VerticesOfThePolygonalShutter" });
-	dicom_dictionary.insert(0x00181622, DicomDictElt { vr : "US", vm: "1", name: "Shutter Presentation Value", retired: "", keyword: "ShutterPresentationValue" });
-	dicom_dictionary.insert(0x00181623, DicomDictElt { vr : "US", vm: "1", name: "Shutter Overlay Group", retired: "", keyword: "ShutterOverlayGroup" });
-	dicom_dictionary.insert(0x00181624, DicomDictElt { vr : "US", vm: "3", name: "Shutter Presentation Color CIELab Value", retired: "", keyword: "ShutterPresentationColorCIELabValue" });
-	dicom_dictionary.insert(0x00181700, DicomDictElt { vr : "CS", vm: "1-3", name: "Collimator Shape", retired: "", keyword: "CollimatorShape" });
-	dicom_dictionary.insert(0x00181702, DicomDictElt { vr : "IS", vm: "1", name: "Collimator Left Vertical Edge", retired: "", keyword: "CollimatorLeftVerticalEdge" });
-	dicom_dictionary.insert(0x00181704, DicomDictElt { vr : "IS", vm: "1", name: "Collimator Right Vertical Edge", retired: "", keyword: "CollimatorRightVerticalEdge" });
-	dicom_dictionary.insert(0x00181706, DicomDictElt { vr : "IS", vm: "1", name: "Collimator Upper Horizontal Edge", retired: "", keyword: "CollimatorUpperHorizontalEdge" });
-	dicom_dictionary.insert(0x00181708, DicomDictElt { vr : "IS", vm: "1", name: "Collimator Lower Horizontal Edge", retired: "", keyword: "CollimatorLowerHorizontalEdge" });
-	dicom_dictionary.insert(0x00181710, DicomDictElt { vr : "IS", vm: "2", name: "Center of Circular Collimator", retired: "", keyword: "CenterOfCircularCollimator" });
-	dicom_dictionary.insert(0x00181712, DicomDictElt { vr : "IS", vm: "1", name: "Radius of Circular Collimator", retired: "", keyword: "RadiusOfCircularCollimator" });
-	dicom_dictionary.insert(0x00181720, DicomDictElt { vr : "IS", vm: "2-2n", name: "Vertices of the Polygonal Collimator", retired: "", keyword: "VerticesOfThePolygonalCollimator" });
-	dicom_dictionary.insert(0x00181800, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Time Synchronized", retired: "", keyword: "AcquisitionTimeSynchronized" });
-	dicom_dictionary.insert(0x00181801, DicomDictElt { vr : "SH", vm: "1", name: "Time Source", retired: "", keyword: "TimeSource" });
-	dicom_dictionary.insert(0x00181802, DicomDictElt { vr : "CS", vm: "1", name: "Time Distribution Protocol", retired: "", keyword: "TimeDistributionProtocol" });
-	dicom_dictionary.insert(0x00181803, DicomDictElt { vr : "LO", vm: "1", name: "NTP Source Address", retired: "", keyword: "NTPSourceAddress" });
-	dicom_dictionary.insert(0x00182001, DicomDictElt { vr : "IS", vm: "1-n", name: "Page Number Vector", retired: "", keyword: "PageNumberVector" });
-	dicom_dictionary.insert(0x00182002, DicomDictElt { vr : "SH", vm: "1-n", name: "Frame Label Vector", retired: "", keyword: "FrameLabelVector" });
-	dicom_dictionary.insert(0x00182003, DicomDictElt { vr : "DS", vm: "1-n", name: "Frame Primary Angle Vector", retired: "", keyword: "FramePrimaryAngleVector" });
-	dicom_dictionary.insert(0x00182004, DicomDictElt { vr : "DS", vm: "1-n", name: "Frame Secondary Angle Vector", retired: "", keyword: "FrameSecondaryAngleVector" });
-	dicom_dictionary.insert(0x00182005, DicomDictElt { vr : "DS", vm: "1-n", name: "Slice Location Vector", retired: "", keyword: "SliceLocationVector" });
-	dicom_dictionary.insert(0x00182006, DicomDictElt { vr : "SH", vm: "1-n", name: "Display Window Label Vector", retired: "", keyword: "DisplayWindowLabelVector" });
-	dicom_dictionary.insert(0x00182010, DicomDictElt { vr : "DS", vm: "2", name: "Nominal Scanned Pixel Spacing", retired: "", keyword: "NominalScannedPixelSpacing" });
-	dicom_dictionary.insert(0x00182020, DicomDictElt { vr : "CS", vm: "1", name: "Digitizing Device Transport Direction", retired: "", keyword: "DigitizingDeviceTransportDirection" });
-	dicom_dictionary.insert(0x00182030, DicomDictElt { vr : "DS", vm: "1", name: "Rotation of Scanned Film", retired: "", keyword: "RotationOfScannedFilm" });
-	dicom_dictionary.insert(0x00182041, DicomDictElt { vr : "SQ", vm: "1", name: "Biopsy Target Sequence", retired: "", keyword: "BiopsyTargetSequence" });
-	dicom_dictionary.insert(0x00182042, DicomDictElt { vr : "UI", vm: "1", name: "Target UID", retired: "", keyword: "TargetUID" });
-	dicom_dictionary.insert(0x00182043, DicomDictElt { vr : "FL", vm: "2", name: "Localizing Cursor Position", retired: "", keyword: "LocalizingCursorPosition" });
-	dicom_dictionary.insert(0x00182044, DicomDictElt { vr : "FL", vm: "3", name: "Calculated Target Position", retired: "", keyword: "CalculatedTargetPosition" });
-	dicom_dictionary.insert(0x00182045, DicomDictElt { vr : "SH", vm: "1", name: "Target Label", retired: "", keyword: "TargetLabel" });
-	dicom_dictionary.insert(0x00182046, DicomDictElt { vr : "FL", vm: "1", name: "Displayed Z Value", retired: "", keyword: "DisplayedZValue" });
-	dicom_dictionary.insert(0x00183100, DicomDictElt { vr : "CS", vm: "1", name: "IVUS Acquisition", retired: "", keyword: "IVUSAcquisition" });
-	dicom_dictionary.insert(0x00183101, DicomDictElt { vr : "DS", vm: "1", name: "IVUS Pullback Rate", retired: "", keyword: "IVUSPullbackRate" });
-	dicom_dictionary.insert(0x00183102, DicomDictElt { vr : "DS", vm: "1", name: "IVUS Gated Rate", retired: "", keyword: "IVUSGatedRate" });
-	dicom_dictionary.insert(0x00183103, DicomDictElt { vr : "IS", vm: "1", name: "IVUS Pullback Start Frame Number", retired: "", keyword: "IVUSPullbackStartFrameNumber" });
-	dicom_dictionary.insert(0x00183104, DicomDictElt { vr : "IS", vm: "1", name: "IVUS Pullback Stop Frame Number", retired: "", keyword: "IVUSPullbackStopFrameNumber" });
-	dicom_dictionary.insert(0x00183105, DicomDictElt { vr : "IS", vm: "1-n", name: "Lesion Number", retired: "", keyword: "LesionNumber" });
-	dicom_dictionary.insert(0x00184000, DicomDictElt { vr : "LT", vm: "1", name: "Acquisition Comments", retired: "Retired", keyword: "AcquisitionComments" });
-	dicom_dictionary.insert(0x00185000, DicomDictElt { vr : "SH", vm: "1-n", name: "Output Power", retired: "", keyword: "OutputPower" });
-	dicom_dictionary.insert(0x00185010, DicomDictElt { vr : "LO", vm: "1-n", name: "Transducer Data", retired: "", keyword: "TransducerData" });
-	dicom_dictionary.insert(0x00185012, DicomDictElt { vr : "DS", vm: "1", name: "Focus Depth", retired: "", keyword: "FocusDepth" });
-	dicom_dictionary.insert(0x00185020, DicomDictElt { vr : "LO", vm: "1", name: "Processing Function", retired: "", keyword: "ProcessingFunction" });
-	dicom_dictionary.insert(0x00185021, DicomDictElt { vr : "LO", vm: "1", name: "Postprocessing Function", retired: "Retired", keyword: "PostprocessingFunction" });
-	dicom_dictionary.insert(0x00185022, DicomDictElt { vr : "DS", vm: "1", name: "Mechanical Index", retired: "", keyword: "MechanicalIndex" });
-	dicom_dictionary.insert(0x00185024, DicomDictElt { vr : "DS", vm: "1", name: "Bone Thermal Index", retired: "", keyword: "BoneThermalIndex" });
-	dicom_dictionary.insert(0x00185026, DicomDictElt { vr : "DS", vm: "1", name: "Cranial Thermal Index", retired: "", keyword: "CranialThermalIndex" });
-	dicom_dictionary.insert(0x00185027, DicomDictElt { vr : "DS", vm: "1", name: "Soft Tissue Thermal Index", retired: "", keyword: "SoftTissueThermalIndex" });
-	dicom_dictionary.insert(0x00185028, DicomDictElt { vr : "DS", vm: "1", name: "Soft Tissue-focus Thermal Index", retired: "", keyword: "SoftTissueFocusThermalIndex" });
-	dicom_dictionary.insert(0x00185029, DicomDictElt { vr : "DS", vm: "1", name: "Soft Tissue-surface Thermal Index", retired: "", keyword: "SoftTissueSurfaceThermalIndex" });
-	dicom_dictionary.insert(0x00185030, DicomDictElt { vr : "DS", vm: "1", name: "Dynamic Range", retired: "Retired", keyword: "DynamicRange" });
-	dicom_dictionary.insert(0x00185040, DicomDictElt { vr : "DS", vm: "1", name: "Total Gain", retired: "Retired", keyword: "TotalGain" });
-	dicom_dictionary.insert(0x00185050, DicomDictElt { vr : "IS", vm: "1", name: "Depth of Scan Field", retired: "", keyword: "DepthOfScanField" });
-	dicom_dictionary.insert(0x00185100, DicomDictElt { vr : "CS", vm: "1", name: "Patient Position", retired: "", keyword: "PatientPosition" });
-	dicom_dictionary.insert(0x00185101, DicomDictElt { vr : "CS", vm: "1", name: "View Position", retired: "", keyword: "ViewPosition" });
-	dicom_dictionary.insert(0x00185104, DicomDictElt { vr : "SQ", vm: "1", name: "Projection Eponymous Name Code Sequence", retired: "", keyword: "ProjectionEponymousNameCodeSequence" });
-	dicom_dictionary.insert(0x00185210, DicomDictElt { vr : "DS", vm: "6", name: "Image Transformation Matrix", retired: "Retired", keyword: "ImageTransformationMatrix" });
-	dicom_dictionary.insert(0x00185212, DicomDictElt { vr : "DS", vm: "3", name: "Image Translation Vector", retired: "Retired", keyword: "ImageTranslationVector" });
-	dicom_dictionary.insert(0x00186000, DicomDictElt { vr : "DS", vm: "1", name: "Sensitivity", retired: "", keyword: "Sensitivity" });
-	dicom_dictionary.insert(0x00186011, DicomDictElt { vr : "SQ", vm: "1", name: "Sequence of Ultrasound Regions", retired: "", keyword: "SequenceOfUltrasoundRegions" });
-	dicom_dictionary.insert(0x00186012, DicomDictElt { vr : "US", vm: "1", name: "Region Spatial Format", retired: "", keyword: "RegionSpatialFormat" });
-	dicom_dictionary.insert(0x00186014, DicomDictElt { vr : "US", vm: "1", name: "Region Data Type", retired: "", keyword: "RegionDataType" });
-	dicom_dictionary.insert(0x00186016, DicomDictElt { vr : "UL", vm: "1", name: "Region Flags", retired: "", keyword: "RegionFlags" });
-	dicom_dictionary.insert(0x00186018, DicomDictElt { vr : "UL", vm: "1", name: "Region Location Min X0", retired: "", keyword: "RegionLocationMinX0" });
-	dicom_dictionary.insert(0x0018601A, DicomDictElt { vr : "UL", vm: "1", name: "Region Location Min Y0", retired: "", keyword: "RegionLocationMinY0" });
-	dicom_dictionary.insert(0x0018601C, DicomDictElt { vr : "UL", vm: "1", name: "Region Location Max X1", retired: "", keyword: "RegionLocationMaxX1" });
-	dicom_dictionary.insert(0x0018601E, DicomDictElt { vr : "UL", vm: "1", name: "Region Location Max Y1", retired: "", keyword: "RegionLocationMaxY1" });
-	dicom_dictionary.insert(0x00186020, DicomDictElt { vr : "SL", vm: "1", name: "Reference Pixel X0", retired: "", keyword: "ReferencePixelX0" });
-	dicom_dictionary.insert(0x00186022, DicomDictElt { vr : "SL", vm: "1", name: "Reference Pixel Y0", retired: "", keyword: "ReferencePixelY0" });
-	dicom_dictionary.insert(0x00186024, DicomDictElt { vr : "US", vm: "1", name: "Physical Units X Direction", retired: "", keyword: "PhysicalUnitsXDirection" });
-	dicom_dictionary.insert(0x00186026, DicomDictElt { vr : "US", vm: "1", name: "Physical Units Y Direction", retired: "", keyword: "PhysicalUnitsYDirection" });
-	dicom_dictionary.insert(0x00186028, DicomDictElt { vr : "FD", vm: "1", name: "Reference Pixel Physical Value X", retired: "", keyword: "ReferencePixelPhysicalValueX" });
-	dicom_dictionary.insert(0x0018602A, DicomDictElt { vr : "FD", vm: "1", name: "Reference Pixel Physical Value Y", retired: "", keyword: "ReferencePixelPhysicalValueY" });
-	dicom_dictionary.insert(0x0018602C, DicomDictElt { vr : "FD", vm: "1", name: "Physical Delta X", retired: "", keyword: "PhysicalDeltaX" });
-	dicom_dictionary.insert(0x0018602E, DicomDictElt { vr : "FD", vm: "1", name: "Physical Delta Y", retired: "", keyword: "PhysicalDeltaY" });
-	dicom_dictionary.insert(0x00186030, DicomDictElt { vr : "UL", vm: "1", name: "Transducer Frequency", retired: "", keyword: "TransducerFrequency" });
-	dicom_dictionary.insert(0x00186031, DicomDictElt { vr : "CS", vm: "1", name: "Transducer Type", retired: "", keyword: "TransducerType" });
-	dicom_dictionary.insert(0x00186032, DicomDictElt { vr : "UL", vm: "1", name: "Pulse Repetition Frequency", retired: "", keyword: "PulseRepetitionFrequency" });
-	dicom_dictionary.insert(0x00186034, DicomDictElt { vr : "FD", vm: "1", name: "Doppler Correction Angle", retired: "", keyword: "DopplerCorrectionAngle" });
-	dicom_dictionary.insert(0x00186036, DicomDictElt { vr : "FD", vm: "1", name: "Steering Angle", retired: "", keyword: "SteeringAngle" });
-	dicom_dictionary.insert(0x00186038, DicomDictElt { vr : "UL", vm: "1", name: "Doppler Sample Volume X Position (Retired)", retired: "Retired", keyword: "DopplerSampleVolumeXPositionRetired" });
-	dicom_dictionary.insert(0x00186039, DicomDictElt { vr : "SL", vm: "1", name: "Doppler Sample Volume X Position", retired: "", keyword: "DopplerSampleVolumeXPosition" });
-	dicom_dictionary.insert(0x0018603A, DicomDictElt { vr : "UL", vm: "1", name: "Doppler Sample Volume Y Position (Retired)", retired: "Retired", keyword: "DopplerSampleVolumeYPositionRetired" });
-	dicom_dictionary.insert(0x0018603B, DicomDictElt { vr : "SL", vm: "1", name: "Doppler Sample Volume Y Position", retired: "", keyword: "DopplerSampleVolumeYPosition" });
-	dicom_dictionary.insert(0x0018603C, DicomDictElt { vr : "UL", vm: "1", name: "TM-Line Position X0 (Retired)", retired: "Retired", keyword: "TMLinePositionX0Retired" });
-	dicom_dictionary.insert(0x0018603D, DicomDictElt { vr : "SL", vm: "1", name: "TM-Line Position X0", retired: "", keyword: "TMLinePositionX0" });
-	dicom_dictionary.insert(0x0018603E, DicomDictElt { vr : "UL", vm: "1", name: "TM-Line Position Y0 (Retired)", retired: "Retired", keyword: "TMLinePositionY0Retired" });
-	dicom_dictionary.insert(0x0018603F, DicomDictElt { vr : "SL", vm: "1", name: "TM-Line Position Y0", retired: "", keyword: "TMLinePositionY0" });
-	dicom_dictionary.insert(0x00186040, DicomDictElt { vr : "UL", vm: "1", name: "TM-Line Position X1 (Retired)", retired: "Retired", keyword: "TMLinePositionX1Retired" });
-	dicom_dictionary.insert(0x00186041, DicomDictElt { vr : "SL", vm: "1", name: "TM-Line Position X1", retired: "", keyword: "TMLinePositionX1" });
-	dicom_dictionary.insert(0x00186042, DicomDictElt { vr : "UL", vm: "1", name: "TM-Line Position Y1 (Retired)", retired: "Retired", keyword: "TMLinePositionY1Retired" });
-	dicom_dictionary.insert(0x00186043, DicomDictElt { vr : "SL", vm: "1", name: "TM-Line Position Y1", retired: "", keyword: "TMLinePositionY1" });
-	dicom_dictionary.insert(0x00186044, DicomDictElt { vr : "US", vm: "1", name: "Pixel Component Organization", retired: "", keyword: "PixelComponentOrganization" });
-	dicom_dictionary.insert(0x00186046, DicomDictElt { vr : "UL", vm: "1", name: "Pixel Component Mask", retired: "", keyword: "PixelComponentMask" });
-	dicom_dictionary.insert(0x00186048, DicomDictElt { vr : "UL", vm: "1", name: "Pixel Component Range Start", retired: "", keyword: "PixelComponentRangeStart" });
-	dicom_dictionary.insert(0x0018604A, DicomDictElt { vr : "UL", vm: "1", name: "Pixel Component Range Stop", retired: "", keyword: "PixelComponentRangeStop" });
-	dicom_dictionary.insert(0x0018604C, DicomDictElt { vr : "US", vm: "1", name: "Pixel Component Physical Units", retired: "", keyword: "PixelComponentPhysicalUnits" });
-	dicom_dictionary.insert(0x0018604E, DicomDictElt { vr : "US", vm: "1", name: "Pixel Component Data Type", retired: "", keyword: "PixelComponentDataType" });
-	dicom_dictionary.insert(0x00186050, DicomDictElt { vr : "UL", vm: "1", name: "Number of Table Break Points", retired: "", keyword: "NumberOfTableBreakPoints" });
-	dicom_dictionary.insert(0x00186052, DicomDictElt { vr : "UL", vm: "1-n", name: "Table of X Break Points", retired: "", keyword: "TableOfXBreakPoints" });
-	dicom_dictionary.insert(0x00186054, DicomDictElt { vr : "FD", vm: "1-n", name: "Table of Y Break Points", retired: "", keyword: "TableOfYBreakPoints" });
-	dicom_dictionary.insert(0x00186056, DicomDictElt { vr : "UL", vm: "1", name: "Number of Table Entries", retired: "", keyword: "NumberOfTableEntries" });
-	dicom_dictionary.insert(0x00186058, DicomDictElt { vr : "UL", vm: "1-n", name: "Table of Pixel Values", retired: "", keyword: "TableOfPixelValues" });
-	dicom_dictionary.insert(0x0018605A, DicomDictElt { vr : "FL", vm: "1-n", name: "Table of Parameter Values", retired: "", keyword: "TableOfParameterValues" });
-	dicom_dictionary.insert(0x00186060, DicomDictElt { vr : "FL", vm: "1-n", name: "R Wave Time Vector", retired: "", keyword: "RWaveTimeVector" });
-	dicom_dictionary.insert(0x00187000, DicomDictElt { vr : "CS", vm: "1", name: "Detector Conditions Nominal Flag", retired: "", keyword: "DetectorConditionsNominalFlag" });
-	dicom_dictionary.insert(0x00187001, DicomDictElt { vr : "DS", vm: "1", name: "Detector Temperature", retired: "", keyword: "DetectorTemperature" });
-	dicom_dictionary.insert(0x00187004, DicomDictElt { vr : "CS", vm: "1", name: "Detector Type", retired: "", keyword: "DetectorType" });
-	dicom_dictionary.insert(0x00187005, DicomDictElt { vr : "CS", vm: "1", name: "Detector Configuration", retired: "", keyword: "DetectorConfiguration" });
-	dicom_dictionary.insert(0x00187006, DicomDictElt { vr : "LT", vm: "1", name: "Detector Description", retired: "", keyword: "DetectorDescription" });
-	dicom_dictionary.insert(0x00187008, DicomDictElt { vr : "LT", vm: "1", name: "Detector Mode", retired: "", keyword: "DetectorMode" });
-	dicom_dictionary.insert(0x0018700A, DicomDictElt { vr : "SH", vm: "1", name: "Detector ID", retired: "", keyword: "DetectorID" });
-	dicom_dictionary.insert(0x0018700C, DicomDictElt { vr : "DA", vm: "1", name: "Date of Last Detector Calibration", retired: "", keyword: "DateOfLastDetectorCalibration" });
-	dicom_dictionary.insert(0x0018700E, DicomDictElt { vr : "TM", vm: "1", name: "Time of Last Detector Calibration", retired: "", keyword: "TimeOfLastDetectorCalibration" });
-	dicom_dictionary.insert(0x00187010, DicomDictElt { vr : "IS", vm: "1", name: "Exposures on Detector Since Last Calibration", retired: "", keyword: "ExposuresOnDetectorSinceLastCalibration" });
-	dicom_dictionary.insert(0x00187011, DicomDictElt { vr : "IS", vm: "1", name: "Exposures on Detector Since Manufactured", retired: "", keyword: "ExposuresOnDetectorSinceManufactured" });
-	dicom_dictionary.insert(0x00187012, DicomDictElt { vr : "DS", vm: "1", name: "Detector Time Since Last Exposure", retired: "", keyword: "DetectorTimeSinceLastExposure" });
-	dicom_dictionary.insert(0x00187014, DicomDictElt { vr : "DS", vm: "1", name: "Detector Active Time", retired: "", keyword: "DetectorActiveTime" });
-	dicom_dictionary.insert(0x00187016, DicomDictElt { vr : "DS", vm: "1", name: "Detector Activation Offset From Exposure", retired: "", keyword: "DetectorActivationOffsetFromExposure" });
-	dicom_dictionary.insert(0x0018701A, DicomDictElt { vr : "DS", vm: "2", name: "Detector Binning", retired: "", keyword: "DetectorBinning" });
-	dicom_dictionary.insert(0x00187020, DicomDictElt { vr : "DS", vm: "2", name: "Detector Element Physical Size", retired: "", keyword: "DetectorElementPhysicalSize" });
-	dicom_dictionary.insert(0x00187022, DicomDictElt { vr : "DS", vm: "2", name: "Detector Element Spacing", retired: "", keyword: "DetectorElementSpacing" });
-	dicom_dictionary.insert(0x00187024, DicomDictElt { vr : "CS", vm: "1", name: "Detector Active Shape", retired: "", keyword: "DetectorActiveShape" });
-	dicom_dictionary.insert(0x00187026, DicomDictElt { vr : "DS", vm: "1-2", name: "Detector Active Dimension(s)", retired: "", keyword: "DetectorActiveDimensions" });
-	dicom_dictionary.insert(0x00187028, DicomDictElt { vr : "DS", vm: "2", name: "Detector Active Origin", retired: "", keyword: "DetectorActiveOrigin" });
-	dicom_dictionary.insert(0x0018702A, DicomDictElt { vr : "LO", vm: "1", name: "Detector Manufacturer Name", retired: "", keyword: "DetectorManufacturerName" });
-	dicom_dictionary.insert(0x0018702B, DicomDictElt { vr : "LO", vm: "1", name: "Detector Manufacturer's Model Name", retired: "", keyword: "DetectorManufacturerModelName" });
-	dicom_dictionary.insert(0x00187030, DicomDictElt { vr : "DS", vm: "2", name: "Field of View Origin", retired: "", keyword: "FieldOfViewOrigin" });
-	dicom_dictionary.insert(0x00187032, DicomDictElt { vr : "DS", vm: "1", name: "Field of View Rotation", retired: "", keyword: "FieldOfViewRotation" });
-	dicom_dictionary.insert(0x00187034, DicomDictElt { vr : "CS", vm: "1", name: "Field of View Horizontal Flip", retired: "", keyword: "FieldOfViewHorizontalFlip" });
-	dicom_dictionary.insert(0x00187036, DicomDictElt { vr : "FL", vm: "2", name: "Pixel Data Area Origin Relative To FOV", retired: "", keyword: "PixelDataAreaOriginRelativeToFOV" });
-	dicom_dictionary.insert(0x00187038, DicomDictElt { vr : "FL", vm: "1", name: "Pixel Data Area Rotation Angle Relative To FOV", retired: "", keyword: "PixelDataAreaRotationAngleRelativeToFOV" });
-	dicom_dictionary.insert(0x00187040, DicomDictElt { vr : "LT", vm: "1", name: "Grid Absorbing Material", retired: "", keyword: "GridAbsorbingMaterial" });
-	dicom_dictionary.insert(0x00187041, DicomDictElt { vr : "LT", vm: "1", name: "Grid Spacing Material", retired: "", keyword: "GridSpacingMaterial" });
-	dicom_dictionary.insert(0x00187042, DicomDictElt { vr : "DS", vm: "1", name: "Grid Thickness", retired: "", keyword: "GridThickness" });
-	dicom_dictionary.insert(0x00187044, DicomDictElt { vr : "DS", vm: "1", name: "Grid Pitch", retired: "", keyword: "GridPitch" });
-	dicom_dictionary.insert(0x00187046, DicomDictElt { vr : "IS", vm: "2", name: "Grid Aspect Ratio", retired: "", keyword: "GridAspectRatio" });
-	dicom_dictionary.insert(0x00187048, DicomDictElt { vr : "DS", vm: "1", name: "Grid Period", retired: "", keyword: "GridPeriod" });
-	dicom_dictionary.insert(0x0018704C, DicomDictElt { vr : "DS", vm: "1", name: "Grid Focal Distance", retired: "", keyword: "GridFocalDistance" });
-	dicom_dictionary.insert(0x00187050, DicomDictElt { vr : "CS", vm: "1-n", name: "Filter Material", retired: "", keyword: "FilterMaterial" });
-	dicom_dictionary.insert(0x00187052, DicomDictElt { vr : "DS", vm: "1-n", name: "Filter Thickness Minimum", retired: "", keyword: "FilterThicknessMinimum" });
-	dicom_dictionary.insert(0x00187054, DicomDictElt { vr : "DS", vm: "1-n", name: "Filter Thickness Maximum", retired: "", keyword: "FilterThicknessMaximum" });
-	dicom_dictionary.insert(0x00187056, DicomDictElt { vr : "FL", vm: "1-n", name: "Filter Beam Path Length Minimum", retired: "", keyword: "FilterBeamPathLengthMinimum" });
-	dicom_dictionary.insert(0x00187058, DicomDictElt { vr : "FL", vm: "1-n", name: "Filter Beam Path Length Maximum", retired: "", keyword: "FilterBeamPathLengthMaximum" });
-	dicom_dictionary.insert(0x00187060, DicomDictElt { vr : "CS", vm: "1", name: "Exposure Control Mode", retired: "", keyword: "ExposureControlMode" });
-	dicom_dictionary.insert(0x00187062, DicomDictElt { vr : "LT", vm: "1", name: "Exposure Control Mode Description", retired: "", keyword: "ExposureControlModeDescription" });
-	dicom_dictionary.insert(0x00187064, DicomDictElt { vr : "CS", vm: "1", name: "Exposure Status", retired: "", keyword: "ExposureStatus" });
-	dicom_dictionary.insert(0x00187065, DicomDictElt { vr : "DS", vm: "1", name: "Phototimer Setting", retired: "", keyword: "PhototimerSetting" });
-	dicom_dictionary.insert(0x00188150, DicomDictElt { vr : "DS", vm: "1", name: "Exposure Time in uS", retired: "", keyword: "ExposureTimeInuS" });
-	dicom_dictionary.insert(0x00188151, DicomDictElt { vr : "DS", vm: "1", name: "X-Ray Tube Current in uA", retired: "", keyword: "XRayTubeCurrentInuA" });
-	dicom_dictionary.insert(0x00189004, DicomDictElt { vr : "CS", vm: "1", name: "Content Qualification", retired: "", keyword: "ContentQualification" });
-	dicom_dictionary.insert(0x00189005, DicomDictElt { vr : "SH", vm: "1", name: "Pulse Sequence Name", retired: "", keyword: "PulseSequenceName" });
-	dicom_dictionary.insert(0x00189006, DicomDictElt { vr : "SQ", vm: "1", name: "MR Imaging Modifier Sequence", retired: "", keyword: "MRImagingModifierSequence" });
-	dicom_dictionary.insert(0x00189008, DicomDictElt { vr : "CS", vm: "1", name: "Echo Pulse Sequence", retired: "", keyword: "EchoPulseSequence" });
-	dicom_dictionary.insert(0x00189009, DicomDictElt { vr : "CS", vm: "1", name: "Inversion Recovery", retired: "", keyword: "InversionRecovery" });
-	dicom_dictionary.insert(0x00189010, DicomDictElt { vr : "CS", vm: "1", name: "Flow Compensation", retired: "", keyword: "FlowCompensation" });
-	dicom_dictionary.insert(0x00189011, DicomDictElt { vr : "CS", vm: "1", name: "Multiple Spin Echo", retired: "", keyword: "MultipleSpinEcho" });
-	dicom_dictionary.insert(0x00189012, DicomDictElt { vr : "CS", vm: "1", name: "Multi-planar Excitation", retired: "", keyword: "MultiPlanarExcitation" });
-	dicom_dictionary.insert(0x00189014, DicomDictElt { vr : "CS", vm: "1", name: "Phase Contrast", retired: "", keyword: "PhaseContrast" });
-	dicom_dictionary.insert(0x00189015, DicomDictElt { vr : "CS", vm: "1", name: "Time of Flight Contrast", retired: "", keyword: "TimeOfFlightContrast" });
-	dicom_dictionary.insert(0x00189016, DicomDictElt { vr : "CS", vm: "1", name: "Spoiling", retired: "", keyword: "Spoiling" });
-	dicom_dictionary.insert(0x00189017, DicomDictElt { vr : "CS", vm: "1", name: "Steady State Pulse Sequence", retired: "", keyword: "SteadyStatePulseSequence" });
-	dicom_dictionary.insert(0x00189018, DicomDictElt { vr : "CS", vm: "1", name: "Echo Planar Pulse Sequence", retired: "", keyword: "EchoPlanarPulseSequence" });
-	dicom_dictionary.insert(0x00189019, DicomDictElt { vr : "FD", vm: "1", name: "Tag Angle First Axis", retired: "", keyword: "TagAngleFirstAxis" });
-	dicom_dictionary.insert(0x00189020, DicomDictElt { vr : "CS", vm: "1", name: "Magnetization Transfer", retired: "", keyword: "MagnetizationTransfer" });
-	dicom_dictionary.insert(0x00189021, DicomDictElt { vr : "CS", vm: "1", name: "T2 Preparation", retired: "", keyword: "T2Preparation" });
-	dicom_dictionary.insert(0x00189022, DicomDictElt { vr : "CS", vm: "1", name: "Blood Signal Nulling", retired: "", keyword: "BloodSignalNulling" });
-	dicom_dictionary.insert(0x00189024, DicomDictElt { vr : "CS", vm: "1", name: "Saturation Recovery", retired: "", keyword: "SaturationRecovery" });
-	dicom_dictionary.insert(0x00189025, DicomDictElt { vr : "CS", vm: "1", name: "Spectrally Selected Suppression", retired: "", keyword: "SpectrallySelectedSuppression" });
-	dicom_dictionary.insert(0x00189026, DicomDictElt { vr : "CS", vm: "1", name: "Spectrally Selected Excitation", retired: "", keyword: "SpectrallySelectedExcitation" });
-	dicom_dictionary.insert(0x00189027, DicomDictElt { vr : "CS", vm: "1", name: "Spatial Pre-saturation", retired: "", keyword: "SpatialPresaturation" });
-	dicom_dictionary.insert(0x00189028, DicomDictElt { vr : "CS", vm: "1", name: "Tagging", retired: "", keyword: "Tagging" });
-	dicom_dictionary.insert(0x00189029, DicomDictElt { vr : "CS", vm: "1", name: "Oversampling Phase", retired: "", keyword: "OversamplingPhase" });
-	dicom_dictionary.insert(0x00189030, DicomDictElt { vr : "FD", vm: "1", name: "Tag Spacing First Dimension", retired: "", keyword: "TagSpacingFirstDimension" });
-	dicom_dictionary.insert(0x00189032, DicomDictElt { vr : "CS", vm: "1", name: "Geometry of k-Space Traversal", retired: "", keyword: "GeometryOfKSpaceTraversal" });
-	dicom_dictionary.insert(0x00189033, DicomDictElt { vr : "CS", vm: "1", name: "Segmented k-Space Traversal", retired: "", keyword: "SegmentedKSpaceTraversal" });
-	dicom_dictionary.insert(0x00189034, DicomDictElt { vr : "CS", vm: "1", name: "Rectilinear Phase Encode Reordering", retired: "", keyword: "RectilinearPhaseEncodeReordering" });
-	dicom_dictionary.insert(0x00189035, DicomDictElt { vr : "FD", vm: "1", name: "Tag Thickness", retired: "", keyword: "TagThickness" });
-	dicom_dictionary.insert(0x00189036, DicomDictElt { vr : "CS", vm: "1", name: "Partial Fourier Direction", retired: "", keyword: "PartialFourierDirection" });
-	dicom_dictionary.insert(0x00189037, DicomDictElt { vr : "CS", vm: "1", name: "Cardiac Synchronization Technique", retired: "", keyword: "CardiacSynchronizationTechnique" });
-	dicom_dictionary.insert(0x00189041, DicomDictElt { vr : "LO", vm: "1", name: "Receive Coil Manufacturer Name", retired: "", keyword: "ReceiveCoilManufacturerName" });
-	dicom_dictionary.insert(0x00189042, DicomDictElt { vr : "SQ", vm: "1", name: "MR Receive Coil Sequence", retired: "", keyword: "MRReceiveCoilSequence" });
-	dicom_dictionary.insert(0x00189043, DicomDictElt { vr : "CS", vm: "1", name: "Receive Coil Type", retired: "", keyword: "ReceiveCoilType" });
-	dicom_dictionary.insert(0x00189044, DicomDictElt { vr : "CS", vm: "1", name: "Quadrature Receive Coil", retired: "", keyword: "QuadratureReceiveCoil" });
-	dicom_dictionary.insert(0x00189045, DicomDictElt { vr : "SQ", vm: "1", name: "Multi-Coil Definition Sequence", retired: "", keyword: "MultiCoilDefinitionSequence" });
-	dicom_dictionary.insert(0x00189046, DicomDictElt { vr : "LO", vm: "1", name: "Multi-Coil Configuration", retired: "", keyword: "MultiCoilConfiguration" });
-	dicom_dictionary.insert(0x00189047, DicomDictElt { vr : "SH", vm: "1", name: "Multi-Coil Element Name", retired: "", keyword: "MultiCoilElementName" });
-	dicom_dictionary.insert(0x00189048, DicomDictElt { vr : "CS", vm: "1", name: "Multi-Coil Element Used", retired: "", keyword: "MultiCoilElementUsed" });
-	dicom_dictionary.insert(0x00189049, DicomDictElt { vr : "SQ", vm: "1", name: "MR Transmit Coil Sequence", retired: "", keyword: "MRTransmitCoilSequence" });
-	dicom_dictionary.insert(0x00189050, DicomDictElt { vr : "LO", vm: "1", name: "Transmit Coil Manufacturer Name", retired: "", keyword: "TransmitCoilManufacturerName" });
-	dicom_dictionary.insert(0x00189051, DicomDictElt { vr : "CS", vm: "1", name: "Transmit Coil Type", retired: "", keyword: "TransmitCoilType" });
-	dicom_dictionary.insert(0x00189052, DicomDictElt { vr : "FD", vm: "1-2", name: "Spectral Width", retired: "", keyword: "SpectralWidth" });
-	dicom_dictionary.insert(0x00189053, DicomDictElt { vr : "FD", vm: "1-2", name: "Chemical Shift Reference", retired: "", keyword: "ChemicalShiftReference" });
-	dicom_dictionary.insert(0x00189054, DicomDictElt { vr : "CS", vm: "1", name: "Volume Localization Technique", retired: "", keyword: "VolumeLocalizationTechnique" });
-	dicom_dictionary.insert(0x00189058, DicomDictElt { vr : "US", vm: "1", name: "MR Acquisition Frequency Encoding Steps", retired: "", keyword: "MRAcquisitionFrequencyEncodingSteps" });
-	dicom_dictionary.insert(0x00189059, DicomDictElt { vr : "CS", vm: "1", name: "De-coupling", retired: "", keyword: "Decoupling" });
-	dicom_dictionary.insert(0x00189060, DicomDictElt { vr : "CS", vm: "1-2", name: "De-coupled Nucleus", retired: "", keyword: "DecoupledNucleus" });
-	dicom_dictionary.insert(0x00189061, DicomDictElt { vr : "FD", vm: "1-2", name: "De-coupling Frequency", retired: "", keyword: "DecouplingFrequency" });
-	dicom_dictionary.insert(0x00189062, DicomDictElt { vr : "CS", vm: "1", name: "De-coupling Method", retired: "", keyword: "DecouplingMethod" });
-	dicom_dictionary.insert(0x00189063, DicomDictElt { vr : "FD", vm: "1-2", name: "De-coupling Chemical Shift Reference", retired: "", keyword: "DecouplingChemicalShiftReference" });
-	dicom_dictionary.insert(0x00189064, DicomDictElt { vr : "CS", vm: "1", name: "k-space Filtering", retired: "", keyword: "KSpaceFiltering" });
-	dicom_dictionary.insert(0x00189065, DicomDictElt { vr : "CS", vm: "1-2", name: "Time Domain Filtering", retired: "", keyword: "TimeDomainFiltering" });
-	dicom_dictionary.insert(0x00189066, DicomDictElt { vr : "US", vm: "1-2", name: "Number of Zero Fills", retired: "", keyword: "NumberOfZeroFills" });
-	dicom_dictionary.insert(0x00189067, DicomDictElt { vr : "CS", vm: "1", name: "Baseline Correction", retired: "", keyword: "BaselineCorrection" });
-	dicom_dictionary.insert(0x00189069, DicomDictElt { vr : "FD", vm: "1", name: "Parallel Reduction Factor In-plane", retired: "", keyword: "ParallelReductionFactorInPlane" });
-	dicom_dictionary.insert(0x00189070, DicomDictElt { vr : "FD", vm: "1", name: "Cardiac R-R Interval Specified", retired: "", keyword: "CardiacRRIntervalSpecified" });
-	dicom_dictionary.insert(0x00189073, DicomDictElt { vr : "FD", vm: "1", name: "Acquisition Duration", retired: "", keyword: "AcquisitionDuration" });
-	dicom_dictionary.insert(0x00189074, DicomDictElt { vr : "DT", vm: "1", name: "Frame Acquisition DateTime", retired: "", keyword: "FrameAcquisitionDateTime" });
-	dicom_dictionary.insert(0x00189075, DicomDictElt { vr : "CS", vm: "1", name: "Diffusion Directionality", retired: "", keyword: "DiffusionDirectionality" });
-	dicom_dictionary.insert(0x00189076, DicomDictElt { vr : "SQ", vm: "1", name: "Diffusion Gradient Direction Sequence", retired: "", keyword: "DiffusionGradientDirectionSequence" });
-	dicom_dictionary.insert(0x00189077, DicomDictElt { vr : "CS", vm: "1", name: "Parallel Acquisition", retired: "", keyword: "ParallelAcquisition" });
-	dicom_dictionary.insert(0x00189078, DicomDictElt { vr : "CS", vm: "1", name: "Parallel Acquisition Technique", retired: "", keyword: "ParallelAcquisitionTechnique" });
-	dicom_dictionary.insert(0x00189079, DicomDictElt { vr : "FD", vm: "1-n", name: "Inversion Times", retired: "", keyword: "InversionTimes" });
-	dicom_dictionary.insert(0x00189080, DicomDictElt { vr : "ST", vm: "1", name: "Metabolite Map Description", retired: "", keyword: "MetaboliteMapDescription" });
-	dicom_dictionary.insert(0x00189081, DicomDictElt { vr : "CS", vm: "1", name: "Partial Fourier", retired: "", keyword: "PartialFourier" });
-	dicom_dictionary.insert(0x00189082, DicomDictElt { vr : "FD", vm: "1", name: "Effective Echo Time", retired: "", keyword: "EffectiveEchoTime" });
-	dicom_dictionary.insert(0x00189083, DicomDictElt { vr : "SQ", vm: "1", name: "Metabolite Map Code Sequence", retired: "", keyword: "MetaboliteMapCodeSequence" });
-	dicom_dictionary.insert(0x00189084, DicomDictElt { vr : "SQ", vm: "1", name: "Chemical Shift Sequence", retired: "", keyword: "ChemicalShiftSequence" });
-	dicom_dictionary.insert(0x00189085, DicomDictElt { vr : "CS", vm: "1", name: "Cardiac Signal Source", retired: "", keyword: "CardiacSignalSource" });
-	dicom_dictionary.insert(0x00189087, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value", retired: "", keyword: "DiffusionBValue" });
-	dicom_dictionary.insert(0x00189089, DicomDictElt { vr : "FD", vm: "3", name: "Diffusion Gradient Orientation", retired: "", keyword: "DiffusionGradientOrientation" });
-	dicom_dictionary.insert(0x00189090, DicomDictElt { vr : "FD", vm: "3", name: "Velocity Encoding Direction", retired: "", keyword: "VelocityEncodingDirection" });
-	dicom_dictionary.insert(0x00189091, DicomDictElt { vr : "FD", vm: "1", name: "Velocity Encoding Minimum Value", retired: "", keyword: "VelocityEncodingMinimumValue" });
-	dicom_dictionary.insert(0x00189092, DicomDictElt { vr : "SQ", vm: "1", name: "Velocity Encoding Acquisition Sequence", retired: "", keyword: "VelocityEncodingAcquisitionSequence" });
-	dicom_dictionary.insert(0x00189093, DicomDictElt { vr : "US", vm: "1", name: "Number of k-Space Trajectories", retired: "", keyword: "NumberOfKSpaceTrajectories" });
-	dicom_dictionary.insert(0x00189094, DicomDictElt { vr : "CS", vm: "1", name: "Coverage of k-Space", retired: "", keyword: "CoverageOfKSpace" });
-	dicom_dictionary.insert(0x00189095, DicomDictElt { vr : "UL", vm: "1", name: "Spectroscopy Acquisition Phase Rows", retired: "", keyword: "SpectroscopyAcquisitionPhaseRows" });
-	dicom_dictionary.insert(0x00189096, DicomDictElt { vr : "FD", vm: "1", name: "Parallel Reduction Factor In-plane (Retired)", retired: "Retired", keyword: "ParallelReductionFactorInPlaneRetired" });
-	dicom_dictionary.insert(0x00189098, DicomDictElt { vr : "FD", vm: "1-2", name: "Transmitter Frequency", retired: "", keyword: "TransmitterFrequency" });
-	dicom_dictionary.insert(0x00189100, DicomDictElt { vr : "CS", vm: "1-2", name: "Resonant Nucleus", retired: "", keyword: "ResonantNucleus" });
-	dicom_dictionary.insert(0x00189101, DicomDictElt { vr : "CS", vm: "1", name: "Frequency Correction", retired: "", keyword: "FrequencyCorrection" });
-	dicom_dictionary.insert(0x00189103, DicomDictElt { vr : "SQ", vm: "1", name: "MR Spectroscopy FOV/Geometry Sequence", retired: "", keyword: "MRSpectroscopyFOVGeometrySequence" });
-	dicom_dictionary.insert(0x00189104, DicomDictElt { vr : "FD", vm: "1", name: "Slab Thickness", retired: "", keyword: "SlabThickness" });
-	dicom_dictionary.insert(0x00189105, DicomDictElt { vr : "FD", vm: "3", name: "Slab Orientation", retired: "", keyword: "SlabOrientation" });
-	dicom_dictionary.insert(0x00189106, DicomDictElt { vr : "FD", vm: "3", name: "Mid Slab Position", retired: "", keyword: "MidSlabPosition" });
-	dicom_dictionary.insert(0x00189107, DicomDictElt { vr : "SQ", vm: "1", name: "MR Spatial Saturation Sequence", retired: "", keyword: "MRSpatialSaturationSequence" });
-	dicom_dictionary.insert(0x00189112, DicomDictElt { vr : "SQ", vm: "1", name: "MR Timing and Related Parameters Sequence", retired: "", keyword: "MRTimingAndRelatedParametersSequence" });
-	dicom_dictionary.insert(0x00189114, DicomDictElt { vr : "SQ", vm: "1", name: "MR Echo Sequence", retired: "", keyword: "MREchoSequence" });
-	dicom_dictionary.insert(0x00189115, DicomDictElt { vr : "SQ", vm: "1", name: "MR Modifier Sequence", retired: "", keyword: "MRModifierSequence" });
-	dicom_dictionary.insert(0x00189117, DicomDictElt { vr : "SQ", vm: "1", name: "MR Diffusion Sequence", retired: "", keyword: "MRDiffusionSequence" });
-	dicom_dictionary.insert(0x00189118, DicomDictElt { vr : "SQ", vm: "1", name: "Cardiac Synchronization Sequence", retired: "", keyword: "CardiacSynchronizationSequence" });
-	dicom_dictionary.insert(0x00189119, DicomDictElt { vr : "SQ", vm: "1", name: "MR Averages Sequence", retired: "", keyword: "MRAveragesSequence" });
-	dicom_dictionary.insert(0x00189125, DicomDictElt { vr : "SQ", vm: "1", name: "MR FOV/Geometry Sequence", retired: "", keyword: "MRFOVGeometrySequence" });
-	dicom_dictionary.insert(0x00189126, DicomDictElt { vr : "SQ", vm: "1", name: "Volume Localization Sequence", retired: "", keyword: "VolumeLocalizationSequence" });
-	dicom_dictionary.insert(0x00189127, DicomDictElt { vr : "UL", vm: "1", name: "Spectroscopy Acquisition Data Columns", retired: "", keyword: "SpectroscopyAcquisitionDataColumns" });
-	dicom_dictionary.insert(0x00189147, DicomDictElt { vr : "CS", vm: "1", name: "Diffusion Anisotropy Type", retired: "", keyword: "DiffusionAnisotropyType" });
-	dicom_dictionary.insert(0x00189151, DicomDictElt { vr : "DT", vm: "1", name: "Frame Reference DateTime", retired: "", keyword: "FrameReferenceDateTime" });
-	dicom_dictionary.insert(0x00189152, DicomDictElt { vr : "SQ", vm: "1", name: "MR Metabolite Map Sequence", retired: "", keyword: "MRMetaboliteMapSequence" });
-	dicom_dictionary.insert(0x00189155, DicomDictElt { vr : "FD", vm: "1", name: "Parallel Reduction Factor out-of-plane", retired: "", keyword: "ParallelReductionFactorOutOfPlane" });
-	dicom_dictionary.insert(0x00189159, DicomDictElt { vr : "UL", vm: "1", name: "Spectroscopy Acquisition Out-of-plane Phase Steps", retired: "", keyword: "SpectroscopyAcquisitionOutOfPlanePhaseSteps" });
-	dicom_dictionary.insert(0x00189166, DicomDictElt { vr : "CS", vm: "1", name: "Bulk Motion Status", retired: "Retired", keyword: "BulkMotionStatus" });
-	dicom_dictionary.insert(0x00189168, DicomDictElt { vr : "FD", vm: "1", name: "Parallel Reduction Factor Second In-plane", retired: "", keyword: "ParallelReductionFactorSecondInPlane" });
-	dicom_dictionary.insert(0x00189169, DicomDictElt { vr : "CS", vm: "1", name: "Cardiac Beat Rejection Technique", retired: "", keyword: "CardiacBeatRejectionTechnique" });
-	dicom_dictionary.insert(0x00189170, DicomDictElt { vr : "CS", vm: "1", name: "Respiratory Motion Compensation Technique", retired: "", keyword: "RespiratoryMotionCompensationTechnique" });
-	dicom_dictionary.insert(0x00189171, DicomDictElt { vr : "CS", vm: "1", name: "Respiratory Signal Source", retired: "", keyword: "RespiratorySignalSource" });
-	dicom_dictionary.insert(0x00189172, DicomDictElt { vr : "CS", vm: "1", name: "Bulk Motion Compensation Technique", retired: "", keyword: "BulkMotionCompensationTechnique" });
-	dicom_dictionary.insert(0x00189173, DicomDictElt { vr : "CS", vm: "1", name: "Bulk Motion Signal Source", retired: "", keyword: "BulkMotionSignalSource" });
-	dicom_dictionary.insert(0x00189174, DicomDictElt { vr : "CS", vm: "1", name: "Applicable Safety Standard Agency", retired: "", keyword: "ApplicableSafetyStandardAgency" });
-	dicom_dictionary.insert(0x00189175, DicomDictElt { vr : "LO", vm: "1", name: "Applicable Safety Standard Description", retired: "", keyword: "ApplicableSafetyStandardDescription" });
-	dicom_dictionary.insert(0x00189176, DicomDictElt { vr : "SQ", vm: "1", name: "Operating Mode Sequence", retired: "", keyword: "OperatingModeSequence" });
-	dicom_dictionary.insert(0x00189177, DicomDictElt { vr : "CS", vm: "1", name: "Operating Mode Type", retired: "", keyword: "OperatingModeType" });
-	dicom_dictionary.insert(0x00189178, DicomDictElt { vr : "CS", vm: "1", name: "Operating Mode", retired: "", keyword: "OperatingMode" });
-	dicom_dictionary.insert(0x00189179, DicomDictElt { vr : "CS", vm: "1", name: "Specific Absorption Rate Definition", retired: "", keyword: "SpecificAbsorptionRateDefinition" });
-	dicom_dictionary.insert(0x00189180, DicomDictElt { vr : "CS", vm: "1", name: "Gradient Output Type", retired: "", keyword: "GradientOutputType" });
-	dicom_dictionary.insert(0x00189181, DicomDictElt { vr : "FD", vm: "1", name: "Specific Absorption Rate Value", retired: "", keyword: "SpecificAbsorptionRateValue" });
-	dicom_dictionary.insert(0x00189182, DicomDictElt { vr : "FD", vm: "1", name: "Gradient Output", retired: "", keyword: "GradientOutput" });
-	dicom_dictionary.insert(0x00189183, DicomDictElt { vr : "CS", vm: "1", name: "Flow Compensation Direction", retired: "", keyword: "FlowCompensationDirection" });
-	dicom_dictionary.insert(0x00189184, DicomDictElt { vr : "FD", vm: "1", name: "Tagging Delay", retired: "", keyword: "TaggingDelay" });
-	dicom_dictionary.insert(0x00189185, DicomDictElt { vr : "ST", vm: "1", name: "Respiratory Motion Compensation Technique Description", retired: "", keyword: "RespiratoryMotionCompensationTechniqueDescription" });
-	dicom_dictionary.insert(0x00189186, DicomDictElt { vr : "SH", vm: "1", name: "Respiratory Signal Source ID", retired: "", keyword: "RespiratorySignalSourceID" });
-	dicom_dictionary.insert(0x00189195, DicomDictElt { vr : "FD", vm: "1", name: "Chemical Shift Minimum Integration Limit in Hz", retired: "Retired", keyword: "ChemicalShiftMinimumIntegrationLimitInHz" });
-	dicom_dictionary.insert(0x00189196, DicomDictElt { vr : "FD", vm: "1", name: "Chemical Shift Maximum Integration Limit in Hz", retired: "Retired", keyword: "ChemicalShiftMaximumIntegrationLimitInHz" });
-	dicom_dictionary.insert(0x00189197, DicomDictElt { vr : "SQ", vm: "1", name: "MR Velocity Encoding Sequence", retired: "", keyword: "MRVelocityEncodingSequence" });
-	dicom_dictionary.insert(0x00189198, DicomDictElt { vr : "CS", vm: "1", name: "First Order Phase Correction", retired: "", keyword: "FirstOrderPhaseCorrection" });
-	dicom_dictionary.insert(0x00189199, DicomDictElt { vr : "CS", vm: "1", name: "Water Referenced Phase Correction", retired: "", keyword: "WaterReferencedPhaseCorrection" });
-	dicom_dictionary.insert(0x00189200, DicomDictElt { vr : "CS", vm: "1", name: "MR Spectroscopy Acquisition Type", retired: "", keyword: "MRSpectroscopyAcquisitionType" });
-	dicom_dictionary.insert(0x00189214, DicomDictElt { vr : "CS", vm: "1", name: "Respiratory Cycle Position", retired: "", keyword: "RespiratoryCyclePosition" });
-	dicom_dictionary.insert(0x00189217, DicomDictElt { vr : "FD", vm: "1", name: "Velocity Encoding Maximum Value", retired: "", keyword: "VelocityEncodingMaximumValue" });
-	dicom_dictionary.insert(0x00189218, DicomDictElt { vr : "FD", vm: "1", name: "Tag Spacing Second Dimension", retired: "", keyword: "TagSpacingSecondDimension" });
-	dicom_dictionary.insert(0x00189219, DicomDictElt { vr : "SS", vm: "1", name: "Tag Angle Second Axis", retired: "", keyword: "TagAngleSecondAxis" });
-	dicom_dictionary.insert(0x00189220, DicomDictElt { vr : "FD", vm: "1", name: "Frame Acquisition Duration", retired: "", keyword: "FrameAcquisitionDuration" });
-	dicom_dictionary.insert(0x00189226, DicomDictElt { vr : "SQ", vm: "1", name: "MR Image Frame Type Sequence", retired: "", keyword: "MRImageFrameTypeSequence" });
-	dicom_dictionary.insert(0x00189227, DicomDictElt { vr : "SQ", vm: "1", name: "MR Spectroscopy Frame Type Sequence", retired: "", keyword: "MRSpectroscopyFrameTypeSequence" });
-	dicom_dictionary.insert(0x00189231, DicomDictElt { vr : "US", vm: "1", name: "MR Acquisition Phase Encoding Steps in-plane", retired: "", keyword: "MRAcquisitionPhaseEncodingStepsInPlane" });
-	dicom_dictionary.insert(0x00189232, DicomDictElt { vr : "US", vm: "1", name: "MR Acquisition Phase Encoding Steps out-of-plane", retired: "", keyword: "MRAcquisitionPhaseEncodingStepsOutOfPlane" });
-	dicom_dictionary.insert(0x00189234, DicomDictElt { vr : "UL", vm: "1", name: "Spectroscopy Acquisition Phase Columns", retired: "", keyword: "SpectroscopyAcquisitionPhaseColumns" });
-	dicom_dictionary.insert(0x00189236, DicomDictElt { vr : "CS", vm: "1", name: "Cardiac Cycle Position", retired: "", keyword: "CardiacCyclePosition" });
-	dicom_dictionary.insert(0x00189239, DicomDictElt { vr : "SQ", vm: "1", name: "Specific Absorption Rate Sequence", retired: "", keyword: "SpecificAbsorptionRateSequence" });
-	dicom_dictionary.insert(0x00189240, DicomDictElt { vr : "US", vm: "1", name: "RF Echo Train Length", retired: "", keyword: "RFEchoTrainLength" });
-	dicom_dictionary.insert(0x00189241, DicomDictElt { vr : "US", vm: "1", name: "Gradient Echo Train Length", retired: "", keyword: "GradientEchoTrainLength" });
-	dicom_dictionary.insert(0x00189250, DicomDictElt { vr : "CS", vm: "1", name: "Arterial Spin Labeling Contrast", retired: "", keyword: "ArterialSpinLabelingContrast" });
-	dicom_dictionary.insert(0x00189251, DicomDictElt { vr : "SQ", vm: "1", name: "MR Arterial Spin Labeling Sequence", retired: "", keyword: "MRArterialSpinLabelingSequence" });
-	dicom_dictionary.insert(0x00189252, DicomDictElt { vr : "LO", vm: "1", name: "ASL Technique Description", retired: "", keyword: "ASLTechniqueDescription" });
-	dicom_dictionary.insert(0x00189253, DicomDictElt { vr : "US", vm: "1", name: "ASL Slab Number", retired: "", keyword: "ASLSlabNumber" });
-	dicom_dictionary.insert(0x00189254, DicomDictElt { vr : "FD", vm: "1", name: "ASL Slab Thickness", retired: "", keyword: "ASLSlabThickness" });
-	dicom_dictionary.insert(0x00189255, DicomDictElt { vr : "FD", vm: "3", name: "ASL Slab Orientation", retired: "", keyword: "ASLSlabOrientation" });
-	dicom_dictionary.insert(0x00189256, DicomDictElt { vr : "FD", vm: "3", name: "ASL Mid Slab Position", retired: "", keyword: "ASLMidSlabPosition" });
-	dicom_dictionary.insert(0x00189257, DicomDictElt { vr : "CS", vm: "1", name: "ASL Context", retired: "", keyword: "ASLContext" });
-	dicom_dictionary.insert(0x00189258, DicomDictElt { vr : "UL", vm: "1", name: "ASL Pulse Train Duration", retired: "", keyword: "ASLPulseTrainDuration" });
-	dicom_dictionary.insert(0x00189259, DicomDictElt { vr : "CS", vm: "1", name: "ASL Crusher Flag", retired: "", keyword: "ASLCrusherFlag" });
-	dicom_dictionary.insert(0x0018925A, DicomDictElt { vr : "FD", vm: "1", name: "ASL Crusher Flow Limit", retired: "", keyword: "ASLCrusherFlowLimit" });
-	dicom_dictionary.insert(0x0018925B, DicomDictElt { vr : "LO", vm: "1", name: "ASL Crusher Description", retired: "", keyword: "ASLCrusherDescription" });
-	dicom_dictionary.insert(0x0018925C, DicomDictElt { vr : "CS", vm: "1", name: "ASL Bolus Cut-off Flag", retired: "", keyword: "ASLBolusCutoffFlag" });
-	dicom_dictionary.insert(0x0018925D, DicomDictElt { vr : "SQ", vm: "1", name: "ASL Bolus Cut-off Timing Sequence", retired: "", keyword: "ASLBolusCutoffTimingSequence" });
-	dicom_dictionary.insert(0x0018925E, DicomDictElt { vr : "LO", vm: "1", name: "ASL Bolus Cut-off Technique", retired: "", keyword: "ASLBolusCutoffTechnique" });
-	dicom_dictionary.insert(0x0018925F, DicomDictElt { vr : "UL", vm: "1", name: "ASL Bolus Cut-off Delay Time", retired: "", keyword: "ASLBolusCutoffDelayTime" });
-	dicom_dictionary.insert(0x00189260, DicomDictElt { vr : "SQ", vm: "1", name: "ASL Slab Sequence", retired: "", keyword: "ASLSlabSequence" });
-	dicom_dictionary.insert(0x00189295, DicomDictElt { vr : "FD", vm: "1", name: "Chemical Shift Minimum Integration Limit in ppm", retired: "", keyword: "ChemicalShiftMinimumIntegrationLimitInppm" });
-	dicom_dictionary.insert(0x00189296, DicomDictElt { vr : "FD", vm: "1", name: "Chemical Shift Maximum Integration Limit in ppm", retired: "", keyword: "ChemicalShiftMaximumIntegrationLimitInppm" });
-	dicom_dictionary.insert(0x00189297, DicomDictElt { vr : "CS", vm: "1", name: "Water Reference Acquisition", retired: "", keyword: "WaterReferenceAcquisition" });
-	dicom_dictionary.insert(0x00189298, DicomDictElt { vr : "IS", vm: "1", name: "Echo Peak Position", retired: "", keyword: "EchoPeakPosition" });
-	dicom_dictionary.insert(0x00189301, DicomDictElt { vr : "SQ", vm: "1", name: "CT Acquisition Type Sequence", retired: "", keyword: "CTAcquisitionTypeSequence" });
-	dicom_dictionary.insert(0x00189302, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Type", retired: "", keyword: "AcquisitionType" });
-	dicom_dictionary.insert(0x00189303, DicomDictElt { vr : "FD", vm: "1", name: "Tube Angle", retired: "", keyword: "TubeAngle" });
-	dicom_dictionary.insert(0x00189304, DicomDictElt { vr : "SQ", vm: "1", name: "CT Acquisition Details Sequence", retired: "", keyword: "CTAcquisitionDetailsSequence" });
-	dicom_dictionary.insert(0x00189305, DicomDictElt { vr : "FD", vm: "1", name: "Revolution Time", retired: "", keyword: "RevolutionTime" });
-	dicom_dictionary.insert(0x00189306, DicomDictElt { vr : "FD", vm: "1", name: "Single Collimation Width", retired: "", keyword: "SingleCollimationWidth" });
-	dicom_dictionary.insert(0x00189307, DicomDictElt { vr : "FD", vm: "1", name: "Total Collimation Width", retired: "", keyword: "TotalCollimationWidth" });
-	dicom_dictionary.insert(0x00189308, DicomDictElt { vr : "SQ", vm: "1", name: "CT Table Dynamics Sequence", retired: "", keyword: "CTTableDynamicsSequence" });
-	dicom_dictionary.insert(0x00189309, DicomDictElt { vr : "FD", vm: "1", name: "Table Speed", retired: "", keyword: "TableSpeed" });
-	dicom_dictionary.insert(0x00189310, DicomDictElt { vr : "FD", vm: "1", name: "Table Feed per Rotation", retired: "", keyword: "TableFeedPerRotation" });
-	dicom_dictionary.insert(0x00189311, DicomDictElt { vr : "FD", vm: "1", name: "Spiral Pitch Factor", retired: "", keyword: "SpiralPitchFactor" });
-	dicom_dictionary.insert(0x00189312, DicomDictElt { vr : "SQ", vm: "1", name: "CT Geometry Sequence", retired: "", keyword: "CTGeometrySequence" });
-	dicom_dictionary.insert(0x00189313, DicomDictElt { vr : "FD", vm: "3", name: "Data Collection Center (Patient)", retired: "", keyword: "DataCollectionCenterPatient" });
-	dicom_dictionary.insert(0x00189314, DicomDictElt { vr : "SQ", vm: "1", name: "CT Reconstruction Sequence", retired: "", keyword: "CTReconstructionSequence" });
-	dicom_dictionary.insert(0x00189315, DicomDictElt { vr : "CS", vm: "1", name: "Reconstruction Algorithm", retired: "", keyword: "ReconstructionAlgorithm" });
-	dicom_dictionary.insert(0x00189316, DicomDictElt { vr : "CS", vm: "1", name: "Convolution Kernel Group", retired: "", keyword: "ConvolutionKernelGroup" });
-	dicom_dictionary.insert(0x00189317, DicomDictElt { vr : "FD", vm: "2", name: "Reconstruction Field of View", retired: "", keyword: "ReconstructionFieldOfView" });
-	dicom_dictionary.insert(0x00189318, DicomDictElt { vr : "FD", vm: "3", name: "Reconstruction Target Center (Patient)", retired: "", keyword: "ReconstructionTargetCenterPatient" });
-	dicom_dictionary.insert(0x00189319, DicomDictElt { vr : "FD", vm: "1", name: "Reconstruction Angle", retired: "", keyword: "ReconstructionAngle" });
-	dicom_dictionary.insert(0x00189320, DicomDictElt { vr : "SH", vm: "1", name: "Image Filter", retired: "", keyword: "ImageFilter" });
-	dicom_dictionary.insert(0x00189321, DicomDictElt { vr : "SQ", vm: "1", name: "CT Exposure Sequence", retired: "", keyword: "CTExposureSequence" });
-	dicom_dictionary.insert(0x00189322, DicomDictElt { vr : "FD", vm: "2", name: "Reconstruction Pixel Spacing", retired: "", keyword: "ReconstructionPixelSpacing" });
-	dicom_dictionary.insert(0x00189323, DicomDictElt { vr : "CS", vm: "1-n", name: "Exposure Modulation Type", retired: "", keyword: "ExposureModulationType" });
-	dicom_dictionary.insert(0x00189324, DicomDictElt { vr : "FD", vm: "1", name: "Estimated Dose Saving", retired: "", keyword: "EstimatedDoseSaving" });
-	dicom_dictionary.insert(0x00189325, DicomDictElt { vr : "SQ", vm: "1", name: "CT X-Ray Details Sequence", retired: "", keyword: "CTXRayDetailsSequence" });
-	dicom_dictionary.insert(0x00189326, DicomDictElt { vr : "SQ", vm: "1", name: "CT Position Sequence", retired: "", keyword: "CTPositionSequence" });
-	dicom_dictionary.insert(0x00189327, DicomDictElt { vr : "FD", vm: "1", name: "Table Position", retired: "", keyword: "TablePosition" });
-	dicom_dictionary.insert(0x00189328, DicomDictElt { vr : "FD", vm: "1", name: "Exposure Time in ms", retired: "", keyword: "ExposureTimeInms" });
-	dicom_dictionary.insert(0x00189329, DicomDictElt { vr : "SQ", vm: "1", name: "CT Image Frame Type Sequence", retired: "", keyword: "CTImageFrameTypeSequence" });
-	dicom_dictionary.insert(0x00189330, DicomDictElt { vr : "FD", vm: "1", name: "X-Ray Tube Current in mA", retired: "", keyword: "XRayTubeCurrentInmA" });
-	dicom_dictionary.insert(0x00189332, DicomDictElt { vr : "FD", vm: "1", name: "Exposure in mAs", retired: "", keyword: "ExposureInmAs" });
-	dicom_dictionary.insert(0x00189333, DicomDictElt { vr : "CS", vm: "1", name: "Constant Volume Flag", retired: "", keyword: "ConstantVolumeFlag" });
-	dicom_dictionary.insert(0x00189334, DicomDictElt { vr : "CS", vm: "1", name: "Fluoroscopy Flag", retired: "", keyword: "FluoroscopyFlag" });
-	dicom_dictionary.insert(0x00189335, DicomDictElt { vr : "FD", vm: "1", name: "Distance Source to Data Collection Center", retired: "", keyword: "DistanceSourceToDataCollectionCenter" });
-	dicom_dictionary.insert(0x00189337, DicomDictElt { vr : "US", vm: "1", name: "Contrast/Bolus Agent Number", retired: "", keyword: "ContrastBolusAgentNumber" });
-	dicom_dictionary.insert(0x00189338, DicomDictElt { vr : "SQ", vm: "1", name: "Contrast/Bolus Ingredient Code Sequence", retired: "", keyword: "ContrastBolusIngredientCodeSequence" });
-	dicom_dictionary.insert(0x00189340, DicomDictElt { vr : "SQ", vm: "1", name: "Contrast Administration Profile Sequence", retired: "", keyword: "ContrastAdministrationProfileSequence" });
-	dicom_dictionary.insert(0x00189341, DicomDictElt { vr : "SQ", vm: "1", name: "Contrast/Bolus Usage Sequence", retired: "", keyword: "ContrastBolusUsageSequence" });
-	dicom_dictionary.insert(0x00189342, DicomDictElt { vr : "CS", vm: "1", name: "Contrast/Bolus Agent Administered", retired: "", keyword: "ContrastBolusAgentAdministered" });
-	dicom_dictionary.insert(0x00189343, DicomDictElt { vr : "CS", vm: "1", name: "Contrast/Bolus Agent Detected", retired: "", keyword: "ContrastBolusAgentDetected" });
-	dicom_dictionary.insert(0x00189344, DicomDictElt { vr : "CS", vm: "1", name: "Contrast/Bolus Agent Phase", retired: "", keyword: "ContrastBolusAgentPhase" });
-	dicom_dictionary.insert(0x00189345, DicomDictElt { vr : "FD", vm: "1", name: "CTDIvol", retired: "", keyword: "CTDIvol" });
-	dicom_dictionary.insert(0x00189346, DicomDictElt { vr : "SQ", vm: "1", name: "CTDI Phantom Type Code Sequence", retired: "", keyword: "CTDIPhantomTypeCodeSequence" });
-	dicom_dictionary.insert(0x00189351, DicomDictElt { vr : "FL", vm: "1", name: "Calcium Scoring Mass Factor Patient", retired: "", keyword: "CalciumScoringMassFactorPatient" });
-	dicom_dictionary.insert(0x00189352, DicomDictElt { vr : "FL", vm: "3", name: "Calcium Scoring Mass Factor Device", retired: "", keyword: "CalciumScoringMassFactorDevice" });
-	dicom_dictionary.insert(0x00189353, DicomDictElt { vr : "FL", vm: "1", name: "Energy Weighting Factor", retired: "", keyword: "EnergyWeightingFactor" });
-	dicom_dictionary.insert(0x00189360, DicomDictElt { vr : "SQ", vm: "1", name: "CT Additional X-Ray Source Sequence", retired: "", keyword: "CTAdditionalXRaySourceSequence" });
-	dicom_dictionary.insert(0x00189401, DicomDictElt { vr : "SQ", vm: "1", name: "Projection Pixel Calibration Sequence", retired: "", keyword: "ProjectionPixelCalibrationSequence" });
-	dicom_dictionary.insert(0x00189402, DicomDictElt { vr : "FL", vm: "1", name: "Distance Source to Isocenter", retired: "", keyword: "DistanceSourceToIsocenter" });
-	dicom_dictionary.insert(0x00189403, DicomDictElt { vr : "FL", vm: "1", name: "Distance Object to Table Top", retired: "", keyword: "DistanceObjectToTableTop" });
-	dicom_dictionary.insert(0x00189404, DicomDictElt { vr : "FL", vm: "2", name: "Object Pixel Spacing in Center of Beam", retired: "", keyword: "ObjectPixelSpacingInCenterOfBeam" });
-	dicom_dictionary.insert(0x00189405, DicomDictElt { vr : "SQ", vm: "1", name: "Positioner Position Sequence", retired: "", keyword: "PositionerPositionSequence" });
-	dicom_dictionary.insert(0x00189406, DicomDictElt { vr : "SQ", vm: "1", name: "Table Position Sequence", retired: "", keyword: "TablePositionSequence" });
-	dicom_dictionary.insert(0x00189407, DicomDictElt { vr : "SQ", vm: "1", name: "Collimator Shape Sequence", retired: "", keyword: "CollimatorShapeSequence" });
-	dicom_dictionary.insert(0x00189410, DicomDictElt { vr : "CS", vm: "1", name: "Planes in Acquisition", retired: "", keyword: "PlanesInAcquisition" });
-	dicom_dictionary.insert(0x00189412, DicomDictElt { vr : "SQ", vm: "1", name: "XA/XRF Frame Characteristics Sequence", retired: "", keyword: "XAXRFFrameCharacteristicsSequence" });
-	dicom_dictionary.insert(0x00189417, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Acquisition Sequence", retired: "", keyword: "FrameAcquisitionSequence" });
-	dicom_dictionary.insert(0x00189420, DicomDictElt { vr : "CS", vm: "1", name: "X-Ray Receptor Type", retired: "", keyword: "XRayReceptorType" });
-	dicom_dictionary.insert(0x00189423, DicomDictElt { vr : "LO", vm: "1", name: "Acquisition Protocol Name", retired: "", keyword: "AcquisitionProtocolName" });
-	dicom_dictionary.insert(0x00189424, DicomDictElt { vr : "LT", vm: "1", name: "Acquisition Protocol Description", retired: "", keyword: "AcquisitionProtocolDescription" });
-	dicom_dictionary.insert(0x00189425, DicomDictElt { vr : "CS", vm: "1", name: "Contrast/Bolus Ingredient Opaque", retired: "", keyword: "ContrastBolusIngredientOpaque" });
-	dicom_dictionary.insert(0x00189426, DicomDictElt { vr : "FL", vm: "1", name: "Distance Receptor Plane to Detector Housing", retired: "", keyword: "DistanceReceptorPlaneToDetectorHousing" });
-	dicom_dictionary.insert(0x00189427, DicomDictElt { vr : "CS", vm: "1", name: "Intensifier Active Shape", retired: "", keyword: "IntensifierActiveShape" });
-	dicom_dictionary.insert(0x00189428, DicomDictElt { vr : "FL", vm: "1-2", name: "Intensifier Active Dimension(s)", retired: "", keyword: "IntensifierActiveDimensions" });
-	dicom_dictionary.insert(0x00189429, DicomDictElt { vr : "FL", vm: "2", name: "Physical Detector Size", retired: "", keyword: "PhysicalDetectorSize" });
-	dicom_dictionary.insert(0x00189430, DicomDictElt { vr : "FL", vm: "2", name: "Position of Isocenter Projection", retired: "", keyword: "PositionOfIsocenterProjection" });
-	dicom_dictionary.insert(0x00189432, DicomDictElt { vr : "SQ", vm: "1", name: "Field of View Sequence", retired: "", keyword: "FieldOfViewSequence" });
-	dicom_dictionary.insert(0x00189433, DicomDictElt { vr : "LO", vm: "1", name: "Field of View Description", retired: "", keyword: "FieldOfViewDescription" });
-	dicom_dictionary.insert(0x00189434, DicomDictElt { vr : "SQ", vm: "1", name: "Exposure Control Sensing Regions Sequence", retired: "", keyword: "ExposureControlSensingRegionsSequence" });
-	dicom_dictionary.insert(0x00189435, DicomDictElt { vr : "CS", vm: "1", name: "Exposure Control Sensing Region Shape", retired: "", keyword: "ExposureControlSensingRegionShape" });
-	dicom_dictionary.insert(0x00189436, DicomDictElt { vr : "SS", vm: "1", name: "Exposure Control Sensing Region Left Vertical Edge", retired: "", keyword: "ExposureControlSensingRegionLeftVerticalEdge" });
-	dicom_dictionary.insert(0x00189437, DicomDictElt { vr : "SS", vm: "1", name: "Exposure Control Sensing Region Right Vertical Edge", retired: "", keyword: "ExposureControlSensingRegionRightVerticalEdge" });
-	dicom_dictionary.insert(0x00189438, DicomDictElt { vr : "SS", vm: "1", name: "Exposure Control Sensing Region Upper Horizontal Edge", retired: "", keyword: "ExposureControlSensingRegionUpperHorizontalEdge" });
-	dicom_dictionary.insert(0x00189439, DicomDictElt { vr : "SS", vm: "1", name: "Exposure Control Sensing Region Lower Horizontal Edge", retired: "", keyword: "ExposureControlSensingRegionLowerHorizontalEdge" });
-	dicom_dictionary.insert(0x00189440, DicomDictElt { vr : "SS", vm: "2", name: "Center of Circular Exposure Control Sensing Region", retired: "", keyword: "CenterOfCircularExposureControlSensingRegion" });
-	dicom_dictionary.insert(0x00189441, DicomDictElt { vr : "US", vm: "1", name: "Radius of Circular Exposure Control Sensing Region", retired: "", keyword: "RadiusOfCircularExposureControlSensingRegion" });
-	dicom_dictionary.insert(0x00189442, DicomDictElt { vr : "SS", vm: "2-n", name: "Vertices of the Polygonal Exposure Control Sensing Region", retired: "", keyword: "VerticesOfThePolygonalExposureControlSensingRegion" });
-	dicom_dictionary.insert(0x00189445, DicomDictElt { vr : "OB", vm: "1", name: "Retired-blank", retired: "Retired", keyword: "" });
-	dicom_dictionary.insert(0x00189447, DicomDictElt { vr : "FL", vm: "1", name: "Column Angulation (Patient)", retired: "", keyword: "ColumnAngulationPatient" });
-	dicom_dictionary.insert(0x00189449, DicomDictElt { vr : "FL", vm: "1", name: "Beam Angle", retired: "", keyword: "BeamAngle" });
-	dicom_dictionary.insert(0x00189451, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Detector Parameters Sequence", retired: "", keyword: "FrameDetectorParametersSequence" });
-	dicom_dictionary.insert(0x00189452, DicomDictElt { vr : "FL", vm: "1", name: "Calculated Anatomy Thickness", retired: "", keyword: "CalculatedAnatomyThickness" });
-	dicom_dictionary.insert(0x00189455, DicomDictElt { vr : "SQ", vm: "1", name: "Calibration Sequence", retired: "", keyword: "CalibrationSequence" });
-	dicom_dictionary.insert(0x00189456, DicomDictElt { vr : "SQ", vm: "1", name: "Object Thickness Sequence", retired: "", keyword: "ObjectThicknessSequence" });
-	dicom_dictionary.insert(0x00189457, DicomDictElt { vr : "CS", vm: "1", name: "Plane Identification", retired: "", keyword: "PlaneIdentification" });
-	dicom_dictionary.insert(0x00189461, DicomDictElt { vr : "FL", vm: "1-2", name: "Field of View Dimension(s) in Float", retired: "", keyword: "FieldOfViewDimensionsInFloat" });
-	dicom_dictionary.insert(0x00189462, DicomDictElt { vr : "SQ", vm: "1", name: "Isocenter Reference System Sequence", retired: "", keyword: "IsocenterReferenceSystemSequence" });
-	dicom_dictionary.insert(0x00189463, DicomDictElt { vr : "FL", vm: "1", name: "Positioner Isocenter Primary Angle", retired: "", keyword: "PositionerIsocenterPrimaryAngle" });
-	dicom_dictionary.insert(0x00189464, DicomDictElt { vr : "FL", vm: "1", name: "Positioner Isocenter Secondary Angle", retired: "", keyword: "PositionerIsocenterSecondaryAngle" });
-	dicom_dictionary.insert(0x00189465, DicomDictElt { vr : "FL", vm: "1", name: "Positioner Isocenter Detector Rotation Angle", retired: "", keyword: "PositionerIsocenterDetectorRotationAngle" });
-	dicom_dictionary.insert(0x00189466, DicomDictElt { vr : "FL", vm: "1", name: "Table X Position to Isocenter", retired: "", keyword: "TableXPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189467, DicomDictElt { vr : "FL", vm: "1", name: "Table Y Position to Isocenter", retired: "", keyword: "TableYPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189468, DicomDictElt { vr : "FL", vm: "1", name: "Table Z Position to Isocenter", retired: "", keyword: "TableZPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189469, DicomDictElt { vr : "FL", vm: "1", name: "Table Horizontal Rotation Angle", retired: "", keyword: "TableHorizontalRotationAngle" });
-	dicom_dictionary.insert(0x00189470, DicomDictElt { vr : "FL", vm: "1", name: "Table Head Tilt Angle", retired: "", keyword: "TableHeadTiltAngle" });
-	dicom_dictionary.insert(0x00189471, DicomDictElt { vr : "FL", vm: "1", name: "Table Cradle Tilt Angle", retired: "", keyword: "TableCradleTiltAngle" });
-	dicom_dictionary.insert(0x00189472, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Display Shutter Sequence", retired: "", keyword: "FrameDisplayShutterSequence" });
-	dicom_dictionary.insert(0x00189473, DicomDictElt { vr : "FL", vm: "1", name: "Acquired Image Area Dose Product", retired: "", keyword: "AcquiredImageAreaDoseProduct" });
-	dicom_dictionary.insert(0x00189474, DicomDictElt { vr : "CS", vm: "1", name: "C-arm Positioner Tabletop Relationship", retired: "", keyword: "CArmPositionerTabletopRelationship" });
-	dicom_dictionary.insert(0x00189476, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray Geometry Sequence", retired: "", keyword: "XRayGeometrySequence" });
-	dicom_dictionary.insert(0x00189477, DicomDictElt { vr : "SQ", vm: "1", name: "Irradiation Event Identification Sequence", retired: "", keyword: "IrradiationEventIdentificationSequence" });
-	dicom_dictionary.insert(0x00189504, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray 3D Frame Type Sequence", retired: "", keyword: "XRay3DFrameTypeSequence" });
-	dicom_dictionary.insert(0x00189506, DicomDictElt { vr : "SQ", vm: "1", name: "Contributing Sources Sequence", retired: "", keyword: "ContributingSourcesSequence" });
-	dicom_dictionary.insert(0x00189507, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray 3D Acquisition Sequence", retired: "", keyword: "XRay3DAcquisitionSequence" });
-	dicom_dictionary.insert(0x00189508, DicomDictElt { vr : "FL", vm: "1", name: "Primary Positioner Scan Arc", retired: "", keyword: "PrimaryPositionerScanArc" });
-	dicom_dictionary.insert(0x00189509, DicomDictElt { vr : "FL", vm: "1", name: "Secondary Positioner Scan Arc", retired: "", keyword: "SecondaryPositionerScanArc" });
-	dicom_dictionary.insert(0x00189510, DicomDictElt { vr : "FL", vm: "1", name: "Primary Positioner Scan Start Angle", retired: "", keyword: "PrimaryPositionerScanStartAngle" });
-	dicom_dictionary.insert(0x00189511, DicomDictElt { vr : "FL", vm: "1", name: "Secondary Positioner Scan Start Angle", retired: "", keyword: "SecondaryPositionerScanStartAngle" });
-	dicom_dictionary.insert(0x00189514, DicomDictElt { vr : "FL", vm: "1", name: "Primary Positioner Increment", retired: "", keyword: "PrimaryPositionerIncrement" });
-	dicom_dictionary.insert(0x00189515, DicomDictElt { vr : "FL", vm: "1", name: "Secondary Positioner Increment", retired: "", keyword: "SecondaryPositionerIncrement" });
-	dicom_dictionary.insert(0x00189516, DicomDictElt { vr : "DT", vm: "1", name: "Start Acquisition DateTime", retired: "", keyword: "StartAcquisitionDateTime" });
-	dicom_dictionary.insert(0x00189517, DicomDictElt { vr : "DT", vm: "1", name: "End Acquisition DateTime", retired: "", keyword: "EndAcquisitionDateTime" });
-	dicom_dictionary.insert(0x00189518, DicomDictElt { vr : "SS", vm: "1", name: "Primary Positioner Increment Sign", retired: "", keyword: "PrimaryPositionerIncrementSign" });
-	dicom_dictionary.insert(0x00189519, DicomDictElt { vr : "SS", vm: "1", name: "Secondary Positioner Increment Sign", retired: "", keyword: "SecondaryPositionerIncrementSign" });
-	dicom_dictionary.insert(0x00189524, DicomDictElt { vr : "LO", vm: "1", name: "Application Name", retired: "", keyword: "ApplicationName" });
-	dicom_dictionary.insert(0x00189525, DicomDictElt { vr : "LO", vm: "1", name: "Application Version", retired: "", keyword: "ApplicationVersion" });
-	dicom_dictionary.insert(0x00189526, DicomDictElt { vr : "LO", vm: "1", name: "Application Manufacturer", retired: "", keyword: "ApplicationManufacturer" });
-	dicom_dictionary.insert(0x00189527, DicomDictElt { vr : "CS", vm: "1", name: "Algorithm Type", retired: "", keyword: "AlgorithmType" });
-	dicom_dictionary.insert(0x00189528, DicomDictElt { vr : "LO", vm: "1", name: "Algorithm Description", retired: "", keyword: "AlgorithmDescription" });
-	dicom_dictionary.insert(0x00189530, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray 3D Reconstruction Sequence", retired: "", keyword: "XRay3DReconstructionSequence" });
-	dicom_dictionary.insert(0x00189531, DicomDictElt { vr : "LO", vm: "1", name: "Reconstruction Description", retired: "", keyword: "ReconstructionDescription" });
-	dicom_dictionary.insert(0x00189538, DicomDictElt { vr : "SQ", vm: "1", name: "Per Projection Acquisition Sequence", retired: "", keyword: "PerProjectionAcquisitionSequence" });
-	dicom_dictionary.insert(0x00189541, DicomDictElt { vr : "SQ", vm: "1", name: "Detector Position Sequence", retired: "", keyword: "DetectorPositionSequence" });
-	dicom_dictionary.insert(0x00189542, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray Acquisition Dose Sequence", retired: "", keyword: "XRayAcquisitionDoseSequence" });
-	dicom_dictionary.insert(0x00189543, DicomDictElt { vr : "FD", vm: "1", name: "X-Ray Source Isocenter Primary Angle", retired: "", keyword: "XRaySourceIsocenterPrimaryAngle" });
-	dicom_dictionary.insert(0x00189544, DicomDictElt { vr : "FD", vm: "1", name: "X-Ray Source Isocenter Secondary Angle", retired: "", keyword: "XRaySourceIsocenterSecondaryAngle" });
-	dicom_dictionary.insert(0x00189545, DicomDictElt { vr : "FD", vm: "1", name: "Breast Support Isocenter Primary Angle", retired: "", keyword: "BreastSupportIsocenterPrimaryAngle" });
-	dicom_dictionary.insert(0x00189546, DicomDictElt { vr : "FD", vm: "1", name: "Breast Support Isocenter Secondary Angle", retired: "", keyword: "BreastSupportIsocenterSecondaryAngle" });
-	dicom_dictionary.insert(0x00189547, DicomDictElt { vr : "FD", vm: "1", name: "Breast Support X Position to Isocenter", retired: "", keyword: "BreastSupportXPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189548, DicomDictElt { vr : "FD", vm: "1", name: "Breast Support Y Position to Isocenter", retired: "", keyword: "BreastSupportYPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189549, DicomDictElt { vr : "FD", vm: "1", name: "Breast Support Z Position to Isocenter", retired: "", keyword: "BreastSupportZPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189550, DicomDictElt { vr : "FD", vm: "1", name: "Detector Isocenter Primary Angle", retired: "", keyword: "DetectorIsocenterPrimaryAngle" });
-	dicom_dictionary.insert(0x00189551, DicomDictElt { vr : "FD", vm: "1", name: "Detector Isocenter Secondary Angle", retired: "", keyword: "DetectorIsocenterSecondaryAngle" });
-	dicom_dictionary.insert(0x00189552, DicomDictElt { vr : "FD", vm: "1", name: "Detector X Position to Isocenter", retired: "", keyword: "DetectorXPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189553, DicomDictElt { vr : "FD", vm: "1", name: "Detector Y Position to Isocenter", retired: "", keyword: "DetectorYPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189554, DicomDictElt { vr : "FD", vm: "1", name: "Detector Z Position to Isocenter", retired: "", keyword: "DetectorZPositionToIsocenter" });
-	dicom_dictionary.insert(0x00189555, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray Grid Sequence", retired: "", keyword: "XRayGridSequence" });
-	dicom_dictionary.insert(0x00189556, DicomDictElt { vr : "SQ", vm: "1", name: "X-Ray Filter Sequence", retired: "", keyword: "XRayFilterSequence" });
-	dicom_dictionary.insert(0x00189557, DicomDictElt { vr : "FD", vm: "3", name: "Detector Active Area TLHC Position", retired: "", keyword: "DetectorActiveAreaTLHCPosition" });
-	dicom_dictionary.insert(0x00189558, DicomDictElt { vr : "FD", vm: "6", name: "Detector Active Area Orientation", retired: "", keyword: "DetectorActiveAreaOrientation" });
-	dicom_dictionary.insert(0x00189559, DicomDictElt { vr : "CS", vm: "1", name: "Positioner Primary Angle Direction", retired: "", keyword: "PositionerPrimaryAngleDirection" });
-	dicom_dictionary.insert(0x00189601, DicomDictElt { vr : "SQ", vm: "1", name: "Diffusion b-matrix Sequence", retired: "", keyword: "DiffusionBMatrixSequence" });
-	dicom_dictionary.insert(0x00189602, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value XX", retired: "", keyword: "DiffusionBValueXX" });
-	dicom_dictionary.insert(0x00189603, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value XY", retired: "", keyword: "DiffusionBValueXY" });
-	dicom_dictionary.insert(0x00189604, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value XZ", retired: "", keyword: "DiffusionBValueXZ" });
-	dicom_dictionary.insert(0x00189605, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value YY", retired: "", keyword: "DiffusionBValueYY" });
-	dicom_dictionary.insert(0x00189606, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value YZ", retired: "", keyword: "DiffusionBValueYZ" });
-	dicom_dictionary.insert(0x00189607, DicomDictElt { vr : "FD", vm: "1", name: "Diffusion b-value ZZ", retired: "", keyword: "DiffusionBValueZZ" });
-	dicom_dictionary.insert(0x00189621, DicomDictElt { vr : "SQ", vm: "1", name: "Functional MR Sequence", retired: "", keyword: "FunctionalMRSequence" });
-	dicom_dictionary.insert(0x00189622, DicomDictElt { vr : "CS", vm: "1", name: "Functional Settling Phase Frames Present", retired: "", keyword: "FunctionalSettlingPhaseFramesPresent" });
-	dicom_dictionary.insert(0x00189623, DicomDictElt { vr : "DT", vm: "1", name: "Functional Sync Pulse", retired: "", keyword: "FunctionalSyncPulse" });
-	dicom_dictionary.insert(0x00189624, DicomDictElt { vr : "CS", vm: "1", name: "Settling Phase Frame", retired: "", keyword: "SettlingPhaseFrame" });
-	dicom_dictionary.insert(0x00189701, DicomDictElt { vr : "DT", vm: "1", name: "Decay Correction DateTime", retired: "", keyword: "DecayCorrectionDateTime" });
-	dicom_dictionary.insert(0x00189715, DicomDictElt { vr : "FD", vm: "1", name: "Start Density Threshold", retired: "", keyword: "StartDensityThreshold" });
-	dicom_dictionary.insert(0x00189716, DicomDictElt { vr : "FD", vm: "1", name: "Start Relative Density Difference Threshold", retired: "", keyword: "StartRelativeDensityDifferenceThreshold" });
-	dicom_dictionary.insert(0x00189717, DicomDictElt { vr : "FD", vm: "1", name: "Start Cardiac Trigger Count Threshold", retired: "", keyword: "StartCardiacTriggerCountThreshold" });
-	dicom_dictionary.insert(0x00189718, DicomDictElt { vr : "FD", vm: "1", name: "Start Respiratory Trigger Count Threshold", retired: "", keyword: "StartRespiratoryTriggerCountThreshold" });
-	dicom_dictionary.insert(0x00189719, DicomDictElt { vr : "FD", vm: "1", name: "Termination Counts Threshold", retired: "", keyword: "TerminationCountsThreshold" });
-	dicom_dictionary.insert(0x00189720, DicomDictElt { vr : "FD", vm: "1", name: "Termination Density Threshold", retired: "", keyword: "TerminationDensityThreshold" });
-	dicom_dictionary.insert(0x00189721, DicomDictElt { vr : "FD", vm: "1", name: "Termination Relative Density Threshold", retired: "", keyword: "TerminationRelativeDensityThreshold" });
-	dicom_dictionary.insert(0x00189722, DicomDictElt { vr : "FD", vm: "1", name: "Termination Time Threshold", retired: "", keyword: "TerminationTimeThreshold" });
-	dicom_dictionary.insert(0x00189723, DicomDictElt { vr : "FD", vm: "1", name: "Termination Cardiac Trigger Count Threshold", retired: "", keyword: "TerminationCardiacTriggerCountThreshold" });
-	dicom_dictionary.insert(0x00189724, DicomDictElt { vr : "FD", vm: "1", name: "Termination Respiratory Trigger Count Threshold", retired: "", keyword: "TerminationRespiratoryTriggerCountThreshold" });
-	dicom_dictionary.insert(0x00189725, DicomDictElt { vr : "CS", vm: "1", name: "Detector Geometry", retired: "", keyword: "DetectorGeometry" });
-	dicom_dictionary.insert(0x00189726, DicomDictElt { vr : "FD", vm: "1", name: "Transverse Detector Separation", retired: "", keyword: "TransverseDetectorSeparation" });
-	dicom_dictionary.insert(0x00189727, DicomDictElt { vr : "FD", vm: "1", name: "Axial Detector Dimension", retired: "", keyword: "AxialDetectorDimension" });
-	dicom_dictionary.insert(0x00189729, DicomDictElt { vr : "US", vm: "1", name: "Radiopharmaceutical Agent Number", retired: "", keyword: "RadiopharmaceuticalAgentNumber" });
-	dicom_dictionary.insert(0x00189732, DicomDictElt { vr : "SQ", vm: "1", name: "PET Frame Acquisition Sequence", retired: "", keyword: "PETFrameAcquisitionSequence" });
-	dicom_dictionary.insert(0x00189733, DicomDictElt { vr : "SQ", vm: "1", name: "PET Detector Motion Details Sequence", retired: "", keyword: "PETDetectorMotionDetailsSequence" });
-	dicom_dictionary.insert(0x00189734, DicomDictElt { vr : "SQ", vm: "1", name: "PET Table Dynamics Sequence", retired: "", keyword: "PETTableDynamicsSequence" });
-	dicom_dictionary.insert(0x00189735, DicomDictElt { vr : "SQ", vm: "1", name: "PET Position Sequence", retired: "", keyword: "PETPositionSequence" });
-	dicom_dictionary.insert(0x00189736, DicomDictElt { vr : "SQ", vm: "1", name: "PET Frame Correction Factors Sequence", retired: "", keyword: "PETFrameCorrectionFactorsSequence" });
-	dicom_dictionary.insert(0x00189737, DicomDictElt { vr : "SQ", vm: "1", name: "Radiopharmaceutical Usage Sequence", retired: "", keyword: "RadiopharmaceuticalUsageSequence" });
-	dicom_dictionary.insert(0x00189738, DicomDictElt { vr : "CS", vm: "1", name: "Attenuation Correction Source", retired: "", keyword: "AttenuationCorrectionSource" });
-	dicom_dictionary.insert(0x00189739, DicomDictElt { vr : "US", vm: "1", name: "Number of Iterations", retired: "", keyword: "NumberOfIterations" });
-	dicom_dictionary.insert(0x00189740, DicomDictElt { vr : "US", vm: "1", name: "Number of Subsets", retired: "", keyword: "NumberOfSubsets" });
-	dicom_dictionary.insert(0x00189749, DicomDictElt { vr : "SQ", vm: "1", name: "PET Reconstruction Sequence", retired: "", keyword: "PETReconstructionSequence" });
-	dicom_dictionary.insert(0x00189751, DicomDictElt { vr : "SQ", vm: "1", name: "PET Frame Type Sequence", retired: "", keyword: "PETFrameTypeSequence" });
-	dicom_dictionary.insert(0x00189755, DicomDictElt { vr : "CS", vm: "1", name: "Time of Flight Information Used", retired: "", keyword: "TimeOfFlightInformationUsed" });
-	dicom_dictionary.insert(0x00189756, DicomDictElt { vr : "CS", vm: "1", name: "Reconstruction Type", retired: "", keyword: "ReconstructionType" });
-	dicom_dictionary.insert(0x00189758, DicomDictElt { vr : "CS", vm: "1", name: "Decay Corrected", retired: "", keyword: "DecayCorrected" });
-	dicom_dictionary.insert(0x00189759, DicomDictElt { vr : "CS", vm: "1", name: "Attenuation Corrected", retired: "", keyword: "AttenuationCorrected" });
-	dicom_dictionary.insert(0x00189760, DicomDictElt { vr : "CS", vm: "1", name: "Scatter Corrected", retired: "", keyword: "ScatterCorrected" });
-	dicom_dictionary.insert(0x00189761, DicomDictElt { vr : "CS", vm: "1", name: "Dead Time Corrected", retired: "", keyword: "DeadTimeCorrected" });
-	dicom_dictionary.insert(0x00189762, DicomDictElt { vr : "CS", vm: "1", name: "Gantry Motion Corrected", retired: "", keyword: "GantryMotionCorrected" });
-	dicom_dictionary.insert(0x00189763, DicomDictElt { vr : "CS", vm: "1", name: "Patient Motion Corrected", retired: "", keyword: "PatientMotionCorrected" });
-	dicom_dictionary.insert(0x00189764, DicomDictElt { vr : "CS", vm: "1", name: "Count Loss Normalization Corrected", retired: "", keyword: "CountLossNormalizationCorrected" });
-	dicom_dictionary.insert(0x00189765, DicomDictElt { vr : "CS", vm: "1", name: "Randoms Corrected", retired: "", keyword: "RandomsCorrected" });
-	dicom_dictionary.insert(0x00189766, DicomDictElt { vr : "CS", vm: "1", name: "Non-uniform Radial Sampling Corrected", retired: "", keyword: "NonUniformRadialSamplingCorrected" });
-	dicom_dictionary.insert(0x00189767, DicomDictElt { vr : "CS", vm: "1", name: "Sensitivity Calibrated", retired: "", keyword: "SensitivityCalibrated" });
-	dicom_dictionary.insert(0x00189768, DicomDictElt { vr : "CS", vm: "1", name: "Detector Normalization Correction", retired: "", keyword: "DetectorNormalizationCorrection" });
-	dicom_dictionary.insert(0x00189769, DicomDictElt { vr : "CS", vm: "1", name: "Iterative Reconstruction Method", retired: "", keyword: "IterativeReconstructionMethod" });
-	dicom_dictionary.insert(0x00189770, DicomDictElt { vr : "CS", vm: "1", name: "Attenuation Correction Temporal Relationship", retired: "", keyword: "AttenuationCorrectionTemporalRelationship" });
-	dicom_dictionary.insert(0x00189771, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Physiological State Sequence", retired: "", keyword: "PatientPhysiologicalStateSequence" });
-	dicom_dictionary.insert(0x00189772, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Physiological State Code Sequence", retired: "", keyword: "PatientPhysiologicalStateCodeSequence" });
-	dicom_dictionary.insert(0x00189801, DicomDictElt { vr : "FD", vm: "1-n", name: "Depth(s) of Focus", retired: "", keyword: "DepthsOfFocus" });
-	dicom_dictionary.insert(0x00189803, DicomDictElt { vr : "SQ", vm: "1", name: "Excluded Intervals Sequence", retired: "", keyword: "ExcludedIntervalsSequence" });
-	dicom_dictionary.insert(0x00189804, DicomDictElt { vr : "DT", vm: "1", name: "Exclusion Start DateTime", retired: "", keyword: "ExclusionStartDateTime" });
-	dicom_dictionary.insert(0x00189805, DicomDictElt { vr : "FD", vm: "1", name: "Exclusion Duration", retired: "", keyword: "ExclusionDuration" });
-	dicom_dictionary.insert(0x00189806, DicomDictElt { vr : "SQ", vm: "1", name: "US Image Description Sequence", retired: "", keyword: "USImageDescriptionSequence" });
-	dicom_dictionary.insert(0x00189807, DicomDictElt { vr : "SQ", vm: "1", name: "Image Data Type Sequence", retired: "", keyword: "ImageDataTypeSequence" });
-	dicom_dictionary.insert(0x00189808, DicomDictElt { vr : "CS", vm: "1", name: "Data Type", retired: "", keyword: "DataType" });
-	dicom_dictionary.insert(0x00189809, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Scan Pattern Code Sequence", retired: "", keyword: "TransducerScanPatternCodeSequence" });
-	dicom_dictionary.insert(0x0018980B, DicomDictElt { vr : "CS", vm: "1", name: "Aliased Data Type", retired: "", keyword: "AliasedDataType" });
-	dicom_dictionary.insert(0x0018980C, DicomDictElt { vr : "CS", vm: "1", name: "Position Measuring Device Used", retired: "", keyword: "PositionMeasuringDeviceUsed" });
-	dicom_dictionary.insert(0x0018980D, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Geometry Code Sequence", retired: "", keyword: "TransducerGeometryCodeSequence" });
-	dicom_dictionary.insert(0x0018980E, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Beam Steering Code Sequence", retired: "", keyword: "TransducerBeamSteeringCodeSequence" });
-	dicom_dictionary.insert(0x0018980F, DicomDictElt { vr : "SQ", vm: "1", name: "Transducer Application Code Sequence", retired: "", keyword: "TransducerApplicationCodeSequence" });
-	dicom_dictionary.insert(0x00189810, DicomDictElt { vr : "US or SS", vm: "1", name: "Zero Velocity Pixel Value", retired: "", keyword: "ZeroVelocityPixelValue" });
-	dicom_dictionary.insert(0x00189900, DicomDictElt { vr : "LO", vm: "1", name: "Reference Location Label", retired: "", keyword: "ReferenceLocationLabel" });
-	dicom_dictionary.insert(0x00189901, DicomDictElt { vr : "UT", vm: "1", name: "Reference Location Description", retired: "", keyword: "ReferenceLocationDescription" });
-	dicom_dictionary.insert(0x00189902, DicomDictElt { vr : "SQ", vm: "1", name: "Reference Basis Code Sequence", retired: "", keyword: "ReferenceBasisCodeSequence" });
-	dicom_dictionary.insert(0x00189903, DicomDictElt { vr : "SQ", vm: "1", name: "Reference Geometry Code Sequence", retired: "", keyword: "ReferenceGeometryCodeSequence" });
-	dicom_dictionary.insert(0x00189904, DicomDictElt { vr : "DS", vm: "1", name: "Offset Distance", retired: "", keyword: "OffsetDistance" });
-	dicom_dictionary.insert(0x00189905, DicomDictElt { vr : "CS", vm: "1", name: "Offset Direction", retired: "", keyword: "OffsetDirection" });
-	dicom_dictionary.insert(0x00189906, DicomDictElt { vr : "SQ", vm: "1", name: "Potential Scheduled Protocol Code Sequence", retired: "", keyword: "PotentialScheduledProtocolCodeSequence" });
-	dicom_dictionary.insert(0x00189907, DicomDictElt { vr : "SQ", vm: "1", name: "Potential Requested Procedure Code Sequence", retired: "", keyword: "PotentialRequestedProcedureCodeSequence" });
-	dicom_dictionary.insert(0x00189908, DicomDictElt { vr : "UC", vm: "1-n", name: "Potential Reasons for Procedure", retired: "", keyword: "PotentialReasonsForProcedure" });
-	dicom_dictionary.insert(0x00189909, DicomDictElt { vr : "SQ", vm: "1", name: "Potential Reasons for Procedure Code Sequence", retired: "", keyword: "PotentialReasonsForProcedureCodeSequence" });
-	dicom_dictionary.insert(0x0018990A, DicomDictElt { vr : "UC", vm: "1-n", name: "Potential Diagnostic Tasks", retired: "", keyword: "PotentialDiagnosticTasks" });
-	dicom_dictionary.insert(0x0018990B, DicomDictElt { vr : "SQ", vm: "1", name: "Contraindications Code Sequence", retired: "", keyword: "ContraindicationsCodeSequence" });
-	dicom_dictionary.insert(0x0018990C, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Defined Protocol Sequence", retired: "", keyword: "ReferencedDefinedProtocolSequence" });
-	dicom_dictionary.insert(0x0018990D, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Performed Protocol Sequence", retired: "", keyword: "ReferencedPerformedProtocolSequence" });
-	dicom_dictionary.insert(0x0018990E, DicomDictElt { vr : "SQ", vm: "1", name: "Predecessor Protocol Sequence", retired: "", keyword: "PredecessorProtocolSequence" });
-	dicom_dictionary.insert(0x0018990F, DicomDictElt { vr : "UT", vm: "1", name: "Protocol Planning Information", retired: "", keyword: "ProtocolPlanningInformation" });
-	dicom_dictionary.insert(0x00189910, DicomDictElt { vr : "UT", vm: "1", name: "Protocol Design Rationale", retired: "", keyword: "ProtocolDesignRationale" });
-	dicom_dictionary.insert(0x00189911, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Specification Sequence", retired: "", keyword: "PatientSpecificationSequence" });
-	dicom_dictionary.insert(0x00189912, DicomDictElt { vr : "SQ", vm: "1", name: "Model Specification Sequence", retired: "", keyword: "ModelSpecificationSequence" });
-	dicom_dictionary.insert(0x00189913, DicomDictElt { vr : "SQ", vm: "1", name: "Parameters Specification Sequence", retired: "", keyword: "ParametersSpecificationSequence" });
-	dicom_dictionary.insert(0x00189914, DicomDictElt { vr : "SQ", vm: "1", name: "Instruction Sequence", retired: "", keyword: "InstructionSequence" });
-	dicom_dictionary.insert(0x00189915, DicomDictElt { vr : "US", vm: "1", name: "Instruction Index", retired: "", keyword: "InstructionIndex" });
-	dicom_dictionary.insert(0x00189916, DicomDictElt { vr : "LO", vm: "1", name: "Instruction Text", retired: "", keyword: "InstructionText" });
-	dicom_dictionary.insert(0x00189917, DicomDictElt { vr : "UT", vm: "1", name: "Instruction Description", retired: "", keyword: "InstructionDescription" });
-	dicom_dictionary.insert(0x00189918, DicomDictElt { vr : "CS", vm: "1", name: "Instruction Performed Flag", retired: "", keyword: "InstructionPerformedFlag" });
-	dicom_dictionary.insert(0x00189919, DicomDictElt { vr : "DT", vm: "1", name: "Instruction Performed DateTime", retired: "", keyword: "InstructionPerformedDateTime" });
-	dicom_dictionary.insert(0x0018991A, DicomDictElt { vr : "UT", vm: "1", name: "Instruction Performance Comment", retired: "", keyword: "InstructionPerformanceComment" });
-	dicom_dictionary.insert(0x0018991B, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Positioning Instruction Sequence", retired: "", keyword: "PatientPositioningInstructionSequence" });
-	dicom_dictionary.insert(0x0018991C, DicomDictElt { vr : "SQ", vm: "1", name: "Positioning Method Code Sequence", retired: "", keyword: "PositioningMethodCodeSequence" });
-	dicom_dictionary.insert(0x0018991D, DicomDictElt { vr : "SQ", vm: "1", name: "Positioning Landmark Sequence", retired: "", keyword: "PositioningLandmarkSequence" });
-	dicom_dictionary.insert(0x0018991E, DicomDictElt { vr : "UI", vm: "1", name: "Target Frame of Reference UID", retired: "", keyword: "TargetFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x0018991F, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Protocol Element Specification Sequence", retired: "", keyword: "AcquisitionProtocolElementSpecificationSequence" });
-	dicom_dictionary.insert(0x00189920, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Protocol Element Sequence", retired: "", keyword: "AcquisitionProtocolElementSequence" });
-	dicom_dictionary.insert(0x00189921, DicomDictElt { vr : "US", vm: "1", name: "Protocol Element Number", retired: "", keyword: "ProtocolElementNumber" });
-	dicom_dictionary.insert(0x00189922, DicomDictElt { vr : "LO", vm: "1", name: "Protocol Element Name", retired: "", keyword: "ProtocolElementName" });
-	dicom_dictionary.insert(0x00189923, DicomDictElt { vr : "UT", vm: "1", name: "Protocol Element Characteristics Summary", retired: "", keyword: "ProtocolElementCharacteristicsSummary" });
-	dicom_dictionary.insert(0x00189924, DicomDictElt { vr : "UT", vm: "1", name: "Protocol Element Purpose", retired: "", keyword: "ProtocolElementPurpose" });
-	dicom_dictionary.insert(0x00189930, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Motion", retired: "", keyword: "AcquisitionMotion" });
-	dicom_dictionary.insert(0x00189931, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Start Location Sequence", retired: "", keyword: "AcquisitionStartLocationSequence" });
-	dicom_dictionary.insert(0x00189932, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition End Location Sequence", retired: "", keyword: "AcquisitionEndLocationSequence" });
-	dicom_dictionary.insert(0x00189933, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction Protocol Element Specification Sequence", retired: "", keyword: "ReconstructionProtocolElementSpecificationSequence" });
-	dicom_dictionary.insert(0x00189934, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction Protocol Element Sequence", retired: "", keyword: "ReconstructionProtocolElementSequence" });
-	dicom_dictionary.insert(0x00189935, DicomDictElt { vr : "SQ", vm: "1", name: "Storage Protocol Element Specification Sequence", retired: "", keyword: "StorageProtocolElementSpecificationSequence" });
-	dicom_dictionary.insert(0x00189936, DicomDictElt { vr : "SQ", vm: "1", name: "Storage Protocol Element Sequence", retired: "", keyword: "StorageProtocolElementSequence" });
-	dicom_dictionary.insert(0x00189937, DicomDictElt { vr : "LO", vm: "1", name: "Requested Series Description", retired: "", keyword: "RequestedSeriesDescription" });
-	dicom_dictionary.insert(0x00189938, DicomDictElt { vr : "US", vm: "1-n", name: "Source Acquisition Protocol Element Number", retired: "", keyword: "SourceAcquisitionProtocolElementNumber" });
-	dicom_dictionary.insert(0x00189939, DicomDictElt { vr : "US", vm: "1-n", name: "Source Acquisition Beam Number", retired: "", keyword: "SourceAcquisitionBeamNumber" });
-	dicom_dictionary.insert(0x0018993A, DicomDictElt { vr : "US", vm: "1-n", name: "Source Reconstruction Protocol Element Number", retired: "", keyword: "SourceReconstructionProtocolElementNumber" });
-	dicom_dictionary.insert(0x0018993B, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction Start Location Sequence", retired: "", keyword: "ReconstructionStartLocationSequence" });
-	dicom_dictionary.insert(0x0018993C, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction End Location Sequence", retired: "", keyword: "ReconstructionEndLocationSequence" });
-	dicom_dictionary.insert(0x0018993D, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction Algorithm Sequence", retired: "", keyword: "ReconstructionAlgorithmSequence" });
-	dicom_dictionary.insert(0x0018993E, DicomDictElt { vr : "SQ", vm: "1", name: "Reconstruction Target Center Location Sequence", retired: "", keyword: "ReconstructionTargetCenterLocationSequence" });
-	dicom_dictionary.insert(0x00189941, DicomDictElt { vr : "UT", vm: "1", name: "Image Filter Description", retired: "", keyword: "ImageFilterDescription" });
-	dicom_dictionary.insert(0x00189942, DicomDictElt { vr : "FD", vm: "1", name: "CTDIvol Notification Trigger", retired: "", keyword: "CTDIvolNotificationTrigger" });
-	dicom_dictionary.insert(0x00189943, DicomDictElt { vr : "FD", vm: "1", name: "DLP Notification Trigger", retired: "", keyword: "DLPNotificationTrigger" });
-	dicom_dictionary.insert(0x00189944, DicomDictElt { vr : "CS", vm: "1", name: "Auto KVP Selection Type", retired: "", keyword: "AutoKVPSelectionType" });
-	dicom_dictionary.insert(0x00189945, DicomDictElt { vr : "FD", vm: "1", name: "Auto KVP Upper Bound", retired: "", keyword: "AutoKVPUpperBound" });
-	dicom_dictionary.insert(0x00189946, DicomDictElt { vr : "FD", vm: "1", name: "Auto KVP Lower Bound", retired: "", keyword: "AutoKVPLowerBound" });
-	dicom_dictionary.insert(0x00189947, DicomDictElt { vr : "CS", vm: "1", name: "Protocol Defined Patient Position", retired: "", keyword: "ProtocolDefinedPatientPosition" });
-	dicom_dictionary.insert(0x0018A001, DicomDictElt { vr : "SQ", vm: "1", name: "Contributing Equipment Sequence", retired: "", keyword: "ContributingEquipmentSequence" });
-	dicom_dictionary.insert(0x0018A002, DicomDictElt { vr : "DT", vm: "1", name: "Contribution DateTime", retired: "", keyword: "ContributionDateTime" });
-	dicom_dictionary.insert(0x0018A003, DicomDictElt { vr : "ST", vm: "1", name: "Contribution Description", retired: "", keyword: "ContributionDescription" });
-	dicom_dictionary.insert(0x0020000D, DicomDictElt { vr : "UI", vm: "1", name: "Study Instance UID", retired: "", keyword: "StudyInstanceUID" });
-	dicom_dictionary.insert(0x0020000E, DicomDictElt { vr : "UI", vm: "1", name: "Series Instance UID", retired: "", keyword: "SeriesInstanceUID" });
-	dicom_dictionary.insert(0x00200010, DicomDictElt { vr : "SH", vm: "1", name: "Study ID", retired: "", keyword: "StudyID" });
-	dicom_dictionary.insert(0x00200011, DicomDictElt { vr : "IS", vm: "1", name: "Series Number", retired: "", keyword: "SeriesNumber" });
-	dicom_dictionary.insert(0x00200012, DicomDictElt { vr : "IS", vm: "1", name: "Acquisition Number", retired: "", keyword: "AcquisitionNumber" });
-	dicom_dictionary.insert(0x00200013, DicomDictElt { vr : "IS", vm: "1", name: "Instance Number", retired: "", keyword: "InstanceNumber" });
-	dicom_dictionary.insert(0x00200014, DicomDictElt { vr : "IS", vm: "1", name: "Isotope Number", retired: "Retired", keyword: "IsotopeNumber" });
-	dicom_dictionary.insert(0x00200015, DicomDictElt { vr : "IS", vm: "1", name: "Phase Number", retired: "Retired", keyword: "PhaseNumber" });
-	dicom_dictionary.insert(0x00200016, DicomDictElt { vr : "IS", vm: "1", name: "Interval Number", retired: "Retired", keyword: "IntervalNumber" });
-	dicom_dictionary.insert(0x00200017, DicomDictElt { vr : "IS", vm: "1", name: "Time Slot Number", retired: "Retired", keyword: "TimeSlotNumber" });
-	dicom_dictionary.insert(0x00200018, DicomDictElt { vr : "IS", vm: "1", name: "Angle Number", retired: "Retired", keyword: "AngleNumber" });
-	dicom_dictionary.insert(0x00200019, DicomDictElt { vr : "IS", vm: "1", name: "Item Number", retired: "", keyword: "ItemNumber" });
-	dicom_dictionary.insert(0x00200020, DicomDictElt { vr : "CS", vm: "2", name: "Patient Orientation", retired: "", keyword: "PatientOrientation" });
-	dicom_dictionary.insert(0x00200022, DicomDictElt { vr : "IS", vm: "1", name: "Overlay Number", retired: "Retired", keyword: "OverlayNumber" });
-	dicom_dictionary.insert(0x00200024, DicomDictElt { vr : "IS", vm: "1", name: "Curve Number", retired: "Retired", keyword: "CurveNumber" });
-	dicom_dictionary.insert(0x00200026, DicomDictElt { vr : "IS", vm: "1", name: "LUT Number", retired: "Retired", keyword: "LUTNumber" });
-	dicom_dictionary.insert(0x00200030, DicomDictElt { vr : "DS", vm: "3", name: "Image Position", retired: "Retired", keyword: "ImagePosition" });
-	dicom_dictionary.insert(0x00200032, DicomDictElt { vr : "DS", vm: "3", name: "Image Position (Patient)", retired: "", keyword: "ImagePositionPatient" });
-	dicom_dictionary.insert(0x00200035, DicomDictElt { vr : "DS", vm: "6", name: "Image Orientation", retired: "Retired", keyword: "ImageOrientation" });
-	dicom_dictionary.insert(0x00200037, DicomDictElt { vr : "DS", vm: "6", name: "Image Orientation (Patient)", retired: "", keyword: "ImageOrientationPatient" });
-	dicom_dictionary.insert(0x00200050, DicomDictElt { vr : "DS", vm: "1", name: "Location", retired: "Retired", keyword: "Location" });
-	dicom_dictionary.insert(0x00200052, DicomDictElt { vr : "UI", vm: "1", name: "Frame of Reference UID", retired: "", keyword: "FrameOfReferenceUID" });
-	dicom_dictionary.insert(0x00200060, DicomDictElt { vr : "CS", vm: "1", name: "Laterality", retired: "", keyword: "Laterality" });
-	dicom_dictionary.insert(0x00200062, DicomDictElt { vr : "CS", vm: "1", name: "Image Laterality", retired: "", keyword: "ImageLaterality" });
-	dicom_dictionary.insert(0x00200070, DicomDictElt { vr : "LO", vm: "1", name: "Image Geometry Type", retired: "Retired", keyword: "ImageGeometryType" });
-	dicom_dictionary.insert(0x00200080, DicomDictElt { vr : "CS", vm: "1-n", name: "Masking Image", retired: "Retired", keyword: "MaskingImage" });
-	dicom_dictionary.insert(0x002000AA, DicomDictElt { vr : "IS", vm: "1", name: "Report Number", retired: "Retired", keyword: "ReportNumber" });
-	dicom_dictionary.insert(0x00200100, DicomDictElt { vr : "IS", vm: "1", name: "Temporal Position Identifier", retired: "", keyword: "TemporalPositionIdentifier" });
-	dicom_dictionary.insert(0x00200105, DicomDictElt { vr : "IS", vm: "1", name: "Number of Temporal Positions", retired: "", keyword: "NumberOfTemporalPositions" });
-	dicom_dictionary.insert(0x00200110, DicomDictElt { vr : "DS", vm: "1", name: "Temporal Resolution", retired: "", keyword: "TemporalResolution" });
-	dicom_dictionary.insert(0x00200200, DicomDictElt { vr : "UI", vm: "1", name: "Synchronization Frame of Reference UID", retired: "", keyword: "SynchronizationFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x00200242, DicomDictElt { vr : "UI", vm: "1", name: "SOP Instance UID of Concatenation Source", retired: "", keyword: "SOPInstanceUIDOfConcatenationSource" });
-	dicom_dictionary.insert(0x00201000, DicomDictElt { vr : "IS", vm: "1", name: "Series in Study", retired: "Retired", keyword: "SeriesInStudy" });
-	dicom_dictionary.insert(0x00201001, DicomDictElt { vr : "IS", vm: "1", name: "Acquisitions in Series", retired: "Retired", keyword: "AcquisitionsInSeries" });
-	dicom_dictionary.insert(0x00201002, DicomDictElt { vr : "IS", vm: "1", name: "Images in Acquisition", retired: "", keyword: "ImagesInAcquisition" });
-	dicom_dictionary.insert(0x00201003, DicomDictElt { vr : "IS", vm: "1", name: "Images in Series", retired: "Retired", keyword: "ImagesInSeries" });
-	dicom_dictionary.insert(0x00201004, DicomDictElt { vr : "IS", vm: "1", name: "Acquisitions in Study", retired: "Retired", keyword: "AcquisitionsInStudy" });
-	dicom_dictionary.insert(0x00201005, DicomDictElt { vr : "IS", vm: "1", name: "Images in Study", retired: "Retired", keyword: "ImagesInStudy" });
-	dicom_dictionary.insert(0x00201020, DicomDictElt { vr : "LO", vm: "1-n", name: "Reference", retired: "Retired", keyword: "Reference" });
-	dicom_dictionary.insert(0x0020103F, DicomDictElt { vr : "LO", vm: "1", name: "Target Position Reference Indicator", retired: "", keyword: "TargetPositionReferenceIndicator" });
-	dicom_dictionary.insert(0x00201040, DicomDictElt { vr : "LO", vm: "1", name: "Position Reference Indicator", retired: "", keyword: "PositionReferenceIndicator" });
-	dicom_dictionary.insert(0x00201041, DicomDictElt { vr : "DS", vm: "1", name: "Slice Location", retired: "", keyword: "SliceLocation" });
-	dicom_dictionary.insert(0x00201070, DicomDictElt { vr : "IS", vm: "1-n", name: "Other Study Numbers", retired: "Retired", keyword: "OtherStudyNumbers" });
-	dicom_dictionary.insert(0x00201200, DicomDictElt { vr : "IS", vm: "1", name: "Number of Patient Related Studies", retired: "", keyword: "NumberOfPatientRelatedStudies" });
-	dicom_dictionary.insert(0x00201202, DicomDictElt { vr : "IS", vm: "1", name: "Number of Patient Related Series", retired: "", keyword: "NumberOfPatientRelatedSeries" });
-	dicom_dictionary.insert(0x00201204, DicomDictElt { vr : "IS", vm: "1", name: "Number of Patient Related Instances", retired: "", keyword: "NumberOfPatientRelatedInstances" });
-	dicom_dictionary.insert(0x00201206, DicomDictElt { vr : "IS", vm: "1", name: "Number of Study Related Series", retired: "", keyword: "NumberOfStudyRelatedSeries" });
-	dicom_dictionary.insert(0x00201208, DicomDictElt { vr : "IS", vm: "1", name: "Number of Study Related Instances", retired: "", keyword: "NumberOfStudyRelatedInstances" });
-	dicom_dictionary.insert(0x00201209, DicomDictElt { vr : "IS", vm: "1", name: "Number of Series Related Instances", retired: "", keyword: "NumberOfSeriesRelatedInstances" });
-	dicom_dictionary.insert(0x00203401, DicomDictElt { vr : "CS", vm: "1", name: "Modifying Device ID", retired: "Retired", keyword: "ModifyingDeviceID" });
-	dicom_dictionary.insert(0x00203402, DicomDictElt { vr : "CS", vm: "1", name: "Modified Image ID", retired: "Retired", keyword: "ModifiedImageID" });
-	dicom_dictionary.insert(0x00203403, DicomDictElt { vr : "DA", vm: "1", name: "Modified Image Date", retired: "Retired", keyword: "ModifiedImageDate" });
-	dicom_dictionary.insert(0x00203404, DicomDictElt { vr : "LO", vm: "1", name: "Modifying Device Manufacturer", retired: "Retired", keyword: "ModifyingDeviceManufacturer" });
-	dicom_dictionary.insert(0x00203405, DicomDictElt { vr : "TM", vm: "1", name: "Modified Image Time", retired: "Retired", keyword: "ModifiedImageTime" });
-	dicom_dictionary.insert(0x00203406, DicomDictElt { vr : "LO", vm: "1", name: "Modified Image Description", retired: "Retired", keyword: "ModifiedImageDescription" });
-	dicom_dictionary.insert(0x00204000, DicomDictElt { vr : "LT", vm: "1", name: "Image Comments", retired: "", keyword: "ImageComments" });
-	dicom_dictionary.insert(0x00205000, DicomDictElt { vr : "AT", vm: "1-n", name: "Original Image Identification", retired: "Retired", keyword: "OriginalImageIdentification" });
-	dicom_dictionary.insert(0x00205002, DicomDictElt { vr : "LO", vm: "1-n", name: "Original Image Identification Nomenclature", retired: "Retired", keyword: "OriginalImageIdentificationNomenclature" });
-	dicom_dictionary.insert(0x00209056, DicomDictElt { vr : "SH", vm: "1", name: "Stack ID", retired: "", keyword: "StackID" });
-	dicom_dictionary.insert(0x00209057, DicomDictElt { vr : "UL", vm: "1", name: "In-Stack Position Number", retired: "", keyword: "InStackPositionNumber" });
-	dicom_dictionary.insert(0x00209071, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Anatomy Sequence", retired: "", keyword: "FrameAnatomySequence" });
-	dicom_dictionary.insert(0x00209072, DicomDictElt { vr : "CS", vm: "1", name: "Frame Laterality", retired: "", keyword: "FrameLaterality" });
-	dicom_dictionary.insert(0x00209111, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Content Sequence", retired: "", keyword: "FrameContentSequence" });
-	dicom_dictionary.insert(0x00209113, DicomDictElt { vr : "SQ", vm: "1", name: "Plane Position Sequence", retired: "", keyword: "PlanePositionSequence" });
-	dicom_dictionary.insert(0x00209116, DicomDictElt { vr : "SQ", vm: "1", name: "Plane Orientation Sequence", retired: "", keyword: "PlaneOrientationSequence" });
-	dicom_dictionary.insert(0x00209128, DicomDictElt { vr : "UL", vm: "1", name: "Temporal Position Index", retired: "", keyword: "TemporalPositionIndex" });
-	dicom_dictionary.insert(0x00209153, DicomDictElt { vr : "FD", vm: "1", name: "Nominal Cardiac Trigger Delay Time", retired: "", keyword: "NominalCardiacTriggerDelayTime" });
-	dicom_dictionary.insert(0x00209154, DicomDictElt { vr : "FL", vm: "1", name: "Nominal Cardiac Trigger Time Prior To R-Peak", retired: "", keyword: "NominalCardiacTriggerTimePriorToRPeak" });
-	dicom_dictionary.insert(0x00209155, DicomDictElt { vr : "FL", vm: "1", name: "Actual Cardiac Trigger Time Prior To R-Peak", retired: "", keyword: "ActualCardiacTriggerTimePriorToRPeak" });
-	dicom_dictionary.insert(0x00209156, DicomDictElt { vr : "US", vm: "1", name: "Frame Acquisition Number", retired: "", keyword: "FrameAcquisitionNumber" });
-	dicom_dictionary.insert(0x00209157, DicomDictElt { vr : "UL", vm: "1-n", name: "Dimension Index Values", retired: "", keyword: "DimensionIndexValues" });
-	dicom_dictionary.insert(0x00209158, DicomDictElt { vr : "LT", vm: "1", name: "Frame Comments", retired: "", keyword: "FrameComments" });
-	dicom_dictionary.insert(0x00209161, DicomDictElt { vr : "UI", vm: "1", name: "Concatenation UID", retired: "", keyword: "ConcatenationUID" });
-	dicom_dictionary.insert(0x00209162, DicomDictElt { vr : "US", vm: "1", name: "In-concatenation Number", retired: "", keyword: "InConcatenationNumber" });
-	dicom_dictionary.insert(0x00209163, DicomDictElt { vr : "US", vm: "1", name: "In-concatenation Total Number", retired: "", keyword: "InConcatenationTotalNumber" });
-	dicom_dictionary.insert(0x00209164, DicomDictElt { vr : "UI", vm: "1", name: "Dimension Organization UID", retired: "", keyword: "DimensionOrganizationUID" });
-	dicom_dictionary.insert(0x00209165, DicomDictElt { vr : "AT", vm: "1", name: "Dimension Index Pointer", retired: "", keyword: "DimensionIndexPointer" });
-	dicom_dictionary.insert(0x00209167, DicomDictElt { vr : "AT", vm: "1", name: "Functional Group Pointer", retired: "", keyword: "FunctionalGroupPointer" });
-	dicom_dictionary.insert(0x00209170, DicomDictElt { vr : "SQ", vm: "1", name: "Unassigned Shared Converted Attributes Sequence", retired: "", keyword: "UnassignedSharedConvertedAttributesSequence" });
-	dicom_dictionary.insert(0x00209171, DicomDictElt { vr : "SQ", vm: "1", name: "Unassigned Per-Frame Converted Attributes Sequence", retired: "", keyword: "UnassignedPerFrameConvertedAttributesSequence" });
-	dicom_dictionary.insert(0x00209172, DicomDictElt { vr : "SQ", vm: "1", name: "Conversion Source Attributes Sequence", retired: "", keyword: "ConversionSourceAttributesSequence" });
-	dicom_dictionary.insert(0x00209213, DicomDictElt { vr : "LO", vm: "1", name: "Dimension Index Private Creator", retired: "", keyword: "DimensionIndexPrivateCreator" });
-	dicom_dictionary.insert(0x00209221, DicomDictElt { vr : "SQ", vm: "1", name: "Dimension Organization Sequence", retired: "", keyword: "DimensionOrganizationSequence" });
-	dicom_dictionary.insert(0x00209222, DicomDictElt { vr : "SQ", vm: "1", name: "Dimension Index Sequence", retired: "", keyword: "DimensionIndexSequence" });
-	dicom_dictionary.insert(0x00209228, DicomDictElt { vr : "UL", vm: "1", name: "Concatenation Frame Offset Number", retired: "", keyword: "ConcatenationFrameOffsetNumber" });
-	dicom_dictionary.insert(0x00209238, DicomDictElt { vr : "LO", vm: "1", name: "Functional Group Private Creator", retired: "", keyword: "FunctionalGroupPrivateCreator" });
-	dicom_dictionary.insert(0x00209241, DicomDictElt { vr : "FL", vm: "1", name: "Nominal Percentage of Cardiac Phase", retired: "", keyword: "NominalPercentageOfCardiacPhase" });
-	dicom_dictionary.insert(0x00209245, DicomDictElt { vr : "FL", vm: "1", name: "Nominal Percentage of Respiratory Phase", retired: "", keyword: "NominalPercentageOfRespiratoryPhase" });
-	dicom_dictionary.insert(0x00209246, DicomDictElt { vr : "FL", vm: "1", name: "Starting Respiratory Amplitude", retired: "", keyword: "StartingRespiratoryAmplitude" });
-	dicom_dictionary.insert(0x00209247, DicomDictElt { vr : "CS", vm: "1", name: "Starting Respiratory Phase", retired: "", keyword: "StartingRespiratoryPhase" });
-	dicom_dictionary.insert(0x00209248, DicomDictElt { vr : "FL", vm: "1", name: "Ending Respiratory Amplitude", retired: "", keyword: "EndingRespiratoryAmplitude" });
-	dicom_dictionary.insert(0x00209249, DicomDictElt { vr : "CS", vm: "1", name: "Ending Respiratory Phase", retired: "", keyword: "EndingRespiratoryPhase" });
-	dicom_dictionary.insert(0x00209250, DicomDictElt { vr : "CS", vm: "1", name: "Respiratory Trigger Type", retired: "", keyword: "RespiratoryTriggerType" });
-	dicom_dictionary.insert(0x00209251, DicomDictElt { vr : "FD", vm: "1", name: "R-R Interval Time Nominal", retired: "", keyword: "RRIntervalTimeNominal" });
-	dicom_dictionary.insert(0x00209252, DicomDictElt { vr : "FD", vm: "1", name: "Actual Cardiac Trigger Delay Time", retired: "", keyword: "ActualCardiacTriggerDelayTime" });
-	dicom_dictionary.insert(0x00209253, DicomDictElt { vr : "SQ", vm: "1", name: "Respiratory Synchronization Sequence", retired: "", keyword: "RespiratorySynchronizationSequence" });
-	dicom_dictionary.insert(0x00209254, DicomDictElt { vr : "FD", vm: "1", name: "Respiratory Interval Time", retired: "", keyword: "RespiratoryIntervalTime" });
-	dicom_dictionary.insert(0x00209255, DicomDictElt { vr : "FD", vm: "1", name: "Nominal Respiratory Trigger Delay Time", retired: "", keyword: "NominalRespiratoryTriggerDelayTime" });
-	dicom_dictionary.insert(0x00209256, DicomDictElt { vr : "FD", vm: "1", name: "Respiratory Trigger Delay Threshold", retired: "", keyword: "RespiratoryTriggerDelayThreshold" });
-	dicom_dictionary.insert(0x00209257, DicomDictElt { vr : "FD", vm: "1", name: "Actual Respiratory Trigger Delay Time", retired: "", keyword: "ActualRespiratoryTriggerDelayTime" });
-	dicom_dictionary.insert(0x00209301, DicomDictElt { vr : "FD", vm: "3", name: "Image Position (Volume)", retired: "", keyword: "ImagePositionVolume" });
-	dicom_dictionary.insert(0x00209302, DicomDictElt { vr : "FD", vm: "6", name: "Image Orientation (Volume)", retired: "", keyword: "ImageOrientationVolume" });
-	dicom_dictionary.insert(0x00209307, DicomDictElt { vr : "CS", vm: "1", name: "Ultrasound Acquisition Geometry", retired: "", keyword: "UltrasoundAcquisitionGeometry" });
-	dicom_dictionary.insert(0x00209308, DicomDictElt { vr : "FD", vm: "3", name: "Apex Position", retired: "", keyword: "ApexPosition" });
-	dicom_dictionary.insert(0x00209309, DicomDictElt { vr : "FD", vm: "16", name: "Volume to Transducer Mapping Matrix", retired: "", keyword: "VolumeToTransducerMappingMatrix" });
-	dicom_dictionary.insert(0x0020930A, DicomDictElt { vr : "FD", vm: "16", name: "Volume to Table Mapping Matrix", retired: "", keyword: "VolumeToTableMappingMatrix" });
-	dicom_dictionary.insert(0x0020930B, DicomDictElt { vr : "CS", vm: "1", name: "Volume to Transducer Relationship", retired: "", keyword: "VolumeToTransducerRelationship" });
-	dicom_dictionary.insert(0x0020930C, DicomDictElt { vr : "CS", vm: "1", name: "Patient Frame of Reference Source", retired: "", keyword: "PatientFrameOfReferenceSource" });
-	dicom_dictionary.insert(0x0020930D, DicomDictElt { vr : "FD", vm: "1", name: "Temporal Position Time Offset", retired: "", keyword: "TemporalPositionTimeOffset" });
-	dicom_dictionary.insert(0x0020930E, DicomDictElt { vr : "SQ", vm: "1", name: "Plane Position (Volume) Sequence", retired: "", keyword: "PlanePositionVolumeSequence" });
-	dicom_dictionary.insert(0x0020930F, DicomDictElt { vr : "SQ", vm: "1", name: "Plane Orientation (Volume) Sequence", retired: "", keyword: "PlaneOrientationVolumeSequence" });
-	dicom_dictionary.insert(0x00209310, DicomDictElt { vr : "SQ", vm: "1", name: "Temporal Position Sequence", retired: "", keyword: "TemporalPositionSequence" });
-	dicom_dictionary.insert(0x00209311, DicomDictElt { vr : "CS", vm: "1", name: "Dimension Organization Type", retired: "", keyword: "DimensionOrganizationType" });
-	dicom_dictionary.insert(0x00209312, DicomDictElt { vr : "UI", vm: "1", name: "Volume Frame of Reference UID", retired: "", keyword: "VolumeFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x00209313, DicomDictElt { vr : "UI", vm: "1", name: "Table Frame of Reference UID", retired: "", keyword: "TableFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x00209421, DicomDictElt { vr : "LO", vm: "1", name: "Dimension Description Label", retired: "", keyword: "DimensionDescriptionLabel" });
-	dicom_dictionary.insert(0x00209450, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Orientation in Frame Sequence", retired: "", keyword: "PatientOrientationInFrameSequence" });
-	dicom_dictionary.insert(0x00209453, DicomDictElt { vr : "LO", vm: "1", name: "Frame Label", retired: "", keyword: "FrameLabel" });
-	dicom_dictionary.insert(0x00209518, DicomDictElt { vr : "US", vm: "1-n", name: "Acquisition Index", retired: "", keyword: "AcquisitionIndex" });
-	dicom_dictionary.insert(0x00209529, DicomDictElt { vr : "SQ", vm: "1", name: "Contributing SOP Instances Reference Sequence", retired: "", keyword: "ContributingSOPInstancesReferenceSequence" });
-	dicom_dictionary.insert(0x00209536, DicomDictElt { vr : "US", vm: "1", name: "Reconstruction Index", retired: "", keyword: "ReconstructionIndex" });
-	dicom_dictionary.insert(0x00220001, DicomDictElt { vr : "US", vm: "1", name: "Light Path Filter Pass-Through Wavelength", retired: "", keyword: "LightPathFilterPassThroughWavelength" });
-	dicom_dictionary.insert(0x00220002, DicomDictElt { vr : "US", vm: "2", name: "Light Path Filter Pass Band", retired: "", keyword: "LightPathFilterPassBand" });
-	dicom_dictionary.insert(0x00220003, DicomDictElt { vr : "US", vm: "1", name: "Image Path Filter Pass-Through Wavelength", retired: "", keyword: "ImagePathFilterPassThroughWavelength" });
-	dicom_dictionary.insert(0x00220004, DicomDictElt { vr : "US", vm: "2", name: "Image Path Filter Pass Band", retired: "", keyword: "ImagePathFilterPassBand" });
-	dicom_dictionary.insert(0x00220005, DicomDictElt { vr : "CS", vm: "1", name: "Patient Eye Movement Commanded", retired: "", keyword: "PatientEyeMovementCommanded" });
-	dicom_dictionary.insert(0x00220006, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Eye Movement Command Code Sequence", retired: "", keyword: "PatientEyeMovementCommandCodeSequence" });
-	dicom_dictionary.insert(0x00220007, DicomDictElt { vr : "FL", vm: "1", name: "Spherical Lens Power", retired: "", keyword: "SphericalLensPower" });
-	dicom_dictionary.insert(0x00220008, DicomDictElt { vr : "FL", vm: "1", name: "Cylinder Lens Power", retired: "", keyword: "CylinderLensPower" });
-	dicom_dictionary.insert(0x00220009, DicomDictElt { vr : "FL", vm: "1", name: "Cylinder Axis", retired: "", keyword: "CylinderAxis" });
-	dicom_dictionary.insert(0x0022000A, DicomDictElt { vr : "FL", vm: "1", name: "Emmetropic Magnification", retired: "", keyword: "EmmetropicMagnification" });
-	dicom_dictionary.insert(0x0022000B, DicomDictElt { vr : "FL", vm: "1", name: "Intra Ocular Pressure", retired: "", keyword: "IntraOcularPressure" });
-	dicom_dictionary.insert(0x0022000C, DicomDictElt { vr : "FL", vm: "1", name: "Horizontal Field of View", retired: "", keyword: "HorizontalFieldOfView" });
-	dicom_dictionary.insert(0x0022000D, DicomDictElt { vr : "CS", vm: "1", name: "Pupil Dilated", retired: "", keyword: "PupilDilated" });
-	dicom_dictionary.insert(0x0022000E, DicomDictElt { vr : "FL", vm: "1", name: "Degree of Dilation", retired: "", keyword: "DegreeOfDilation" });
-	dicom_dictionary.insert(0x00220010, DicomDictElt { vr : "FL", vm: "1", name: "Stereo Baseline Angle", retired: "", keyword: "StereoBaselineAngle" });
-	dicom_dictionary.insert(0x00220011, DicomDictElt { vr : "FL", vm: "1", name: "Stereo Baseline Displacement", retired: "", keyword: "StereoBaselineDisplacement" });
-	dicom_dictionary.insert(0x00220012, DicomDictElt { vr : "FL", vm: "1", name: "Stereo Horizontal Pixel Offset", retired: "", keyword: "StereoHorizontalPixelOffset" });
-	dicom_dictionary.insert(0x00220013, DicomDictElt { vr : "FL", vm: "1", name: "Stereo Vertical Pixel Offset", retired: "", keyword: "StereoVerticalPixelOffset" });
-	dicom_dictionary.insert(0x00220014, DicomDictElt { vr : "FL", vm: "1", name: "Stereo Rotation", retired: "", keyword: "StereoRotation" });
-	dicom_dictionary.insert(0x00220015, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Device Type Code Sequence", retired: "", keyword: "AcquisitionDeviceTypeCodeSequence" });
-	dicom_dictionary.insert(0x00220016, DicomDictElt { vr : "SQ", vm: "1", name: "Illumination Type Code Sequence", retired: "", keyword: "IlluminationTypeCodeSequence" });
-	dicom_dictionary.insert(0x00220017, DicomDictElt { vr : "SQ", vm: "1", name: "Light Path Filter Type Stack Code Sequence", retired: "", keyword: "LightPathFilterTypeStackCodeSequence" });
-	dicom_dictionary.insert(0x00220018, DicomDictElt { vr : "SQ", vm: "1", name: "Image Path Filter Type Stack Code Sequence", retired: "", keyword: "ImagePathFilterTypeStackCodeSequence" });
-	dicom_dictionary.insert(0x00220019, DicomDictElt { vr : "SQ", vm: "1", name: "Lenses Code Sequence", retired: "", keyword: "LensesCodeSequence" });
-	dicom_dictionary.insert(0x0022001A, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Description Code Sequence", retired: "", keyword: "ChannelDescriptionCodeSequence" });
-	dicom_dictionary.insert(0x0022001B, DicomDictElt { vr : "SQ", vm: "1", name: "Refractive State Sequence", retired: "", keyword: "RefractiveStateSequence" });
-	dicom_dictionary.insert(0x0022001C, DicomDictElt { vr : "SQ", vm: "1", name: "Mydriatic Agent Code Sequence", retired: "", keyword: "MydriaticAgentCodeSequence" });
-	dicom_dictionary.insert(0x0022001D, DicomDictElt { vr : "SQ", vm: "1", name: "Relative Image Position Code Sequence", retired: "", keyword: "RelativeImagePositionCodeSequence" });
-	dicom_dictionary.insert(0x0022001E, DicomDictElt { vr : "FL", vm: "1", name: "Camera Angle of View", retired: "", keyword: "CameraAngleOfView" });
-	dicom_dictionary.insert(0x00220020, DicomDictElt { vr : "SQ", vm: "1", name: "Stereo Pairs Sequence", retired: "", keyword: "StereoPairsSequence" });
-	dicom_dictionary.insert(0x00220021, DicomDictElt { vr : "SQ", vm: "1", name: "Left Image Sequence", retired: "", keyword: "LeftImageSequence" });
-	dicom_dictionary.insert(0x00220022, DicomDictElt { vr : "SQ", vm: "1", name: "Right Image Sequence", retired: "", keyword: "RightImageSequence" });
-	dicom_dictionary.insert(0x00220028, DicomDictElt { vr : "CS", vm: "1", name: "Stereo Pairs Present", retired: "", keyword: "StereoPairsPresent" });
-	dicom_dictionary.insert(0x00220030, DicomDictElt { vr : "FL", vm: "1", name: "Axial Length of the Eye", retired: "", keyword: "AxialLengthOfTheEye" });
-	dicom_dictionary.insert(0x00220031, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Frame Location Sequence", retired: "", keyword: "OphthalmicFrameLocationSequence" });
-	dicom_dictionary.insert(0x00220032, DicomDictElt { vr : "FL", vm: "2-2n", name: "Reference Coordinates", retired: "", keyword: "ReferenceCoordinates" });
-	dicom_dictionary.insert(0x00220035, DicomDictElt { vr : "FL", vm: "1", name: "Depth Spatial Resolution", retired: "", keyword: "DepthSpatialResolution" });
-	dicom_dictionary.insert(0x00220036, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Depth Distortion", retired: "", keyword: "MaximumDepthDistortion" });
-	dicom_dictionary.insert(0x00220037, DicomDictElt { vr : "FL", vm: "1", name: "Along-scan Spatial Resolution", retired: "", keyword: "AlongScanSpatialResolution" });
-	dicom_dictionary.insert(0x00220038, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Along-scan Distortion", retired: "", keyword: "MaximumAlongScanDistortion" });
-	dicom_dictionary.insert(0x00220039, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Image Orientation", retired: "", keyword: "OphthalmicImageOrientation" });
-	dicom_dictionary.insert(0x00220041, DicomDictElt { vr : "FL", vm: "1", name: "Depth of Transverse Image", retired: "", keyword: "DepthOfTransverseImage" });
-	dicom_dictionary.insert(0x00220042, DicomDictElt { vr : "SQ", vm: "1", name: "Mydriatic Agent Concentration Units Sequence", retired: "", keyword: "MydriaticAgentConcentrationUnitsSequence" });
-	dicom_dictionary.insert(0x00220048, DicomDictElt { vr : "FL", vm: "1", name: "Across-scan Spatial Resolution", retired: "", keyword: "AcrossScanSpatialResolution" });
-	dicom_dictionary.insert(0x00220049, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Across-scan Distortion", retired: "", keyword: "MaximumAcrossScanDistortion" });
-	dicom_dictionary.insert(0x0022004E, DicomDictElt { vr : "DS", vm: "1", name: "Mydriatic Agent Concentration", retired: "", keyword: "MydriaticAgentConcentration" });
-	dicom_dictionary.insert(0x00220055, DicomDictElt { vr : "FL", vm: "1", name: "Illumination Wave Length", retired: "", keyword: "IlluminationWaveLength" });
-	dicom_dictionary.insert(0x00220056, DicomDictElt { vr : "FL", vm: "1", name: "Illumination Power", retired: "", keyword: "IlluminationPower" });
-	dicom_dictionary.insert(0x00220057, DicomDictElt { vr : "FL", vm: "1", name: "Illumination Bandwidth", retired: "", keyword: "IlluminationBandwidth" });
-	dicom_dictionary.insert(0x00220058, DicomDictElt { vr : "SQ", vm: "1", name: "Mydriatic Agent Sequence", retired: "", keyword: "MydriaticAgentSequence" });
-	dicom_dictionary.insert(0x00221007, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Measurements Right Eye Sequence", retired: "", keyword: "OphthalmicAxialMeasurementsRightEyeSequence" });
-	dicom_dictionary.insert(0x00221008, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Measurements Left Eye Sequence", retired: "", keyword: "OphthalmicAxialMeasurementsLeftEyeSequence" });
-	dicom_dictionary.insert(0x00221009, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Axial Measurements Device Type", retired: "", keyword: "OphthalmicAxialMeasurementsDeviceType" });
-	dicom_dictionary.insert(0x00221010, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Axial Length Measurements Type", retired: "", keyword: "OphthalmicAxialLengthMeasurementsType" });
-	dicom_dictionary.insert(0x00221012, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Sequence", retired: "", keyword: "OphthalmicAxialLengthSequence" });
-	dicom_dictionary.insert(0x00221019, DicomDictElt { vr : "FL", vm: "1", name: "Ophthalmic Axial Length", retired: "", keyword: "OphthalmicAxialLength" });
-	dicom_dictionary.insert(0x00221024, DicomDictElt { vr : "SQ", vm: "1", name: "Lens Status Code Sequence", retired: "", keyword: "LensStatusCodeSequence" });
-	dicom_dictionary.insert(0x00221025, DicomDictElt { vr : "SQ", vm: "1", name: "Vitreous Status Code Sequence", retired: "", keyword: "VitreousStatusCodeSequence" });
-	dicom_dictionary.insert(0x00221028, DicomDictElt { vr : "SQ", vm: "1", name: "IOL Formula Code Sequence", retired: "", keyword: "IOLFormulaCodeSequence" });
-	dicom_dictionary.insert(0x00221029, DicomDictElt { vr : "LO", vm: "1", name: "IOL Formula Detail", retired: "", keyword: "IOLFormulaDetail" });
-	dicom_dictionary.insert(0x00221033, DicomDictElt { vr : "FL", vm: "1", name: "Keratometer Index", retired: "", keyword: "KeratometerIndex" });
-	dicom_dictionary.insert(0x00221035, DicomDictElt { vr : "SQ", vm: "1", name: "Source of Ophthalmic Axial Length Code Sequence", retired: "", keyword: "SourceOfOphthalmicAxialLengthCodeSequence" });
-	dicom_dictionary.insert(0x00221037, DicomDictElt { vr : "FL", vm: "1", name: "Target Refraction", retired: "", keyword: "TargetRefraction" });
-	dicom_dictionary.insert(0x00221039, DicomDictElt { vr : "CS", vm: "1", name: "Refractive Procedure Occurred", retired: "", keyword: "RefractiveProcedureOccurred" });
-	dicom_dictionary.insert(0x00221040, DicomDictElt { vr : "SQ", vm: "1", name: "Refractive Surgery Type Code Sequence", retired: "", keyword: "RefractiveSurgeryTypeCodeSequence" });
-	dicom_dictionary.insert(0x00221044, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Ultrasound Method Code Sequence", retired: "", keyword: "OphthalmicUltrasoundMethodCodeSequence" });
-	dicom_dictionary.insert(0x00221050, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Measurements Sequence", retired: "", keyword: "OphthalmicAxialLengthMeasurementsSequence" });
-	dicom_dictionary.insert(0x00221053, DicomDictElt { vr : "FL", vm: "1", name: "IOL Power", retired: "", keyword: "IOLPower" });
-	dicom_dictionary.insert(0x00221054, DicomDictElt { vr : "FL", vm: "1", name: "Predicted Refractive Error", retired: "", keyword: "PredictedRefractiveError" });
-	dicom_dictionary.insert(0x00221059, DicomDictElt { vr : "FL", vm: "1", name: "Ophthalmic Axial Length Velocity", retired: "", keyword: "OphthalmicAxialLengthVelocity" });
-	dicom_dictionary.insert(0x00221065, DicomDictElt { vr : "LO", vm: "1", name: "Lens Status Description", retired: "", keyword: "LensStatusDescription" });
-	dicom_dictionary.insert(0x00221066, DicomDictElt { vr : "LO", vm: "1", name: "Vitreous Status Description", retired: "", keyword: "VitreousStatusDescription" });
-	dicom_dictionary.insert(0x00221090, DicomDictElt { vr : "SQ", vm: "1", name: "IOL Power Sequence", retired: "", keyword: "IOLPowerSequence" });
-	dicom_dictionary.insert(0x00221092, DicomDictElt { vr : "SQ", vm: "1", name: "Lens Constant Sequence", retired: "", keyword: "LensConstantSequence" });
-	dicom_dictionary.insert(0x00221093, DicomDictElt { vr : "LO", vm: "1", name: "IOL Manufacturer", retired: "", keyword: "IOLManufacturer" });
-	dicom_dictionary.insert(0x00221094, DicomDictElt { vr : "LO", vm: "1", name: "Lens Constant Description", retired: "Retired", keyword: "LensConstantDescription" });
-	dicom_dictionary.insert(0x00221095, DicomDictElt { vr : "LO", vm: "1", name: "Implant Name", retired: "", keyword: "ImplantName" });
-	dicom_dictionary.insert(0x00221096, DicomDictElt { vr : "SQ", vm: "1", name: "Keratometry Measurement Type Code Sequence", retired: "", keyword: "KeratometryMeasurementTypeCodeSequence" });
-	dicom_dictionary.insert(0x00221097, DicomDictElt { vr : "LO", vm: "1", name: "Implant Part Number", retired: "", keyword: "ImplantPartNumber" });
-	dicom_dictionary.insert(0x00221100, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Ophthalmic Axial Measurements Sequence", retired: "", keyword: "ReferencedOphthalmicAxialMeasurementsSequence" });
-	dicom_dictionary.insert(0x00221101, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Measurements Segment Name Code Sequence", retired: "", keyword: "OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence" });
-	dicom_dictionary.insert(0x00221103, DicomDictElt { vr : "SQ", vm: "1", name: "Refractive Error Before Refractive Surgery Code Sequence", retired: "", keyword: "RefractiveErrorBeforeRefractiveSurgeryCodeSequence" });
-	dicom_dictionary.insert(0x00221121, DicomDictElt { vr : "FL", vm: "1", name: "IOL Power For Exact Emmetropia", retired: "", keyword: "IOLPowerForExactEmmetropia" });
-	dicom_dictionary.insert(0x00221122, DicomDictElt { vr : "FL", vm: "1", name: "IOL Power For Exact Target Refraction", retired: "", keyword: "IOLPowerForExactTargetRefraction" });
-	dicom_dictionary.insert(0x00221125, DicomDictElt { vr : "SQ", vm: "1", name: "Anterior Chamber Depth Definition Code Sequence", retired: "", keyword: "AnteriorChamberDepthDefinitionCodeSequence" });
-	dicom_dictionary.insert(0x00221127, DicomDictElt { vr : "SQ", vm: "1", name: "Lens Thickness Sequence", retired: "", keyword: "LensThicknessSequence" });
-	dicom_dictionary.insert(0x00221128, DicomDictElt { vr : "SQ", vm: "1", name: "Anterior Chamber Depth Sequence", retired: "", keyword: "AnteriorChamberDepthSequence" });
-	dicom_dictionary.insert(0x00221130, DicomDictElt { vr : "FL", vm: "1", name: "Lens Thickness", retired: "", keyword: "LensThickness" });
-	dicom_dictionary.insert(0x00221131, DicomDictElt { vr : "FL", vm: "1", name: "Anterior Chamber Depth", retired: "", keyword: "AnteriorChamberDepth" });
-	dicom_dictionary.insert(0x00221132, DicomDictElt { vr : "SQ", vm: "1", name: "Source of Lens Thickness Data Code Sequence", retired: "", keyword: "SourceOfLensThicknessDataCodeSequence" });
-	dicom_dictionary.insert(0x00221133, DicomDictElt { vr : "SQ", vm: "1", name: "Source of Anterior Chamber Depth Data Code Sequence", retired: "", keyword: "SourceOfAnteriorChamberDepthDataCodeSequence" });
-	dicom_dictionary.insert(0x00221134, DicomDictElt { vr : "SQ", vm: "1", name: "Source of Refractive Measurements Sequence", retired: "", keyword: "SourceOfRefractiveMeasurementsSequence" });
-	dicom_dictionary.insert(0x00221135, DicomDictElt { vr : "SQ", vm: "1", name: "Source of Refractive Measurements Code Sequence", retired: "", keyword: "SourceOfRefractiveMeasurementsCodeSequence" });
-	dicom_dictionary.insert(0x00221140, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Axial Length Measurement Modified", retired: "", keyword: "OphthalmicAxialLengthMeasurementModified" });
-	dicom_dictionary.insert(0x00221150, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Data Source Code Sequence", retired: "", keyword: "OphthalmicAxialLengthDataSourceCodeSequence" });
-	dicom_dictionary.insert(0x00221153, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Acquisition Method Code Sequence", retired: "Retired", keyword: "OphthalmicAxialLengthAcquisitionMethodCodeSequence" });
-	dicom_dictionary.insert(0x00221155, DicomDictElt { vr : "FL", vm: "1", name: "Signal to Noise Ratio", retired: "", keyword: "SignalToNoiseRatio" });
-	dicom_dictionary.insert(0x00221159, DicomDictElt { vr : "LO", vm: "1", name: "Ophthalmic Axial Length Data Source Description", retired: "", keyword: "OphthalmicAxialLengthDataSourceDescription" });
-	dicom_dictionary.insert(0x00221210, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Measurements Total Length Sequence", retired: "", keyword: "OphthalmicAxialLengthMeasurementsTotalLengthSequence" });
-	dicom_dictionary.insert(0x00221211, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Measurements Segmental Length Sequence", retired: "", keyword: "OphthalmicAxialLengthMeasurementsSegmentalLengthSequence" });
-	dicom_dictionary.insert(0x00221212, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Measurements Length Summation Sequence", retired: "", keyword: "OphthalmicAxialLengthMeasurementsLengthSummationSequence" });
-	dicom_dictionary.insert(0x00221220, DicomDictElt { vr : "SQ", vm: "1", name: "Ultrasound Ophthalmic Axial Length Measurements Sequence", retired: "", keyword: "UltrasoundOphthalmicAxialLengthMeasurementsSequence" });
-	dicom_dictionary.insert(0x00221225, DicomDictElt { vr : "SQ", vm: "1", name: "Optical Ophthalmic Axial Length Measurements Sequence", retired: "", keyword: "OpticalOphthalmicAxialLengthMeasurementsSequence" });
-	dicom_dictionary.insert(0x00221230, DicomDictElt { vr : "SQ", vm: "1", name: "Ultrasound Selected Ophthalmic Axial Length Sequence", retired: "", keyword: "UltrasoundSelectedOphthalmicAxialLengthSequence" });
-	dicom_dictionary.insert(0x00221250, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Selection Method Code Sequence", retired: "", keyword: "OphthalmicAxialLengthSelectionMethodCodeSequence" });
-	dicom_dictionary.insert(0x00221255, DicomDictElt { vr : "SQ", vm: "1", name: "Optical Selected Ophthalmic Axial Length Sequence", retired: "", keyword: "OpticalSelectedOphthalmicAxialLengthSequence" });
-	dicom_dictionary.insert(0x00221257, DicomDictElt { vr : "SQ", vm: "1", name: "Selected Segmental Ophthalmic Axial Length Sequence", retired: "", keyword: "SelectedSegmentalOphthalmicAxialLengthSequence" });
-	dicom_dictionary.insert(0x00221260, DicomDictElt { vr : "SQ", vm: "1", name: "Selected Total Ophthalmic Axial Length Sequence", retired: "", keyword: "SelectedTotalOphthalmicAxialLengthSequence" });
-	dicom_dictionary.insert(0x00221262, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Quality Metric Sequence", retired: "", keyword: "OphthalmicAxialLengthQualityMetricSequence" });
-	dicom_dictionary.insert(0x00221265, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Axial Length Quality Metric Type Code Sequence", retired: "Retired", keyword: "OphthalmicAxialLengthQualityMetricTypeCodeSequence" });
-	dicom_dictionary.insert(0x00221273, DicomDictElt { vr : "LO", vm: "1", name: "Ophthalmic Axial Length Quality Metric Type Description", retired: "Retired", keyword: "OphthalmicAxialLengthQualityMetricTypeDescription" });
-	dicom_dictionary.insert(0x00221300, DicomDictElt { vr : "SQ", vm: "1", name: "Intraocular Lens Calculations Right Eye Sequence", retired: "", keyword: "IntraocularLensCalculationsRightEyeSequence" });
-	dicom_dictionary.insert(0x00221310, DicomDictElt { vr : "SQ", vm: "1", name: "Intraocular Lens Calculations Left Eye Sequence", retired: "", keyword: "IntraocularLensCalculationsLeftEyeSequence" });
-	dicom_dictionary.insert(0x00221330, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Ophthalmic Axial Length Measurement QC Image Sequence", retired: "", keyword: "ReferencedOphthalmicAxialLengthMeasurementQCImageSequence" });
-	dicom_dictionary.insert(0x00221415, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Mapping Device Type", retired: "", keyword: "OphthalmicMappingDeviceType" });
-	dicom_dictionary.insert(0x00221420, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Method Code Sequence", retired: "", keyword: "AcquisitionMethodCodeSequence" });
-	dicom_dictionary.insert(0x00221423, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Method Algorithm Sequence", retired: "", keyword: "AcquisitionMethodAlgorithmSequence" });
-	dicom_dictionary.insert(0x00221436, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Thickness Map Type Code Sequence", retired: "", keyword: "OphthalmicThicknessMapTypeCodeSequence" });
-	dicom_dictionary.insert(0x00221443, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Thickness Mapping Normals Sequence", retired: "", keyword: "OphthalmicThicknessMappingNormalsSequence" });
-	dicom_dictionary.insert(0x00221445, DicomDictElt { vr : "SQ", vm: "1", name: "Retinal Thickness Definition Code Sequence", retired: "", keyword: "RetinalThicknessDefinitionCodeSequence" });
-	dicom_dictionary.insert(0x00221450, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Value Mapping to Coded Concept Sequence", retired: "", keyword: "PixelValueMappingToCodedConceptSequence" });
-	dicom_dictionary.insert(0x00221452, DicomDictElt { vr : "US or SS", vm: "1", name: "Mapped Pixel Value", retired: "", keyword: "MappedPixelValue" });
-	dicom_dictionary.insert(0x00221454, DicomDictElt { vr : "LO", vm: "1", name: "Pixel Value Mapping Explanation", retired: "", keyword: "PixelValueMappingExplanation" });
-	dicom_dictionary.insert(0x00221458, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Thickness Map Quality Threshold Sequence", retired: "", keyword: "OphthalmicThicknessMapQualityThresholdSequence" });
-	dicom_dictionary.insert(0x00221460, DicomDictElt { vr : "FL", vm: "1", name: "Ophthalmic Thickness Map Threshold Quality Rating", retired: "", keyword: "OphthalmicThicknessMapThresholdQualityRating" });
-	dicom_dictionary.insert(0x00221463, DicomDictElt { vr : "FL", vm: "2", name: "Anatomic Structure Reference Point", retired: "", keyword: "AnatomicStructureReferencePoint" });
-	dicom_dictionary.insert(0x00221465, DicomDictElt { vr : "SQ", vm: "1", name: "Registration to Localizer Sequence", retired: "", keyword: "RegistrationToLocalizerSequence" });
-	dicom_dictionary.insert(0x00221466, DicomDictElt { vr : "CS", vm: "1", name: "Registered Localizer Units", retired: "", keyword: "RegisteredLocalizerUnits" });
-	dicom_dictionary.insert(0x00221467, DicomDictElt { vr : "FL", vm: "2", name: "Registered Localizer Top Left Hand Corner", retired: "", keyword: "RegisteredLocalizerTopLeftHandCorner" });
-	dicom_dictionary.insert(0x00221468, DicomDictElt { vr : "FL", vm: "2", name: "Registered Localizer Bottom Right Hand Corner", retired: "", keyword: "RegisteredLocalizerBottomRightHandCorner" });
-	dicom_dictionary.insert(0x00221470, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Thickness Map Quality Rating Sequence", retired: "", keyword: "OphthalmicThicknessMapQualityRatingSequence" });
-	dicom_dictionary.insert(0x00221472, DicomDictElt { vr : "SQ", vm: "1", name: "Relevant OPT Attributes Sequence", retired: "", keyword: "RelevantOPTAttributesSequence" });
-	dicom_dictionary.insert(0x00221512, DicomDictElt { vr : "SQ", vm: "1", name: "Transformation Method Code Sequence", retired: "", keyword: "TransformationMethodCodeSequence" });
-	dicom_dictionary.insert(0x00221513, DicomDictElt { vr : "SQ", vm: "1", name: "Transformation Algorithm Sequence", retired: "", keyword: "TransformationAlgorithmSequence" });
-	dicom_dictionary.insert(0x00221515, DicomDictElt { vr : "CS", vm: "1", name: "Ophthalmic Axial Length Method", retired: "", keyword: "OphthalmicAxialLengthMethod" });
-	dicom_dictionary.insert(0x00221517, DicomDictElt { vr : "FL", vm: "1", name: "Ophthalmic FOV", retired: "", keyword: "OphthalmicFOV" });
-	dicom_dictionary.insert(0x00221518, DicomDictElt { vr : "SQ", vm: "1", name: "Two Dimensional to Three Dimensional Map Sequence", retired: "", keyword: "TwoDimensionalToThreeDimensionalMapSequence" });
-	dicom_dictionary.insert(0x00221525, DicomDictElt { vr : "SQ", vm: "1", name: "Wide Field Ophthalmic Photography Quality Rating Sequence", retired: "", keyword: "WideFieldOphthalmicPhotographyQualityRatingSequence" });
-	dicom_dictionary.insert(0x00221526, DicomDictElt { vr : "SQ", vm: "1", name: "Wide Field Ophthalmic Photography Quality Threshold Sequence", retired: "", keyword: "WideFieldOphthalmicPhotographyQualityThresholdSequence" });
-	dicom_dictionary.insert(0x00221527, DicomDictElt { vr : "FL", vm: "1", name: "Wide Field Ophthalmic Photography Threshold Quality Rating", retired: "", keyword: "WideFieldOphthalmicPhotographyThresholdQualityRating" });
-	dicom_dictionary.insert(0x00221528, DicomDictElt { vr : "FL", vm: "1", name: "X Coordinates Center Pixel View Angle", retired: "", keyword: "XCoordinatesCenterPixelViewAngle" });
-	dicom_dictionary.insert(0x00221529, DicomDictElt { vr : "FL", vm: "1", name: "Y Coordinates Center Pixel View Angle", retired: "", keyword: "YCoordinatesCenterPixelViewAngle" });
-	dicom_dictionary.insert(0x00221530, DicomDictElt { vr : "UL", vm: "1", name: "Number of Map Points", retired: "", keyword: "NumberOfMapPoints" });
-	dicom_dictionary.insert(0x00221531, DicomDictElt { vr : "OF", vm: "1", name: "Two Dimensional to Three Dimensional Map Data", retired: "", keyword: "TwoDimensionalToThreeDimensionalMapData" });
-	dicom_dictionary.insert(0x00240010, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Horizontal Extent", retired: "", keyword: "VisualFieldHorizontalExtent" });
-	dicom_dictionary.insert(0x00240011, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Vertical Extent", retired: "", keyword: "VisualFieldVerticalExtent" });
-	dicom_dictionary.insert(0x00240012, DicomDictElt { vr : "CS", vm: "1", name: "Visual Field Shape", retired: "", keyword: "VisualFieldShape" });
-	dicom_dictionary.insert(0x00240016, DicomDictElt { vr : "SQ", vm: "1", name: "Screening Test Mode Code Sequence", retired: "", keyword: "ScreeningTestModeCodeSequence" });
-	dicom_dictionary.insert(0x00240018, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Stimulus Luminance", retired: "", keyword: "MaximumStimulusLuminance" });
-	dicom_dictionary.insert(0x00240020, DicomDictElt { vr : "FL", vm: "1", name: "Background Luminance", retired: "", keyword: "BackgroundLuminance" });
-	dicom_dictionary.insert(0x00240021, DicomDictElt { vr : "SQ", vm: "1", name: "Stimulus Color Code Sequence", retired: "", keyword: "StimulusColorCodeSequence" });
-	dicom_dictionary.insert(0x00240024, DicomDictElt { vr : "SQ", vm: "1", name: "Background Illumination Color Code Sequence", retired: "", keyword: "BackgroundIlluminationColorCodeSequence" });
-	dicom_dictionary.insert(0x00240025, DicomDictElt { vr : "FL", vm: "1", name: "Stimulus Area", retired: "", keyword: "StimulusArea" });
-	dicom_dictionary.insert(0x00240028, DicomDictElt { vr : "FL", vm: "1", name: "Stimulus Presentation Time", retired: "", keyword: "StimulusPresentationTime" });
-	dicom_dictionary.insert(0x00240032, DicomDictElt { vr : "SQ", vm: "1", name: "Fixation Sequence", retired: "", keyword: "FixationSequence" });
-	dicom_dictionary.insert(0x00240033, DicomDictElt { vr : "SQ", vm: "1", name: "Fixation Monitoring Code Sequence", retired: "", keyword: "FixationMonitoringCodeSequence" });
-	dicom_dictionary.insert(0x00240034, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Field Catch Trial Sequence", retired: "", keyword: "VisualFieldCatchTrialSequence" });
-	dicom_dictionary.insert(0x00240035, DicomDictElt { vr : "US", vm: "1", name: "Fixation Checked Quantity", retired: "", keyword: "FixationCheckedQuantity" });
-	dicom_dictionary.insert(0x00240036, DicomDictElt { vr : "US", vm: "1", name: "Patient Not Properly Fixated Quantity", retired: "", keyword: "PatientNotProperlyFixatedQuantity" });
-	dicom_dictionary.insert(0x00240037, DicomDictElt { vr : "CS", vm: "1", name: "Presented Visual Stimuli Data Flag", retired: "", keyword: "PresentedVisualStimuliDataFlag" });
-	dicom_dictionary.insert(0x00240038, DicomDictElt { vr : "US", vm: "1", name: "Number of Visual Stimuli", retired: "", keyword: "NumberOfVisualStimuli" });
-	dicom_dictionary.insert(0x00240039, DicomDictElt { vr : "CS", vm: "1", name: "Excessive Fixation Losses Data Flag", retired: "", keyword: "ExcessiveFixationLossesDataFlag" });
-	dicom_dictionary.insert(0x00240040, DicomDictElt { vr : "CS", vm: "1", name: "Excessive Fixation Losses", retired: "", keyword: "ExcessiveFixationLosses" });
-	dicom_dictionary.insert(0x00240042, DicomDictElt { vr : "US", vm: "1", name: "Stimuli Retesting Quantity", retired: "", keyword: "StimuliRetestingQuantity" });
-	dicom_dictionary.insert(0x00240044, DicomDictElt { vr : "LT", vm: "1", name: "Comments on Patient's Performance of Visual Field", retired: "", keyword: "CommentsOnPatientPerformanceOfVisualField" });
-	dicom_dictionary.insert(0x00240045, DicomDictElt { vr : "CS", vm: "1", name: "False Negatives Estimate Flag", retired: "", keyword: "FalseNegativesEstimateFlag" });
-	dicom_dictionary.insert(0x00240046, DicomDictElt { vr : "FL", vm: "1", name: "False Negatives Estimate", retired: "", keyword: "FalseNegativesEstimate" });
-	dicom_dictionary.insert(0x00240048, DicomDictElt { vr : "US", vm: "1", name: "Negative Catch Trials Quantity", retired: "", keyword: "NegativeCatchTrialsQuantity" });
-	dicom_dictionary.insert(0x00240050, DicomDictElt { vr : "US", vm: "1", name: "False Negatives Quantity", retired: "", keyword: "FalseNegativesQuantity" });
-	dicom_dictionary.insert(0x00240051, DicomDictElt { vr : "CS", vm: "1", name: "Excessive False Negatives Data Flag", retired: "", keyword: "ExcessiveFalseNegativesDataFlag" });
-	dicom_dictionary.insert(0x00240052, DicomDictElt { vr : "CS", vm: "1", name: "Excessive False Negatives", retired: "", keyword: "ExcessiveFalseNegatives" });
-	dicom_dictionary.insert(0x00240053, DicomDictElt { vr : "CS", vm: "1", name: "False Positives Estimate Flag", retired: "", keyword: "FalsePositivesEstimateFlag" });
-	dicom_dictionary.insert(0x00240054, DicomDictElt { vr : "FL", vm: "1", name: "False Positives Estimate", retired: "", keyword: "FalsePositivesEstimate" });
-	dicom_dictionary.insert(0x00240055, DicomDictElt { vr : "CS", vm: "1", name: "Catch Trials Data Flag", retired: "", keyword: "CatchTrialsDataFlag" });
-	dicom_dictionary.insert(0x00240056, DicomDictElt { vr : "US", vm: "1", name: "Positive Catch Trials Quantity", retired: "", keyword: "PositiveCatchTrialsQuantity" });
-	dicom_dictionary.insert(0x00240057, DicomDictElt { vr : "CS", vm: "1", name: "Test Point Normals Data Flag", retired: "", keyword: "TestPointNormalsDataFlag" });
-	dicom_dictionary.insert(0x00240058, DicomDictElt { vr : "SQ", vm: "1", name: "Test Point Normals Sequence", retired: "", keyword: "TestPointNormalsSequence" });
-	dicom_dictionary.insert(0x00240059, DicomDictElt { vr : "CS", vm: "1", name: "Global Deviation Probability Normals Flag", retired: "", keyword: "GlobalDeviationProbabilityNormalsFlag" });
-	dicom_dictionary.insert(0x00240060, DicomDictElt { vr : "US", vm: "1", name: "False Positives Quantity", retired: "", keyword: "FalsePositivesQuantity" });
-	dicom_dictionary.insert(0x00240061, DicomDictElt { vr : "CS", vm: "1", name: "Excessive False Positives Data Flag", retired: "", keyword: "ExcessiveFalsePositivesDataFlag" });
-	dicom_dictionary.insert(0x00240062, DicomDictElt { vr : "CS", vm: "1", name: "Excessive False Positives", retired: "", keyword: "ExcessiveFalsePositives" });
-	dicom_dictionary.insert(0x00240063, DicomDictElt { vr : "CS", vm: "1", name: "Visual Field Test Normals Flag", retired: "", keyword: "VisualFieldTestNormalsFlag" });
-	dicom_dictionary.insert(0x00240064, DicomDictElt { vr : "SQ", vm: "1", name: "Results Normals Sequence", retired: "", keyword: "ResultsNormalsSequence" });
-	dicom_dictionary.insert(0x00240065, DicomDictElt { vr : "SQ", vm: "1", name: "Age Corrected Sensitivity Deviation Algorithm Sequence", retired: "", keyword: "AgeCorrectedSensitivityDeviationAlgorithmSequence" });
-	dicom_dictionary.insert(0x00240066, DicomDictElt { vr : "FL", vm: "1", name: "Global Deviation From Normal", retired: "", keyword: "GlobalDeviationFromNormal" });
-	dicom_dictionary.insert(0x00240067, DicomDictElt { vr : "SQ", vm: "1", name: "Generalized Defect Sensitivity Deviation Algorithm Sequence", retired: "", keyword: "GeneralizedDefectSensitivityDeviationAlgorithmSequence" });
-	dicom_dictionary.insert(0x00240068, DicomDictElt { vr : "FL", vm: "1", name: "Localized Deviation From Normal", retired: "", keyword: "LocalizedDeviationFromNormal" });
-	dicom_dictionary.insert(0x00240069, DicomDictElt { vr : "LO", vm: "1", name: "Patient Reliability Indicator", retired: "", keyword: "PatientReliabilityIndicator" });
-	dicom_dictionary.insert(0x00240070, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Mean Sensitivity", retired: "", keyword: "VisualFieldMeanSensitivity" });
-	dicom_dictionary.insert(0x00240071, DicomDictElt { vr : "FL", vm: "1", name: "Global Deviation Probability", retired: "", keyword: "GlobalDeviationProbability" });
-	dicom_dictionary.insert(0x00240072, DicomDictElt { vr : "CS", vm: "1", name: "Local Deviation Probability Normals Flag", retired: "", keyword: "LocalDeviationProbabilityNormalsFlag" });
-	dicom_dictionary.insert(0x00240073, DicomDictElt { vr : "FL", vm: "1", name: "Localized Deviation Probability", retired: "", keyword: "LocalizedDeviationProbability" });
-	dicom_dictionary.insert(0x00240074, DicomDictElt { vr : "CS", vm: "1", name: "Short Term Fluctuation Calculated", retired: "", keyword: "ShortTermFluctuationCalculated" });
-	dicom_dictionary.insert(0x00240075, DicomDictElt { vr : "FL", vm: "1", name: "Short Term Fluctuation", retired: "", keyword: "ShortTermFluctuation" });
-	dicom_dictionary.insert(0x00240076, DicomDictElt { vr : "CS", vm: "1", name: "Short Term Fluctuation Probability Calculated", retired: "", keyword: "ShortTermFluctuationProbabilityCalculated" });
-	dicom_dictionary.insert(0x00240077, DicomDictElt { vr : "FL", vm: "1", name: "Short Term Fluctuation Probability", retired: "", keyword: "ShortTermFluctuationProbability" });
-	dicom_dictionary.insert(0x00240078, DicomDictElt { vr : "CS", vm: "1", name: "Corrected Localized Deviation From Normal Calculated", retired: "", keyword: "CorrectedLocalizedDeviationFromNormalCalculated" });
-	dicom_dictionary.insert(0x00240079, DicomDictElt { vr : "FL", vm: "1", name: "Corrected Localized Deviation From Normal", retired: "", keyword: "CorrectedLocalizedDeviationFromNormal" });
-	dicom_dictionary.insert(0x00240080, DicomDictElt { vr : "CS", vm: "1", name: "Corrected Localized Deviation From Normal Probability Calculated", retired: "", keyword: "CorrectedLocalizedDeviationFromNormalProbabilityCalculated" });
-	dicom_dictionary.insert(0x00240081, DicomDictElt { vr : "FL", vm: "1", name: "Corrected Localized Deviation From Normal Probability", retired: "", keyword: "CorrectedLocalizedDeviationFromNormalProbability" });
-	dicom_dictionary.insert(0x00240083, DicomDictElt { vr : "SQ", vm: "1", name: "Global Deviation Probability Sequence", retired: "", keyword: "GlobalDeviationProbabilitySequence" });
-	dicom_dictionary.insert(0x00240085, DicomDictElt { vr : "SQ", vm: "1", name: "Localized Deviation Probability Sequence", retired: "", keyword: "LocalizedDeviationProbabilitySequence" });
-	dicom_dictionary.insert(0x00240086, DicomDictElt { vr : "CS", vm: "1", name: "Foveal Sensitivity Measured", retired: "", keyword: "FovealSensitivityMeasured" });
-	dicom_dictionary.insert(0x00240087, DicomDictElt { vr : "FL", vm: "1", name: "Foveal Sensitivity", retired: "", keyword: "FovealSensitivity" });
-	dicom_dictionary.insert(0x00240088, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Test Duration", retired: "", keyword: "VisualFieldTestDuration" });
-	dicom_dictionary.insert(0x00240089, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Field Test Point Sequence", retired: "", keyword: "VisualFieldTestPointSequence" });
-	dicom_dictionary.insert(0x00240090, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Test Point X-Coordinate", retired: "", keyword: "VisualFieldTestPointXCoordinate" });
-	dicom_dictionary.insert(0x00240091, DicomDictElt { vr : "FL", vm: "1", name: "Visual Field Test Point Y-Coordinate", retired: "", keyword: "VisualFieldTestPointYCoordinate" });
-	dicom_dictionary.insert(0x00240092, DicomDictElt { vr : "FL", vm: "1", name: "Age Corrected Sensitivity Deviation Value", retired: "", keyword: "AgeCorrectedSensitivityDeviationValue" });
-	dicom_dictionary.insert(0x00240093, DicomDictElt { vr : "CS", vm: "1", name: "Stimulus Results", retired: "", keyword: "StimulusResults" });
-	dicom_dictionary.insert(0x00240094, DicomDictElt { vr : "FL", vm: "1", name: "Sensitivity Value", retired: "", keyword: "SensitivityValue" });
-	dicom_dictionary.insert(0x00240095, DicomDictElt { vr : "CS", vm: "1", name: "Retest Stimulus Seen", retired: "", keyword: "RetestStimulusSeen" });
-	dicom_dictionary.insert(0x00240096, DicomDictElt { vr : "FL", vm: "1", name: "Retest Sensitivity Value", retired: "", keyword: "RetestSensitivityValue" });
-	dicom_dictionary.insert(0x00240097, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Field Test Point Normals Sequence", retired: "", keyword: "VisualFieldTestPointNormalsSequence" });
-	dicom_dictionary.insert(0x00240098, DicomDictElt { vr : "FL", vm: "1", name: "Quantified Defect", retired: "", keyword: "QuantifiedDefect" });
-	dicom_dictionary.insert(0x00240100, DicomDictElt { vr : "FL", vm: "1", name: "Age Corrected Sensitivity Deviation Probability Value", retired: "", keyword: "AgeCorrectedSensitivityDeviationProbabilityValue" });
-	dicom_dictionary.insert(0x00240102, DicomDictElt { vr : "CS", vm: "1", name: "Generalized Defect Corrected Sensitivity Deviation Flag", retired: "", keyword: "GeneralizedDefectCorrectedSensitivityDeviationFlag" });
-	dicom_dictionary.insert(0x00240103, DicomDictElt { vr : "FL", vm: "1", name: "Generalized Defect Corrected Sensitivity Deviation Value", retired: "", keyword: "GeneralizedDefectCorrectedSensitivityDeviationValue" });
-	dicom_dictionary.insert(0x00240104, DicomDictElt { vr : "FL", vm: "1", name: "Generalized Defect Corrected Sensitivity Deviation Probability Value", retired: "", keyword: "GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue" });
-	dicom_dictionary.insert(0x00240105, DicomDictElt { vr : "FL", vm: "1", name: "Minimum Sensitivity Value", retired: "", keyword: "MinimumSensitivityValue" });
-	dicom_dictionary.insert(0x00240106, DicomDictElt { vr : "CS", vm: "1", name: "Blind Spot Localized", retired: "", keyword: "BlindSpotLocalized" });
-	dicom_dictionary.insert(0x00240107, DicomDictElt { vr : "FL", vm: "1", name: "Blind Spot X-Coordinate", retired: "", keyword: "BlindSpotXCoordinate" });
-	dicom_dictionary.insert(0x00240108, DicomDictElt { vr : "FL", vm: "1", name: "Blind Spot Y-Coordinate", retired: "", keyword: "BlindSpotYCoordinate" });
-	dicom_dictionary.insert(0x00240110, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Acuity Measurement Sequence", retired: "", keyword: "VisualAcuityMeasurementSequence" });
-	dicom_dictionary.insert(0x00240112, DicomDictElt { vr : "SQ", vm: "1", name: "Refractive Parameters Used on Patient Sequence", retired: "", keyword: "RefractiveParametersUsedOnPatientSequence" });
-	dicom_dictionary.insert(0x00240113, DicomDictElt { vr : "CS", vm: "1", name: "Measurement Laterality", retired: "", keyword: "MeasurementLaterality" });
-	dicom_dictionary.insert(0x00240114, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Patient Clinical Information Left Eye Sequence", retired: "", keyword: "OphthalmicPatientClinicalInformationLeftEyeSequence" });
-	dicom_dictionary.insert(0x00240115, DicomDictElt { vr : "SQ", vm: "1", name: "Ophthalmic Patient Clinical Information Right Eye Sequence", retired: "", keyword: "OphthalmicPatientClinicalInformationRightEyeSequence" });
-	dicom_dictionary.insert(0x00240117, DicomDictElt { vr : "CS", vm: "1", name: "Foveal Point Normative Data Flag", retired: "", keyword: "FovealPointNormativeDataFlag" });
-	dicom_dictionary.insert(0x00240118, DicomDictElt { vr : "FL", vm: "1", name: "Foveal Point Probability Value", retired: "", keyword: "FovealPointProbabilityValue" });
-	dicom_dictionary.insert(0x00240120, DicomDictElt { vr : "CS", vm: "1", name: "Screening Baseline Measured", retired: "", keyword: "ScreeningBaselineMeasured" });
-	dicom_dictionary.insert(0x00240122, DicomDictElt { vr : "SQ", vm: "1", name: "Screening Baseline Measured Sequence", retired: "", keyword: "ScreeningBaselineMeasuredSequence" });
-	dicom_dictionary.insert(0x00240124, DicomDictElt { vr : "CS", vm: "1", name: "Screening Baseline Type", retired: "", keyword: "ScreeningBaselineType" });
-	dicom_dictionary.insert(0x00240126, DicomDictElt { vr : "FL", vm: "1", name: "Screening Baseline Value", retired: "", keyword: "ScreeningBaselineValue" });
-	dicom_dictionary.insert(0x00240202, DicomDictElt { vr : "LO", vm: "1", name: "Algorithm Source", retired: "", keyword: "AlgorithmSource" });
-	dicom_dictionary.insert(0x00240306, DicomDictElt { vr : "LO", vm: "1", name: "Data Set Name", retired: "", keyword: "DataSetName" });
-	dicom_dictionary.insert(0x00240307, DicomDictElt { vr : "LO", vm: "1", name: "Data Set Version", retired: "", keyword: "DataSetVersion" });
-	dicom_dictionary.insert(0x00240308, DicomDictElt { vr : "LO", vm: "1", name: "Data Set Source", retired: "", keyword: "DataSetSource" });
-	dicom_dictionary.insert(0x00240309, DicomDictElt { vr : "LO", vm: "1", name: "Data Set Description", retired: "", keyword: "DataSetDescription" });
-	dicom_dictionary.insert(0x00240317, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Field Test Reliability Global Index Sequence", retired: "", keyword: "VisualFieldTestReliabilityGlobalIndexSequence" });
-	dicom_dictionary.insert(0x00240320, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Field Global Results Index Sequence", retired: "", keyword: "VisualFieldGlobalResultsIndexSequence" });
-	dicom_dictionary.insert(0x00240325, DicomDictElt { vr : "SQ", vm: "1", name: "Data Observation Sequence", retired: "", keyword: "DataObservationSequence" });
-	dicom_dictionary.insert(0x00240338, DicomDictElt { vr : "CS", vm: "1", name: "Index Normals Flag", retired: "", keyword: "IndexNormalsFlag" });
-	dicom_dictionary.insert(0x00240341, DicomDictElt { vr : "FL", vm: "1", name: "Index Probability", retired: "", keyword: "IndexProbability" });
-	dicom_dictionary.insert(0x00240344, DicomDictElt { vr : "SQ", vm: "1", name: "Index Probability Sequence", retired: "", keyword: "IndexProbabilitySequence" });
-	dicom_dictionary.insert(0x00280002, DicomDictElt { vr : "US", vm: "1", name: "Samples per Pixel", retired: "", keyword: "SamplesPerPixel" });
-	dicom_dictionary.insert(0x00280003, DicomDictElt { vr : "US", vm: "1", name: "Samples per Pixel Used", retired: "", keyword: "SamplesPerPixelUsed" });
-	dicom_dictionary.insert(0x00280004, DicomDictElt { vr : "CS", vm: "1", name: "Photometric Interpretation", retired: "", keyword: "PhotometricInterpretation" });
-	dicom_dictionary.insert(0x00280005, DicomDictElt { vr : "US", vm: "1", name: "Image Dimensions", retired: "Retired", keyword: "ImageDimensions" });
-	dicom_dictionary.insert(0x00280006, DicomDictElt { vr : "US", vm: "1", name: "Planar Configuration", retired: "", keyword: "PlanarConfiguration" });
-	dicom_dictionary.insert(0x00280008, DicomDictElt { vr : "IS", vm: "1", name: "Number of Frames", retired: "", keyword: "NumberOfFrames" });
-	dicom_dictionary.insert(0x00280009, DicomDictElt { vr : "AT", vm: "1-n", name: "Frame Increment Pointer", retired: "", keyword: "FrameIncrementPointer" });
-	dicom_dictionary.insert(0x0028000A, DicomDictElt { vr : "AT", vm: "1-n", name: "Frame Dimension Pointer", retired: "", keyword: "FrameDimensionPointer" });
-	dicom_dictionary.insert(0x00280010, DicomDictElt { vr : "US", vm: "1", name: "Rows", retired: "", keyword: "Rows" });
-	dicom_dictionary.insert(0x00280011, DicomDictElt { vr : "US", vm: "1", name: "Columns", retired: "", keyword: "Columns" });
-	dicom_dictionary.insert(0x00280012, DicomDictElt { vr : "US", vm: "1", name: "Planes", retired: "Retired", keyword: "Planes" });
-	dicom_dictionary.insert(0x00280014, DicomDictElt { vr : "US", vm: "1", name: "Ultrasound Color Data Present", retired: "", keyword: "UltrasoundColorDataPresent" });
-	dicom_dictionary.insert(0x00280020, DicomDictElt { vr : "OB", vm: "1", name: "Retired-blank", retired: "Retired", keyword: "" });
-	dicom_dictionary.insert(0x00280030, DicomDictElt { vr : "DS", vm: "2", name: "Pixel Spacing", retired: "", keyword: "PixelSpacing" });
-	dicom_dictionary.insert(0x00280031, DicomDictElt { vr : "DS", vm: "2", name: "Zoom Factor", retired: "", keyword: "ZoomFactor" });
-	dicom_dictionary.insert(0x00280032, DicomDictElt { vr : "DS", vm: "2", name: "Zoom Center", retired: "", keyword: "ZoomCenter" });
-	dicom_dictionary.insert(0x00280034, DicomDictElt { vr : "IS", vm: "2", name: "Pixel Aspect Ratio", retired: "", keyword: "PixelAspectRatio" });
-	dicom_dictionary.insert(0x00280040, DicomDictElt { vr : "CS", vm: "1", name: "Image Format", retired: "Retired", keyword: "ImageFormat" });
-	dicom_dictionary.insert(0x00280050, DicomDictElt { vr : "LO", vm: "1-n", name: "Manipulated Image", retired: "Retired", keyword: "ManipulatedImage" });
-	dicom_dictionary.insert(0x00280051, DicomDictElt { vr : "CS", vm: "1-n", name: "Corrected Image", retired: "", keyword: "CorrectedImage" });
-	dicom_dictionary.insert(0x0028005F, DicomDictElt { vr : "LO", vm: "1", name: "Compression Recognition Code", retired: "Retired", keyword: "CompressionRecognitionCode" });
-	dicom_dictionary.insert(0x00280060, DicomDictElt { vr : "CS", vm: "1", name: "Compression Code", retired: "Retired", keyword: "CompressionCode" });
-	dicom_dictionary.insert(0x00280061, DicomDictElt { vr : "SH", vm: "1", name: "Compression Originator", retired: "Retired", keyword: "CompressionOriginator" });
-	dicom_dictionary.insert(0x00280062, DicomDictElt { vr : "LO", vm: "1", name: "Compression Label", retired: "Retired", keyword: "CompressionLabel" });
-	dicom_dictionary.insert(0x00280063, DicomDictElt { vr : "SH", vm: "1", name: "Compression Description", retired: "Retired", keyword: "CompressionDescription" });
-	dicom_dictionary.insert(0x00280065, DicomDictElt { vr : "CS", vm: "1-n", name: "Compression Sequence", retired: "Retired", keyword: "CompressionSequence" });
-	dicom_dictionary.insert(0x00280066, DicomDictElt { vr : "AT", vm: "1-n", name: "Compression Step Pointers", retired: "Retired", keyword: "CompressionStepPointers" });
-	dicom_dictionary.insert(0x00280068, DicomDictElt { vr : "US", vm: "1", name: "Repeat Interval", retired: "Retired", keyword: "RepeatInterval" });
-	dicom_dictionary.insert(0x00280069, DicomDictElt { vr : "US", vm: "1", name: "Bits Grouped", retired: "Retired", keyword: "BitsGrouped" });
-	dicom_dictionary.insert(0x00280070, DicomDictElt { vr : "US", vm: "1-n", name: "Perimeter Table", retired: "Retired", keyword: "PerimeterTable" });
-	dicom_dictionary.insert(0x00280071, DicomDictElt { vr : "US or SS", vm: "1", name: "Perimeter Value", retired: "Retired", keyword: "PerimeterValue" });
-	dicom_dictionary.insert(0x00280080, DicomDictElt { vr : "US", vm: "1", name: "Predictor Rows", retired: "Retired", keyword: "PredictorRows" });
-	dicom_dictionary.insert(0x00280081, DicomDictElt { vr : "US", vm: "1", name: "Predictor Columns", retired: "Retired", keyword: "PredictorColumns" });
-	dicom_dictionary.insert(0x00280082, DicomDictElt { vr : "US", vm: "1-n", name: "Predictor Constants", retired: "Retired", keyword: "PredictorConstants" });
-	dicom_dictionary.insert(0x00280090, DicomDictElt { vr : "CS", vm: "1", name: "Blocked Pixels", retired: "Retired", keyword: "BlockedPixels" });
-	dicom_dictionary.insert(0x00280091, DicomDictElt { vr : "US", vm: "1", name: "Block Rows", retired: "Retired", keyword: "BlockRows" });
-	dicom_dictionary.insert(0x00280092, DicomDictElt { vr : "US", vm: "1", name: "Block Columns", retired: "Retired", keyword: "BlockColumns" });
-	dicom_dictionary.insert(0x00280093, DicomDictElt { vr : "US", vm: "1", name: "Row Overlap", retired: "Retired", keyword: "RowOverlap" });
-	dicom_dictionary.insert(0x00280094, DicomDictElt { vr : "US", vm: "1", name: "Column Overlap", retired: "Retired", keyword: "ColumnOverlap" });
-	dicom_dictionary.insert(0x00280100, DicomDictElt { vr : "US", vm: "1", name: "Bits Allocated", retired: "", keyword: "BitsAllocated" });
-	dicom_dictionary.insert(0x00280101, DicomDictElt { vr : "US", vm: "1", name: "Bits Stored", retired: "", keyword: "BitsStored" });
-	dicom_dictionary.insert(0x00280102, DicomDictElt { vr : "US", vm: "1", name: "High Bit", retired: "", keyword: "HighBit" });
-	dicom_dictionary.insert(0x00280103, DicomDictElt { vr : "US", vm: "1", name: "Pixel Representation", retired: "", keyword: "PixelRepresentation" });
-	dicom_dictionary.insert(0x00280104, DicomDictElt { vr : "US or SS", vm: "1", name: "Smallest Valid Pixel Value", retired: "Retired", keyword: "SmallestValidPixelValue" });
-	dicom_dictionary.insert(0x00280105, DicomDictElt { vr : "US or SS", vm: "1", name: "Largest Valid Pixel Value", retired: "Retired", keyword: "LargestValidPixelValue" });
-	dicom_dictionary.insert(0x00280106, DicomDictElt { vr : "US or SS", vm: "1", name: "Smallest Image Pixel Value", retired: "", keyword: "SmallestImagePixelValue" });
-	dicom_dictionary.insert(0x00280107, DicomDictElt { vr : "US or SS", vm: "1", name: "Largest Image Pixel Value", retired: "", keyword: "LargestImagePixelValue" });
-	dicom_dictionary.insert(0x00280108, DicomDictElt { vr : "US or SS", vm: "1", name: "Smallest Pixel Value in Series", retired: "", keyword: "SmallestPixelValueInSeries" });
-	dicom_dictionary.insert(0x00280109, DicomDictElt { vr : "US or SS", vm: "1", name: "Largest Pixel Value in Series", retired: "", keyword: "LargestPixelValueInSeries" });
-	dicom_dictionary.insert(0x00280110, DicomDictElt { vr : "US or SS", vm: "1", name: "Smallest Image Pixel Value in Plane", retired: "Retired", keyword: "SmallestImagePixelValueInPlane" });
-	dicom_dictionary.insert(0x00280111, DicomDictElt { vr : "US or SS", vm: "1", name: "Largest Image Pixel Value in Plane", retired: "Retired", keyword: "LargestImagePixelValueInPlane" });
-	dicom_dictionary.insert(0x00280120, DicomDictElt { vr : "US or SS", vm: "1", name: "Pixel Padding Value", retired: "", keyword: "PixelPaddingValue" });
-	dicom_dictionary.insert(0x00280121, DicomDictElt { vr : "US or SS", vm: "1", name: "Pixel Padding Range Limit", retired: "", keyword: "PixelPaddingRangeLimit" });
-	dicom_dictionary.insert(0x00280122, DicomDictElt { vr : "FL", vm: "1", name: "Float Pixel Padding Value", retired: "", keyword: "FloatPixelPaddingValue" });
-	dicom_dictionary.insert(0x00280123, DicomDictElt { vr : "FD", vm: "1", name: "Double Float Pixel Padding Value", retired: "", keyword: "DoubleFloatPixelPaddingValue" });
-	dicom_dictionary.insert(0x00280124, DicomDictElt { vr : "FL", vm: "1", name: "Float Pixel Padding Range Limit", retired: "", keyword: "FloatPixelPaddingRangeLimit" });
-	dicom_dictionary.insert(0x00280125, DicomDictElt { vr : "FD", vm: "1", name: "Double Float Pixel Padding Range Limit", retired: "", keyword: "DoubleFloatPixelPaddingRangeLimit" });
-	dicom_dictionary.insert(0x00280200, DicomDictElt { vr : "US", vm: "1", name: "Image Location", retired: "Retired", keyword: "ImageLocation" });
-	dicom_dictionary.insert(0x00280300, DicomDictElt { vr : "CS", vm: "1", name: "Quality Control Image", retired: "", keyword: "QualityControlImage" });
-	dicom_dictionary.insert(0x00280301, DicomDictElt { vr : "CS", vm: "1", name: "Burned In Annotation", retired: "", keyword: "BurnedInAnnotation" });
-	dicom_dictionary.insert(0x00280302, DicomDictElt { vr : "CS", vm: "1", name: "Recognizable Visual Features", retired: "", keyword: "RecognizableVisualFeatures" });
-	dicom_dictionary.insert(0x00280303, DicomDictElt { vr : "CS", vm: "1", name: "Longitudinal Temporal Information Modified", retired: "", keyword: "LongitudinalTemporalInformationModified" });
-	dicom_dictionary.insert(0x00280304, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Color Palette Instance UID", retired: "", keyword: "ReferencedColorPaletteInstanceUID" });
-	dicom_dictionary.insert(0x00280400, DicomDictElt { vr : "LO", vm: "1", name: "Transform Label", retired: "Retired", keyword: "TransformLabel" });
-	dicom_dictionary.insert(0x00280401, DicomDictElt { vr : "LO", vm: "1", name: "Transform Version Number", retired: "Retired", keyword: "TransformVersionNumber" });
-	dicom_dictionary.insert(0x00280402, DicomDictElt { vr : "US", vm: "1", name: "Number of Transform Steps", retired: "Retired", keyword: "NumberOfTransformSteps" });
-	dicom_dictionary.insert(0x00280403, DicomDictElt { vr : "LO", vm: "1-n", name: "Sequence of Compressed Data", retired: "Retired", keyword: "SequenceOfCompressedData" });
-	dicom_dictionary.insert(0x00280404, DicomDictElt { vr : "AT", vm: "1-n", name: "Details of Coefficients", retired: "Retired", keyword: "DetailsOfCoefficients" });
-	dicom_dictionary.insert(0x00280700, DicomDictElt { vr : "LO", vm: "1", name: "DCT Label", retired: "Retired", keyword: "DCTLabel" });
-	dicom_dictionary.insert(0x00280701, DicomDictElt { vr : "CS", vm: "1-n", name: "Data Block Description", retired: "Retired", keyword: "DataBlockDescription" });
-	dicom_dictionary.insert(0x00280702, DicomDictElt { vr : "AT", vm: "1-n", name: "Data Block", retired: "Retired", keyword: "DataBlock" });
-	dicom_dictionary.insert(0x00280710, DicomDictElt { vr : "US", vm: "1", name: "Normalization Factor Format", retired: "Retired", keyword: "NormalizationFactorFormat" });
-	dicom_dictionary.insert(0x00280720, DicomDictElt { vr : "US", vm: "1", name: "Zonal Map Number Format", retired: "Retired", keyword: "ZonalMapNumberFormat" });
-	dicom_dictionary.insert(0x00280721, DicomDictElt { vr : "AT", vm: "1-n", name: "Zonal Map Location", retired: "Retired", keyword: "ZonalMapLocation" });
-	dicom_dictionary.insert(0x00280722, DicomDictElt { vr : "US", vm: "1", name: "Zonal Map Format", retired: "Retired", keyword: "ZonalMapFormat" });
-	dicom_dictionary.insert(0x00280730, DicomDictElt { vr : "US", vm: "1", name: "Adaptive Map Format", retired: "Retired", keyword: "AdaptiveMapFormat" });
-	dicom_dictionary.insert(0x00280740, DicomDictElt { vr : "US", vm: "1", name: "Code Number Format", retired: "Retired", keyword: "CodeNumberFormat" });
-	dicom_dictionary.insert(0x00280A02, DicomDictElt { vr : "CS", vm: "1", name: "Pixel Spacing Calibration Type", retired: "", keyword: "PixelSpacingCalibrationType" });
-	dicom_dictionary.insert(0x00280A04, DicomDictElt { vr : "LO", vm: "1", name: "Pixel Spacing Calibration Description", retired: "", keyword: "PixelSpacingCalibrationDescription" });
-	dicom_dictionary.insert(0x00281040, DicomDictElt { vr : "CS", vm: "1", name: "Pixel Intensity Relationship", retired: "", keyword: "PixelIntensityRelationship" });
-	dicom_dictionary.insert(0x00281041, DicomDictElt { vr : "SS", vm: "1", name: "Pixel Intensity Relationship Sign", retired: "", keyword: "PixelIntensityRelationshipSign" });
-	dicom_dictionary.insert(0x00281050, DicomDictElt { vr : "DS", vm: "1-n", name: "Window Center", retired: "", keyword: "WindowCenter" });
-	dicom_dictionary.insert(0x00281051, DicomDictElt { vr : "DS", vm: "1-n", name: "Window Width", retired: "", keyword: "WindowWidth" });
-	dicom_dictionary.insert(0x00281052, DicomDictElt { vr : "DS", vm: "1", name: "Rescale Intercept", retired: "", keyword: "RescaleIntercept" });
-	dicom_dictionary.insert(0x00281053, DicomDictElt { vr : "DS", vm: "1", name: "Rescale Slope", retired: "", keyword: "RescaleSlope" });
-	dicom_dictionary.insert(0x00281054, DicomDictElt { vr : "LO", vm: "1", name: "Rescale Type", retired: "", keyword: "RescaleType" });
-	dicom_dictionary.insert(0x00281055, DicomDictElt { vr : "LO", vm: "1-n", name: "Window Center & Width Explanation", retired: "", keyword: "WindowCenterWidthExplanation" });
-	dicom_dictionary.insert(0x00281056, DicomDictElt { vr : "CS", vm: "1", name: "VOI LUT Function", retired: "", keyword: "VOILUTFunction" });
-	dicom_dictionary.insert(0x00281080, DicomDictElt { vr : "CS", vm: "1", name: "Gray Scale", retired: "Retired", keyword: "GrayScale" });
-	dicom_dictionary.insert(0x00281090, DicomDictElt { vr : "CS", vm: "1", name: "Recommended Viewing Mode", retired: "", keyword: "RecommendedViewingMode" });
-	dicom_dictionary.insert(0x00281100, DicomDictElt { vr : "US or SS", vm: "3", name: "Gray Lookup Table Descriptor", retired: "Retired", keyword: "GrayLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281101, DicomDictElt { vr : "US or SS", vm: "3", name: "Red Palette Color Lookup Table Descriptor", retired: "", keyword: "RedPaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281102, DicomDictElt { vr : "US or SS", vm: "3", name: "Green Palette Color Lookup Table Descriptor", retired: "", keyword: "GreenPaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281103, DicomDictElt { vr : "US or SS", vm: "3", name: "Blue Palette Color Lookup Table Descriptor", retired: "", keyword: "BluePaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281104, DicomDictElt { vr : "US", vm: "3", name: "Alpha Palette Color Lookup Table Descriptor", retired: "", keyword: "AlphaPaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281111, DicomDictElt { vr : "US or SS", vm: "4", name: "Large Red Palette Color Lookup Table Descriptor", retired: "Retired", keyword: "LargeRedPaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281112, DicomDictElt { vr : "US or SS", vm: "4", name: "Large Green Palette Color Lookup Table Descriptor", retired: "Retired", keyword: "LargeGreenPaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281113, DicomDictElt { vr : "US or SS", vm: "4", name: "Large Blue Palette Color Lookup Table Descriptor", retired: "Retired", keyword: "LargeBluePaletteColorLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281199, DicomDictElt { vr : "UI", vm: "1", name: "Palette Color Lookup Table UID", retired: "", keyword: "PaletteColorLookupTableUID" });
-	dicom_dictionary.insert(0x00281200, DicomDictElt { vr : "US or SS or OW", vm: "1-n 1", name: "Gray Lookup Table Data", retired: "Retired", keyword: "GrayLookupTableData" });
-	dicom_dictionary.insert(0x00281201, DicomDictElt { vr : "OW", vm: "1", name: "Red Palette Color Lookup Table Data", retired: "", keyword: "RedPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281202, DicomDictElt { vr : "OW", vm: "1", name: "Green Palette Color Lookup Table Data", retired: "", keyword: "GreenPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281203, DicomDictElt { vr : "OW", vm: "1", name: "Blue Palette Color Lookup Table Data", retired: "", keyword: "BluePaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281204, DicomDictElt { vr : "OW", vm: "1", name: "Alpha Palette Color Lookup Table Data", retired: "", keyword: "AlphaPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281211, DicomDictElt { vr : "OW", vm: "1", name: "Large Red Palette Color Lookup Table Data", retired: "Retired", keyword: "LargeRedPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281212, DicomDictElt { vr : "OW", vm: "1", name: "Large Green Palette Color Lookup Table Data", retired: "Retired", keyword: "LargeGreenPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281213, DicomDictElt { vr : "OW", vm: "1", name: "Large Blue Palette Color Lookup Table Data", retired: "Retired", keyword: "LargeBluePaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281214, DicomDictElt { vr : "UI", vm: "1", name: "Large Palette Color Lookup Table UID", retired: "Retired", keyword: "LargePaletteColorLookupTableUID" });
-	dicom_dictionary.insert(0x00281221, DicomDictElt { vr : "OW", vm: "1", name: "Segmented Red Palette Color Lookup Table Data", retired: "", keyword: "SegmentedRedPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281222, DicomDictElt { vr : "OW", vm: "1", name: "Segmented Green Palette Color Lookup Table Data", retired: "", keyword: "SegmentedGreenPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281223, DicomDictElt { vr : "OW", vm: "1", name: "Segmented Blue Palette Color Lookup Table Data", retired: "", keyword: "SegmentedBluePaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281224, DicomDictElt { vr : "OW", vm: "1", name: "Segmented Alpha Palette Color Lookup Table Data", retired: "", keyword: "SegmentedAlphaPaletteColorLookupTableData" });
-	dicom_dictionary.insert(0x00281230, DicomDictElt { vr : "SQ", vm: "1", name: "Stored Value Color Range Sequence", retired: "", keyword: "StoredValueColorRangeSequence" });
-	dicom_dictionary.insert(0x00281231, DicomDictElt { vr : "FD", vm: "1", name: "Minimum Stored Value Mapped", retired: "", keyword: "MinimumStoredValueMapped" });
-	dicom_dictionary.insert(0x00281232, DicomDictElt { vr : "FD", vm: "1", name: "Maximum Stored Value Mapped", retired: "", keyword: "MaximumStoredValueMapped" });
-	dicom_dictionary.insert(0x00281300, DicomDictElt { vr : "CS", vm: "1", name: "Breast Implant Present", retired: "", keyword: "BreastImplantPresent" });
-	dicom_dictionary.insert(0x00281350, DicomDictElt { vr : "CS", vm: "1", name: "Partial View", retired: "", keyword: "PartialView" });
-	dicom_dictionary.insert(0x00281351, DicomDictElt { vr : "ST", vm: "1", name: "Partial View Description", retired: "", keyword: "PartialViewDescription" });
-	dicom_dictionary.insert(0x00281352, DicomDictElt { vr : "SQ", vm: "1", name: "Partial View Code Sequence", retired: "", keyword: "PartialViewCodeSequence" });
-	dicom_dictionary.insert(0x0028135A, DicomDictElt { vr : "CS", vm: "1", name: "Spatial Locations Preserved", retired: "", keyword: "SpatialLocationsPreserved" });
-	dicom_dictionary.insert(0x00281401, DicomDictElt { vr : "SQ", vm: "1", name: "Data Frame Assignment Sequence", retired: "", keyword: "DataFrameAssignmentSequence" });
-	dicom_dictionary.insert(0x00281402, DicomDictElt { vr : "CS", vm: "1", name: "Data Path Assignment", retired: "", keyword: "DataPathAssignment" });
-	dicom_dictionary.insert(0x00281403, DicomDictElt { vr : "US", vm: "1", name: "Bits Mapped to Color Lookup Table", retired: "", keyword: "BitsMappedToColorLookupTable" });
-	dicom_dictionary.insert(0x00281404, DicomDictElt { vr : "SQ", vm: "1", name: "Blending LUT 1 Sequence", retired: "", keyword: "BlendingLUT1Sequence" });
-	dicom_dictionary.insert(0x00281405, DicomDictElt { vr : "CS", vm: "1", name: "Blending LUT 1 Transfer Function", retired: "", keyword: "BlendingLUT1TransferFunction" });
-	dicom_dictionary.insert(0x00281406, DicomDictElt { vr : "FD", vm: "1", name: "Blending Weight Constant", retired: "", keyword: "BlendingWeightConstant" });
-	dicom_dictionary.insert(0x00281407, DicomDictElt { vr : "US", vm: "3", name: "Blending Lookup Table Descriptor", retired: "", keyword: "BlendingLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00281408, DicomDictElt { vr : "OW", vm: "1", name: "Blending Lookup Table Data", retired: "", keyword: "BlendingLookupTableData" });
-	dicom_dictionary.insert(0x0028140B, DicomDictElt { vr : "SQ", vm: "1", name: "Enhanced Palette Color Lookup Table Sequence", retired: "", keyword: "EnhancedPaletteColorLookupTableSequence" });
-	dicom_dictionary.insert(0x0028140C, DicomDictElt { vr : "SQ", vm: "1", name: "Blending LUT 2 Sequence", retired: "", keyword: "BlendingLUT2Sequence" });
-	dicom_dictionary.insert(0x0028140D, DicomDictElt { vr : "CS", vm: "1", name: "Blending LUT 2 Transfer Function", retired: "", keyword: "BlendingLUT2TransferFunction" });
-	dicom_dictionary.insert(0x0028140E, DicomDictElt { vr : "CS", vm: "1", name: "Data Path ID", retired: "", keyword: "DataPathID" });
-	dicom_dictionary.insert(0x0028140F, DicomDictElt { vr : "CS", vm: "1", name: "RGB LUT Transfer Function", retired: "", keyword: "RGBLUTTransferFunction" });
-	dicom_dictionary.insert(0x00281410, DicomDictElt { vr : "CS", vm: "1", name: "Alpha LUT Transfer Function", retired: "", keyword: "AlphaLUTTransferFunction" });
-	dicom_dictionary.insert(0x00282000, DicomDictElt { vr : "OB", vm: "1", name: "ICC Profile", retired: "", keyword: "ICCProfile" });
-	dicom_dictionary.insert(0x00282002, DicomDictElt { vr : "CS", vm: "1", name: "Color Space", retired: "", keyword: "ColorSpace" });
-	dicom_dictionary.insert(0x00282110, DicomDictElt { vr : "CS", vm: "1", name: "Lossy Image Compression", retired: "", keyword: "LossyImageCompression" });
-	dicom_dictionary.insert(0x00282112, DicomDictElt { vr : "DS", vm: "1-n", name: "Lossy Image Compression Ratio", retired: "", keyword: "LossyImageCompressionRatio" });
-	dicom_dictionary.insert(0x00282114, DicomDictElt { vr : "CS", vm: "1-n", name: "Lossy Image Compression Method", retired: "", keyword: "LossyImageCompressionMethod" });
-	dicom_dictionary.insert(0x00283000, DicomDictElt { vr : "SQ", vm: "1", name: "Modality LUT Sequence", retired: "", keyword: "ModalityLUTSequence" });
-	dicom_dictionary.insert(0x00283002, DicomDictElt { vr : "US or SS", vm: "3", name: "LUT Descriptor", retired: "", keyword: "LUTDescriptor" });
-	dicom_dictionary.insert(0x00283003, DicomDictElt { vr : "LO", vm: "1", name: "LUT Explanation", retired: "", keyword: "LUTExplanation" });
-	dicom_dictionary.insert(0x00283004, DicomDictElt { vr : "LO", vm: "1", name: "Modality LUT Type", retired: "", keyword: "ModalityLUTType" });
-	dicom_dictionary.insert(0x00283006, DicomDictElt { vr : "US or OW", vm: "1-n 1", name: "LUT Data", retired: "", keyword: "LUTData" });
-	dicom_dictionary.insert(0x00283010, DicomDictElt { vr : "SQ", vm: "1", name: "VOI LUT Sequence", retired: "", keyword: "VOILUTSequence" });
-	dicom_dictionary.insert(0x00283110, DicomDictElt { vr : "SQ", vm: "1", name: "Softcopy VOI LUT Sequence", retired: "", keyword: "SoftcopyVOILUTSequence" });
-	dicom_dictionary.insert(0x00284000, DicomDictElt { vr : "LT", vm: "1", name: "Image Presentation Comments", retired: "Retired", keyword: "ImagePresentationComments" });
-	dicom_dictionary.insert(0x00285000, DicomDictElt { vr : "SQ", vm: "1", name: "Bi-Plane Acquisition Sequence", retired: "Retired", keyword: "BiPlaneAcquisitionSequence" });
-	dicom_dictionary.insert(0x00286010, DicomDictElt { vr : "US", vm: "1", name: "Representative Frame Number", retired: "", keyword: "RepresentativeFrameNumber" });
-	dicom_dictionary.insert(0x00286020, DicomDictElt { vr : "US", vm: "1-n", name: "Frame Numbers of Interest (FOI)", retired: "", keyword: "FrameNumbersOfInterest" });
-	dicom_dictionary.insert(0x00286022, DicomDictElt { vr : "LO", vm: "1-n", name: "Frame of Interest Description", retired: "", keyword: "FrameOfInterestDescription" });
-	dicom_dictionary.insert(0x00286023, DicomDictElt { vr : "CS", vm: "1-n", name: "Frame of Interest Type", retired: "", keyword: "FrameOfInterestType" });
-	dicom_dictionary.insert(0x00286030, DicomDictElt { vr : "US", vm: "1-n", name: "Mask Pointer(s)", retired: "Retired", keyword: "MaskPointers" });
-	dicom_dictionary.insert(0x00286040, DicomDictElt { vr : "US", vm: "1-n", name: "R Wave Pointer", retired: "", keyword: "RWavePointer" });
-	dicom_dictionary.insert(0x00286100, DicomDictElt { vr : "SQ", vm: "1", name: "Mask Subtraction Sequence", retired: "", keyword: "MaskSubtractionSequence" });
-	dicom_dictionary.insert(0x00286101, DicomDictElt { vr : "CS", vm: "1", name: "Mask Operation", retired: "", keyword: "MaskOperation" });
-	dicom_dictionary.insert(0x00286102, DicomDictElt { vr : "US", vm: "2-2n", name: "Applicable Frame Range", retired: "", keyword: "ApplicableFrameRange" });
-	dicom_dictionary.insert(0x00286110, DicomDictElt { vr : "US", vm: "1-n", name: "Mask Frame Numbers", retired: "", keyword: "MaskFrameNumbers" });
-	dicom_dictionary.insert(0x00286112, DicomDictElt { vr : "US", vm: "1", name: "Contrast Frame Averaging", retired: "", keyword: "ContrastFrameAveraging" });
-	dicom_dictionary.insert(0x00286114, DicomDictElt { vr : "FL", vm: "2", name: "Mask Sub-pixel Shift", retired: "", keyword: "MaskSubPixelShift" });
-	dicom_dictionary.insert(0x00286120, DicomDictElt { vr : "SS", vm: "1", name: "TID Offset", retired: "", keyword: "TIDOffset" });
-	dicom_dictionary.insert(0x00286190, DicomDictElt { vr : "ST", vm: "1", name: "Mask Operation Explanation", retired: "", keyword: "MaskOperationExplanation" });
-	dicom_dictionary.insert(0x00287000, DicomDictElt { vr : "SQ", vm: "1", name: "Equipment Administrator Sequence", retired: "", keyword: "EquipmentAdministratorSequence" });
-	dicom_dictionary.insert(0x00287001, DicomDictElt { vr : "US", vm: "1", name: "Number of Display Subsystems", retired: "", keyword: "NumberOfDisplaySubsystems" });
-	dicom_dictionary.insert(0x00287002, DicomDictElt { vr : "US", vm: "1", name: "Current Configuration ID", retired: "", keyword: "CurrentConfigurationID" });
-	dicom_dictionary.insert(0x00287003, DicomDictElt { vr : "US", vm: "1", name: "Display Subsystem ID", retired: "", keyword: "DisplaySubsystemID" });
-	dicom_dictionary.insert(0x00287004, DicomDictElt { vr : "SH", vm: "1", name: "Display Subsystem Name", retired: "", keyword: "DisplaySubsystemName" });
-	dicom_dictionary.insert(0x00287005, DicomDictElt { vr : "LO", vm: "1", name: "Display Subsystem Description", retired: "", keyword: "DisplaySubsystemDescription" });
-	dicom_dictionary.insert(0x00287006, DicomDictElt { vr : "CS", vm: "1", name: "System Status", retired: "", keyword: "SystemStatus" });
-	dicom_dictionary.insert(0x00287007, DicomDictElt { vr : "LO", vm: "1", name: "System Status Comment", retired: "", keyword: "SystemStatusComment" });
-	dicom_dictionary.insert(0x00287008, DicomDictElt { vr : "SQ", vm: "1", name: "Target Luminance Characteristics Sequence", retired: "", keyword: "TargetLuminanceCharacteristicsSequence" });
-	dicom_dictionary.insert(0x00287009, DicomDictElt { vr : "US", vm: "1", name: "Luminance Characteristics ID", retired: "", keyword: "LuminanceCharacteristicsID" });
-	dicom_dictionary.insert(0x0028700A, DicomDictElt { vr : "SQ", vm: "1", name: "Display Subsystem Configuration Sequence", retired: "", keyword: "DisplaySubsystemConfigurationSequence" });
-	dicom_dictionary.insert(0x0028700B, DicomDictElt { vr : "US", vm: "1", name: "Configuration ID", retired: "", keyword: "ConfigurationID" });
-	dicom_dictionary.insert(0x0028700C, DicomDictElt { vr : "SH", vm: "1", name: "Configuration Name", retired: "", keyword: "ConfigurationName" });
-	dicom_dictionary.insert(0x0028700D, DicomDictElt { vr : "LO", vm: "1", name: "Configuration Description", retired: "", keyword: "ConfigurationDescription" });
-	dicom_dictionary.insert(0x0028700E, DicomDictElt { vr : "US", vm: "1", name: "Referenced Target Luminance Characteristics ID", retired: "", keyword: "ReferencedTargetLuminanceCharacteristicsID" });
-	dicom_dictionary.insert(0x0028700F, DicomDictElt { vr : "SQ", vm: "1", name: "QA Results Sequence", retired: "", keyword: "QAResultsSequence" });
-	dicom_dictionary.insert(0x00287010, DicomDictElt { vr : "SQ", vm: "1", name: "Display Subsystem QA Results Sequence", retired: "", keyword: "DisplaySubsystemQAResultsSequence" });
-	dicom_dictionary.insert(0x00287011, DicomDictElt { vr : "SQ", vm: "1", name: "Configuration QA Results Sequence", retired: "", keyword: "ConfigurationQAResultsSequence" });
-	dicom_dictionary.insert(0x00287012, DicomDictElt { vr : "SQ", vm: "1", name: "Measurement Equipment Sequence", retired: "", keyword: "MeasurementEquipmentSequence" });
-	dicom_dictionary.insert(0x00287013, DicomDictElt { vr : "CS", vm: "1-n", name: "Measurement Functions", retired: "", keyword: "MeasurementFunctions" });
-	dicom_dictionary.insert(0x00287014, DicomDictElt { vr : "CS", vm: "1", name: "Measurement Equipment Type", retired: "", keyword: "MeasurementEquipmentType" });
-	dicom_dictionary.insert(0x00287015, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Evaluation Result Sequence", retired: "", keyword: "VisualEvaluationResultSequence" });
-	dicom_dictionary.insert(0x00287016, DicomDictElt { vr : "SQ", vm: "1", name: "Display Calibration Result Sequence", retired: "", keyword: "DisplayCalibrationResultSequence" });
-	dicom_dictionary.insert(0x00287017, DicomDictElt { vr : "US", vm: "1", name: "DDL Value", retired: "", keyword: "DDLValue" });
-	dicom_dictionary.insert(0x00287018, DicomDictElt { vr : "FL", vm: "2", name: "CIExy White Point", retired: "", keyword: "CIExyWhitePoint" });
-	dicom_dictionary.insert(0x00287019, DicomDictElt { vr : "CS", vm: "1", name: "Display Function Type", retired: "", keyword: "DisplayFunctionType" });
-	dicom_dictionary.insert(0x0028701A, DicomDictElt { vr : "FL", vm: "1", name: "Gamma Value", retired: "", keyword: "GammaValue" });
-	dicom_dictionary.insert(0x0028701B, DicomDictElt { vr : "US", vm: "1", name: "Number of Luminance Points", retired: "", keyword: "NumberOfLuminancePoints" });
-	dicom_dictionary.insert(0x0028701C, DicomDictElt { vr : "SQ", vm: "1", name: "Luminance Response Sequence", retired: "", keyword: "LuminanceResponseSequence" });
-	dicom_dictionary.insert(0x0028701D, DicomDictElt { vr : "FL", vm: "1", name: "Target Minimum Luminance", retired: "", keyword: "TargetMinimumLuminance" });
-	dicom_dictionary.insert(0x0028701E, DicomDictElt { vr : "FL", vm: "1", name: "Target Maximum Luminance", retired: "", keyword: "TargetMaximumLuminance" });
-	dicom_dictionary.insert(0x0028701F, DicomDictElt { vr : "FL", vm: "1", name: "Luminance Value", retired: "", keyword: "LuminanceValue" });
-	dicom_dictionary.insert(0x00287020, DicomDictElt { vr : "LO", vm: "1", name: "Luminance Response Description", retired: "", keyword: "LuminanceResponseDescription" });
-	dicom_dictionary.insert(0x00287021, DicomDictElt { vr : "CS", vm: "1", name: "White Point Flag", retired: "", keyword: "WhitePointFlag" });
-	dicom_dictionary.insert(0x00287022, DicomDictElt { vr : "SQ", vm: "1", name: "Display Device Type Code Sequence", retired: "", keyword: "DisplayDeviceTypeCodeSequence" });
-	dicom_dictionary.insert(0x00287023, DicomDictElt { vr : "SQ", vm: "1", name: "Display Subsystem Sequence", retired: "", keyword: "DisplaySubsystemSequence" });
-	dicom_dictionary.insert(0x00287024, DicomDictElt { vr : "SQ", vm: "1", name: "Luminance Result Sequence", retired: "", keyword: "LuminanceResultSequence" });
-	dicom_dictionary.insert(0x00287025, DicomDictElt { vr : "CS", vm: "1", name: "Ambient Light Value Source", retired: "", keyword: "AmbientLightValueSource" });
-	dicom_dictionary.insert(0x00287026, DicomDictElt { vr : "CS", vm: "1-n", name: "Measured Characteristics", retired: "", keyword: "MeasuredCharacteristics" });
-	dicom_dictionary.insert(0x00287027, DicomDictElt { vr : "SQ", vm: "1", name: "Luminance Uniformity Result Sequence", retired: "", keyword: "LuminanceUniformityResultSequence" });
-	dicom_dictionary.insert(0x00287028, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Evaluation Test Sequence", retired: "", keyword: "VisualEvaluationTestSequence" });
-	dicom_dictionary.insert(0x00287029, DicomDictElt { vr : "CS", vm: "1", name: "Test Result", retired: "", keyword: "TestResult" });
-	dicom_dictionary.insert(0x0028702A, DicomDictElt { vr : "LO", vm: "1", name: "Test Result Comment", retired: "", keyword: "TestResultComment" });
-	dicom_dictionary.insert(0x0028702B, DicomDictElt { vr : "CS", vm: "1", name: "Test Image Validation", retired: "", keyword: "TestImageValidation" });
-	dicom_dictionary.insert(0x0028702C, DicomDictElt { vr : "SQ", vm: "1", name: "Test Pattern Code Sequence", retired: "", keyword: "TestPatternCodeSequence" });
-	dicom_dictionary.insert(0x0028702D, DicomDictElt { vr : "SQ", vm: "1", name: "Measurement Pattern Code Sequence", retired: "", keyword: "MeasurementPatternCodeSequence" });
-	dicom_dictionary.insert(0x0028702E, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Evaluation Method Code Sequence", retired: "", keyword: "VisualEvaluationMethodCodeSequence" });
-	dicom_dictionary.insert(0x00287FE0, DicomDictElt { vr : "UR", vm: "1", name: "Pixel Data Provider URL", retired: "", keyword: "PixelDataProviderURL" });
-	dicom_dictionary.insert(0x00289001, DicomDictElt { vr : "UL", vm: "1", name: "Data Point Rows", retired: "", keyword: "DataPointRows" });
-	dicom_dictionary.insert(0x00289002, DicomDictElt { vr : "UL", vm: "1", name: "Data Point Columns", retired: "", keyword: "DataPointColumns" });
-	dicom_dictionary.insert(0x00289003, DicomDictElt { vr : "CS", vm: "1", name: "Signal Domain Columns", retired: "", keyword: "SignalDomainColumns" });
-	dicom_dictionary.insert(0x00289099, DicomDictElt { vr : "US", vm: "1", name: "Largest Monochrome Pixel Value", retired: "Retired", keyword: "LargestMonochromePixelValue" });
-	dicom_dictionary.insert(0x00289108, DicomDictElt { vr : "CS", vm: "1", name: "Data Representation", retired: "", keyword: "DataRepresentation" });
-	dicom_dictionary.insert(0x00289110, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Measures Sequence", retired: "", keyword: "PixelMeasuresSequence" });
-	dicom_dictionary.insert(0x00289132, DicomDictElt { vr : "SQ", vm: "1", name: "Frame VOI LUT Sequence", retired: "", keyword: "FrameVOILUTSequence" });
-	dicom_dictionary.insert(0x00289145, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Value Transformation Sequence", retired: "", keyword: "PixelValueTransformationSequence" });
-	dicom_dictionary.insert(0x00289235, DicomDictElt { vr : "CS", vm: "1", name: "Signal Domain Rows", retired: "", keyword: "SignalDomainRows" });
-	dicom_dictionary.insert(0x00289411, DicomDictElt { vr : "FL", vm: "1", name: "Display Filter Percentage", retired: "", keyword: "DisplayFilterPercentage" });
-	dicom_dictionary.insert(0x00289415, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Pixel Shift Sequence", retired: "", keyword: "FramePixelShiftSequence" });
-	dicom_dictionary.insert(0x00289416, DicomDictElt { vr : "US", vm: "1", name: "Subtraction Item ID", retired: "", keyword: "SubtractionItemID" });
-	dicom_dictionary.insert(0x00289422, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Intensity Relationship LUT Sequence", retired: "", keyword: "PixelIntensityRelationshipLUTSequence" });
-	dicom_dictionary.insert(0x00289443, DicomDictElt { vr : "SQ", vm: "1", name: "Frame Pixel Data Properties Sequence", retired: "", keyword: "FramePixelDataPropertiesSequence" });
-	dicom_dictionary.insert(0x00289444, DicomDictElt { vr : "CS", vm: "1", name: "Geometrical Properties", retired: "", keyword: "GeometricalProperties" });
-	dicom_dictionary.insert(0x00289445, DicomDictElt { vr : "FL", vm: "1", name: "Geometric Maximum Distortion", retired: "", keyword: "GeometricMaximumDistortion" });
-	dicom_dictionary.insert(0x00289446, DicomDictElt { vr : "CS", vm: "1-n", name: "Image Processing Applied", retired: "", keyword: "ImageProcessingApplied" });
-	dicom_dictionary.insert(0x00289454, DicomDictElt { vr : "CS", vm: "1", name: "Mask Selection Mode", retired: "", keyword: "MaskSelectionMode" });
-	dicom_dictionary.insert(0x00289474, DicomDictElt { vr : "CS", vm: "1", name: "LUT Function", retired: "", keyword: "LUTFunction" });
-	dicom_dictionary.insert(0x00289478, DicomDictElt { vr : "FL", vm: "1", name: "Mask Visibility Percentage", retired: "", keyword: "MaskVisibilityPercentage" });
-	dicom_dictionary.insert(0x00289501, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Shift Sequence", retired: "", keyword: "PixelShiftSequence" });
-	dicom_dictionary.insert(0x00289502, DicomDictElt { vr : "SQ", vm: "1", name: "Region Pixel Shift Sequence", retired: "", keyword: "RegionPixelShiftSequence" });
-	dicom_dictionary.insert(0x00289503, DicomDictElt { vr : "SS", vm: "2-2n", name: "Vertices of the Region", retired: "", keyword: "VerticesOfTheRegion" });
-	dicom_dictionary.insert(0x00289505, DicomDictElt { vr : "SQ", vm: "1", name: "Multi-frame Presentation Sequence", retired: "", keyword: "MultiFramePresentationSequence" });
-	dicom_dictionary.insert(0x00289506, DicomDictElt { vr : "US", vm: "2-2n", name: "Pixel Shift Frame Range", retired: "", keyword: "PixelShiftFrameRange" });
-	dicom_dictionary.insert(0x00289507, DicomDictElt { vr : "US", vm: "2-2n", name: "LUT Frame Range", retired: "", keyword: "LUTFrameRange" });
-	dicom_dictionary.insert(0x00289520, DicomDictElt { vr : "DS", vm: "16", name: "Image to Equipment Mapping Matrix", retired: "", keyword: "ImageToEquipmentMappingMatrix" });
-	dicom_dictionary.insert(0x00289537, DicomDictElt { vr : "CS", vm: "1", name: "Equipment Coordinate System Identification", retired: "", keyword: "EquipmentCoordinateSystemIdentification" });
-	dicom_dictionary.insert(0x0032000A, DicomDictElt { vr : "CS", vm: "1", name: "Study Status ID", retired: "Retired", keyword: "StudyStatusID" });
-	dicom_dictionary.insert(0x0032000C, DicomDictElt { vr : "CS", vm: "1", name: "Study Priority ID", retired: "Retired", keyword: "StudyPriorityID" });
-	dicom_dictionary.insert(0x00320012, DicomDictElt { vr : "LO", vm: "1", name: "Study ID Issuer", retired: "Retired", keyword: "StudyIDIssuer" });
-	dicom_dictionary.insert(0x00320032, DicomDictElt { vr : "DA", vm: "1", name: "Study Verified Date", retired: "Retired", keyword: "StudyVerifiedDate" });
-	dicom_dictionary.insert(0x00320033, DicomDictElt { vr : "TM", vm: "1", name: "Study Verified Time", retired: "Retired", keyword: "StudyVerifiedTime" });
-	dicom_dictionary.insert(0x00320034, DicomDictElt { vr : "DA", vm: "1", name: "Study Read Date", retired: "Retired", keyword: "StudyReadDate" });
-	dicom_dictionary.insert(0x00320035, DicomDictElt { vr : "TM", vm: "1", name: "Study Read Time", retired: "Retired", keyword: "StudyReadTime" });
-	dicom_dictionary.insert(0x00321000, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Study Start Date", retired: "Retired", keyword: "ScheduledStudyStartDate" });
-	dicom_dictionary.insert(0x00321001, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Study Start Time", retired: "Retired", keyword: "ScheduledStudyStartTime" });
-	dicom_dictionary.insert(0x00321010, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Study Stop Date", retired: "Retired", keyword: "ScheduledStudyStopDate" });
-	dicom_dictionary.insert(0x00321011, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Study Stop Time", retired: "Retired", keyword: "ScheduledStudyStopTime" });
-	dicom_dictionary.insert(0x00321020, DicomDictElt { vr : "LO", vm: "1", name: "Scheduled Study Location", retired: "Retired", keyword: "ScheduledStudyLocation" });
-	dicom_dictionary.insert(0x00321021, DicomDictElt { vr : "AE", vm: "1-n", name: "Scheduled Study Location AE Title", retired: "Retired", keyword: "ScheduledStudyLocationAETitle" });
-	dicom_dictionary.insert(0x00321030, DicomDictElt { vr : "LO", vm: "1", name: "Reason for Study", retired: "Retired", keyword: "ReasonForStudy" });
-	dicom_dictionary.insert(0x00321031, DicomDictElt { vr : "SQ", vm: "1", name: "Requesting Physician Identification Sequence", retired: "", keyword: "RequestingPhysicianIdentificationSequence" });
-	dicom_dictionary.insert(0x00321032, DicomDictElt { vr : "PN", vm: "1", name: "Requesting Physician", retired: "", keyword: "RequestingPhysician" });
-	dicom_dictionary.insert(0x00321033, DicomDictElt { vr : "LO", vm: "1", name: "Requesting Service", retired: "", keyword: "RequestingService" });
-	dicom_dictionary.insert(0x00321034, DicomDictElt { vr : "SQ", vm: "1", name: "Requesting Service Code Sequence", retired: "", keyword: "RequestingServiceCodeSequence" });
-	dicom_dictionary.insert(0x00321040, DicomDictElt { vr : "DA", vm: "1", name: "Study Arrival Date", retired: "Retired", keyword: "StudyArrivalDate" });
-	dicom_dictionary.insert(0x00321041, DicomDictElt { vr : "TM", vm: "1", name: "Study Arrival Time", retired: "Retired", keyword: "StudyArrivalTime" });
-	dicom_dictionary.insert(0x00321050, DicomDictElt { vr : "DA", vm: "1", name: "Study Completion Date", retired: "Retired", keyword: "StudyCompletionDate" });
-	dicom_dictionary.insert(0x00321051, DicomDictElt { vr : "TM", vm: "1", name: "Study Completion Time", retired: "Retired", keyword: "StudyCompletionTime" });
-	dicom_dictionary.insert(0x00321055, DicomDictElt { vr : "CS", vm: "1", name: "Study Component Status ID", retired: "Retired", keyword: "StudyComponentStatusID" });
-	dicom_dictionary.insert(0x00321060, DicomDictElt { vr : "LO", vm: "1", name: "Requested Procedure Description", retired: "", keyword: "RequestedProcedureDescription" });
-	dicom_dictionary.insert(0x00321064, DicomDictElt { vr : "SQ", vm: "1", name: "Requested Procedure Code Sequence", retired: "", keyword: "RequestedProcedureCodeSequence" });
-	dicom_dictionary.insert(0x00321070, DicomDictElt { vr : "LO", vm: "1", name: "Requested Contrast Agent", retired: "", keyword: "RequestedContrastAgent" });
-	dicom_dictionary.insert(0x00324000, DicomDictElt { vr : "LT", vm: "1", name: "Study Comments", retired: "Retired", keyword: "StudyComments" });
-	dicom_dictionary.insert(0x00380004, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Patient Alias Sequence", retired: "", keyword: "ReferencedPatientAliasSequence" });
-	dicom_dictionary.insert(0x00380008, DicomDictElt { vr : "CS", vm: "1", name: "Visit Status ID", retired: "", keyword: "VisitStatusID" });
-	dicom_dictionary.insert(0x00380010, DicomDictElt { vr : "LO", vm: "1", name: "Admission ID", retired: "", keyword: "AdmissionID" });
-	dicom_dictionary.insert(0x00380011, DicomDictElt { vr : "LO", vm: "1", name: "Issuer of Admission ID", retired: "Retired", keyword: "IssuerOfAdmissionID" });
-	dicom_dictionary.insert(0x00380014, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of Admission ID Sequence", retired: "", keyword: "IssuerOfAdmissionIDSequence" });
-	dicom_dictionary.insert(0x00380016, DicomDictElt { vr : "LO", vm: "1", name: "Route of Admissions", retired: "", keyword: "RouteOfAdmissions" });
-	dicom_dictionary.insert(0x0038001A, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Admission Date", retired: "Retired", keyword: "ScheduledAdmissionDate" });
-	dicom_dictionary.insert(0x0038001B, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Admission Time", retired: "Retired", keyword: "ScheduledAdmissionTime" });
-	dicom_dictionary.insert(0x0038001C, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Discharge Date", retired: "Retired", keyword: "ScheduledDischargeDate" });
-	dicom_dictionary.insert(0x0038001D, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Discharge Time", retired: "Retired", keyword: "ScheduledDischargeTime" });
-	dicom_dictionary.insert(0x0038001E, DicomDictElt { vr : "LO", vm: "1", name: "Scheduled Patient Institution Residence", retired: "Retired", keyword: "ScheduledPatientInstitutionResidence" });
-	dicom_dictionary.insert(0x00380020, DicomDictElt { vr : "DA", vm: "1", name: "Admitting Date", retired: "", keyword: "AdmittingDate" });
-	dicom_dictionary.insert(0x00380021, DicomDictElt { vr : "TM", vm: "1", name: "Admitting Time", retired: "", keyword: "AdmittingTime" });
-	dicom_dictionary.insert(0x00380030, DicomDictElt { vr : "DA", vm: "1", name: "Discharge Date", retired: "Retired", keyword: "DischargeDate" });
-	dicom_dictionary.insert(0x00380032, DicomDictElt { vr : "TM", vm: "1", name: "Discharge Time", retired: "Retired", keyword: "DischargeTime" });
-	dicom_dictionary.insert(0x00380040, DicomDictElt { vr : "LO", vm: "1", name: "Discharge Diagnosis Description", retired: "Retired", keyword: "DischargeDiagnosisDescription" });
-	dicom_dictionary.insert(0x00380044, DicomDictElt { vr : "SQ", vm: "1", name: "Discharge Diagnosis Code Sequence", retired: "Retired", keyword: "DischargeDiagnosisCodeSequence" });
-	dicom_dictionary.insert(0x00380050, DicomDictElt { vr : "LO", vm: "1", name: "Special Needs", retired: "", keyword: "SpecialNeeds" });
-	dicom_dictionary.insert(0x00380060, DicomDictElt { vr : "LO", vm: "1", name: "Service Episode ID", retired: "", keyword: "ServiceEpisodeID" });
-	dicom_dictionary.insert(0x00380061, DicomDictElt { vr : "LO", vm: "1", name: "Issuer of Service Episode ID", retired: "Retired", keyword: "IssuerOfServiceEpisodeID" });
-	dicom_dictionary.insert(0x00380062, DicomDictElt { vr : "LO", vm: "1", name: "Service Episode Description", retired: "", keyword: "ServiceEpisodeDescription" });
-	dicom_dictionary.insert(0x00380064, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of Service Episode ID Sequence", retired: "", keyword: "IssuerOfServiceEpisodeIDSequence" });
-	dicom_dictionary.insert(0x00380100, DicomDictElt { vr : "SQ", vm: "1", name: "Pertinent Documents Sequence", retired: "", keyword: "PertinentDocumentsSequence" });
-	dicom_dictionary.insert(0x00380101, DicomDictElt { vr : "SQ", vm: "1", name: "Pertinent Resources Sequence", retired: "", keyword: "PertinentResourcesSequence" });
-	dicom_dictionary.insert(0x00380102, DicomDictElt { vr : "LO", vm: "1", name: "Resource Description", retired: "", keyword: "ResourceDescription" });
-	dicom_dictionary.insert(0x00380300, DicomDictElt { vr : "LO", vm: "1", name: "Current Patient Location", retired: "", keyword: "CurrentPatientLocation" });
-	dicom_dictionary.insert(0x00380400, DicomDictElt { vr : "LO", vm: "1", name: "Patient's Institution Residence", retired: "", keyword: "PatientInstitutionResidence" });
-	dicom_dictionary.insert(0x00380500, DicomDictElt { vr : "LO", vm: "1", name: "Patient State", retired: "", keyword: "PatientState" });
-	dicom_dictionary.insert(0x00380502, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Clinical Trial Participation Sequence", retired: "", keyword: "PatientClinicalTrialParticipationSequence" });
-	dicom_dictionary.insert(0x00384000, DicomDictElt { vr : "LT", vm: "1", name: "Visit Comments", retired: "", keyword: "VisitComments" });
-	dicom_dictionary.insert(0x003A0004, DicomDictElt { vr : "CS", vm: "1", name: "Waveform Originality", retired: "", keyword: "WaveformOriginality" });
-	dicom_dictionary.insert(0x003A0005, DicomDictElt { vr : "US", vm: "1", name: "Number of Waveform Channels", retired: "", keyword: "NumberOfWaveformChannels" });
-	dicom_dictionary.insert(0x003A0010, DicomDictElt { vr : "UL", vm: "1", name: "Number of Waveform Samples", retired: "", keyword: "NumberOfWaveformSamples" });
-	dicom_dictionary.insert(0x003A001A, DicomDictElt { vr : "DS", vm: "1", name: "Sampling Frequency", retired: "", keyword: "SamplingFrequency" });
-	dicom_dictionary.insert(0x003A0020, DicomDictElt { vr : "SH", vm: "1", name: "Multiplex Group Label", retired: "", keyword: "MultiplexGroupLabel" });
-	dicom_dictionary.insert(0x003A0200, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Definition Sequence", retired: "", keyword: "ChannelDefinitionSequence" });
-	dicom_dictionary.insert(0x003A0202, DicomDictElt { vr : "IS", vm: "1", name: "Waveform Channel Number", retired: "", keyword: "WaveformChannelNumber" });
-	dicom_dictionary.insert(0x003A0203, DicomDictElt { vr : "SH", vm: "1", name: "Channel Label", retired: "", keyword: "ChannelLabel" });
-	dicom_dictionary.insert(0x003A0205, DicomDictElt { vr : "CS", vm: "1-n", name: "Channel Status", retired: "", keyword: "ChannelStatus" });
-	dicom_dictionary.insert(0x003A0208, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Source Sequence", retired: "", keyword: "ChannelSourceSequence" });
-	dicom_dictionary.insert(0x003A0209, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Source Modifiers Sequence", retired: "", keyword: "ChannelSourceModifiersSequence" });
-	dicom_dictionary.insert(0x003A020A, DicomDictElt { vr : "SQ", vm: "1", name: "Source Waveform Sequence", retired: "", keyword: "SourceWaveformSequence" });
-	dicom_dictionary.insert(0x003A020C, DicomDictElt { vr : "LO", vm: "1", name: "Channel Derivation Description", retired: "", keyword: "ChannelDerivationDescription" });
-	dicom_dictionary.insert(0x003A0210, DicomDictElt { vr : "DS", vm: "1", name: "Channel Sensitivity", retired: "", keyword: "ChannelSensitivity" });
-	dicom_dictionary.insert(0x003A0211, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Sensitivity Units Sequence", retired: "", keyword: "ChannelSensitivityUnitsSequence" });
-	dicom_dictionary.insert(0x003A0212, DicomDictElt { vr : "DS", vm: "1", name: "Channel Sensitivity Correction Factor", retired: "", keyword: "ChannelSensitivityCorrectionFactor" });
-	dicom_dictionary.insert(0x003A0213, DicomDictElt { vr : "DS", vm: "1", name: "Channel Baseline", retired: "", keyword: "ChannelBaseline" });
-	dicom_dictionary.insert(0x003A0214, DicomDictElt { vr : "DS", vm: "1", name: "Channel Time Skew", retired: "", keyword: "ChannelTimeSkew" });
-	dicom_dictionary.insert(0x003A0215, DicomDictElt { vr : "DS", vm: "1", name: "Channel Sample Skew", retired: "", keyword: "ChannelSampleSkew" });
-	dicom_dictionary.insert(0x003A0218, DicomDictElt { vr : "DS", vm: "1", name: "Channel Offset", retired: "", keyword: "ChannelOffset" });
-	dicom_dictionary.insert(0x003A021A, DicomDictElt { vr : "US", vm: "1", name: "Waveform Bits Stored", retired: "", keyword: "WaveformBitsStored" });
-	dicom_dictionary.insert(0x003A0220, DicomDictElt { vr : "DS", vm: "1", name: "Filter Low Frequency", retired: "", keyword: "FilterLowFrequency" });
-	dicom_dictionary.insert(0x003A0221, DicomDictElt { vr : "DS", vm: "1", name: "Filter High Frequency", retired: "", keyword: "FilterHighFrequency" });
-	dicom_dictionary.insert(0x003A0222, DicomDictElt { vr : "DS", vm: "1", name: "Notch Filter Frequency", retired: "", keyword: "NotchFilterFrequency" });
-	dicom_dictionary.insert(0x003A0223, DicomDictElt { vr : "DS", vm: "1", name: "Notch Filter Bandwidth", retired: "", keyword: "NotchFilterBandwidth" });
-	dicom_dictionary.insert(0x003A0230, DicomDictElt { vr : "FL", vm: "1", name: "Waveform Data Display Scale", retired: "", keyword: "WaveformDataDisplayScale" });
-	dicom_dictionary.insert(0x003A0231, DicomDictElt { vr : "US", vm: "3", name: "Waveform Display Background CIELab Value", retired: "", keyword: "WaveformDisplayBackgroundCIELabValue" });
-	dicom_dictionary.insert(0x003A0240, DicomDictElt { vr : "SQ", vm: "1", name: "Waveform Presentation Group Sequence", retired: "", keyword: "WaveformPresentationGroupSequence" });
-	dicom_dictionary.insert(0x003A0241, DicomDictElt { vr : "US", vm: "1", name: "Presentation Group Number", retired: "", keyword: "PresentationGroupNumber" });
-	dicom_dictionary.insert(0x003A0242, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Display Sequence", retired: "", keyword: "ChannelDisplaySequence" });
-	dicom_dictionary.insert(0x003A0244, DicomDictElt { vr : "US", vm: "3", name: "Channel Recommended Display CIELab Value", retired: "", keyword: "ChannelRecommendedDisplayCIELabValue" });
-	dicom_dictionary.insert(0x003A0245, DicomDictElt { vr : "FL", vm: "1", name: "Channel Position", retired: "", keyword: "ChannelPosition" });
-	dicom_dictionary.insert(0x003A0246, DicomDictElt { vr : "CS", vm: "1", name: "Display Shading Flag", retired: "", keyword: "DisplayShadingFlag" });
-	dicom_dictionary.insert(0x003A0247, DicomDictElt { vr : "FL", vm: "1", name: "Fractional Channel Display Scale", retired: "", keyword: "FractionalChannelDisplayScale" });
-	dicom_dictionary.insert(0x003A0248, DicomDictElt { vr : "FL", vm: "1", name: "Absolute Channel Display Scale", retired: "", keyword: "AbsoluteChannelDisplayScale" });
-	dicom_dictionary.insert(0x003A0300, DicomDictElt { vr : "SQ", vm: "1", name: "Multiplexed Audio Channels Description Code Sequence", retired: "", keyword: "MultiplexedAudioChannelsDescriptionCodeSequence" });
-	dicom_dictionary.insert(0x003A0301, DicomDictElt { vr : "IS", vm: "1", name: "Channel Identification Code", retired: "", keyword: "ChannelIdentificationCode" });
-	dicom_dictionary.insert(0x003A0302, DicomDictElt { vr : "CS", vm: "1", name: "Channel Mode", retired: "", keyword: "ChannelMode" });
-	dicom_dictionary.insert(0x00400001, DicomDictElt { vr : "AE", vm: "1-n", name: "Scheduled Station AE Title", retired: "", keyword: "ScheduledStationAETitle" });
-	dicom_dictionary.insert(0x00400002, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Procedure Step Start Date", retired: "", keyword: "ScheduledProcedureStepStartDate" });
-	dicom_dictionary.insert(0x00400003, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Procedure Step Start Time", retired: "", keyword: "ScheduledProcedureStepStartTime" });
-	dicom_dictionary.insert(0x00400004, DicomDictElt { vr : "DA", vm: "1", name: "Scheduled Procedure Step End Date", retired: "", keyword: "ScheduledProcedureStepEndDate" });
-	dicom_dictionary.insert(0x00400005, DicomDictElt { vr : "TM", vm: "1", name: "Scheduled Procedure Step End Time", retired: "", keyword: "ScheduledProcedureStepEndTime" });
-	dicom_dictionary.insert(0x00400006, DicomDictElt { vr : "PN", vm: "1", name: "Scheduled Performing Physician's Name", retired: "", keyword: "ScheduledPerformingPhysicianName" });
-	dicom_dictionary.insert(0x00400007, DicomDictElt { vr : "LO", vm: "1", name: "Scheduled Procedure Step Description", retired: "", keyword: "ScheduledProcedureStepDescription" });
-	dicom_dictionary.insert(0x00400008, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Protocol Code Sequence", retired: "", keyword: "ScheduledProtocolCodeSequence" });
-	dicom_dictionary.insert(0x00400009, DicomDictElt { vr : "SH", vm: "1", name: "Scheduled Procedure Step ID", retired: "", keyword: "ScheduledProcedureStepID" });
-	dicom_dictionary.insert(0x0040000A, DicomDictElt { vr : "SQ", vm: "1", name: "Stage Code Sequence", retired: "", keyword: "StageCodeSequence" });
-	dicom_dictionary.insert(0x0040000B, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Performing Physician Identification Sequence", retired: "", keyword: "ScheduledPerformingPhysicianIdentificationSequence" });
-	dicom_dictionary.insert(0x00400010, DicomDictElt { vr : "SH", vm: "1-n", name: "Scheduled Station Name", retired: "", keyword: "ScheduledStationName" });
-	dicom_dictionary.insert(0x00400011, DicomDictElt { vr : "SH", vm: "1", name: "Scheduled Procedure Step Location", retired: "", keyword: "ScheduledProcedureStepLocation" });
-	dicom_dictionary.insert(0x00400012, DicomDictElt { vr : "LO", vm: "1", name: "Pre-Medication", retired: "", keyword: "PreMedication" });
-	dicom_dictionary.insert(0x00400020, DicomDictElt { vr : "CS", vm: "1", name: "Scheduled Procedure Step Status", retired: "", keyword: "ScheduledProcedureStepStatus" });
-	dicom_dictionary.insert(0x00400026, DicomDictElt { vr : "SQ", vm: "1", name: "Order Placer Identifier Sequence", retired: "", keyword: "OrderPlacerIdentifierSequence" });
-	dicom_dictionary.insert(0x00400027, DicomDictElt { vr : "SQ", vm: "1", name: "Order Filler Identifier Sequence", retired: "", keyword: "OrderFillerIdentifierSequence" });
-	dicom_dictionary.insert(0x00400031, DicomDictElt { vr : "UT", vm: "1", name: "Local Namespace Entity ID", retired: "", keyword: "LocalNamespaceEntityID" });
-	dicom_dictionary.insert(0x00400032, DicomDictElt { vr : "UT", vm: "1", name: "Universal Entity ID", retired: "", keyword: "UniversalEntityID" });
-	dicom_dictionary.insert(0x00400033, DicomDictElt { vr : "CS", vm: "1", name: "Universal Entity ID Type", retired: "", keyword: "UniversalEntityIDType" });
-	dicom_dictionary.insert(0x00400035, DicomDictElt { vr : "CS", vm: "1", name: "Identifier Type Code", retired: "", keyword: "IdentifierTypeCode" });
-	dicom_dictionary.insert(0x00400036, DicomDictElt { vr : "SQ", vm: "1", name: "Assigning Facility Sequence", retired: "", keyword: "AssigningFacilitySequence" });
-	dicom_dictionary.insert(0x00400039, DicomDictElt { vr : "SQ", vm: "1", name: "Assigning Jurisdiction Code Sequence", retired: "", keyword: "AssigningJurisdictionCodeSequence" });
-	dicom_dictionary.insert(0x0040003A, DicomDictElt { vr : "SQ", vm: "1", name: "Assigning Agency or Department Code Sequence", retired: "", keyword: "AssigningAgencyOrDepartmentCodeSequence" });
-	dicom_dictionary.insert(0x00400100, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Procedure Step Sequence", retired: "", keyword: "ScheduledProcedureStepSequence" });
-	dicom_dictionary.insert(0x00400220, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Non-Image Composite SOP Instance Sequence", retired: "", keyword: "ReferencedNonImageCompositeSOPInstanceSequence" });
-	dicom_dictionary.insert(0x00400241, DicomDictElt { vr : "AE", vm: "1", name: "Performed Station AE Title", retired: "", keyword: "PerformedStationAETitle" });
-	dicom_dictionary.insert(0x00400242, DicomDictElt { vr : "SH", vm: "1", name: "Performed Station Name", retired: "", keyword: "PerformedStationName" });
-	dicom_dictionary.insert(0x00400243, DicomDictElt { vr : "SH", vm: "1", name: "Performed Location", retired: "", keyword: "PerformedLocation" });
-	dicom_dictionary.insert(0x00400244, DicomDictElt { vr : "DA", vm: "1", name: "Performed Procedure Step Start Date", retired: "", keyword: "PerformedProcedureStepStartDate" });
-	dicom_dictionary.insert(0x00400245, DicomDictElt { vr : "TM", vm: "1", name: "Performed Procedure Step Start Time", retired: "", keyword: "PerformedProcedureStepStartTime" });
-	dicom_dictionary.insert(0x00400250, DicomDictElt { vr : "DA", vm: "1", name: "Performed Procedure Step End Date", retired: "", keyword: "PerformedProcedureStepEndDate" });
-	dicom_dictionary.insert(0x00400251, DicomDictElt { vr : "TM", vm: "1", name: "Performed Procedure Step End Time", retired: "", keyword: "PerformedProcedureStepEndTime" });
-	dicom_dictionary.insert(0x00400252, DicomDictElt { vr : "CS", vm: "1", name: "Performed Procedure Step Status", retired: "", keyword: "PerformedProcedureStepStatus" });
-	dicom_dictionary.insert(0x00400253, DicomDictElt { vr : "SH", vm: "1", name: "Performed Procedure Step ID", retired: "", keyword: "PerformedProcedureStepID" });
-	dicom_dictionary.insert(0x00400254, DicomDictElt { vr : "LO", vm: "1", name: "Performed Procedure Step Description", retired: "", keyword: "PerformedProcedureStepDescription" });
-	dicom_dictionary.insert(0x00400255, DicomDictElt { vr : "LO", vm: "1", name: "Performed Procedure Type Description", retired: "", keyword: "PerformedProcedureTypeDescription" });
-	dicom_dictionary.insert(0x00400260, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Protocol Code Sequence", retired: "", keyword: "PerformedProtocolCodeSequence" });
-	dicom_dictionary.insert(0x00400261, DicomDictElt { vr : "CS", vm: "1", name: "Performed Protocol Type", retired: "", keyword: "PerformedProtocolType" });
-	dicom_dictionary.insert(0x00400270, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Step Attributes Sequence", retired: "", keyword: "ScheduledStepAttributesSequence" });
-	dicom_dictionary.insert(0x00400275, DicomDictElt { vr : "SQ", vm: "1", name: "Request Attributes Sequence", retired: "", keyword: "RequestAttributesSequence" });
-	dicom_dictionary.insert(0x00400280, DicomDictElt { vr : "ST", vm: "1", name: "Comments on the Performed Procedure Step", retired: "", keyword: "CommentsOnThePerformedProcedureStep" });
-	dicom_dictionary.insert(0x00400281, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Procedure Step Discontinuation Reason Code Sequence", retired: "", keyword: "PerformedProcedureStepDiscontinuationReasonCodeSequence" });
-	dicom_dictionary.insert(0x00400293, DicomDictElt { vr : "SQ", vm: "1", name: "Quantity Sequence", retired: "", keyword: "QuantitySequence" });
-	dicom_dictionary.insert(0x00400294, DicomDictElt { vr : "DS", vm: "1", name: "Quantity", retired: "", keyword: "Quantity" });
-	dicom_dictionary.insert(0x00400295, DicomDictElt { vr : "SQ", vm: "1", name: "Measuring Units Sequence", retired: "", keyword: "MeasuringUnitsSequence" });
-	dicom_dictionary.insert(0x00400296, DicomDictElt { vr : "SQ", vm: "1", name: "Billing Item Sequence", retired: "", keyword: "BillingItemSequence" });
-	dicom_dictionary.insert(0x00400300, DicomDictElt { vr : "US", vm: "1", name: "Total Time of Fluoroscopy", retired: "", keyword: "TotalTimeOfFluoroscopy" });
-	dicom_dictionary.insert(0x00400301, DicomDictElt { vr : "US", vm: "1", name: "Total Number of Exposures", retired: "", keyword: "TotalNumberOfExposures" });
-	dicom_dictionary.insert(0x00400302, DicomDictElt { vr : "US", vm: "1", name: "Entrance Dose", retired: "", keyword: "EntranceDose" });
-	dicom_dictionary.insert(0x00400303, DicomDictElt { vr : "US", vm: "1-2", name: "Exposed Area", retired: "", keyword: "ExposedArea" });
-	dicom_dictionary.insert(0x00400306, DicomDictElt { vr : "DS", vm: "1", name: "Distance Source to Entrance", retired: "", keyword: "DistanceSourceToEntrance" });
-	dicom_dictionary.insert(0x00400307, DicomDictElt { vr : "DS", vm: "1", name: "Distance Source to Support", retired: "Retired", keyword: "DistanceSourceToSupport" });
-	dicom_dictionary.insert(0x0040030E, DicomDictElt { vr : "SQ", vm: "1", name: "Exposure Dose Sequence", retired: "", keyword: "ExposureDoseSequence" });
-	dicom_dictionary.insert(0x00400310, DicomDictElt { vr : "ST", vm: "1", name: "Comments on Radiation Dose", retired: "", keyword: "CommentsOnRadiationDose" });
-	dicom_dictionary.insert(0x00400312, DicomDictElt { vr : "DS", vm: "1", name: "X-Ray Output", retired: "", keyword: "XRayOutput" });
-	dicom_dictionary.insert(0x00400314, DicomDictElt { vr : "DS", vm: "1", name: "Half Value Layer", retired: "", keyword: "HalfValueLayer" });
-	dicom_dictionary.insert(0x00400316, DicomDictElt { vr : "DS", vm: "1", name: "Organ Dose", retired: "", keyword: "OrganDose" });
-	dicom_dictionary.insert(0x00400318, DicomDictElt { vr : "CS", vm: "1", name: "Organ Exposed", retired: "", keyword: "OrganExposed" });
-	dicom_dictionary.insert(0x00400320, DicomDictElt { vr : "SQ", vm: "1", name: "Billing Procedure Step Sequence", retired: "", keyword: "BillingProcedureStepSequence" });
-	dicom_dictionary.insert(0x00400321, DicomDictElt { vr : "SQ", vm: "1", name: "Film Consumption Sequence", retired: "", keyword: "FilmConsumptionSequence" });
-	dicom_dictionary.insert(0x00400324, DicomDictElt { vr : "SQ", vm: "1", name: "Billing Supplies and Devices Sequence", retired: "", keyword: "BillingSuppliesAndDevicesSequence" });
-	dicom_dictionary.insert(0x00400330, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Procedure Step Sequence", retired: "Retired", keyword: "ReferencedProcedureStepSequence" });
-	dicom_dictionary.insert(0x00400340, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Series Sequence", retired: "", keyword: "PerformedSeriesSequence" });
-	dicom_dictionary.insert(0x00400400, DicomDictElt { vr : "LT", vm: "1", name: "Comments on the Scheduled Procedure Step", retired: "", keyword: "CommentsOnTheScheduledProcedureStep" });
-	dicom_dictionary.insert(0x00400440, DicomDictElt { vr : "SQ", vm: "1", name: "Protocol Context Sequence", retired: "", keyword: "ProtocolContextSequence" });
-	dicom_dictionary.insert(0x00400441, DicomDictElt { vr : "SQ", vm: "1", name: "Content Item Modifier Sequence", retired: "", keyword: "ContentItemModifierSequence" });
-	dicom_dictionary.insert(0x00400500, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Specimen Sequence", retired: "", keyword: "ScheduledSpecimenSequence" });
-	dicom_dictionary.insert(0x0040050A, DicomDictElt { vr : "LO", vm: "1", name: "Specimen Accession Number", retired: "Retired", keyword: "SpecimenAccessionNumber" });
-	dicom_dictionary.insert(0x00400512, DicomDictElt { vr : "LO", vm: "1", name: "Container Identifier", retired: "", keyword: "ContainerIdentifier" });
-	dicom_dictionary.insert(0x00400513, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of the Container Identifier Sequence", retired: "", keyword: "IssuerOfTheContainerIdentifierSequence" });
-	dicom_dictionary.insert(0x00400515, DicomDictElt { vr : "SQ", vm: "1", name: "Alternate Container Identifier Sequence", retired: "", keyword: "AlternateContainerIdentifierSequence" });
-	dicom_dictionary.insert(0x00400518, DicomDictElt { vr : "SQ", vm: "1", name: "Container Type Code Sequence", retired: "", keyword: "ContainerTypeCodeSequence" });
-	dicom_dictionary.insert(0x0040051A, DicomDictElt { vr : "LO", vm: "1", name: "Container Description", retired: "", keyword: "ContainerDescription" });
-	dicom_dictionary.insert(0x00400520, DicomDictElt { vr : "SQ", vm: "1", name: "Container Component Sequence", retired: "", keyword: "ContainerComponentSequence" });
-	dicom_dictionary.insert(0x00400550, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Sequence", retired: "Retired", keyword: "SpecimenSequence" });
-	dicom_dictionary.insert(0x00400551, DicomDictElt { vr : "LO", vm: "1", name: "Specimen Identifier", retired: "", keyword: "SpecimenIdentifier" });
-	dicom_dictionary.insert(0x00400552, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Description Sequence (Trial)", retired: "Retired", keyword: "SpecimenDescriptionSequenceTrial" });
-	dicom_dictionary.insert(0x00400553, DicomDictElt { vr : "ST", vm: "1", name: "Specimen Description (Trial)", retired: "Retired", keyword: "SpecimenDescriptionTrial" });
-	dicom_dictionary.insert(0x00400554, DicomDictElt { vr : "UI", vm: "1", name: "Specimen UID", retired: "", keyword: "SpecimenUID" });
-	dicom_dictionary.insert(0x00400555, DicomDictElt { vr : "SQ", vm: "1", name: "Acquisition Context Sequence", retired: "", keyword: "AcquisitionContextSequence" });
-	dicom_dictionary.insert(0x00400556, DicomDictElt { vr : "ST", vm: "1", name: "Acquisition Context Description", retired: "", keyword: "AcquisitionContextDescription" });
-	dicom_dictionary.insert(0x00400560, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Description Sequence", retired: "", keyword: "SpecimenDescriptionSequence" });
-	dicom_dictionary.insert(0x00400562, DicomDictElt { vr : "SQ", vm: "1", name: "Issuer of the Specimen Identifier Sequence", retired: "", keyword: "IssuerOfTheSpecimenIdentifierSequence" });
-	dicom_dictionary.insert(0x0040059A, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Type Code Sequence", retired: "", keyword: "SpecimenTypeCodeSequence" });
-	dicom_dictionary.insert(0x00400600, DicomDictElt { vr : "LO", vm: "1", name: "Specimen Short Description", retired: "", keyword: "SpecimenShortDescription" });
-	dicom_dictionary.insert(0x00400602, DicomDictElt { vr : "UT", vm: "1", name: "Specimen Detailed Description", retired: "", keyword: "SpecimenDetailedDescription" });
-	dicom_dictionary.insert(0x00400610, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Preparation Sequence", retired: "", keyword: "SpecimenPreparationSequence" });
-	dicom_dictionary.insert(0x00400612, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Preparation Step Content Item Sequence", retired: "", keyword: "SpecimenPreparationStepContentItemSequence" });
-	dicom_dictionary.insert(0x00400620, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Localization Content Item Sequence", retired: "", keyword: "SpecimenLocalizationContentItemSequence" });
-	dicom_dictionary.insert(0x004006FA, DicomDictElt { vr : "LO", vm: "1", name: "Slide Identifier", retired: "Retired", keyword: "SlideIdentifier" });
-	dicom_dictionary.insert(0x0040071A, DicomDictElt { vr : "SQ", vm: "1", name: "Image Center Point Coordinates Sequence", retired: "", keyword: "ImageCenterPointCoordinatesSequence" });
-	dicom_dictionary.insert(0x0040072A, DicomDictElt { vr : "DS", vm: "1", name: "X Offset in Slide Coordinate System", retired: "", keyword: "XOffsetInSlideCoordinateSystem" });
-	dicom_dictionary.insert(0x0040073A, DicomDictElt { vr : "DS", vm: "1", name: "Y Offset in Slide Coordinate System", retired: "", keyword: "YOffsetInSlideCoordinateSystem" });
-	dicom_dictionary.insert(0x0040074A, DicomDictElt { vr : "DS", vm: "1", name: "Z Offset in Slide Coordinate System", retired: "", keyword: "ZOffsetInSlideCoordinateSystem" });
-	dicom_dictionary.insert(0x004008D8, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Spacing Sequence", retired: "Retired", keyword: "PixelSpacingSequence" });
-	dicom_dictionary.insert(0x004008DA, DicomDictElt { vr : "SQ", vm: "1", name: "Coordinate System Axis Code Sequence", retired: "Retired", keyword: "CoordinateSystemAxisCodeSequence" });
-	dicom_dictionary.insert(0x004008EA, DicomDictElt { vr : "SQ", vm: "1", name: "Measurement Units Code Sequence", retired: "", keyword: "MeasurementUnitsCodeSequence" });
-	dicom_dictionary.insert(0x004009F8, DicomDictElt { vr : "SQ", vm: "1", name: "Vital Stain Code Sequence (Trial)", retired: "Retired", keyword: "VitalStainCodeSequenceTrial" });
-	dicom_dictionary.insert(0x00401001, DicomDictElt { vr : "SH", vm: "1", name: "Requested Procedure ID", retired: "", keyword: "RequestedProcedureID" });
-	dicom_dictionary.insert(0x00401002, DicomDictElt { vr : "LO", vm: "1", name: "Reason for the Requested Procedure", retired: "", keyword: "ReasonForTheRequestedProcedure" });
-	dicom_dictionary.insert(0x00401003, DicomDictElt { vr : "SH", vm: "1", name: "Requested Procedure Priority", retired: "", keyword: "RequestedProcedurePriority" });
-	dicom_dictionary.insert(0x00401004, DicomDictElt { vr : "LO", vm: "1", name: "Patient Transport Arrangements", retired: "", keyword: "PatientTransportArrangements" });
-	dicom_dictionary.insert(0x00401005, DicomDictElt { vr : "LO", vm: "1", name: "Requested Procedure Location", retired: "", keyword: "RequestedProcedureLocation" });
-	dicom_dictionary.insert(0x00401006, DicomDictElt { vr : "SH", vm: "1", name: "Placer Order Number / Procedure", retired: "Retired", keyword: "PlacerOrderNumberProcedure" });
-	dicom_dictionary.insert(0x00401007, DicomDictElt { vr : "SH", vm: "1", name: "Filler Order Number / Procedure", retired: "Retired", keyword: "FillerOrderNumberProcedure" });
-	dicom_dictionary.insert(0x00401008, DicomDictElt { vr : "LO", vm: "1", name: "Confidentiality Code", retired: "", keyword: "ConfidentialityCode" });
-	dicom_dictionary.insert(0x00401009, DicomDictElt { vr : "SH", vm: "1", name: "Reporting Priority", retired: "", keyword: "ReportingPriority" });
-	dicom_dictionary.insert(0x0040100A, DicomDictElt { vr : "SQ", vm: "1", name: "Reason for Requested Procedure Code Sequence", retired: "", keyword: "ReasonForRequestedProcedureCodeSequence" });
-	dicom_dictionary.insert(0x00401010, DicomDictElt { vr : "PN", vm: "1-n", name: "Names of Intended Recipients of Results", retired: "", keyword: "NamesOfIntendedRecipientsOfResults" });
-	dicom_dictionary.insert(0x00401011, DicomDictElt { vr : "SQ", vm: "1", name: "Intended Recipients of Results Identification Sequence", retired: "", keyword: "IntendedRecipientsOfResultsIdentificationSequence" });
-	dicom_dictionary.insert(0x00401012, DicomDictElt { vr : "SQ", vm: "1", name: "Reason For Performed Procedure Code Sequence", retired: "", keyword: "ReasonForPerformedProcedureCodeSequence" });
-	dicom_dictionary.insert(0x00401060, DicomDictElt { vr : "LO", vm: "1", name: "Requested Procedure Description (Trial)", retired: "Retired", keyword: "RequestedProcedureDescriptionTrial" });
-	dicom_dictionary.insert(0x00401101, DicomDictElt { vr : "SQ", vm: "1", name: "Person Identification Code Sequence", retired: "", keyword: "PersonIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x00401102, DicomDictElt { vr : "ST", vm: "1", name: "Person's Address", retired: "", keyword: "PersonAddress" });
-	dicom_dictionary.insert(0x00401103, DicomDictElt { vr : "LO", vm: "1-n", name: "Person's Telephone Numbers", retired: "", keyword: "PersonTelephoneNumbers" });
-	dicom_dictionary.insert(0x00401104, DicomDictElt { vr : "LT", vm: "1", name: "Person's Telecom Information", retired: "", keyword: "PersonTelecomInformation" });
-	dicom_dictionary.insert(0x00401400, DicomDictElt { vr : "LT", vm: "1", name: "Requested Procedure Comments", retired: "", keyword: "RequestedProcedureComments" });
-	dicom_dictionary.insert(0x00402001, DicomDictElt { vr : "LO", vm: "1", name: "Reason for the Imaging Service Request", retired: "Retired", keyword: "ReasonForTheImagingServiceRequest" });
-	dicom_dictionary.insert(0x00402004, DicomDictElt { vr : "DA", vm: "1", name: "Issue Date of Imaging Service Request", retired: "", keyword: "IssueDateOfImagingServiceRequest" });
-	dicom_dictionary.insert(0x00402005, DicomDictElt { vr : "TM", vm: "1", name: "Issue Time of Imaging Service Request", retired: "", keyword: "IssueTimeOfImagingServiceRequest" });
-	dicom_dictionary.insert(0x00402006, DicomDictElt { vr : "SH", vm: "1", name: "Placer Order Number / Imaging Service Request (Retired)", retired: "Retired", keyword: "PlacerOrderNumberImagingServiceRequestRetired" });
-	dicom_dictionary.insert(0x00402007, DicomDictElt { vr : "SH", vm: "1", name: "Filler Order Number / Imaging Service Request (Retired)", retired: "Retired", keyword: "FillerOrderNumberImagingServiceRequestRetired" });
-	dicom_dictionary.insert(0x00402008, DicomDictElt { vr : "PN", vm: "1", name: "Order Entered By", retired: "", keyword: "OrderEnteredBy" });
-	dicom_dictionary.insert(0x00402009, DicomDictElt { vr : "SH", vm: "1", name: "Order Enterer's Location", retired: "", keyword: "OrderEntererLocation" });
-	dicom_dictionary.insert(0x00402010, DicomDictElt { vr : "SH", vm: "1", name: "Order Callback Phone Number", retired: "", keyword: "OrderCallbackPhoneNumber" });
-	dicom_dictionary.insert(0x00402011, DicomDictElt { vr : "LT", vm: "1", name: "Order Callback Telecom Information", retired: "", keyword: "OrderCallbackTelecomInformation" });
-	dicom_dictionary.insert(0x00402016, DicomDictElt { vr : "LO", vm: "1", name: "Placer Order Number / Imaging Service Request", retired: "", keyword: "PlacerOrderNumberImagingServiceRequest" });
-	dicom_dictionary.insert(0x00402017, DicomDictElt { vr : "LO", vm: "1", name: "Filler Order Number / Imaging Service Request", retired: "", keyword: "FillerOrderNumberImagingServiceRequest" });
-	dicom_dictionary.insert(0x00402400, DicomDictElt { vr : "LT", vm: "1", name: "Imaging Service Request Comments", retired: "", keyword: "ImagingServiceRequestComments" });
-	dicom_dictionary.insert(0x00403001, DicomDictElt { vr : "LO", vm: "1", name: "Confidentiality Constraint on Patient Data Description", retired: "", keyword: "ConfidentialityConstraintOnPatientDataDescription" });
-	dicom_dictionary.insert(0x00404001, DicomDictElt { vr : "CS", vm: "1", name: "General Purpose Scheduled Procedure Step Status", retired: "Retired", keyword: "GeneralPurposeScheduledProcedureStepStatus" });
-	dicom_dictionary.insert(0x00404002, DicomDictElt { vr : "CS", vm: "1", name: "General Purpose Performed Procedure Step Status", retired: "Retired", keyword: "GeneralPurposePerformedProcedureStepStatus" });
-	dicom_dictionary.insert(0x00404003, DicomDictElt { vr : "CS", vm: "1", name: "General Purpose Scheduled Procedure Step Priority", retired: "Retired", keyword: "GeneralPurposeScheduledProcedureStepPriority" });
-	dicom_dictionary.insert(0x00404004, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Processing Applications Code Sequence", retired: "Retired", keyword: "ScheduledProcessingApplicationsCodeSequence" });
-	dicom_dictionary.insert(0x00404005, DicomDictElt { vr : "DT", vm: "1", name: "Scheduled Procedure Step Start DateTime", retired: "", keyword: "ScheduledProcedureStepStartDateTime" });
-	dicom_dictionary.insert(0x00404006, DicomDictElt { vr : "CS", vm: "1", name: "Multiple Copies Flag", retired: "Retired", keyword: "MultipleCopiesFlag" });
-	dicom_dictionary.insert(0x00404007, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Processing Applications Code Sequence", retired: "", keyword: "PerformedProcessingApplicationsCodeSequence" });
-	dicom_dictionary.insert(0x00404009, DicomDictElt { vr : "SQ", vm: "1", name: "Human Performer Code Sequence", retired: "", keyword: "HumanPerformerCodeSequence" });
-	dicom_dictionary.insert(0x00404010, DicomDictElt { vr : "DT", vm: "1", name: "Scheduled Procedure Step Modification DateTime", retired: "", keyword: "ScheduledProcedureStepModificationDateTime" });
-	dicom_dictionary.insert(0x00404011, DicomDictElt { vr : "DT", vm: "1", name: "Expected Completion DateTime", retired: "", keyword: "ExpectedCompletionDateTime" });
-	dicom_dictionary.insert(0x00404015, DicomDictElt { vr : "SQ", vm: "1", name: "Resulting General Purpose Performed Procedure Steps Sequence", retired: "Retired", keyword: "ResultingGeneralPurposePerformedProcedureStepsSequence" });
-	dicom_dictionary.insert(0x00404016, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced General Purpose Scheduled Procedure Step Sequence", retired: "Retired", keyword: "ReferencedGeneralPurposeScheduledProcedureStepSequence" });
-	dicom_dictionary.insert(0x00404018, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Workitem Code Sequence", retired: "", keyword: "ScheduledWorkitemCodeSequence" });
-	dicom_dictionary.insert(0x00404019, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Workitem Code Sequence", retired: "", keyword: "PerformedWorkitemCodeSequence" });
-	dicom_dictionary.insert(0x00404020, DicomDictElt { vr : "CS", vm: "1", name: "Input Availability Flag", retired: "", keyword: "InputAvailabilityFlag" });
-	dicom_dictionary.insert(0x00404021, DicomDictElt { vr : "SQ", vm: "1", name: "Input Information Sequence", retired: "", keyword: "InputInformationSequence" });
-	dicom_dictionary.insert(0x00404022, DicomDictElt { vr : "SQ", vm: "1", name: "Relevant Information Sequence", retired: "Retired", keyword: "RelevantInformationSequence" });
-	dicom_dictionary.insert(0x00404023, DicomDictElt { vr : "UI", vm: "1", name: "Referenced General Purpose Scheduled Procedure Step Transaction UID", retired: "Retired", keyword: "ReferencedGeneralPurposeScheduledProcedureStepTransactionUID" });
-	dicom_dictionary.insert(0x00404025, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Station Name Code Sequence", retired: "", keyword: "ScheduledStationNameCodeSequence" });
-	dicom_dictionary.insert(0x00404026, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Station Class Code Sequence", retired: "", keyword: "ScheduledStationClassCodeSequence" });
-	dicom_dictionary.insert(0x00404027, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Station Geographic Location Code Sequence", retired: "", keyword: "ScheduledStationGeographicLocationCodeSequence" });
-	dicom_dictionary.insert(0x00404028, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Station Name Code Sequence", retired: "", keyword: "PerformedStationNameCodeSequence" });
-	dicom_dictionary.insert(0x00404029, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Station Class Code Sequence", retired: "", keyword: "PerformedStationClassCodeSequence" });
-	dicom_dictionary.insert(0x00404030, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Station Geographic Location Code Sequence", retired: "", keyword: "PerformedStationGeographicLocationCodeSequence" });
-	dicom_dictionary.insert(0x00404031, DicomDictElt { vr : "SQ", vm: "1", name: "Requested Subsequent Workitem Code Sequence", retired: "Retired", keyword: "RequestedSubsequentWorkitemCodeSequence" });
-	dicom_dictionary.insert(0x00404032, DicomDictElt { vr : "SQ", vm: "1", name: "Non-DICOM Output Code Sequence", retired: "Retired", keyword: "NonDICOMOutputCodeSequence" });
-	dicom_dictionary.insert(0x00404033, DicomDictElt { vr : "SQ", vm: "1", name: "Output Information Sequence", retired: "", keyword: "OutputInformationSequence" });
-	dicom_dictionary.insert(0x00404034, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Human Performers Sequence", retired: "", keyword: "ScheduledHumanPerformersSequence" });
-	dicom_dictionary.insert(0x00404035, DicomDictElt { vr : "SQ", vm: "1", name: "Actual Human Performers Sequence", retired: "", keyword: "ActualHumanPerformersSequence" });
-	dicom_dictionary.insert(0x00404036, DicomDictElt { vr : "LO", vm: "1", name: "Human Performer's Organization", retired: "", keyword: "HumanPerformerOrganization" });
-	dicom_dictionary.insert(0x00404037, DicomDictElt { vr : "PN", vm: "1", name: "Human Performer's Name", retired: "", keyword: "HumanPerformerName" });
-	dicom_dictionary.insert(0x00404040, DicomDictElt { vr : "CS", vm: "1", name: "Raw Data Handling", retired: "", keyword: "RawDataHandling" });
-	dicom_dictionary.insert(0x00404041, DicomDictElt { vr : "CS", vm: "1", name: "Input Readiness State", retired: "", keyword: "InputReadinessState" });
-	dicom_dictionary.insert(0x00404050, DicomDictElt { vr : "DT", vm: "1", name: "Performed Procedure Step Start DateTime", retired: "", keyword: "PerformedProcedureStepStartDateTime" });
-	dicom_dictionary.insert(0x00404051, DicomDictElt { vr : "DT", vm: "1", name: "Performed Procedure Step End DateTime", retired: "", keyword: "PerformedProcedureStepEndDateTime" });
-	dicom_dictionary.insert(0x00404052, DicomDictElt { vr : "DT", vm: "1", name: "Procedure Step Cancellation DateTime", retired: "", keyword: "ProcedureStepCancellationDateTime" });
-	dicom_dictionary.insert(0x00404070, DicomDictElt { vr : "SQ", vm: "1", name: "Output Destination Sequence", retired: "", keyword: "OutputDestinationSequence" });
-	dicom_dictionary.insert(0x00404071, DicomDictElt { vr : "SQ", vm: "1", name: "DICOM Storage Sequence", retired: "", keyword: "DICOMStorageSequence" });
-	dicom_dictionary.insert(0x00404072, DicomDictElt { vr : "SQ", vm: "1", name: "STOW-RS Storage Sequence", retired: "", keyword: "STOWRSStorageSequence" });
-	dicom_dictionary.insert(0x00404073, DicomDictElt { vr : "UR", vm: "1", name: "Storage URL", retired: "", keyword: "StorageURL" });
-	dicom_dictionary.insert(0x00404074, DicomDictElt { vr : "SQ", vm: "1", name: "XDS Storage Sequence", retired: "", keyword: "XDSStorageSequence" });
-	dicom_dictionary.insert(0x00408302, DicomDictElt { vr : "DS", vm: "1", name: "Entrance Dose in mGy", retired: "", keyword: "EntranceDoseInmGy" });
-	dicom_dictionary.insert(0x00409092, DicomDictElt { vr : "SQ", vm: "1", name: "Parametric Map Frame Type Sequence", retired: "", keyword: "ParametricMapFrameTypeSequence" });
-	dicom_dictionary.insert(0x00409094, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Real World Value Mapping Sequence", retired: "", keyword: "ReferencedImageRealWorldValueMappingSequence" });
-	dicom_dictionary.insert(0x00409096, DicomDictElt { vr : "SQ", vm: "1", name: "Real World Value Mapping Sequence", retired: "", keyword: "RealWorldValueMappingSequence" });
-	dicom_dictionary.insert(0x00409098, DicomDictElt { vr : "SQ", vm: "1", name: "Pixel Value Mapping Code Sequence", retired: "", keyword: "PixelValueMappingCodeSequence" });
-	dicom_dictionary.insert(0x00409210, DicomDictElt { vr : "SH", vm: "1", name: "LUT Label", retired: "", keyword: "LUTLabel" });
-	dicom_dictionary.insert(0x00409211, DicomDictElt { vr : "US or SS", vm: "1", name: "Real World Value Last Value Mapped", retired: "", keyword: "RealWorldValueLastValueMapped" });
-	dicom_dictionary.insert(0x00409212, DicomDictElt { vr : "FD", vm: "1-n", name: "Real World Value LUT Data", retired: "", keyword: "RealWorldValueLUTData" });
-	dicom_dictionary.insert(0x00409213, DicomDictElt { vr : "FD", vm: "1", name: "Double Float Real World Value Last Value Mapped", retired: "", keyword: "DoubleFloatRealWorldValueLastValueMapped" });
-	dicom_dictionary.insert(0x00409214, DicomDictElt { vr : "FD", vm: "1", name: "Double Float Real World Value First Value Mapped", retired: "", keyword: "DoubleFloatRealWorldValueFirstValueMapped" });
-	dicom_dictionary.insert(0x00409216, DicomDictElt { vr : "US or SS", vm: "1", name: "Real World Value First Value Mapped", retired: "", keyword: "RealWorldValueFirstValueMapped" });
-	dicom_dictionary.insert(0x00409220, DicomDictElt { vr : "SQ", vm: "1", name: "Quantity Definition Sequence", retired: "", keyword: "QuantityDefinitionSequence" });
-	dicom_dictionary.insert(0x00409224, DicomDictElt { vr : "FD", vm: "1", name: "Real World Value Intercept", retired: "", keyword: "RealWorldValueIntercept" });
-	dicom_dictionary.insert(0x00409225, DicomDictElt { vr : "FD", vm: "1", name: "Real World Value Slope", retired: "", keyword: "RealWorldValueSlope" });
-	dicom_dictionary.insert(0x0040A007, DicomDictElt { vr : "CS", vm: "1", name: "Findings Flag (Trial)", retired: "Retired", keyword: "FindingsFlagTrial" });
-	dicom_dictionary.insert(0x0040A010, DicomDictElt { vr : "CS", vm: "1", name: "Relationship Type", retired: "", keyword: "RelationshipType" });
-	dicom_dictionary.insert(0x0040A020, DicomDictElt { vr : "SQ", vm: "1", name: "Findings Sequence (Trial)", retired: "Retired", keyword: "FindingsSequenceTrial" });
-	dicom_dictionary.insert(0x0040A021, DicomDictElt { vr : "UI", vm: "1", name: "Findings Group UID (Trial)", retired: "Retired", keyword: "FindingsGroupUIDTrial" });
-	dicom_dictionary.insert(0x0040A022, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Findings Group UID (Trial)", retired: "Retired", keyword: "ReferencedFindingsGroupUIDTrial" });
-	dicom_dictionary.insert(0x0040A023, DicomDictElt { vr : "DA", vm: "1", name: "Findings Group Recording Date (Trial)", retired: "Retired", keyword: "FindingsGroupRecordingDateTrial" });
-	dicom_dictionary.insert(0x0040A024, DicomDictElt { vr : "TM", vm: "1", name: "Findings Group Recording Time (Trial)", retired: "Retired", keyword: "FindingsGroupRecordingTimeTrial" });
-	dicom_dictionary.insert(0x0040A026, DicomDictElt { vr : "SQ", vm: "1", name: "Findings Source Category Code Sequence (Trial)", retired: "Retired", keyword: "FindingsSourceCategoryCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A027, DicomDictElt { vr : "LO", vm: "1", name: "Verifying Organization", retired: "", keyword: "VerifyingOrganization" });
-	dicom_dictionary.insert(0x0040A028, DicomDictElt { vr : "SQ", vm: "1", name: "Documenting Organization Identifier Code Sequence (Trial)", retired: "Retired", keyword: "DocumentingOrganizationIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A030, DicomDictElt { vr : "DT", vm: "1", name: "Verification DateTime", retired: "", keyword: "VerificationDateTime" });
-	dicom_dictionary.insert(0x0040A032, DicomDictElt { vr : "DT", vm: "1", name: "Observation DateTime", retired: "", keyword: "ObservationDateTime" });
-	dicom_dictionary.insert(0x0040A040, DicomDictElt { vr : "CS", vm: "1", name: "Value Type", retired: "", keyword: "ValueType" });
-	dicom_dictionary.insert(0x0040A043, DicomDictElt { vr : "SQ", vm: "1", name: "Concept Name Code Sequence", retired: "", keyword: "ConceptNameCodeSequence" });
-	dicom_dictionary.insert(0x0040A047, DicomDictElt { vr : "LO", vm: "1", name: "Measurement Precision Description (Trial)", retired: "Retired", keyword: "MeasurementPrecisionDescriptionTrial" });
-	dicom_dictionary.insert(0x0040A050, DicomDictElt { vr : "CS", vm: "1", name: "Continuity Of Content", retired: "", keyword: "ContinuityOfContent" });
-	dicom_dictionary.insert(0x0040A057, DicomDictElt { vr : "CS", vm: "1-n", name: "Urgency or Priority Alerts (Trial)", retired: "Retired", keyword: "UrgencyOrPriorityAlertsTrial" });
-	dicom_dictionary.insert(0x0040A060, DicomDictElt { vr : "LO", vm: "1", name: "Sequencing Indicator (Trial)", retired: "Retired", keyword: "SequencingIndicatorTrial" });
-	dicom_dictionary.insert(0x0040A066, DicomDictElt { vr : "SQ", vm: "1", name: "Document Identifier Code Sequence (Trial)", retired: "Retired", keyword: "DocumentIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A067, DicomDictElt { vr : "PN", vm: "1", name: "Document Author (Trial)", retired: "Retired", keyword: "DocumentAuthorTrial" });
-	dicom_dictionary.insert(0x0040A068, DicomDictElt { vr : "SQ", vm: "1", name: "Document Author Identifier Code Sequence (Trial)", retired: "Retired", keyword: "DocumentAuthorIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A070, DicomDictElt { vr : "SQ", vm: "1", name: "Identifier Code Sequence (Trial)", retired: "Retired", keyword: "IdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A073, DicomDictElt { vr : "SQ", vm: "1", name: "Verifying Observer Sequence", retired: "", keyword: "VerifyingObserverSequence" });
-	dicom_dictionary.insert(0x0040A074, DicomDictElt { vr : "OB", vm: "1", name: "Object Binary Identifier (Trial)", retired: "Retired", keyword: "ObjectBinaryIdentifierTrial" });
-	dicom_dictionary.insert(0x0040A075, DicomDictElt { vr : "PN", vm: "1", name: "Verifying Observer Name", retired: "", keyword: "VerifyingObserverName" });
-	dicom_dictionary.insert(0x0040A076, DicomDictElt { vr : "SQ", vm: "1", name: "Documenting Observer Identifier Code Sequence (Trial)", retired: "Retired", keyword: "DocumentingObserverIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A078, DicomDictElt { vr : "SQ", vm: "1", name: "Author Observer Sequence", retired: "", keyword: "AuthorObserverSequence" });
-	dicom_dictionary.insert(0x0040A07A, DicomDictElt { vr : "SQ", vm: "1", name: "Participant Sequence", retired: "", keyword: "ParticipantSequence" });
-	dicom_dictionary.insert(0x0040A07C, DicomDictElt { vr : "SQ", vm: "1", name: "Custodial Organization Sequence", retired: "", keyword: "CustodialOrganizationSequence" });
-	dicom_dictionary.insert(0x0040A080, DicomDictElt { vr : "CS", vm: "1", name: "Participation Type", retired: "", keyword: "ParticipationType" });
-	dicom_dictionary.insert(0x0040A082, DicomDictElt { vr : "DT", vm: "1", name: "Participation DateTime", retired: "", keyword: "ParticipationDateTime" });
-	dicom_dictionary.insert(0x0040A084, DicomDictElt { vr : "CS", vm: "1", name: "Observer Type", retired: "", keyword: "ObserverType" });
-	dicom_dictionary.insert(0x0040A085, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Identifier Code Sequence (Trial)", retired: "Retired", keyword: "ProcedureIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A088, DicomDictElt { vr : "SQ", vm: "1", name: "Verifying Observer Identification Code Sequence", retired: "", keyword: "VerifyingObserverIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x0040A089, DicomDictElt { vr : "OB", vm: "1", name: "Object Directory Binary Identifier (Trial)", retired: "Retired", keyword: "ObjectDirectoryBinaryIdentifierTrial" });
-	dicom_dictionary.insert(0x0040A090, DicomDictElt { vr : "SQ", vm: "1", name: "Equivalent CDA Document Sequence", retired: "Retired", keyword: "EquivalentCDADocumentSequence" });
-	dicom_dictionary.insert(0x0040A0B0, DicomDictElt { vr : "US", vm: "2-2n", name: "Referenced Waveform Channels", retired: "", keyword: "ReferencedWaveformChannels" });
-	dicom_dictionary.insert(0x0040A110, DicomDictElt { vr : "DA", vm: "1", name: "Date of Document or Verbal Transaction (Trial)", retired: "Retired", keyword: "DateOfDocumentOrVerbalTransactionTrial" });
-	dicom_dictionary.insert(0x0040A112, DicomDictElt { vr : "TM", vm: "1", name: "Time of Document Creation or Verbal Transaction (Trial)", retired: "Retired", keyword: "TimeOfDocumentCreationOrVerbalTransactionTrial" });
-	dicom_dictionary.insert(0x0040A120, DicomDictElt { vr : "DT", vm: "1", name: "DateTime", retired: "", keyword: "DateTime" });
-	dicom_dictionary.insert(0x0040A121, DicomDictElt { vr : "DA", vm: "1", name: "Date", retired: "", keyword: "Date" });
-	dicom_dictionary.insert(0x0040A122, DicomDictElt { vr : "TM", vm: "1", name: "Time", retired: "", keyword: "Time" });
-	dicom_dictionary.insert(0x0040A123, DicomDictElt { vr : "PN", vm: "1", name: "Person Name", retired: "", keyword: "PersonName" });
-	dicom_dictionary.insert(0x0040A124, DicomDictElt { vr : "UI", vm: "1", name: "UID", retired: "", keyword: "UID" });
-	dicom_dictionary.insert(0x0040A125, DicomDictElt { vr : "CS", vm: "2", name: "Report Status ID (Trial)", retired: "Retired", keyword: "ReportStatusIDTrial" });
-	dicom_dictionary.insert(0x0040A130, DicomDictElt { vr : "CS", vm: "1", name: "Temporal Range Type", retired: "", keyword: "TemporalRangeType" });
-	dicom_dictionary.insert(0x0040A132, DicomDictElt { vr : "UL", vm: "1-n", name: "Referenced Sample Positions", retired: "", keyword: "ReferencedSamplePositions" });
-	dicom_dictionary.insert(0x0040A136, DicomDictElt { vr : "US", vm: "1-n", name: "Referenced Frame Numbers", retired: "", keyword: "ReferencedFrameNumbers" });
-	dicom_dictionary.insert(0x0040A138, DicomDictElt { vr : "DS", vm: "1-n", name: "Referenced Time Offsets", retired: "", keyword: "ReferencedTimeOffsets" });
-	dicom_dictionary.insert(0x0040A13A, DicomDictElt { vr : "DT", vm: "1-n", name: "Referenced DateTime", retired: "", keyword: "ReferencedDateTime" });
-	dicom_dictionary.insert(0x0040A160, DicomDictElt { vr : "UT", vm: "1", name: "Text Value", retired: "", keyword: "TextValue" });
-	dicom_dictionary.insert(0x0040A161, DicomDictElt { vr : "FD", vm: "1-n", name: "Floating Point Value", retired: "", keyword: "FloatingPointValue" });
-	dicom_dictionary.insert(0x0040A162, DicomDictElt { vr : "SL", vm: "1-n", name: "Rational Numerator Value", retired: "", keyword: "RationalNumeratorValue" });
-	dicom_dictionary.insert(0x0040A163, DicomDictElt { vr : "UL", vm: "1-n", name: "Rational Denominator Value", retired: "", keyword: "RationalDenominatorValue" });
-	dicom_dictionary.insert(0x0040A167, DicomDictElt { vr : "SQ", vm: "1", name: "Observation Category Code Sequence (Trial)", retired: "Retired", keyword: "ObservationCategoryCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A168, DicomDictElt { vr : "SQ", vm: "1", name: "Concept Code Sequence", retired: "", keyword: "ConceptCodeSequence" });
-	dicom_dictionary.insert(0x0040A16A, DicomDictElt { vr : "ST", vm: "1", name: "Bibliographic Citation (Trial)", retired: "Retired", keyword: "BibliographicCitationTrial" });
-	dicom_dictionary.insert(0x0040A170, DicomDictElt { vr : "SQ", vm: "1", name: "Purpose of Reference Code Sequence", retired: "", keyword: "PurposeOfReferenceCodeSequence" });
-	dicom_dictionary.insert(0x0040A171, DicomDictElt { vr : "UI", vm: "1", name: "Observation UID", retired: "", keyword: "ObservationUID" });
-	dicom_dictionary.insert(0x0040A172, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Observation UID (Trial)", retired: "Retired", keyword: "ReferencedObservationUIDTrial" });
-	dicom_dictionary.insert(0x0040A173, DicomDictElt { vr : "CS", vm: "1", name: "Referenced Observation Class (Trial)", retired: "Retired", keyword: "ReferencedObservationClassTrial" });
-	dicom_dictionary.insert(0x0040A174, DicomDictElt { vr : "CS", vm: "1", name: "Referenced Object Observation Class (Trial)", retired: "Retired", keyword: "ReferencedObjectObservationClassTrial" });
-	dicom_dictionary.insert(0x0040A180, DicomDictElt { vr : "US", vm: "1", name: "Annotation Group Number", retired: "", keyword: "AnnotationGroupNumber" });
-	dicom_dictionary.insert(0x0040A192, DicomDictElt { vr : "DA", vm: "1", name: "Observation Date (Trial)", retired: "Retired", keyword: "ObservationDateTrial" });
-	dicom_dictionary.insert(0x0040A193, DicomDictElt { vr : "TM", vm: "1", name: "Observation Time (Trial)", retired: "Retired", keyword: "ObservationTimeTrial" });
-	dicom_dictionary.insert(0x0040A194, DicomDictElt { vr : "CS", vm: "1", name: "Measurement Automation (Trial)", retired: "Retired", keyword: "MeasurementAutomationTrial" });
-	dicom_dictionary.insert(0x0040A195, DicomDictElt { vr : "SQ", vm: "1", name: "Modifier Code Sequence", retired: "", keyword: "ModifierCodeSequence" });
-	dicom_dictionary.insert(0x0040A224, DicomDictElt { vr : "ST", vm: "1", name: "Identification Description (Trial)", retired: "Retired", keyword: "IdentificationDescriptionTrial" });
-	dicom_dictionary.insert(0x0040A290, DicomDictElt { vr : "CS", vm: "1", name: "Coordinates Set Geometric Type (Trial)", retired: "Retired", keyword: "CoordinatesSetGeometricTypeTrial" });
-	dicom_dictionary.insert(0x0040A296, DicomDictElt { vr : "SQ", vm: "1", name: "Algorithm Code Sequence (Trial)", retired: "Retired", keyword: "AlgorithmCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A297, DicomDictElt { vr : "ST", vm: "1", name: "Algorithm Description (Trial)", retired: "Retired", keyword: "AlgorithmDescriptionTrial" });
-	dicom_dictionary.insert(0x0040A29A, DicomDictElt { vr : "SL", vm: "2-2n", name: "Pixel Coordinates Set (Trial)", retired: "Retired", keyword: "PixelCoordinatesSetTrial" });
-	dicom_dictionary.insert(0x0040A300, DicomDictElt { vr : "SQ", vm: "1", name: "Measured Value Sequence", retired: "", keyword: "MeasuredValueSequence" });
-	dicom_dictionary.insert(0x0040A301, DicomDictElt { vr : "SQ", vm: "1", name: "Numeric Value Qualifier Code Sequence", retired: "", keyword: "NumericValueQualifierCodeSequence" });
-	dicom_dictionary.insert(0x0040A307, DicomDictElt { vr : "PN", vm: "1", name: "Current Observer (Trial)", retired: "Retired", keyword: "CurrentObserverTrial" });
-	dicom_dictionary.insert(0x0040A30A, DicomDictElt { vr : "DS", vm: "1-n", name: "Numeric Value", retired: "", keyword: "NumericValue" });
-	dicom_dictionary.insert(0x0040A313, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Accession Sequence (Trial)", retired: "Retired", keyword: "ReferencedAccessionSequenceTrial" });
-	dicom_dictionary.insert(0x0040A33A, DicomDictElt { vr : "ST", vm: "1", name: "Report Status Comment (Trial)", retired: "Retired", keyword: "ReportStatusCommentTrial" });
-	dicom_dictionary.insert(0x0040A340, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Context Sequence (Trial)", retired: "Retired", keyword: "ProcedureContextSequenceTrial" });
-	dicom_dictionary.insert(0x0040A352, DicomDictElt { vr : "PN", vm: "1", name: "Verbal Source (Trial)", retired: "Retired", keyword: "VerbalSourceTrial" });
-	dicom_dictionary.insert(0x0040A353, DicomDictElt { vr : "ST", vm: "1", name: "Address (Trial)", retired: "Retired", keyword: "AddressTrial" });
-	dicom_dictionary.insert(0x0040A354, DicomDictElt { vr : "LO", vm: "1", name: "Telephone Number (Trial)", retired: "Retired", keyword: "TelephoneNumberTrial" });
-	dicom_dictionary.insert(0x0040A358, DicomDictElt { vr : "SQ", vm: "1", name: "Verbal Source Identifier Code Sequence (Trial)", retired: "Retired", keyword: "VerbalSourceIdentifierCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A360, DicomDictElt { vr : "SQ", vm: "1", name: "Predecessor Documents Sequence", retired: "", keyword: "PredecessorDocumentsSequence" });
-	dicom_dictionary.insert(0x0040A370, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Request Sequence", retired: "", keyword: "ReferencedRequestSequence" });
-	dicom_dictionary.insert(0x0040A372, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Procedure Code Sequence", retired: "", keyword: "PerformedProcedureCodeSequence" });
-	dicom_dictionary.insert(0x0040A375, DicomDictElt { vr : "SQ", vm: "1", name: "Current Requested Procedure Evidence Sequence", retired: "", keyword: "CurrentRequestedProcedureEvidenceSequence" });
-	dicom_dictionary.insert(0x0040A380, DicomDictElt { vr : "SQ", vm: "1", name: "Report Detail Sequence (Trial)", retired: "Retired", keyword: "ReportDetailSequenceTrial" });
-	dicom_dictionary.insert(0x0040A385, DicomDictElt { vr : "SQ", vm: "1", name: "Pertinent Other Evidence Sequence", retired: "", keyword: "PertinentOtherEvidenceSequence" });
-	dicom_dictionary.insert(0x0040A390, DicomDictElt { vr : "SQ", vm: "1", name: "HL7 Structured Document Reference Sequence", retired: "", keyword: "HL7StructuredDocumentReferenceSequence" });
-	dicom_dictionary.insert(0x0040A402, DicomDictElt { vr : "UI", vm: "1", name: "Observation Subject UID (Trial)", retired: "Retired", keyword: "ObservationSubjectUIDTrial" });
-	dicom_dictionary.insert(0x0040A403, DicomDictElt { vr : "CS", vm: "1", name: "Observation Subject Class (Trial)", retired: "Retired", keyword: "ObservationSubjectClassTrial" });
-	dicom_dictionary.insert(0x0040A404, DicomDictElt { vr : "SQ", vm: "1", name: "Observation Subject Type Code Sequence (Trial)", retired: "Retired", keyword: "ObservationSubjectTypeCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A491, DicomDictElt { vr : "CS", vm: "1", name: "Completion Flag", retired: "", keyword: "CompletionFlag" });
-	dicom_dictionary.insert(0x0040A492, DicomDictElt { vr : "LO", vm: "1", name: "Completion Flag Description", retired: "", keyword: "CompletionFlagDescription" });
-	dicom_dictionary.insert(0x0040A493, DicomDictElt { vr : "CS", vm: "1", name: "Verification Flag", retired: "", keyword: "VerificationFlag" });
-	dicom_dictionary.insert(0x0040A494, DicomDictElt { vr : "CS", vm: "1", name: "Archive Requested", retired: "", keyword: "ArchiveRequested" });
-	dicom_dictionary.insert(0x0040A496, DicomDictElt { vr : "CS", vm: "1", name: "Preliminary Flag", retired: "", keyword: "PreliminaryFlag" });
-	dicom_dictionary.insert(0x0040A504, DicomDictElt { vr : "SQ", vm: "1", name: "Content Template Sequence", retired: "", keyword: "ContentTemplateSequence" });
-	dicom_dictionary.insert(0x0040A525, DicomDictElt { vr : "SQ", vm: "1", name: "Identical Documents Sequence", retired: "", keyword: "IdenticalDocumentsSequence" });
-	dicom_dictionary.insert(0x0040A600, DicomDictElt { vr : "CS", vm: "1", name: "Observation Subject Context Flag (Trial)", retired: "Retired", keyword: "ObservationSubjectContextFlagTrial" });
-	dicom_dictionary.insert(0x0040A601, DicomDictElt { vr : "CS", vm: "1", name: "Observer Context Flag (Trial)", retired: "Retired", keyword: "ObserverContextFlagTrial" });
-	dicom_dictionary.insert(0x0040A603, DicomDictElt { vr : "CS", vm: "1", name: "Procedure Context Flag (Trial)", retired: "Retired", keyword: "ProcedureContextFlagTrial" });
-	dicom_dictionary.insert(0x0040A730, DicomDictElt { vr : "SQ", vm: "1", name: "Content Sequence", retired: "", keyword: "ContentSequence" });
-	dicom_dictionary.insert(0x0040A731, DicomDictElt { vr : "SQ", vm: "1", name: "Relationship Sequence (Trial)", retired: "Retired", keyword: "RelationshipSequenceTrial" });
-	dicom_dictionary.insert(0x0040A732, DicomDictElt { vr : "SQ", vm: "1", name: "Relationship Type Code Sequence (Trial)", retired: "Retired", keyword: "RelationshipTypeCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A744, DicomDictElt { vr : "SQ", vm: "1", name: "Language Code Sequence (Trial)", retired: "Retired", keyword: "LanguageCodeSequenceTrial" });
-	dicom_dictionary.insert(0x0040A992, DicomDictElt { vr : "ST", vm: "1", name: "Uniform Resource Locator (Trial)", retired: "Retired", keyword: "UniformResourceLocatorTrial" });
-	dicom_dictionary.insert(0x0040B020, DicomDictElt { vr : "SQ", vm: "1", name: "Waveform Annotation Sequence", retired: "", keyword: "WaveformAnnotationSequence" });
-	dicom_dictionary.insert(0x0040DB00, DicomDictElt { vr : "CS", vm: "1", name: "Template Identifier", retired: "", keyword: "TemplateIdentifier" });
-	dicom_dictionary.insert(0x0040DB06, DicomDictElt { vr : "DT", vm: "1", name: "Template Version", retired: "Retired", keyword: "TemplateVersion" });
-	dicom_dictionary.insert(0x0040DB07, DicomDictElt { vr : "DT", vm: "1", name: "Template Local Version", retired: "Retired", keyword: "TemplateLocalVersion" });
-	dicom_dictionary.insert(0x0040DB0B, DicomDictElt { vr : "CS", vm: "1", name: "Template Extension Flag", retired: "Retired", keyword: "TemplateExtensionFlag" });
-	dicom_dictionary.insert(0x0040DB0C, DicomDictElt { vr : "UI", vm: "1", name: "Template Extension Organization UID", retired: "Retired", keyword: "TemplateExtensionOrganizationUID" });
-	dicom_dictionary.insert(0x0040DB0D, DicomDictElt { vr : "UI", vm: "1", name: "Template Extension Creator UID", retired: "Retired", keyword: "TemplateExtensionCreatorUID" });
-	dicom_dictionary.insert(0x0040DB73, DicomDictElt { vr : "UL", vm: "1-n", name: "Referenced Content Item Identifier", retired: "", keyword: "ReferencedContentItemIdentifier" });
-	dicom_dictionary.insert(0x0040E001, DicomDictElt { vr : "ST", vm: "1", name: "HL7 Instance Identifier", retired: "", keyword: "HL7InstanceIdentifier" });
-	dicom_dictionary.insert(0x0040E004, DicomDictElt { vr : "DT", vm: "1", name: "HL7 Document Effective Time", retired: "", keyword: "HL7DocumentEffectiveTime" });
-	dicom_dictionary.insert(0x0040E006, DicomDictElt { vr : "SQ", vm: "1", name: "HL7 Document Type Code Sequence", retired: "", keyword: "HL7DocumentTypeCodeSequence" });
-	dicom_dictionary.insert(0x0040E008, DicomDictElt { vr : "SQ", vm: "1", name: "Document Class Code Sequence", retired: "", keyword: "DocumentClassCodeSequence" });
-	dicom_dictionary.insert(0x0040E010, DicomDictElt { vr : "UR", vm: "1", name: "Retrieve URI", retired: "", keyword: "RetrieveURI" });
-	dicom_dictionary.insert(0x0040E011, DicomDictElt { vr : "UI", vm: "1", name: "Retrieve Location UID", retired: "", keyword: "RetrieveLocationUID" });
-	dicom_dictionary.insert(0x0040E020, DicomDictElt { vr : "CS", vm: "1", name: "Type of Instances", retired: "", keyword: "TypeOfInstances" });
-	dicom_dictionary.insert(0x0040E021, DicomDictElt { vr : "SQ", vm: "1", name: "DICOM Retrieval Sequence", retired: "", keyword: "DICOMRetrievalSequence" });
-	dicom_dictionary.insert(0x0040E022, DicomDictElt { vr : "SQ", vm: "1", name: "DICOM Media Retrieval Sequence", retired: "", keyword: "DICOMMediaRetrievalSequence" });
-	dicom_dictionary.insert(0x0040E023, DicomDictElt { vr : "SQ", vm: "1", name: "WADO Retrieval Sequence", retired: "", keyword: "WADORetrievalSequence" });
-	dicom_dictionary.insert(0x0040E024, DicomDictElt { vr : "SQ", vm: "1", name: "XDS Retrieval Sequence", retired: "", keyword: "XDSRetrievalSequence" });
-	dicom_dictionary.insert(0x0040E025, DicomDictElt { vr : "SQ", vm: "1", name: "WADO-RS Retrieval Sequence", retired: "", keyword: "WADORSRetrievalSequence" });
-	dicom_dictionary.insert(0x0040E030, DicomDictElt { vr : "UI", vm: "1", name: "Repository Unique ID", retired: "", keyword: "RepositoryUniqueID" });
-	dicom_dictionary.insert(0x0040E031, DicomDictElt { vr : "UI", vm: "1", name: "Home Community ID", retired: "", keyword: "HomeCommunityID" });
-	dicom_dictionary.insert(0x00420010, DicomDictElt { vr : "ST", vm: "1", name: "Document Title", retired: "", keyword: "DocumentTitle" });
-	dicom_dictionary.insert(0x00420011, DicomDictElt { vr : "OB", vm: "1", name: "Encapsulated Document", retired: "", keyword: "EncapsulatedDocument" });
-	dicom_dictionary.insert(0x00420012, DicomDictElt { vr : "LO", vm: "1", name: "MIME Type of Encapsulated Document", retired: "", keyword: "MIMETypeOfEncapsulatedDocument" });
-	dicom_dictionary.insert(0x00420013, DicomDictElt { vr : "SQ", vm: "1", name: "Source Instance Sequence", retired: "", keyword: "SourceInstanceSequence" });
-	dicom_dictionary.insert(0x00420014, DicomDictElt { vr : "LO", vm: "1-n", name: "List of MIME Types", retired: "", keyword: "ListOfMIMETypes" });
-	dicom_dictionary.insert(0x00440001, DicomDictElt { vr : "ST", vm: "1", name: "Product Package Identifier", retired: "", keyword: "ProductPackageIdentifier" });
-	dicom_dictionary.insert(0x00440002, DicomDictElt { vr : "CS", vm: "1", name: "Substance Administration Approval", retired: "", keyword: "SubstanceAdministrationApproval" });
-	dicom_dictionary.insert(0x00440003, DicomDictElt { vr : "LT", vm: "1", name: "Approval Status Further Description", retired: "", keyword: "ApprovalStatusFurtherDescription" });
-	dicom_dictionary.insert(0x00440004, DicomDictElt { vr : "DT", vm: "1", name: "Approval Status DateTime", retired: "", keyword: "ApprovalStatusDateTime" });
-	dicom_dictionary.insert(0x00440007, DicomDictElt { vr : "SQ", vm: "1", name: "Product Type Code Sequence", retired: "", keyword: "ProductTypeCodeSequence" });
-	dicom_dictionary.insert(0x00440008, DicomDictElt { vr : "LO", vm: "1-n", name: "Product Name", retired: "", keyword: "ProductName" });
-	dicom_dictionary.insert(0x00440009, DicomDictElt { vr : "LT", vm: "1", name: "Product Description", retired: "", keyword: "ProductDescription" });
-	dicom_dictionary.insert(0x0044000A, DicomDictElt { vr : "LO", vm: "1", name: "Product Lot Identifier", retired: "", keyword: "ProductLotIdentifier" });
-	dicom_dictionary.insert(0x0044000B, DicomDictElt { vr : "DT", vm: "1", name: "Product Expiration DateTime", retired: "", keyword: "ProductExpirationDateTime" });
-	dicom_dictionary.insert(0x00440010, DicomDictElt { vr : "DT", vm: "1", name: "Substance Administration DateTime", retired: "", keyword: "SubstanceAdministrationDateTime" });
-	dicom_dictionary.insert(0x00440011, DicomDictElt { vr : "LO", vm: "1", name: "Substance Administration Notes", retired: "", keyword: "SubstanceAdministrationNotes" });
-	dicom_dictionary.insert(0x00440012, DicomDictElt { vr : "LO", vm: "1", name: "Substance Administration Device ID", retired: "", keyword: "SubstanceAdministrationDeviceID" });
-	dicom_dictionary.insert(0x00440013, DicomDictElt { vr : "SQ", vm: "1", name: "Product Parameter Sequence", retired: "", keyword: "ProductParameterSequence" });
-	dicom_dictionary.insert(0x00440019, DicomDictElt { vr : "SQ", vm: "1", name: "Substance Administration Parameter Sequence", retired: "", keyword: "SubstanceAdministrationParameterSequence" });
-	dicom_dictionary.insert(0x00460012, DicomDictElt { vr : "LO", vm: "1", name: "Lens Description", retired: "", keyword: "LensDescription" });
-	dicom_dictionary.insert(0x00460014, DicomDictElt { vr : "SQ", vm: "1", name: "Right Lens Sequence", retired: "", keyword: "RightLensSequence" });
-	dicom_dictionary.insert(0x00460015, DicomDictElt { vr : "SQ", vm: "1", name: "Left Lens Sequence", retired: "", keyword: "LeftLensSequence" });
-	dicom_dictionary.insert(0x00460016, DicomDictElt { vr : "SQ", vm: "1", name: "Unspecified Laterality Lens Sequence", retired: "", keyword: "UnspecifiedLateralityLensSequence" });
-	dicom_dictionary.insert(0x00460018, DicomDictElt { vr : "SQ", vm: "1", name: "Cylinder Sequence", retired: "", keyword: "CylinderSequence" });
-	dicom_dictionary.insert(0x00460028, DicomDictElt { vr : "SQ", vm: "1", name: "Prism Sequence", retired: "", keyword: "PrismSequence" });
-	dicom_dictionary.insert(0x00460030, DicomDictElt { vr : "FD", vm: "1", name: "Horizontal Prism Power", retired: "", keyword: "HorizontalPrismPower" });
-	dicom_dictionary.insert(0x00460032, DicomDictElt { vr : "CS", vm: "1", name: "Horizontal Prism Base", retired: "", keyword: "HorizontalPrismBase" });
-	dicom_dictionary.insert(0x00460034, DicomDictElt { vr : "FD", vm: "1", name: "Vertical Prism Power", retired: "", keyword: "VerticalPrismPower" });
-	dicom_dictionary.insert(0x00460036, DicomDictElt { vr : "CS", vm: "1", name: "Vertical Prism Base", retired: "", keyword: "VerticalPrismBase" });
-	dicom_dictionary.insert(0x00460038, DicomDictElt { vr : "CS", vm: "1", name: "Lens Segment Type", retired: "", keyword: "LensSegmentType" });
-	dicom_dictionary.insert(0x00460040, DicomDictElt { vr : "FD", vm: "1", name: "Optical Transmittance", retired: "", keyword: "OpticalTransmittance" });
-	dicom_dictionary.insert(0x00460042, DicomDictElt { vr : "FD", vm: "1", name: "Channel Width", retired: "", keyword: "ChannelWidth" });
-	dicom_dictionary.insert(0x00460044, DicomDictElt { vr : "FD", vm: "1", name: "Pupil Size", retired: "", keyword: "PupilSize" });
-	dicom_dictionary.insert(0x00460046, DicomDictElt { vr : "FD", vm: "1", name: "Corneal Size", retired: "", keyword: "CornealSize" });
-	dicom_dictionary.insert(0x00460050, DicomDictElt { vr : "SQ", vm: "1", name: "Autorefraction Right Eye Sequence", retired: "", keyword: "AutorefractionRightEyeSequence" });
-	dicom_dictionary.insert(0x00460052, DicomDictElt { vr : "SQ", vm: "1", name: "Autorefraction Left Eye Sequence", retired: "", keyword: "AutorefractionLeftEyeSequence" });
-	dicom_dictionary.insert(0x00460060, DicomDictElt { vr : "FD", vm: "1", name: "Distance Pupillary Distance", retired: "", keyword: "DistancePupillaryDistance" });
-	dicom_dictionary.insert(0x00460062, DicomDictElt { vr : "FD", vm: "1", name: "Near Pupillary Distance", retired: "", keyword: "NearPupillaryDistance" });
-	dicom_dictionary.insert(0x00460063, DicomDictElt { vr : "FD", vm: "1", name: "Intermediate Pupillary Distance", retired: "", keyword: "IntermediatePupillaryDistance" });
-	dicom_dictionary.insert(0x00460064, DicomDictElt { vr : "FD", vm: "1", name: "Other Pupillary Distance", retired: "", keyword: "OtherPupillaryDistance" });
-	dicom_dictionary.insert(0x00460070, DicomDictElt { vr : "SQ", vm: "1", name: "Keratometry Right Eye Sequence", retired: "", keyword: "KeratometryRightEyeSequence" });
-	dicom_dictionary.insert(0x00460071, DicomDictElt { vr : "SQ", vm: "1", name: "Keratometry Left Eye Sequence", retired: "", keyword: "KeratometryLeftEyeSequence" });
-	dicom_dictionary.insert(0x00460074, DicomDictElt { vr : "SQ", vm: "1", name: "Steep Keratometric Axis Sequence", retired: "", keyword: "SteepKeratometricAxisSequence" });
-	dicom_dictionary.insert(0x00460075, DicomDictElt { vr : "FD", vm: "1", name: "Radius of Curvature", retired: "", keyword: "RadiusOfCurvature" });
-	dicom_dictionary.insert(0x00460076, DicomDictElt { vr : "FD", vm: "1", name: "Keratometric Power", retired: "", keyword: "KeratometricPower" });
-	dicom_dictionary.insert(0x00460077, DicomDictElt { vr : "FD", vm: "1", name: "Keratometric Axis", retired: "", keyword: "KeratometricAxis" });
-	dicom_dictionary.insert(0x00460080, DicomDictElt { vr : "SQ", vm: "1", name: "Flat Keratometric Axis Sequence", retired: "", keyword: "FlatKeratometricAxisSequence" });
-	dicom_dictionary.insert(0x00460092, DicomDictElt { vr : "CS", vm: "1", name: "Background Color", retired: "", keyword: "BackgroundColor" });
-	dicom_dictionary.insert(0x00460094, DicomDictElt { vr : "CS", vm: "1", name: "Optotype", retired: "", keyword: "Optotype" });
-	dicom_dictionary.insert(0x00460095, DicomDictElt { vr : "CS", vm: "1", name: "Optotype Presentation", retired: "", keyword: "OptotypePresentation" });
-	dicom_dictionary.insert(0x00460097, DicomDictElt { vr : "SQ", vm: "1", name: "Subjective Refraction Right Eye Sequence", retired: "", keyword: "SubjectiveRefractionRightEyeSequence" });
-	dicom_dictionary.insert(0x00460098, DicomDictElt { vr : "SQ", vm: "1", name: "Subjective Refraction Left Eye Sequence", retired: "", keyword: "SubjectiveRefractionLeftEyeSequence" });
-	dicom_dictionary.insert(0x00460100, DicomDictElt { vr : "SQ", vm: "1", name: "Add Near Sequence", retired: "", keyword: "AddNearSequence" });
-	dicom_dictionary.insert(0x00460101, DicomDictElt { vr : "SQ", vm: "1", name: "Add Intermediate Sequence", retired: "", keyword: "AddIntermediateSequence" });
-	dicom_dictionary.insert(0x00460102, DicomDictElt { vr : "SQ", vm: "1", name: "Add Other Sequence", retired: "", keyword: "AddOtherSequence" });
-	dicom_dictionary.insert(0x00460104, DicomDictElt { vr : "FD", vm: "1", name: "Add Power", retired: "", keyword: "AddPower" });
-	dicom_dictionary.insert(0x00460106, DicomDictElt { vr : "FD", vm: "1", name: "Viewing Distance", retired: "", keyword: "ViewingDistance" });
-	dicom_dictionary.insert(0x00460121, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Acuity Type Code Sequence", retired: "", keyword: "VisualAcuityTypeCodeSequence" });
-	dicom_dictionary.insert(0x00460122, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Acuity Right Eye Sequence", retired: "", keyword: "VisualAcuityRightEyeSequence" });
-	dicom_dictionary.insert(0x00460123, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Acuity Left Eye Sequence", retired: "", keyword: "VisualAcuityLeftEyeSequence" });
-	dicom_dictionary.insert(0x00460124, DicomDictElt { vr : "SQ", vm: "1", name: "Visual Acuity Both Eyes Open Sequence", retired: "", keyword: "VisualAcuityBothEyesOpenSequence" });
-	dicom_dictionary.insert(0x00460125, DicomDictElt { vr : "CS", vm: "1", name: "Viewing Distance Type", retired: "", keyword: "ViewingDistanceType" });
-	dicom_dictionary.insert(0x00460135, DicomDictElt { vr : "SS", vm: "2", name: "Visual Acuity Modifiers", retired: "", keyword: "VisualAcuityModifiers" });
-	dicom_dictionary.insert(0x00460137, DicomDictElt { vr : "FD", vm: "1", name: "Decimal Visual Acuity", retired: "", keyword: "DecimalVisualAcuity" });
-	dicom_dictionary.insert(0x00460139, DicomDictElt { vr : "LO", vm: "1", name: "Optotype Detailed Definition", retired: "", keyword: "OptotypeDetailedDefinition" });
-	dicom_dictionary.insert(0x00460145, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Refractive Measurements Sequence", retired: "", keyword: "ReferencedRefractiveMeasurementsSequence" });
-	dicom_dictionary.insert(0x00460146, DicomDictElt { vr : "FD", vm: "1", name: "Sphere Power", retired: "", keyword: "SpherePower" });
-	dicom_dictionary.insert(0x00460147, DicomDictElt { vr : "FD", vm: "1", name: "Cylinder Power", retired: "", keyword: "CylinderPower" });
-	dicom_dictionary.insert(0x00460201, DicomDictElt { vr : "CS", vm: "1", name: "Corneal Topography Surface", retired: "", keyword: "CornealTopographySurface" });
-	dicom_dictionary.insert(0x00460202, DicomDictElt { vr : "FL", vm: "2", name: "Corneal Vertex Location", retired: "", keyword: "CornealVertexLocation" });
-	dicom_dictionary.insert(0x00460203, DicomDictElt { vr : "FL", vm: "1", name: "Pupil Centroid X-Coordinate", retired: "", keyword: "PupilCentroidXCoordinate" });
-	dicom_dictionary.insert(0x00460204, DicomDictElt { vr : "FL", vm: "1", name: "Pupil Centroid Y-Coordinate", retired: "", keyword: "PupilCentroidYCoordinate" });
-	dicom_dictionary.insert(0x00460205, DicomDictElt { vr : "FL", vm: "1", name: "Equivalent Pupil Radius", retired: "", keyword: "EquivalentPupilRadius" });
-	dicom_dictionary.insert(0x00460207, DicomDictElt { vr : "SQ", vm: "1", name: "Corneal Topography Map Type Code Sequence", retired: "", keyword: "CornealTopographyMapTypeCodeSequence" });
-	dicom_dictionary.insert(0x00460208, DicomDictElt { vr : "IS", vm: "2-2n", name: "Vertices of the Outline of Pupil", retired: "", keyword: "VerticesOfTheOutlineOfPupil" });
-	dicom_dictionary.insert(0x00460210, DicomDictElt { vr : "SQ", vm: "1", name: "Corneal Topography Mapping Normals Sequence", retired: "", keyword: "CornealTopographyMappingNormalsSequence" });
-	dicom_dictionary.insert(0x00460211, DicomDictElt { vr : "SQ", vm: "1", name: "Maximum Corneal Curvature Sequence", retired: "", keyword: "MaximumCornealCurvatureSequence" });
-	dicom_dictionary.insert(0x00460212, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Corneal Curvature", retired: "", keyword: "MaximumCornealCurvature" });
-	dicom_dictionary.insert(0x00460213, DicomDictElt { vr : "FL", vm: "2", name: "Maximum Corneal Curvature Location", retired: "", keyword: "MaximumCornealCurvatureLocation" });
-	dicom_dictionary.insert(0x00460215, DicomDictElt { vr : "SQ", vm: "1", name: "Minimum Keratometric Sequence", retired: "", keyword: "MinimumKeratometricSequence" });
-	dicom_dictionary.insert(0x00460218, DicomDictElt { vr : "SQ", vm: "1", name: "Simulated Keratometric Cylinder Sequence", retired: "", keyword: "SimulatedKeratometricCylinderSequence" });
-	dicom_dictionary.insert(0x00460220, DicomDictElt { vr : "FL", vm: "1", name: "Average Corneal Power", retired: "", keyword: "AverageCornealPower" });
-	dicom_dictionary.insert(0x00460224, DicomDictElt { vr : "FL", vm: "1", name: "Corneal I-S Value", retired: "", keyword: "CornealISValue" });
-	dicom_dictionary.insert(0x00460227, DicomDictElt { vr : "FL", vm: "1", name: "Analyzed Area", retired: "", keyword: "AnalyzedArea" });
-	dicom_dictionary.insert(0x00460230, DicomDictElt { vr : "FL", vm: "1", name: "Surface Regularity Index", retired: "", keyword: "SurfaceRegularityIndex" });
-	dicom_dictionary.insert(0x00460232, DicomDictElt { vr : "FL", vm: "1", name: "Surface Asymmetry Index", retired: "", keyword: "SurfaceAsymmetryIndex" });
-	dicom_dictionary.insert(0x00460234, DicomDictElt { vr : "FL", vm: "1", name: "Corneal Eccentricity Index", retired: "", keyword: "CornealEccentricityIndex" });
-	dicom_dictionary.insert(0x00460236, DicomDictElt { vr : "FL", vm: "1", name: "Keratoconus Prediction Index", retired: "", keyword: "KeratoconusPredictionIndex" });
-	dicom_dictionary.insert(0x00460238, DicomDictElt { vr : "FL", vm: "1", name: "Decimal Potential Visual Acuity", retired: "", keyword: "DecimalPotentialVisualAcuity" });
-	dicom_dictionary.insert(0x00460242, DicomDictElt { vr : "CS", vm: "1", name: "Corneal Topography Map Quality Evaluation", retired: "", keyword: "CornealTopographyMapQualityEvaluation" });
-	dicom_dictionary.insert(0x00460244, DicomDictElt { vr : "SQ", vm: "1", name: "Source Image Corneal Processed Data Sequence", retired: "", keyword: "SourceImageCornealProcessedDataSequence" });
-	dicom_dictionary.insert(0x00460247, DicomDictElt { vr : "FL", vm: "3", name: "Corneal Point Location", retired: "", keyword: "CornealPointLocation" });
-	dicom_dictionary.insert(0x00460248, DicomDictElt { vr : "CS", vm: "1", name: "Corneal Point Estimated", retired: "", keyword: "CornealPointEstimated" });
-	dicom_dictionary.insert(0x00460249, DicomDictElt { vr : "FL", vm: "1", name: "Axial Power", retired: "", keyword: "AxialPower" });
-	dicom_dictionary.insert(0x00460250, DicomDictElt { vr : "FL", vm: "1", name: "Tangential Power", retired: "", keyword: "TangentialPower" });
-	dicom_dictionary.insert(0x00460251, DicomDictElt { vr : "FL", vm: "1", name: "Refractive Power", retired: "", keyword: "RefractivePower" });
-	dicom_dictionary.insert(0x00460252, DicomDictElt { vr : "FL", vm: "1", name: "Relative Elevation", retired: "", keyword: "RelativeElevation" });
-	dicom_dictionary.insert(0x00460253, DicomDictElt { vr : "FL", vm: "1", name: "Corneal Wavefront", retired: "", keyword: "CornealWavefront" });
-	dicom_dictionary.insert(0x00480001, DicomDictElt { vr : "FL", vm: "1", name: "Imaged Volume Width", retired: "", keyword: "ImagedVolumeWidth" });
-	dicom_dictionary.insert(0x00480002, DicomDictElt { vr : "FL", vm: "1", name: "Imaged Volume Height", retired: "", keyword: "ImagedVolumeHeight" });
-	dicom_dictionary.insert(0x00480003, DicomDictElt { vr : "FL", vm: "1", name: "Imaged Volume Depth", retired: "", keyword: "ImagedVolumeDepth" });
-	dicom_dictionary.insert(0x00480006, DicomDictElt { vr : "UL", vm: "1", name: "Total Pixel Matrix Columns", retired: "", keyword: "TotalPixelMatrixColumns" });
-	dicom_dictionary.insert(0x00480007, DicomDictElt { vr : "UL", vm: "1", name: "Total Pixel Matrix Rows", retired: "", keyword: "TotalPixelMatrixRows" });
-	dicom_dictionary.insert(0x00480008, DicomDictElt { vr : "SQ", vm: "1", name: "Total Pixel Matrix Origin Sequence", retired: "", keyword: "TotalPixelMatrixOriginSequence" });
-	dicom_dictionary.insert(0x00480010, DicomDictElt { vr : "CS", vm: "1", name: "Specimen Label in Image", retired: "", keyword: "SpecimenLabelInImage" });
-	dicom_dictionary.insert(0x00480011, DicomDictElt { vr : "CS", vm: "1", name: "Focus Method", retired: "", keyword: "FocusMethod" });
-	dicom_dictionary.insert(0x00480012, DicomDictElt { vr : "CS", vm: "1", name: "Extended Depth of Field", retired: "", keyword: "ExtendedDepthOfField" });
-	dicom_dictionary.insert(0x00480013, DicomDictElt { vr : "US", vm: "1", name: "Number of Focal Planes", retired: "", keyword: "NumberOfFocalPlanes" });
-	dicom_dictionary.insert(0x00480014, DicomDictElt { vr : "FL", vm: "1", name: "Distance Between Focal Planes", retired: "", keyword: "DistanceBetweenFocalPlanes" });
-	dicom_dictionary.insert(0x00480015, DicomDictElt { vr : "US", vm: "3", name: "Recommended Absent Pixel CIELab Value", retired: "", keyword: "RecommendedAbsentPixelCIELabValue" });
-	dicom_dictionary.insert(0x00480100, DicomDictElt { vr : "SQ", vm: "1", name: "Illuminator Type Code Sequence", retired: "", keyword: "IlluminatorTypeCodeSequence" });
-	dicom_dictionary.insert(0x00480102, DicomDictElt { vr : "DS", vm: "6", name: "Image Orientation (Slide)", retired: "", keyword: "ImageOrientationSlide" });
-	dicom_dictionary.insert(0x00480105, DicomDictElt { vr : "SQ", vm: "1", name: "Optical Path Sequence", retired: "", keyword: "OpticalPathSequence" });
-	dicom_dictionary.insert(0x00480106, DicomDictElt { vr : "SH", vm: "1", name: "Optical Path Identifier", retired: "", keyword: "OpticalPathIdentifier" });
-	dicom_dictionary.insert(0x00480107, DicomDictElt { vr : "ST", vm: "1", name: "Optical Path Description", retired: "", keyword: "OpticalPathDescription" });
-	dicom_dictionary.insert(0x00480108, DicomDictElt { vr : "SQ", vm: "1", name: "Illumination Color Code Sequence", retired: "", keyword: "IlluminationColorCodeSequence" });
-	dicom_dictionary.insert(0x00480110, DicomDictElt { vr : "SQ", vm: "1", name: "Specimen Reference Sequence", retired: "", keyword: "SpecimenReferenceSequence" });
-	dicom_dictionary.insert(0x00480111, DicomDictElt { vr : "DS", vm: "1", name: "Condenser Lens Power", retired: "", keyword: "CondenserLensPower" });
-	dicom_dictionary.insert(0x00480112, DicomDictElt { vr : "DS", vm: "1", name: "Objective Lens Power", retired: "", keyword: "ObjectiveLensPower" });
-	dicom_dictionary.insert(0x00480113, DicomDictElt { vr : "DS", vm: "1", name: "Objective Lens Numerical Aperture", retired: "", keyword: "ObjectiveLensNumericalAperture" });
-	dicom_dictionary.insert(0x00480120, DicomDictElt { vr : "SQ", vm: "1", name: "Palette Color Lookup Table Sequence", retired: "", keyword: "PaletteColorLookupTableSequence" });
-	dicom_dictionary.insert(0x00480200, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Navigation Sequence", retired: "", keyword: "ReferencedImageNavigationSequence" });
-	dicom_dictionary.insert(0x00480201, DicomDictElt { vr : "US", vm: "2", name: "Top Left Hand Corner of Localizer Area", retired: "", keyword: "TopLeftHandCornerOfLocalizerArea" });
-	dicom_dictionary.insert(0x00480202, DicomDictElt { vr : "US", vm: "2", name: "Bottom Right Hand Corner of Localizer Area", retired: "", keyword: "BottomRightHandCornerOfLocalizerArea" });
-	dicom_dictionary.insert(0x00480207, DicomDictElt { vr : "SQ", vm: "1", name: "Optical Path Identification Sequence", retired: "", keyword: "OpticalPathIdentificationSequence" });
-	dicom_dictionary.insert(0x0048021A, DicomDictElt { vr : "SQ", vm: "1", name: "Plane Position (Slide) Sequence", retired: "", keyword: "PlanePositionSlideSequence" });
-	dicom_dictionary.insert(0x0048021E, DicomDictElt { vr : "SL", vm: "1", name: "Column Position In Total Image Pixel Matrix", retired: "", keyword: "ColumnPositionInTotalImagePixelMatrix" });
-	dicom_dictionary.insert(0x0048021F, DicomDictElt { vr : "SL", vm: "1", name: "Row Position In Total Image Pixel Matrix", retired: "", keyword: "RowPositionInTotalImagePixelMatrix" });
-	dicom_dictionary.insert(0x00480301, DicomDictElt { vr : "CS", vm: "1", name: "Pixel Origin Interpretation", retired: "", keyword: "PixelOriginInterpretation" });
-	dicom_dictionary.insert(0x00500004, DicomDictElt { vr : "CS", vm: "1", name: "Calibration Image", retired: "", keyword: "CalibrationImage" });
-	dicom_dictionary.insert(0x00500010, DicomDictElt { vr : "SQ", vm: "1", name: "Device Sequence", retired: "", keyword: "DeviceSequence" });
-	dicom_dictionary.insert(0x00500012, DicomDictElt { vr : "SQ", vm: "1", name: "Container Component Type Code Sequence", retired: "", keyword: "ContainerComponentTypeCodeSequence" });
-	dicom_dictionary.insert(0x00500013, DicomDictElt { vr : "FD", vm: "1", name: "Container Component Thickness", retired: "", keyword: "ContainerComponentThickness" });
-	dicom_dictionary.insert(0x00500014, DicomDictElt { vr : "DS", vm: "1", name: "Device Length", retired: "", keyword: "DeviceLength" });
-	dicom_dictionary.insert(0x00500015, DicomDictElt { vr : "FD", vm: "1", name: "Container Component Width", retired: "", keyword: "ContainerComponentWidth" });
-	dicom_dictionary.insert(0x00500016, DicomDictElt { vr : "DS", vm: "1", name: "Device Diameter", retired: "", keyword: "DeviceDiameter" });
-	dicom_dictionary.insert(0x00500017, DicomDictElt { vr : "CS", vm: "1", name: "Device Diameter Units", retired: "", keyword: "DeviceDiameterUnits" });
-	dicom_dictionary.insert(0x00500018, DicomDictElt { vr : "DS", vm: "1", name: "Device Volume", retired: "", keyword: "DeviceVolume" });
-	dicom_dictionary.insert(0x00500019, DicomDictElt { vr : "DS", vm: "1", name: "Inter-Marker Distance", retired: "", keyword: "InterMarkerDistance" });
-	dicom_dictionary.insert(0x0050001A, DicomDictElt { vr : "CS", vm: "1", name: "Container Component Material", retired: "", keyword: "ContainerComponentMaterial" });
-	dicom_dictionary.insert(0x0050001B, DicomDictElt { vr : "LO", vm: "1", name: "Container Component ID", retired: "", keyword: "ContainerComponentID" });
-	dicom_dictionary.insert(0x0050001C, DicomDictElt { vr : "FD", vm: "1", name: "Container Component Length", retired: "", keyword: "ContainerComponentLength" });
-	dicom_dictionary.insert(0x0050001D, DicomDictElt { vr : "FD", vm: "1", name: "Container Component Diameter", retired: "", keyword: "ContainerComponentDiameter" });
-	dicom_dictionary.insert(0x0050001E, DicomDictElt { vr : "LO", vm: "1", name: "Container Component Description", retired: "", keyword: "ContainerComponentDescription" });
-	dicom_dictionary.insert(0x00500020, DicomDictElt { vr : "LO", vm: "1", name: "Device Description", retired: "", keyword: "DeviceDescription" });
-	dicom_dictionary.insert(0x00520001, DicomDictElt { vr : "FL", vm: "1", name: "Contrast/Bolus Ingredient Percent by Volume", retired: "", keyword: "ContrastBolusIngredientPercentByVolume" });
-	dicom_dictionary.insert(0x00520002, DicomDictElt { vr : "FD", vm: "1", name: "OCT Focal Distance", retired: "", keyword: "OCTFocalDistance" });
-	dicom_dictionary.insert(0x00520003, DicomDictElt { vr : "FD", vm: "1", name: "Beam Spot Size", retired: "", keyword: "BeamSpotSize" });
-	dicom_dictionary.insert(0x00520004, DicomDictElt { vr : "FD", vm: "1", name: "Effective Refractive Index", retired: "", keyword: "EffectiveRefractiveIndex" });
-	dicom_dictionary.insert(0x00520006, DicomDictElt { vr : "CS", vm: "1", name: "OCT Acquisition Domain", retired: "", keyword: "OCTAcquisitionDomain" });
-	dicom_dictionary.insert(0x00520007, DicomDictElt { vr : "FD", vm: "1", name: "OCT Optical Center Wavelength", retired: "", keyword: "OCTOpticalCenterWavelength" });
-	dicom_dictionary.insert(0x00520008, DicomDictElt { vr : "FD", vm: "1", name: "Axial Resolution", retired: "", keyword: "AxialResolution" });
-	dicom_dictionary.insert(0x00520009, DicomDictElt { vr : "FD", vm: "1", name: "Ranging Depth", retired: "", keyword: "RangingDepth" });
-	dicom_dictionary.insert(0x00520011, DicomDictElt { vr : "FD", vm: "1", name: "A-line Rate", retired: "", keyword: "ALineRate" });
-	dicom_dictionary.insert(0x00520012, DicomDictElt { vr : "US", vm: "1", name: "A-lines Per Frame", retired: "", keyword: "ALinesPerFrame" });
-	dicom_dictionary.insert(0x00520013, DicomDictElt { vr : "FD", vm: "1", name: "Catheter Rotational Rate", retired: "", keyword: "CatheterRotationalRate" });
-	dicom_dictionary.insert(0x00520014, DicomDictElt { vr : "FD", vm: "1", name: "A-line Pixel Spacing", retired: "", keyword: "ALinePixelSpacing" });
-	dicom_dictionary.insert(0x00520016, DicomDictElt { vr : "SQ", vm: "1", name: "Mode of Percutaneous Access Sequence", retired: "", keyword: "ModeOfPercutaneousAccessSequence" });
-	dicom_dictionary.insert(0x00520025, DicomDictElt { vr : "SQ", vm: "1", name: "Intravascular OCT Frame Type Sequence", retired: "", keyword: "IntravascularOCTFrameTypeSequence" });
-	dicom_dictionary.insert(0x00520026, DicomDictElt { vr : "CS", vm: "1", name: "OCT Z Offset Applied", retired: "", keyword: "OCTZOffsetApplied" });
-	dicom_dictionary.insert(0x00520027, DicomDictElt { vr : "SQ", vm: "1", name: "Intravascular Frame Content Sequence", retired: "", keyword: "IntravascularFrameContentSequence" });
-	dicom_dictionary.insert(0x00520028, DicomDictElt { vr : "FD", vm: "1", name: "Intravascular Longitudinal Distance", retired: "", keyword: "IntravascularLongitudinalDistance" });
-	dicom_dictionary.insert(0x00520029, DicomDictElt { vr : "SQ", vm: "1", name: "Intravascular OCT Frame Content Sequence", retired: "", keyword: "IntravascularOCTFrameContentSequence" });
-	dicom_dictionary.insert(0x00520030, DicomDictElt { vr : "SS", vm: "1", name: "OCT Z Offset Correction", retired: "", keyword: "OCTZOffsetCorrection" });
-	dicom_dictionary.insert(0x00520031, DicomDictElt { vr : "CS", vm: "1", name: "Catheter Direction of Rotation", retired: "", keyword: "CatheterDirectionOfRotation" });
-	dicom_dictionary.insert(0x00520033, DicomDictElt { vr : "FD", vm: "1", name: "Seam Line Location", retired: "", keyword: "SeamLineLocation" });
-	dicom_dictionary.insert(0x00520034, DicomDictElt { vr : "FD", vm: "1", name: "First A-line Location", retired: "", keyword: "FirstALineLocation" });
-	dicom_dictionary.insert(0x00520036, DicomDictElt { vr : "US", vm: "1", name: "Seam Line Index", retired: "", keyword: "SeamLineIndex" });
-	dicom_dictionary.insert(0x00520038, DicomDictElt { vr : "US", vm: "1", name: "Number of Padded A-lines", retired: "", keyword: "NumberOfPaddedALines" });
-	dicom_dictionary.insert(0x00520039, DicomDictElt { vr : "CS", vm: "1", name: "Interpolation Type", retired: "", keyword: "InterpolationType" });
-	dicom_dictionary.insert(0x0052003A, DicomDictElt { vr : "CS", vm: "1", name: "Refractive Index Applied", retired: "", keyword: "RefractiveIndexApplied" });
-	dicom_dictionary.insert(0x00540010, DicomDictElt { vr : "US", vm: "1-n", name: "Energy Window Vector", retired: "", keyword: "EnergyWindowVector" });
-	dicom_dictionary.insert(0x00540011, DicomDictElt { vr : "US", vm: "1", name: "Number of Energy Windows", retired: "", keyword: "NumberOfEnergyWindows" });
-	dicom_dictionary.insert(0x00540012, DicomDictElt { vr : "SQ", vm: "1", name: "Energy Window Information Sequence", retired: "", keyword: "EnergyWindowInformationSequence" });
-	dicom_dictionary.insert(0x00540013, DicomDictElt { vr : "SQ", vm: "1", name: "Energy Window Range Sequence", retired: "", keyword: "EnergyWindowRangeSequence" });
-	dicom_dictionary.insert(0x00540014, DicomDictElt { vr : "DS", vm: "1", name: "Energy Window Lower Limit", retired: "", keyword: "EnergyWindowLowerLimit" });
-	dicom_dictionary.insert(0x00540015, DicomDictElt { vr : "DS", vm: "1", name: "Energy Window Upper Limit", retired: "", keyword: "EnergyWindowUpperLimit" });
-	dicom_dictionary.insert(0x00540016, DicomDictElt { vr : "SQ", vm: "1", name: "Radiopharmaceutical Information Sequence", retired: "", keyword: "RadiopharmaceuticalInformationSequence" });
-	dicom_dictionary.insert(0x00540017, DicomDictElt { vr : "IS", vm: "1", name: "Residual Syringe Counts", retired: "", keyword: "ResidualSyringeCounts" });
-	dicom_dictionary.insert(0x00540018, DicomDictElt { vr : "SH", vm: "1", name: "Energy Window Name", retired: "", keyword: "EnergyWindowName" });
-	dicom_dictionary.insert(0x00540020, DicomDictElt { vr : "US", vm: "1-n", name: "Detector Vector", retired: "", keyword: "DetectorVector" });
-	dicom_dictionary.insert(0x00540021, DicomDictElt { vr : "US", vm: "1", name: "Number of Detectors", retired: "", keyword: "NumberOfDetectors" });
-	dicom_dictionary.insert(0x00540022, DicomDictElt { vr : "SQ", vm: "1", name: "Detector Information Sequence", retired: "", keyword: "DetectorInformationSequence" });
-	dicom_dictionary.insert(0x00540030, DicomDictElt { vr : "US", vm: "1-n", name: "Phase Vector", retired: "", keyword: "PhaseVector" });
-	dicom_dictionary.insert(0x00540031, DicomDictElt { vr : "US", vm: "1", name: "Number of Phases", retired: "", keyword: "NumberOfPhases" });
-	dicom_dictionary.insert(0x00540032, DicomDictElt { vr : "SQ", vm: "1", name: "Phase Information Sequence", retired: "", keyword: "PhaseInformationSequence" });
-	dicom_dictionary.insert(0x00540033, DicomDictElt { vr : "US", vm: "1", name: "Number of Frames in Phase", retired: "", keyword: "NumberOfFramesInPhase" });
-	dicom_dictionary.insert(0x00540036, DicomDictElt { vr : "IS", vm: "1", name: "Phase Delay", retired: "", keyword: "PhaseDelay" });
-	dicom_dictionary.insert(0x00540038, DicomDictElt { vr : "IS", vm: "1", name: "Pause Between Frames", retired: "", keyword: "PauseBetweenFrames" });
-	dicom_dictionary.insert(0x00540039, DicomDictElt { vr : "CS", vm: "1", name: "Phase Description", retired: "", keyword: "PhaseDescription" });
-	dicom_dictionary.insert(0x00540050, DicomDictElt { vr : "US", vm: "1-n", name: "Rotation Vector", retired: "", keyword: "RotationVector" });
-	dicom_dictionary.insert(0x00540051, DicomDictElt { vr : "US", vm: "1", name: "Number of Rotations", retired: "", keyword: "NumberOfRotations" });
-	dicom_dictionary.insert(0x00540052, DicomDictElt { vr : "SQ", vm: "1", name: "Rotation Information Sequence", retired: "", keyword: "RotationInformationSequence" });
-	dicom_dictionary.insert(0x00540053, DicomDictElt { vr : "US", vm: "1", name: "Number of Frames in Rotation", retired: "", keyword: "NumberOfFramesInRotation" });
-	dicom_dictionary.insert(0x00540060, DicomDictElt { vr : "US", vm: "1-n", name: "R-R Interval Vector", retired: "", keyword: "RRIntervalVector" });
-	dicom_dictionary.insert(0x00540061, DicomDictElt { vr : "US", vm: "1", name: "Number of R-R Intervals", retired: "", keyword: "NumberOfRRIntervals" });
-	dicom_dictionary.insert(0x00540062, DicomDictElt { vr : "SQ", vm: "1", name: "Gated Information Sequence", retired: "", keyword: "GatedInformationSequence" });
-	dicom_dictionary.insert(0x00540063, DicomDictElt { vr : "SQ", vm: "1", name: "Data Information Sequence", retired: "", keyword: "DataInformationSequence" });
-	dicom_dictionary.insert(0x00540070, DicomDictElt { vr : "US", vm: "1-n", name: "Time Slot Vector", retired: "", keyword: "TimeSlotVector" });
-	dicom_dictionary.insert(0x00540071, DicomDictElt { vr : "US", vm: "1", name: "Number of Time Slots", retired: "", keyword: "NumberOfTimeSlots" });
-	dicom_dictionary.insert(0x00540072, DicomDictElt { vr : "SQ", vm: "1", name: "Time Slot Information Sequence", retired: "", keyword: "TimeSlotInformationSequence" });
-	dicom_dictionary.insert(0x00540073, DicomDictElt { vr : "DS", vm: "1", name: "Time Slot Time", retired: "", keyword: "TimeSlotTime" });
-	dicom_dictionary.insert(0x00540080, DicomDictElt { vr : "US", vm: "1-n", name: "Slice Vector", retired: "", keyword: "SliceVector" });
-	dicom_dictionary.insert(0x00540081, DicomDictElt { vr : "US", vm: "1", name: "Number of Slices", retired: "", keyword: "NumberOfSlices" });
-	dicom_dictionary.insert(0x00540090, DicomDictElt { vr : "US", vm: "1-n", name: "Angular View Vector", retired: "", keyword: "AngularViewVector" });
-	dicom_dictionary.insert(0x00540100, DicomDictElt { vr : "US", vm: "1-n", name: "Time Slice Vector", retired: "", keyword: "TimeSliceVector" });
-	dicom_dictionary.insert(0x00540101, DicomDictElt { vr : "US", vm: "1", name: "Number of Time Slices", retired: "", keyword: "NumberOfTimeSlices" });
-	dicom_dictionary.insert(0x00540200, DicomDictElt { vr : "DS", vm: "1", name: "Start Angle", retired: "", keyword: "StartAngle" });
-	dicom_dictionary.insert(0x00540202, DicomDictElt { vr : "CS", vm: "1", name: "Type of Detector Motion", retired: "", keyword: "TypeOfDetectorMotion" });
-	dicom_dictionary.insert(0x00540210, DicomDictElt { vr : "IS", vm: "1-n", name: "Trigger Vector", retired: "", keyword: "TriggerVector" });
-	dicom_dictionary.insert(0x00540211, DicomDictElt { vr : "US", vm: "1", name: "Number of Triggers in Phase", retired: "", keyword: "NumberOfTriggersInPhase" });
-	dicom_dictionary.insert(0x00540220, DicomDictElt { vr : "SQ", vm: "1", name: "View Code Sequence", retired: "", keyword: "ViewCodeSequence" });
-	dicom_dictionary.insert(0x00540222, DicomDictElt { vr : "SQ", vm: "1", name: "View Modifier Code Sequence", retired: "", keyword: "ViewModifierCodeSequence" });
-	dicom_dictionary.insert(0x00540300, DicomDictElt { vr : "SQ", vm: "1", name: "Radionuclide Code Sequence", retired: "", keyword: "RadionuclideCodeSequence" });
-	dicom_dictionary.insert(0x00540302, DicomDictElt { vr : "SQ", vm: "1", name: "Administration Route Code Sequence", retired: "", keyword: "AdministrationRouteCodeSequence" });
-	dicom_dictionary.insert(0x00540304, DicomDictElt { vr : "SQ", vm: "1", name: "Radiopharmaceutical Code Sequence", retired: "", keyword: "RadiopharmaceuticalCodeSequence" });
-	dicom_dictionary.insert(0x00540306, DicomDictElt { vr : "SQ", vm: "1", name: "Calibration Data Sequence", retired: "", keyword: "CalibrationDataSequence" });
-	dicom_dictionary.insert(0x00540308, DicomDictElt { vr : "US", vm: "1", name: "Energy Window Number", retired: "", keyword: "EnergyWindowNumber" });
-	dicom_dictionary.insert(0x00540400, DicomDictElt { vr : "SH", vm: "1", name: "Image ID", retired: "", keyword: "ImageID" });
-	dicom_dictionary.insert(0x00540410, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Orientation Code Sequence", retired: "", keyword: "PatientOrientationCodeSequence" });
-	dicom_dictionary.insert(0x00540412, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Orientation Modifier Code Sequence", retired: "", keyword: "PatientOrientationModifierCodeSequence" });
-	dicom_dictionary.insert(0x00540414, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Gantry Relationship Code Sequence", retired: "", keyword: "PatientGantryRelationshipCodeSequence" });
-	dicom_dictionary.insert(0x00540500, DicomDictElt { vr : "CS", vm: "1", name: "Slice Progression Direction", retired: "", keyword: "SliceProgressionDirection" });
-	dicom_dictionary.insert(0x00540501, DicomDictElt { vr : "CS", vm: "1", name: "Scan Progression Direction", retired: "", keyword: "ScanProgressionDirection" });
-	dicom_dictionary.insert(0x00541000, DicomDictElt { vr : "CS", vm: "2", name: "Series Type", retired: "", keyword: "SeriesType" });
-	dicom_dictionary.insert(0x00541001, DicomDictElt { vr : "CS", vm: "1", name: "Units", retired: "", keyword: "Units" });
-	dicom_dictionary.insert(0x00541002, DicomDictElt { vr : "CS", vm: "1", name: "Counts Source", retired: "", keyword: "CountsSource" });
-	dicom_dictionary.insert(0x00541004, DicomDictElt { vr : "CS", vm: "1", name: "Reprojection Method", retired: "", keyword: "ReprojectionMethod" });
-	dicom_dictionary.insert(0x00541006, DicomDictElt { vr : "CS", vm: "1", name: "SUV Type", retired: "", keyword: "SUVType" });
-	dicom_dictionary.insert(0x00541100, DicomDictElt { vr : "CS", vm: "1", name: "Randoms Correction Method", retired: "", keyword: "RandomsCorrectionMethod" });
-	dicom_dictionary.insert(0x00541101, DicomDictElt { vr : "LO", vm: "1", name: "Attenuation Correction Method", retired: "", keyword: "AttenuationCorrectionMethod" });
-	dicom_dictionary.insert(0x00541102, DicomDictElt { vr : "CS", vm: "1", name: "Decay Correction", retired: "", keyword: "DecayCorrection" });
-	dicom_dictionary.insert(0x00541103, DicomDictElt { vr : "LO", vm: "1", name: "Reconstruction Method", retired: "", keyword: "ReconstructionMethod" });
-	dicom_dictionary.insert(0x00541104, DicomDictElt { vr : "LO", vm: "1", name: "Detector Lines of Response Used", retired: "", keyword: "DetectorLinesOfResponseUsed" });
-	dicom_dictionary.insert(0x00541105, DicomDictElt { vr : "LO", vm: "1", name: "Scatter Correction Method", retired: "", keyword: "ScatterCorrectionMethod" });
-	dicom_dictionary.insert(0x00541200, DicomDictElt { vr : "DS", vm: "1", name: "Axial Acceptance", retired: "", keyword: "AxialAcceptance" });
-	dicom_dictionary.insert(0x00541201, DicomDictElt { vr : "IS", vm: "2", name: "Axial Mash", retired: "", keyword: "AxialMash" });
-	dicom_dictionary.insert(0x00541202, DicomDictElt { vr : "IS", vm: "1", name: "Transverse Mash", retired: "", keyword: "TransverseMash" });
-	dicom_dictionary.insert(0x00541203, DicomDictElt { vr : "DS", vm: "2", name: "Detector Element Size", retired: "", keyword: "DetectorElementSize" });
-	dicom_dictionary.insert(0x00541210, DicomDictElt { vr : "DS", vm: "1", name: "Coincidence Window Width", retired: "", keyword: "CoincidenceWindowWidth" });
-	dicom_dictionary.insert(0x00541220, DicomDictElt { vr : "CS", vm: "1-n", name: "Secondary Counts Type", retired: "", keyword: "SecondaryCountsType" });
-	dicom_dictionary.insert(0x00541300, DicomDictElt { vr : "DS", vm: "1", name: "Frame Reference Time", retired: "", keyword: "FrameReferenceTime" });
-	dicom_dictionary.insert(0x00541310, DicomDictElt { vr : "IS", vm: "1", name: "Primary (Prompts) Counts Accumulated", retired: "", keyword: "PrimaryPromptsCountsAccumulated" });
-	dicom_dictionary.insert(0x00541311, DicomDictElt { vr : "IS", vm: "1-n", name: "Secondary Counts Accumulated", retired: "", keyword: "SecondaryCountsAccumulated" });
-	dicom_dictionary.insert(0x00541320, DicomDictElt { vr : "DS", vm: "1", name: "Slice Sensitivity Factor", retired: "", keyword: "SliceSensitivityFactor" });
-	dicom_dictionary.insert(0x00541321, DicomDictElt { vr : "DS", vm: "1", name: "Decay Factor", retired: "", keyword: "DecayFactor" });
-	dicom_dictionary.insert(0x00541322, DicomDictElt { vr : "DS", vm: "1", name: "Dose Calibration Factor", retired: "", keyword: "DoseCalibrationFactor" });
-	dicom_dictionary.insert(0x00541323, DicomDictElt { vr : "DS", vm: "1", name: "Scatter Fraction Factor", retired: "", keyword: "ScatterFractionFactor" });
-	dicom_dictionary.insert(0x00541324, DicomDictElt { vr : "DS", vm: "1", name: "Dead Time Factor", retired: "", keyword: "DeadTimeFactor" });
-	dicom_dictionary.insert(0x00541330, DicomDictElt { vr : "US", vm: "1", name: "Image Index", retired: "", keyword: "ImageIndex" });
-	dicom_dictionary.insert(0x00541400, DicomDictElt { vr : "CS", vm: "1-n", name: "Counts Included", retired: "Retired", keyword: "CountsIncluded" });
-	dicom_dictionary.insert(0x00541401, DicomDictElt { vr : "CS", vm: "1", name: "Dead Time Correction Flag", retired: "Retired", keyword: "DeadTimeCorrectionFlag" });
-	dicom_dictionary.insert(0x00603000, DicomDictElt { vr : "SQ", vm: "1", name: "Histogram Sequence", retired: "", keyword: "HistogramSequence" });
-	dicom_dictionary.insert(0x00603002, DicomDictElt { vr : "US", vm: "1", name: "Histogram Number of Bins", retired: "", keyword: "HistogramNumberOfBins" });
-	dicom_dictionary.insert(0x00603004, DicomDictElt { vr : "US or SS", vm: "1", name: "Histogram First Bin Value", retired: "", keyword: "HistogramFirstBinValue" });
-	dicom_dictionary.insert(0x00603006, DicomDictElt { vr : "US or SS", vm: "1", name: "Histogram Last Bin Value", retired: "", keyword: "HistogramLastBinValue" });
-	dicom_dictionary.insert(0x00603008, DicomDictElt { vr : "US", vm: "1", name: "Histogram Bin Width", retired: "", keyword: "HistogramBinWidth" });
-	dicom_dictionary.insert(0x00603010, DicomDictElt { vr : "LO", vm: "1", name: "Histogram Explanation", retired: "", keyword: "HistogramExplanation" });
-	dicom_dictionary.insert(0x00603020, DicomDictElt { vr : "UL", vm: "1-n", name: "Histogram Data", retired: "", keyword: "HistogramData" });
-	dicom_dictionary.insert(0x00620001, DicomDictElt { vr : "CS", vm: "1", name: "Segmentation Type", retired: "", keyword: "SegmentationType" });
-	dicom_dictionary.insert(0x00620002, DicomDictElt { vr : "SQ", vm: "1", name: "Segment Sequence", retired: "", keyword: "SegmentSequence" });
-	dicom_dictionary.insert(0x00620003, DicomDictElt { vr : "SQ", vm: "1", name: "Segmented Property Category Code Sequence", retired: "", keyword: "SegmentedPropertyCategoryCodeSequence" });
-	dicom_dictionary.insert(0x00620004, DicomDictElt { vr : "US", vm: "1", name: "Segment Number", retired: "", keyword: "SegmentNumber" });
-	dicom_dictionary.insert(0x00620005, DicomDictElt { vr : "LO", vm: "1", name: "Segment Label", retired: "", keyword: "SegmentLabel" });
-	dicom_dictionary.insert(0x00620006, DicomDictElt { vr : "ST", vm: "1", name: "Segment Description", retired: "", keyword: "SegmentDescription" });
-	dicom_dictionary.insert(0x00620007, DicomDictElt { vr : "SQ", vm: "1", name: "Segmentation Algorithm Identification Sequence", retired: "", keyword: "SegmentationAlgorithmIdentificationSequence" });
-	dicom_dictionary.insert(0x00620008, DicomDictElt { vr : "CS", vm: "1", name: "Segment Algorithm Type", retired: "", keyword: "SegmentAlgorithmType" });
-	dicom_dictionary.insert(0x00620009, DicomDictElt { vr : "LO", vm: "1", name: "Segment Algorithm Name", retired: "", keyword: "SegmentAlgorithmName" });
-	dicom_dictionary.insert(0x0062000A, DicomDictElt { vr : "SQ", vm: "1", name: "Segment Identification Sequence", retired: "", keyword: "SegmentIdentificationSequence" });
-	dicom_dictionary.insert(0x0062000B, DicomDictElt { vr : "US", vm: "1-n", name: "Referenced Segment Number", retired: "", keyword: "ReferencedSegmentNumber" });
-	dicom_dictionary.insert(0x0062000C, DicomDictElt { vr : "US", vm: "1", name: "Recommended Display Grayscale Value", retired: "", keyword: "RecommendedDisplayGrayscaleValue" });
-	dicom_dictionary.insert(0x0062000D, DicomDictElt { vr : "US", vm: "3", name: "Recommended Display CIELab Value", retired: "", keyword: "RecommendedDisplayCIELabValue" });
-	dicom_dictionary.insert(0x0062000E, DicomDictElt { vr : "US", vm: "1", name: "Maximum Fractional Value", retired: "", keyword: "MaximumFractionalValue" });
-	dicom_dictionary.insert(0x0062000F, DicomDictElt { vr : "SQ", vm: "1", name: "Segmented Property Type Code Sequence", retired: "", keyword: "SegmentedPropertyTypeCodeSequence" });
-	dicom_dictionary.insert(0x00620010, DicomDictElt { vr : "CS", vm: "1", name: "Segmentation Fractional Type", retired: "", keyword: "SegmentationFractionalType" });
-	dicom_dictionary.insert(0x00620011, DicomDictElt { vr : "SQ", vm: "1", name: "Segmented Property Type Modifier Code Sequence", retired: "", keyword: "SegmentedPropertyTypeModifierCodeSequence" });
-	dicom_dictionary.insert(0x00620012, DicomDictElt { vr : "SQ", vm: "1", name: "Used Segments Sequence", retired: "", keyword: "UsedSegmentsSequence" });
-	dicom_dictionary.insert(0x00620020, DicomDictElt { vr : "UT", vm: "1", name: "Tracking ID", retired: "", keyword: "TrackingID" });
-	dicom_dictionary.insert(0x00620021, DicomDictElt { vr : "UI", vm: "1", name: "Tracking UID", retired: "", keyword: "TrackingUID" });
-	dicom_dictionary.insert(0x00640002, DicomDictElt { vr : "SQ", vm: "1", name: "Deformable Registration Sequence", retired: "", keyword: "DeformableRegistrationSequence" });
-	dicom_dictionary.insert(0x00640003, DicomDictElt { vr : "UI", vm: "1", name: "Source Frame of Reference UID", retired: "", keyword: "SourceFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x00640005, DicomDictElt { vr : "SQ", vm: "1", name: "Deformable Registration Grid Sequence", retired: "", keyword: "DeformableRegistrationGridSequence" });
-	dicom_dictionary.insert(0x00640007, DicomDictElt { vr : "UL", vm: "3", name: "Grid Dimensions", retired: "", keyword: "GridDimensions" });
-	dicom_dictionary.insert(0x00640008, DicomDictElt { vr : "FD", vm: "3", name: "Grid Resolution", retired: "", keyword: "GridResolution" });
-	dicom_dictionary.insert(0x00640009, DicomDictElt { vr : "OF", vm: "1", name: "Vector Grid Data", retired: "", keyword: "VectorGridData" });
-	dicom_dictionary.insert(0x0064000F, DicomDictElt { vr : "SQ", vm: "1", name: "Pre Deformation Matrix Registration Sequence", retired: "", keyword: "PreDeformationMatrixRegistrationSequence" });
-	dicom_dictionary.insert(0x00640010, DicomDictElt { vr : "SQ", vm: "1", name: "Post Deformation Matrix Registration Sequence", retired: "", keyword: "PostDeformationMatrixRegistrationSequence" });
-	dicom_dictionary.insert(0x00660001, DicomDictElt { vr : "UL", vm: "1", name: "Number of Surfaces", retired: "", keyword: "NumberOfSurfaces" });
-	dicom_dictionary.insert(0x00660002, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Sequence", retired: "", keyword: "SurfaceSequence" });
-	dicom_dictionary.insert(0x00660003, DicomDictElt { vr : "UL", vm: "1", name: "Surface Number", retired: "", keyword: "SurfaceNumber" });
-	dicom_dictionary.insert(0x00660004, DicomDictElt { vr : "LT", vm: "1", name: "Surface Comments", retired: "", keyword: "SurfaceComments" });
-	dicom_dictionary.insert(0x00660009, DicomDictElt { vr : "CS", vm: "1", name: "Surface Processing", retired: "", keyword: "SurfaceProcessing" });
-	dicom_dictionary.insert(0x0066000A, DicomDictElt { vr : "FL", vm: "1", name: "Surface Processing Ratio", retired: "", keyword: "SurfaceProcessingRatio" });
-	dicom_dictionary.insert(0x0066000B, DicomDictElt { vr : "LO", vm: "1", name: "Surface Processing Description", retired: "", keyword: "SurfaceProcessingDescription" });
-	dicom_dictionary.insert(0x0066000C, DicomDictElt { vr : "FL", vm: "1", name: "Recommended Presentation Opacity", retired: "", keyword: "RecommendedPresentationOpacity" });
-	dicom_dictionary.insert(0x0066000D, DicomDictElt { vr : "CS", vm: "1", name: "Recommended Presentation Type", retired: "", keyword: "RecommendedPresentationType" });
-	dicom_dictionary.insert(0x0066000E, DicomDictElt { vr : "CS", vm: "1", name: "Finite Volume", retired: "", keyword: "FiniteVolume" });
-	dicom_dictionary.insert(0x00660010, DicomDictElt { vr : "CS", vm: "1", name: "Manifold", retired: "", keyword: "Manifold" });
-	dicom_dictionary.insert(0x00660011, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Points Sequence", retired: "", keyword: "SurfacePointsSequence" });
-	dicom_dictionary.insert(0x00660012, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Points Normals Sequence", retired: "", keyword: "SurfacePointsNormalsSequence" });
-	dicom_dictionary.insert(0x00660013, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Mesh Primitives Sequence", retired: "", keyword: "SurfaceMeshPrimitivesSequence" });
-	dicom_dictionary.insert(0x00660015, DicomDictElt { vr : "UL", vm: "1", name: "Number of Surface Points", retired: "", keyword: "NumberOfSurfacePoints" });
-	dicom_dictionary.insert(0x00660016, DicomDictElt { vr : "OF", vm: "1", name: "Point Coordinates Data", retired: "", keyword: "PointCoordinatesData" });
-	dicom_dictionary.insert(0x00660017, DicomDictElt { vr : "FL", vm: "3", name: "Point Position Accuracy", retired: "", keyword: "PointPositionAccuracy" });
-	dicom_dictionary.insert(0x00660018, DicomDictElt { vr : "FL", vm: "1", name: "Mean Point Distance", retired: "", keyword: "MeanPointDistance" });
-	dicom_dictionary.insert(0x00660019, DicomDictElt { vr : "FL", vm: "1", name: "Maximum Point Distance", retired: "", keyword: "MaximumPointDistance" });
-	dicom_dictionary.insert(0x0066001A, DicomDictElt { vr : "FL", vm: "6", name: "Points Bounding Box Coordinates", retired: "", keyword: "PointsBoundingBoxCoordinates" });
-	dicom_dictionary.insert(0x0066001B, DicomDictElt { vr : "FL", vm: "3", name: "Axis of Rotation", retired: "", keyword: "AxisOfRotation" });
-	dicom_dictionary.insert(0x0066001C, DicomDictElt { vr : "FL", vm: "3", name: "Center of Rotation", retired: "", keyword: "CenterOfRotation" });
-	dicom_dictionary.insert(0x0066001E, DicomDictElt { vr : "UL", vm: "1", name: "Number of Vectors", retired: "", keyword: "NumberOfVectors" });
-	dicom_dictionary.insert(0x0066001F, DicomDictElt { vr : "US", vm: "1", name: "Vector Dimensionality", retired: "", keyword: "VectorDimensionality" });
-	dicom_dictionary.insert(0x00660020, DicomDictElt { vr : "FL", vm: "1-n", name: "Vector Accuracy", retired: "", keyword: "VectorAccuracy" });
-	dicom_dictionary.insert(0x00660021, DicomDictElt { vr : "OF", vm: "1", name: "Vector Coordinate Data", retired: "", keyword: "VectorCoordinateData" });
-	dicom_dictionary.insert(0x00660023, DicomDictElt { vr : "OW", vm: "1", name: "Triangle Point Index List", retired: "Retired", keyword: "TrianglePointIndexList" });
-	dicom_dictionary.insert(0x00660024, DicomDictElt { vr : "OW", vm: "1", name: "Edge Point Index List", retired: "Retired", keyword: "EdgePointIndexList" });
-	dicom_dictionary.insert(0x00660025, DicomDictElt { vr : "OW", vm: "1", name: "Vertex Point Index List", retired: "Retired", keyword: "VertexPointIndexList" });
-	dicom_dictionary.insert(0x00660026, DicomDictElt { vr : "SQ", vm: "1", name: "Triangle Strip Sequence", retired: "", keyword: "TriangleStripSequence" });
-	dicom_dictionary.insert(0x00660027, DicomDictElt { vr : "SQ", vm: "1", name: "Triangle Fan Sequence", retired: "", keyword: "TriangleFanSequence" });
-	dicom_dictionary.insert(0x00660028, DicomDictElt { vr : "SQ", vm: "1", name: "Line Sequence", retired: "", keyword: "LineSequence" });
-	dicom_dictionary.insert(0x00660029, DicomDictElt { vr : "OW", vm: "1", name: "Primitive Point Index List", retired: "Retired", keyword: "PrimitivePointIndexList" });
-	dicom_dictionary.insert(0x0066002A, DicomDictElt { vr : "UL", vm: "1", name: "Surface Count", retired: "", keyword: "SurfaceCount" });
-	dicom_dictionary.insert(0x0066002B, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Surface Sequence", retired: "", keyword: "ReferencedSurfaceSequence" });
-	dicom_dictionary.insert(0x0066002C, DicomDictElt { vr : "UL", vm: "1", name: "Referenced Surface Number", retired: "", keyword: "ReferencedSurfaceNumber" });
-	dicom_dictionary.insert(0x0066002D, DicomDictElt { vr : "SQ", vm: "1", name: "Segment Surface Generation Algorithm Identification Sequence", retired: "", keyword: "SegmentSurfaceGenerationAlgorithmIdentificationSequence" });
-	dicom_dictionary.insert(0x0066002E, DicomDictElt { vr : "SQ", vm: "1", name: "Segment Surface Source Instance Sequence", retired: "", keyword: "SegmentSurfaceSourceInstanceSequence" });
-	dicom_dictionary.insert(0x0066002F, DicomDictElt { vr : "SQ", vm: "1", name: "Algorithm Family Code Sequence", retired: "", keyword: "AlgorithmFamilyCodeSequence" });
-	dicom_dictionary.insert(0x00660030, DicomDictElt { vr : "SQ", vm: "1", name: "Algorithm Name Code Sequence", retired: "", keyword: "AlgorithmNameCodeSequence" });
-	dicom_dictionary.insert(0x00660031, DicomDictElt { vr : "LO", vm: "1", name: "Algorithm Version", retired: "", keyword: "AlgorithmVersion" });
-	dicom_dictionary.insert(0x00660032, DicomDictElt { vr : "LT", vm: "1", name: "Algorithm Parameters", retired: "", keyword: "AlgorithmParameters" });
-	dicom_dictionary.insert(0x00660034, DicomDictElt { vr : "SQ", vm: "1", name: "Facet Sequence", retired: "", keyword: "FacetSequence" });
-	dicom_dictionary.insert(0x00660035, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Processing Algorithm Identification Sequence", retired: "", keyword: "SurfaceProcessingAlgorithmIdentificationSequence" });
-	dicom_dictionary.insert(0x00660036, DicomDictElt { vr : "LO", vm: "1", name: "Algorithm Name", retired: "", keyword: "AlgorithmName" });
-	dicom_dictionary.insert(0x00660037, DicomDictElt { vr : "FL", vm: "1", name: "Recommended Point Radius", retired: "", keyword: "RecommendedPointRadius" });
-	dicom_dictionary.insert(0x00660038, DicomDictElt { vr : "FL", vm: "1", name: "Recommended Line Thickness", retired: "", keyword: "RecommendedLineThickness" });
-	dicom_dictionary.insert(0x00660040, DicomDictElt { vr : "OL", vm: "1", name: "Long Primitive Point Index List", retired: "", keyword: "LongPrimitivePointIndexList" });
-	dicom_dictionary.insert(0x00660041, DicomDictElt { vr : "OL", vm: "1", name: "Long Triangle Point Index List", retired: "", keyword: "LongTrianglePointIndexList" });
-	dicom_dictionary.insert(0x00660042, DicomDictElt { vr : "OL", vm: "1", name: "Long Edge Point Index List", retired: "", keyword: "LongEdgePointIndexList" });
-	dicom_dictionary.insert(0x00660043, DicomDictElt { vr : "OL", vm: "1", name: "Long Vertex Point Index List", retired: "", keyword: "LongVertexPointIndexList" });
-	dicom_dictionary.insert(0x00660101, DicomDictElt { vr : "SQ", vm: "1", name: "Track Set Sequence", retired: "", keyword: "TrackSetSequence" });
-	dicom_dictionary.insert(0x00660102, DicomDictElt { vr : "SQ", vm: "1", name: "Track Sequence", retired: "", keyword: "TrackSequence" });
-	dicom_dictionary.insert(0x00660103, DicomDictElt { vr : "OW", vm: "1", name: "Recommended Display CIELab Value List", retired: "", keyword: "RecommendedDisplayCIELabValueList" });
-	dicom_dictionary.insert(0x00660104, DicomDictElt { vr : "SQ", vm: "1", name: "Tracking Algorithm Identification Sequence", retired: "", keyword: "TrackingAlgorithmIdentificationSequence" });
-	dicom_dictionary.insert(0x00660105, DicomDictElt { vr : "UL", vm: "1", name: "Track Set Number", retired: "", keyword: "TrackSetNumber" });
-	dicom_dictionary.insert(0x00660106, DicomDictElt { vr : "LO", vm: "1", name: "Track Set Label", retired: "", keyword: "TrackSetLabel" });
-	dicom_dictionary.insert(0x00660107, DicomDictElt { vr : "UT", vm: "1", name: "Track Set Description", retired: "", keyword: "TrackSetDescription" });
-	dicom_dictionary.insert(0x00660108, DicomDictElt { vr : "SQ", vm: "1", name: "Track Set Anatomical Type Code Sequence", retired: "", keyword: "TrackSetAnatomicalTypeCodeSequence" });
-	dicom_dictionary.insert(0x00660121, DicomDictElt { vr : "SQ", vm: "1", name: "Measurements Sequence", retired: "", keyword: "MeasurementsSequence" });
-	dicom_dictionary.insert(0x00660124, DicomDictElt { vr : "SQ", vm: "1", name: "Track Set Statistics Sequence", retired: "", keyword: "TrackSetStatisticsSequence" });
-	dicom_dictionary.insert(0x00660125, DicomDictElt { vr : "OF", vm: "1", name: "Floating Point Values", retired: "", keyword: "FloatingPointValues" });
-	dicom_dictionary.insert(0x00660129, DicomDictElt { vr : "OL", vm: "1", name: "Track Point Index List", retired: "", keyword: "TrackPointIndexList" });
-	dicom_dictionary.insert(0x00660130, DicomDictElt { vr : "SQ", vm: "1", name: "Track Statistics Sequence", retired: "", keyword: "TrackStatisticsSequence" });
-	dicom_dictionary.insert(0x00660132, DicomDictElt { vr : "SQ", vm: "1", name: "Measurement Values Sequence", retired: "", keyword: "MeasurementValuesSequence" });
-	dicom_dictionary.insert(0x00660133, DicomDictElt { vr : "SQ", vm: "1", name: "Diffusion Acquisition Code Sequence", retired: "", keyword: "DiffusionAcquisitionCodeSequence" });
-	dicom_dictionary.insert(0x00660134, DicomDictElt { vr : "SQ", vm: "1", name: "Diffusion Model Code Sequence", retired: "", keyword: "DiffusionModelCodeSequence" });
-	dicom_dictionary.insert(0x00686210, DicomDictElt { vr : "LO", vm: "1", name: "Implant Size", retired: "", keyword: "ImplantSize" });
-	dicom_dictionary.insert(0x00686221, DicomDictElt { vr : "LO", vm: "1", name: "Implant Template Version", retired: "", keyword: "ImplantTemplateVersion" });
-	dicom_dictionary.insert(0x00686222, DicomDictElt { vr : "SQ", vm: "1", name: "Replaced Implant Template Sequence", retired: "", keyword: "ReplacedImplantTemplateSequence" });
-	dicom_dictionary.insert(0x00686223, DicomDictElt { vr : "CS", vm: "1", name: "Implant Type", retired: "", keyword: "ImplantType" });
-	dicom_dictionary.insert(0x00686224, DicomDictElt { vr : "SQ", vm: "1", name: "Derivation Implant Template Sequence", retired: "", keyword: "DerivationImplantTemplateSequence" });
-	dicom_dictionary.insert(0x00686225, DicomDictElt { vr : "SQ", vm: "1", name: "Original Implant Template Sequence", retired: "", keyword: "OriginalImplantTemplateSequence" });
-	dicom_dictionary.insert(0x00686226, DicomDictElt { vr : "DT", vm: "1", name: "Effective DateTime", retired: "", keyword: "EffectiveDateTime" });
-	dicom_dictionary.insert(0x00686230, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Target Anatomy Sequence", retired: "", keyword: "ImplantTargetAnatomySequence" });
-	dicom_dictionary.insert(0x00686260, DicomDictElt { vr : "SQ", vm: "1", name: "Information From Manufacturer Sequence", retired: "", keyword: "InformationFromManufacturerSequence" });
-	dicom_dictionary.insert(0x00686265, DicomDictElt { vr : "SQ", vm: "1", name: "Notification From Manufacturer Sequence", retired: "", keyword: "NotificationFromManufacturerSequence" });
-	dicom_dictionary.insert(0x00686270, DicomDictElt { vr : "DT", vm: "1", name: "Information Issue DateTime", retired: "", keyword: "InformationIssueDateTime" });
-	dicom_dictionary.insert(0x00686280, DicomDictElt { vr : "ST", vm: "1", name: "Information Summary", retired: "", keyword: "InformationSummary" });
-	dicom_dictionary.insert(0x006862A0, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Regulatory Disapproval Code Sequence", retired: "", keyword: "ImplantRegulatoryDisapprovalCodeSequence" });
-	dicom_dictionary.insert(0x006862A5, DicomDictElt { vr : "FD", vm: "1", name: "Overall Template Spatial Tolerance", retired: "", keyword: "OverallTemplateSpatialTolerance" });
-	dicom_dictionary.insert(0x006862C0, DicomDictElt { vr : "SQ", vm: "1", name: "HPGL Document Sequence", retired: "", keyword: "HPGLDocumentSequence" });
-	dicom_dictionary.insert(0x006862D0, DicomDictElt { vr : "US", vm: "1", name: "HPGL Document ID", retired: "", keyword: "HPGLDocumentID" });
-	dicom_dictionary.insert(0x006862D5, DicomDictElt { vr : "LO", vm: "1", name: "HPGL Document Label", retired: "", keyword: "HPGLDocumentLabel" });
-	dicom_dictionary.insert(0x006862E0, DicomDictElt { vr : "SQ", vm: "1", name: "View Orientation Code Sequence", retired: "", keyword: "ViewOrientationCodeSequence" });
-	dicom_dictionary.insert(0x006862F0, DicomDictElt { vr : "FD", vm: "9", name: "View Orientation Modifier", retired: "", keyword: "ViewOrientationModifier" });
-	dicom_dictionary.insert(0x006862F2, DicomDictElt { vr : "FD", vm: "1", name: "HPGL Document Scaling", retired: "", keyword: "HPGLDocumentScaling" });
-	dicom_dictionary.insert(0x00686300, DicomDictElt { vr : "OB", vm: "1", name: "HPGL Document", retired: "", keyword: "HPGLDocument" });
-	dicom_dictionary.insert(0x00686310, DicomDictElt { vr : "US", vm: "1", name: "HPGL Contour Pen Number", retired: "", keyword: "HPGLContourPenNumber" });
-	dicom_dictionary.insert(0x00686320, DicomDictElt { vr : "SQ", vm: "1", name: "HPGL Pen Sequence", retired: "", keyword: "HPGLPenSequence" });
-	dicom_dictionary.insert(0x00686330, DicomDictElt { vr : "US", vm: "1", name: "HPGL Pen Number", retired: "", keyword: "HPGLPenNumber" });
-	dicom_dictionary.insert(0x00686340, DicomDictElt { vr : "LO", vm: "1", name: "HPGL Pen Label", retired: "", keyword: "HPGLPenLabel" });
-	dicom_dictionary.insert(0x00686345, DicomDictElt { vr : "ST", vm: "1", name: "HPGL Pen Description", retired: "", keyword: "HPGLPenDescription" });
-	dicom_dictionary.insert(0x00686346, DicomDictElt { vr : "FD", vm: "2", name: "Recommended Rotation Point", retired: "", keyword: "RecommendedRotationPoint" });
-	dicom_dictionary.insert(0x00686347, DicomDictElt { vr : "FD", vm: "4", name: "Bounding Rectangle", retired: "", keyword: "BoundingRectangle" });
-	dicom_dictionary.insert(0x00686350, DicomDictElt { vr : "US", vm: "1-n", name: "Implant Template 3D Model Surface Number", retired: "", keyword: "ImplantTemplate3DModelSurfaceNumber" });
-	dicom_dictionary.insert(0x00686360, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Model Description Sequence", retired: "", keyword: "SurfaceModelDescriptionSequence" });
-	dicom_dictionary.insert(0x00686380, DicomDictElt { vr : "LO", vm: "1", name: "Surface Model Label", retired: "", keyword: "SurfaceModelLabel" });
-	dicom_dictionary.insert(0x00686390, DicomDictElt { vr : "FD", vm: "1", name: "Surface Model Scaling Factor", retired: "", keyword: "SurfaceModelScalingFactor" });
-	dicom_dictionary.insert(0x006863A0, DicomDictElt { vr : "SQ", vm: "1", name: "Materials Code Sequence", retired: "", keyword: "MaterialsCodeSequence" });
-	dicom_dictionary.insert(0x006863A4, DicomDictElt { vr : "SQ", vm: "1", name: "Coating Materials Code Sequence", retired: "", keyword: "CoatingMaterialsCodeSequence" });
-	dicom_dictionary.insert(0x006863A8, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Type Code Sequence", retired: "", keyword: "ImplantTypeCodeSequence" });
-	dicom_dictionary.insert(0x006863AC, DicomDictElt { vr : "SQ", vm: "1", name: "Fixation Method Code Sequence", retired: "", keyword: "FixationMethodCodeSequence" });
-	dicom_dictionary.insert(0x006863B0, DicomDictElt { vr : "SQ", vm: "1", name: "Mating Feature Sets Sequence", retired: "", keyword: "MatingFeatureSetsSequence" });
-	dicom_dictionary.insert(0x006863C0, DicomDictElt { vr : "US", vm: "1", name: "Mating Feature Set ID", retired: "", keyword: "MatingFeatureSetID" });
-	dicom_dictionary.insert(0x006863D0, DicomDictElt { vr : "LO", vm: "1", name: "Mating Feature Set Label", retired: "", keyword: "MatingFeatureSetLabel" });
-	dicom_dictionary.insert(0x006863E0, DicomDictElt { vr : "SQ", vm: "1", name: "Mating Feature Sequence", retired: "", keyword: "MatingFeatureSequence" });
-	dicom_dictionary.insert(0x006863F0, DicomDictElt { vr : "US", vm: "1", name: "Mating Feature ID", retired: "", keyword: "MatingFeatureID" });
-	dicom_dictionary.insert(0x00686400, DicomDictElt { vr : "SQ", vm: "1", name: "Mating Feature Degree of Freedom Sequence", retired: "", keyword: "MatingFeatureDegreeOfFreedomSequence" });
-	dicom_dictionary.insert(0x00686410, DicomDictElt { vr : "US", vm: "1", name: "Degree of Freedom ID", retired: "", keyword: "DegreeOfFreedomID" });
-	dicom_dictionary.insert(0x00686420, DicomDictElt { vr : "CS", vm: "1", name: "Degree of Freedom Type", retired: "", keyword: "DegreeOfFreedomType" });
-	dicom_dictionary.insert(0x00686430, DicomDictElt { vr : "SQ", vm: "1", name: "2D Mating Feature Coordinates Sequence", retired: "", keyword: "TwoDMatingFeatureCoordinatesSequence" });
-	dicom_dictionary.insert(0x00686440, DicomDictElt { vr : "US", vm: "1", name: "Referenced HPGL Document ID", retired: "", keyword: "ReferencedHPGLDocumentID" });
-	dicom_dictionary.insert(0x00686450, DicomDictElt { vr : "FD", vm: "2", name: "2D Mating Point", retired: "", keyword: "TwoDMatingPoint" });
-	dicom_dictionary.insert(0x00686460, DicomDictElt { vr : "FD", vm: "4", name: "2D Mating Axes", retired: "", keyword: "TwoDMatingAxes" });
-	dicom_dictionary.insert(0x00686470, DicomDictElt { vr : "SQ", vm: "1", name: "2D Degree of Freedom Sequence", retired: "", keyword: "TwoDDegreeOfFreedomSequence" });
-	dicom_dictionary.insert(0x00686490, DicomDictElt { vr : "FD", vm: "3", name: "3D Degree of Freedom Axis", retired: "", keyword: "ThreeDDegreeOfFreedomAxis" });
-	dicom_dictionary.insert(0x006864A0, DicomDictElt { vr : "FD", vm: "2", name: "Range of Freedom", retired: "", keyword: "RangeOfFreedom" });
-	dicom_dictionary.insert(0x006864C0, DicomDictElt { vr : "FD", vm: "3", name: "3D Mating Point", retired: "", keyword: "ThreeDMatingPoint" });
-	dicom_dictionary.insert(0x006864D0, DicomDictElt { vr : "FD", vm: "9", name: "3D Mating Axes", retired: "", keyword: "ThreeDMatingAxes" });
-	dicom_dictionary.insert(0x006864F0, DicomDictElt { vr : "FD", vm: "3", name: "2D Degree of Freedom Axis", retired: "", keyword: "TwoDDegreeOfFreedomAxis" });
-	dicom_dictionary.insert(0x00686500, DicomDictElt { vr : "SQ", vm: "1", name: "Planning Landmark Point Sequence", retired: "", keyword: "PlanningLandmarkPointSequence" });
-	dicom_dictionary.insert(0x00686510, DicomDictElt { vr : "SQ", vm: "1", name: "Planning Landmark Line Sequence", retired: "", keyword: "PlanningLandmarkLineSequence" });
-	dicom_dictionary.insert(0x00686520, DicomDictElt { vr : "SQ", vm: "1", name: "Planning Landmark Plane Sequence", retired: "", keyword: "PlanningLandmarkPlaneSequence" });
-	dicom_dictionary.insert(0x00686530, DicomDictElt { vr : "US", vm: "1", name: "Planning Landmark ID", retired: "", keyword: "PlanningLandmarkID" });
-	dicom_dictionary.insert(0x00686540, DicomDictElt { vr : "LO", vm: "1", name: "Planning Landmark Description", retired: "", keyword: "PlanningLandmarkDescription" });
-	dicom_dictionary.insert(0x00686545, DicomDictElt { vr : "SQ", vm: "1", name: "Planning Landmark Identification Code Sequence", retired: "", keyword: "PlanningLandmarkIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x00686550, DicomDictElt { vr : "SQ", vm: "1", name: "2D Point Coordinates Sequence", retired: "", keyword: "TwoDPointCoordinatesSequence" });
-	dicom_dictionary.insert(0x00686560, DicomDictElt { vr : "FD", vm: "2", name: "2D Point Coordinates", retired: "", keyword: "TwoDPointCoordinates" });
-	dicom_dictionary.insert(0x00686590, DicomDictElt { vr : "FD", vm: "3", name: "3D Point Coordinates", retired: "", keyword: "ThreeDPointCoordinates" });
-	dicom_dictionary.insert(0x006865A0, DicomDictElt { vr : "SQ", vm: "1", name: "2D Line Coordinates Sequence", retired: "", keyword: "TwoDLineCoordinatesSequence" });
-	dicom_dictionary.insert(0x006865B0, DicomDictElt { vr : "FD", vm: "4", name: "2D Line Coordinates", retired: "", keyword: "TwoDLineCoordinates" });
-	dicom_dictionary.insert(0x006865D0, DicomDictElt { vr : "FD", vm: "6", name: "3D Line Coordinates", retired: "", keyword: "ThreeDLineCoordinates" });
-	dicom_dictionary.insert(0x006865E0, DicomDictElt { vr : "SQ", vm: "1", name: "2D Plane Coordinates Sequence", retired: "", keyword: "TwoDPlaneCoordinatesSequence" });
-	dicom_dictionary.insert(0x006865F0, DicomDictElt { vr : "FD", vm: "4", name: "2D Plane Intersection", retired: "", keyword: "TwoDPlaneIntersection" });
-	dicom_dictionary.insert(0x00686610, DicomDictElt { vr : "FD", vm: "3", name: "3D Plane Origin", retired: "", keyword: "ThreeDPlaneOrigin" });
-	dicom_dictionary.insert(0x00686620, DicomDictElt { vr : "FD", vm: "3", name: "3D Plane Normal", retired: "", keyword: "ThreeDPlaneNormal" });
-	dicom_dictionary.insert(0x00700001, DicomDictElt { vr : "SQ", vm: "1", name: "Graphic Annotation Sequence", retired: "", keyword: "GraphicAnnotationSequence" });
-	dicom_dictionary.insert(0x00700002, DicomDictElt { vr : "CS", vm: "1", name: "Graphic Layer", retired: "", keyword: "GraphicLayer" });
-	dicom_dictionary.insert(0x00700003, DicomDictElt { vr : "CS", vm: "1", name: "Bounding Box Annotation Units", retired: "", keyword: "BoundingBoxAnnotationUnits" });
-	dicom_dictionary.insert(0x00700004, DicomDictElt { vr : "CS", vm: "1", name: "Anchor Point Annotation Units", retired: "", keyword: "AnchorPointAnnotationUnits" });
-	dicom_dictionary.insert(0x00700005, DicomDictElt { vr : "CS", vm: "1", name: "Graphic Annotation Units", retired: "", keyword: "GraphicAnnotationUnits" });
-	dicom_dictionary.insert(0x00700006, DicomDictElt { vr : "ST", vm: "1", name: "Unformatted Text Value", retired: "", keyword: "UnformattedTextValue" });
-	dicom_dictionary.insert(0x00700008, DicomDictElt { vr : "SQ", vm: "1", name: "Text Object Sequence", retired: "", keyword: "TextObjectSequence" });
-	dicom_dictionary.insert(0x00700009, DicomDictElt { vr : "SQ", vm: "1", name: "Graphic Object Sequence", retired: "", keyword: "GraphicObjectSequence" });
-	dicom_dictionary.insert(0x00700010, DicomDictElt { vr : "FL", vm: "2", name: "Bounding Box Top Left Hand Corner", retired: "", keyword: "BoundingBoxTopLeftHandCorner" });
-	dicom_dictionary.insert(0x00700011, DicomDictElt { vr : "FL", vm: "2", name: "Bounding Box Bottom Right Hand Corner", retired: "", keyword: "BoundingBoxBottomRightHandCorner" });
-	dicom_dictionary.insert(0x00700012, DicomDictElt { vr : "CS", vm: "1", name: "Bounding Box Text Horizontal Justification", retired: "", keyword: "BoundingBoxTextHorizontalJustification" });
-	dicom_dictionary.insert(0x00700014, DicomDictElt { vr : "FL", vm: "2", name: "Anchor Point", retired: "", keyword: "AnchorPoint" });
-	dicom_dictionary.insert(0x00700015, DicomDictElt { vr : "CS", vm: "1", name: "Anchor Point Visibility", retired: "", keyword: "AnchorPointVisibility" });
-	dicom_dictionary.insert(0x00700020, DicomDictElt { vr : "US", vm: "1", name: "Graphic Dimensions", retired: "", keyword: "GraphicDimensions" });
-	dicom_dictionary.insert(0x00700021, DicomDictElt { vr : "US", vm: "1", name: "Number of Graphic Points", retired: "", keyword: "NumberOfGraphicPoints" });
-	dicom_dictionary.insert(0x00700022, DicomDictElt { vr : "FL", vm: "2-n", name: "Graphic Data", retired: "", keyword: "GraphicData" });
-	dicom_dictionary.insert(0x00700023, DicomDictElt { vr : "CS", vm: "1", name: "Graphic Type", retired: "", keyword: "GraphicType" });
-	dicom_dictionary.insert(0x00700024, DicomDictElt { vr : "CS", vm: "1", name: "Graphic Filled", retired: "", keyword: "GraphicFilled" });
-	dicom_dictionary.insert(0x00700040, DicomDictElt { vr : "IS", vm: "1", name: "Image Rotation (Retired)", retired: "Retired", keyword: "ImageRotationRetired" });
-	dicom_dictionary.insert(0x00700041, DicomDictElt { vr : "CS", vm: "1", name: "Image Horizontal Flip", retired: "", keyword: "ImageHorizontalFlip" });
-	dicom_dictionary.insert(0x00700042, DicomDictElt { vr : "US", vm: "1", name: "Image Rotation", retired: "", keyword: "ImageRotation" });
-	dicom_dictionary.insert(0x00700050, DicomDictElt { vr : "US", vm: "2", name: "Displayed Area Top Left Hand Corner (Trial)", retired: "Retired", keyword: "DisplayedAreaTopLeftHandCornerTrial" });
-	dicom_dictionary.insert(0x00700051, DicomDictElt { vr : "US", vm: "2", name: "Displayed Area Bottom Right Hand Corner (Trial)", retired: "Retired", keyword: "DisplayedAreaBottomRightHandCornerTrial" });
-	dicom_dictionary.insert(0x00700052, DicomDictElt { vr : "SL", vm: "2", name: "Displayed Area Top Left Hand Corner", retired: "", keyword: "DisplayedAreaTopLeftHandCorner" });
-	dicom_dictionary.insert(0x00700053, DicomDictElt { vr : "SL", vm: "2", name: "Displayed Area Bottom Right Hand Corner", retired: "", keyword: "DisplayedAreaBottomRightHandCorner" });
-	dicom_dictionary.insert(0x0070005A, DicomDictElt { vr : "SQ", vm: "1", name: "Displayed Area Selection Sequence", retired: "", keyword: "DisplayedAreaSelectionSequence" });
-	dicom_dictionary.insert(0x00700060, DicomDictElt { vr : "SQ", vm: "1", name: "Graphic Layer Sequence", retired: "", keyword: "GraphicLayerSequence" });
-	dicom_dictionary.insert(0x00700062, DicomDictElt { vr : "IS", vm: "1", name: "Graphic Layer Order", retired: "", keyword: "GraphicLayerOrder" });
-	dicom_dictionary.insert(0x00700066, DicomDictElt { vr : "US", vm: "1", name: "Graphic Layer Recommended Display Grayscale Value", retired: "", keyword: "GraphicLayerRecommendedDisplayGrayscaleValue" });
-	dicom_dictionary.insert(0x00700067, DicomDictElt { vr : "US", vm: "3", name: "Graphic Layer Recommended Display RGB Value", retired: "Retired", keyword: "GraphicLayerRecommendedDisplayRGBValue" });
-	dicom_dictionary.insert(0x00700068, DicomDictElt { vr : "LO", vm: "1", name: "Graphic Layer Description", retired: "", keyword: "GraphicLayerDescription" });
-	dicom_dictionary.insert(0x00700080, DicomDictElt { vr : "CS", vm: "1", name: "Content Label", retired: "", keyword: "ContentLabel" });
-	dicom_dictionary.insert(0x00700081, DicomDictElt { vr : "LO", vm: "1", name: "Content Description", retired: "", keyword: "ContentDescription" });
-	dicom_dictionary.insert(0x00700082, DicomDictElt { vr : "DA", vm: "1", name: "Presentation Creation Date", retired: "", keyword: "PresentationCreationDate" });
-	dicom_dictionary.insert(0x00700083, DicomDictElt { vr : "TM", vm: "1", name: "Presentation Creation Time", retired: "", keyword: "PresentationCreationTime" });
-	dicom_dictionary.insert(0x00700084, DicomDictElt { vr : "PN", vm: "1", name: "Content Creator's Name", retired: "", keyword: "ContentCreatorName" });
-	dicom_dictionary.insert(0x00700086, DicomDictElt { vr : "SQ", vm: "1", name: "Content Creator's Identification Code Sequence", retired: "", keyword: "ContentCreatorIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x00700087, DicomDictElt { vr : "SQ", vm: "1", name: "Alternate Content Description Sequence", retired: "", keyword: "AlternateContentDescriptionSequence" });
-	dicom_dictionary.insert(0x00700100, DicomDictElt { vr : "CS", vm: "1", name: "Presentation Size Mode", retired: "", keyword: "PresentationSizeMode" });
-	dicom_dictionary.insert(0x00700101, DicomDictElt { vr : "DS", vm: "2", name: "Presentation Pixel Spacing", retired: "", keyword: "PresentationPixelSpacing" });
-	dicom_dictionary.insert(0x00700102, DicomDictElt { vr : "IS", vm: "2", name: "Presentation Pixel Aspect Ratio", retired: "", keyword: "PresentationPixelAspectRatio" });
-	dicom_dictionary.insert(0x00700103, DicomDictElt { vr : "FL", vm: "1", name: "Presentation Pixel Magnification Ratio", retired: "", keyword: "PresentationPixelMagnificationRatio" });
-	dicom_dictionary.insert(0x00700207, DicomDictElt { vr : "LO", vm: "1", name: "Graphic Group Label", retired: "", keyword: "GraphicGroupLabel" });
-	dicom_dictionary.insert(0x00700208, DicomDictElt { vr : "ST", vm: "1", name: "Graphic Group Description", retired: "", keyword: "GraphicGroupDescription" });
-	dicom_dictionary.insert(0x00700209, DicomDictElt { vr : "SQ", vm: "1", name: "Compound Graphic Sequence", retired: "", keyword: "CompoundGraphicSequence" });
-	dicom_dictionary.insert(0x00700226, DicomDictElt { vr : "UL", vm: "1", name: "Compound Graphic Instance ID", retired: "", keyword: "CompoundGraphicInstanceID" });
-	dicom_dictionary.insert(0x00700227, DicomDictElt { vr : "LO", vm: "1", name: "Font Name", retired: "", keyword: "FontName" });
-	dicom_dictionary.insert(0x00700228, DicomDictElt { vr : "CS", vm: "1", name: "Font Name Type", retired: "", keyword: "FontNameType" });
-	dicom_dictionary.insert(0x00700229, DicomDictElt { vr : "LO", vm: "1", name: "CSS Font Name", retired: "", keyword: "CSSFontName" });
-	dicom_dictionary.insert(0x00700230, DicomDictElt { vr : "FD", vm: "1", name: "Rotation Angle", retired: "", keyword: "RotationAngle" });
-	dicom_dictionary.insert(0x00700231, DicomDictElt { vr : "SQ", vm: "1", name: "Text Style Sequence", retired: "", keyword: "TextStyleSequence" });
-	dicom_dictionary.insert(0x00700232, DicomDictElt { vr : "SQ", vm: "1", name: "Line Style Sequence", retired: "", keyword: "LineStyleSequence" });
-	dicom_dictionary.insert(0x00700233, DicomDictElt { vr : "SQ", vm: "1", name: "Fill Style Sequence", retired: "", keyword: "FillStyleSequence" });
-	dicom_dictionary.insert(0x00700234, DicomDictElt { vr : "SQ", vm: "1", name: "Graphic Group Sequence", retired: "", keyword: "GraphicGroupSequence" });
-	dicom_dictionary.insert(0x00700241, DicomDictElt { vr : "US", vm: "3", name: "Text Color CIELab Value", retired: "", keyword: "TextColorCIELabValue" });
-	dicom_dictionary.insert(0x00700242, DicomDictElt { vr : "CS", vm: "1", name: "Horizontal Alignment", retired: "", keyword: "HorizontalAlignment" });
-	dicom_dictionary.insert(0x00700243, DicomDictElt { vr : "CS", vm: "1", name: "Vertical Alignment", retired: "", keyword: "VerticalAlignment" });
-	dicom_dictionary.insert(0x00700244, DicomDictElt { vr : "CS", vm: "1", name: "Shadow Style", retired: "", keyword: "ShadowStyle" });
-	dicom_dictionary.insert(0x00700245, DicomDictElt { vr : "FL", vm: "1", name: "Shadow Offset X", retired: "", keyword: "ShadowOffsetX" });
-	dicom_dictionary.insert(0x00700246, DicomDictElt { vr : "FL", vm: "1", name: "Shadow Offset Y", retired: "", keyword: "ShadowOffsetY" });
-	dicom_dictionary.insert(0x00700247, DicomDictElt { vr : "US", vm: "3", name: "Shadow Color CIELab Value", retired: "", keyword: "ShadowColorCIELabValue" });
-	dicom_dictionary.insert(0x00700248, DicomDictElt { vr : "CS", vm: "1", name: "Underlined", retired: "", keyword: "Underlined" });
-	dicom_dictionary.insert(0x00700249, DicomDictElt { vr : "CS", vm: "1", name: "Bold", retired: "", keyword: "Bold" });
-	dicom_dictionary.insert(0x00700250, DicomDictElt { vr : "CS", vm: "1", name: "Italic", retired: "", keyword: "Italic" });
-	dicom_dictionary.insert(0x00700251, DicomDictElt { vr : "US", vm: "3", name: "Pattern On Color CIELab Value", retired: "", keyword: "PatternOnColorCIELabValue" });
-	dicom_dictionary.insert(0x00700252, DicomDictElt { vr : "US", vm: "3", name: "Pattern Off Color CIELab Value", retired: "", keyword: "PatternOffColorCIELabValue" });
-	dicom_dictionary.insert(0x00700253, DicomDictElt { vr : "FL", vm: "1", name: "Line Thickness", retired: "", keyword: "LineThickness" });
-	dicom_dictionary.insert(0x00700254, DicomDictElt { vr : "CS", vm: "1", name: "Line Dashing Style", retired: "", keyword: "LineDashingStyle" });
-	dicom_dictionary.insert(0x00700255, DicomDictElt { vr : "UL", vm: "1", name: "Line Pattern", retired: "", keyword: "LinePattern" });
-	dicom_dictionary.insert(0x00700256, DicomDictElt { vr : "OB", vm: "1", name: "Fill Pattern", retired: "", keyword: "FillPattern" });
-	dicom_dictionary.insert(0x00700257, DicomDictElt { vr : "CS", vm: "1", name: "Fill Mode", retired: "", keyword: "FillMode" });
-	dicom_dictionary.insert(0x00700258, DicomDictElt { vr : "FL", vm: "1", name: "Shadow Opacity", retired: "", keyword: "ShadowOpacity" });
-	dicom_dictionary.insert(0x00700261, DicomDictElt { vr : "FL", vm: "1", name: "Gap Length", retired: "", keyword: "GapLength" });
-	dicom_dictionary.insert(0x00700262, DicomDictElt { vr : "FL", vm: "1", name: "Diameter of Visibility", retired: "", keyword: "DiameterOfVisibility" });
-	dicom_dictionary.insert(0x00700273, DicomDictElt { vr : "FL", vm: "2", name: "Rotation Point", retired: "", keyword: "RotationPoint" });
-	dicom_dictionary.insert(0x00700274, DicomDictElt { vr : "CS", vm: "1", name: "Tick Alignment", retired: "", keyword: "TickAlignment" });
-	dicom_dictionary.insert(0x00700278, DicomDictElt { vr : "CS", vm: "1", name: "Show Tick Label", retired: "", keyword: "ShowTickLabel" });
-	dicom_dictionary.insert(0x00700279, DicomDictElt { vr : "CS", vm: "1", name: "Tick Label Alignment", retired: "", keyword: "TickLabelAlignment" });
-	dicom_dictionary.insert(0x00700282, DicomDictElt { vr : "CS", vm: "1", name: "Compound Graphic Units", retired: "", keyword: "CompoundGraphicUnits" });
-	dicom_dictionary.insert(0x00700284, DicomDictElt { vr : "FL", vm: "1", name: "Pattern On Opacity", retired: "", keyword: "PatternOnOpacity" });
-	dicom_dictionary.insert(0x00700285, DicomDictElt { vr : "FL", vm: "1", name: "Pattern Off Opacity", retired: "", keyword: "PatternOffOpacity" });
-	dicom_dictionary.insert(0x00700287, DicomDictElt { vr : "SQ", vm: "1", name: "Major Ticks Sequence", retired: "", keyword: "MajorTicksSequence" });
-	dicom_dictionary.insert(0x00700288, DicomDictElt { vr : "FL", vm: "1", name: "Tick Position", retired: "", keyword: "TickPosition" });
-	dicom_dictionary.insert(0x00700289, DicomDictElt { vr : "SH", vm: "1", name: "Tick Label", retired: "", keyword: "TickLabel" });
-	dicom_dictionary.insert(0x00700294, DicomDictElt { vr : "CS", vm: "1", name: "Compound Graphic Type", retired: "", keyword: "CompoundGraphicType" });
-	dicom_dictionary.insert(0x00700295, DicomDictElt { vr : "UL", vm: "1", name: "Graphic Group ID", retired: "", keyword: "GraphicGroupID" });
-	dicom_dictionary.insert(0x00700306, DicomDictElt { vr : "CS", vm: "1", name: "Shape Type", retired: "", keyword: "ShapeType" });
-	dicom_dictionary.insert(0x00700308, DicomDictElt { vr : "SQ", vm: "1", name: "Registration Sequence", retired: "", keyword: "RegistrationSequence" });
-	dicom_dictionary.insert(0x00700309, DicomDictElt { vr : "SQ", vm: "1", name: "Matrix Registration Sequence", retired: "", keyword: "MatrixRegistrationSequence" });
-	dicom_dictionary.insert(0x0070030A, DicomDictElt { vr : "SQ", vm: "1", name: "Matrix Sequence", retired: "", keyword: "MatrixSequence" });
-	dicom_dictionary.insert(0x0070030B, DicomDictElt { vr : "FD", vm: "16", name: "Frame of Reference to Displayed Coordinate System Transformation Matrix", retired: "", keyword: "FrameOfReferenceToDisplayedCoordinateSystemTransformationMatrix" });
-	dicom_dictionary.insert(0x0070030C, DicomDictElt { vr : "CS", vm: "1", name: "Frame of Reference Transformation Matrix Type", retired: "", keyword: "FrameOfReferenceTransformationMatrixType" });
-	dicom_dictionary.insert(0x0070030D, DicomDictElt { vr : "SQ", vm: "1", name: "Registration Type Code Sequence", retired: "", keyword: "RegistrationTypeCodeSequence" });
-	dicom_dictionary.insert(0x0070030F, DicomDictElt { vr : "ST", vm: "1", name: "Fiducial Description", retired: "", keyword: "FiducialDescription" });
-	dicom_dictionary.insert(0x00700310, DicomDictElt { vr : "SH", vm: "1", name: "Fiducial Identifier", retired: "", keyword: "FiducialIdentifier" });
-	dicom_dictionary.insert(0x00700311, DicomDictElt { vr : "SQ", vm: "1", name: "Fiducial Identifier Code Sequence", retired: "", keyword: "FiducialIdentifierCodeSequence" });
-	dicom_dictionary.insert(0x00700312, DicomDictElt { vr : "FD", vm: "1", name: "Contour Uncertainty Radius", retired: "", keyword: "ContourUncertaintyRadius" });
-	dicom_dictionary.insert(0x00700314, DicomDictElt { vr : "SQ", vm: "1", name: "Used Fiducials Sequence", retired: "", keyword: "UsedFiducialsSequence" });
-	dicom_dictionary.insert(0x00700318, DicomDictElt { vr : "SQ", vm: "1", name: "Graphic Coordinates Data Sequence", retired: "", keyword: "GraphicCoordinatesDataSequence" });
-	dicom_dictionary.insert(0x0070031A, DicomDictElt { vr : "UI", vm: "1", name: "Fiducial UID", retired: "", keyword: "FiducialUID" });
-	dicom_dictionary.insert(0x0070031C, DicomDictElt { vr : "SQ", vm: "1", name: "Fiducial Set Sequence", retired: "", keyword: "FiducialSetSequence" });
-	dicom_dictionary.insert(0x0070031E, DicomDictElt { vr : "SQ", vm: "1", name: "Fiducial Sequence", retired: "", keyword: "FiducialSequence" });
-	dicom_dictionary.insert(0x0070031F, DicomDictElt { vr : "SQ", vm: "1", name: "Fiducials Property Category Code Sequence", retired: "", keyword: "FiducialsPropertyCategoryCodeSequence" });
-	dicom_dictionary.insert(0x00700401, DicomDictElt { vr : "US", vm: "3", name: "Graphic Layer Recommended Display CIELab Value", retired: "", keyword: "GraphicLayerRecommendedDisplayCIELabValue" });
-	dicom_dictionary.insert(0x00700402, DicomDictElt { vr : "SQ", vm: "1", name: "Blending Sequence", retired: "", keyword: "BlendingSequence" });
-	dicom_dictionary.insert(0x00700403, DicomDictElt { vr : "FL", vm: "1", name: "Relative Opacity", retired: "", keyword: "RelativeOpacity" });
-	dicom_dictionary.insert(0x00700404, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Spatial Registration Sequence", retired: "", keyword: "ReferencedSpatialRegistrationSequence" });
-	dicom_dictionary.insert(0x00700405, DicomDictElt { vr : "CS", vm: "1", name: "Blending Position", retired: "", keyword: "BlendingPosition" });
-	dicom_dictionary.insert(0x00701101, DicomDictElt { vr : "UI", vm: "1", name: "Presentation Display Collection UID", retired: "", keyword: "PresentationDisplayCollectionUID" });
-	dicom_dictionary.insert(0x00701102, DicomDictElt { vr : "UI", vm: "1", name: "Presentation Sequence Collection UID", retired: "", keyword: "PresentationSequenceCollectionUID" });
-	dicom_dictionary.insert(0x00701103, DicomDictElt { vr : "US", vm: "1", name: "Presentation Sequence Position Index", retired: "", keyword: "PresentationSequencePositionIndex" });
-	dicom_dictionary.insert(0x00701104, DicomDictElt { vr : "SQ", vm: "1", name: "Rendered Image Reference Sequence", retired: "", keyword: "RenderedImageReferenceSequence" });
-	dicom_dictionary.insert(0x00701201, DicomDictElt { vr : "SQ", vm: "1", name: "Volumetric Presentation State Input Sequence", retired: "", keyword: "VolumetricPresentationStateInputSequence" });
-	dicom_dictionary.insert(0x00701202, DicomDictElt { vr : "CS", vm: "1", name: "Presentation Input Type", retired: "", keyword: "PresentationInputType" });
-	dicom_dictionary.insert(0x00701203, DicomDictElt { vr : "US", vm: "1", name: "Input Sequence Position Index", retired: "", keyword: "InputSequencePositionIndex" });
-	dicom_dictionary.insert(0x00701204, DicomDictElt { vr : "CS", vm: "1", name: "Crop", retired: "", keyword: "Crop" });
-	dicom_dictionary.insert(0x00701205, DicomDictElt { vr : "US", vm: "1-n", name: "Cropping Specification Index", retired: "", keyword: "CroppingSpecificationIndex" });
-	dicom_dictionary.insert(0x00701206, DicomDictElt { vr : "CS", vm: "1", name: "Compositing Method", retired: "", keyword: "CompositingMethod" });
-	dicom_dictionary.insert(0x00701207, DicomDictElt { vr : "US", vm: "1", name: "Volumetric Presentation Input Number", retired: "", keyword: "VolumetricPresentationInputNumber" });
-	dicom_dictionary.insert(0x00701208, DicomDictElt { vr : "CS", vm: "1", name: "Image Volume Geometry", retired: "", keyword: "ImageVolumeGeometry" });
-	dicom_dictionary.insert(0x00701301, DicomDictElt { vr : "SQ", vm: "1", name: "Volume Cropping Sequence", retired: "", keyword: "VolumeCroppingSequence" });
-	dicom_dictionary.insert(0x00701302, DicomDictElt { vr : "CS", vm: "1", name: "Volume Cropping Method", retired: "", keyword: "VolumeCroppingMethod" });
-	dicom_dictionary.insert(0x00701303, DicomDictElt { vr : "FD", vm: "6", name: "Bounding Box Crop", retired: "", keyword: "BoundingBoxCrop" });
-	dicom_dictionary.insert(0x00701304, DicomDictElt { vr : "SQ", vm: "1", name: "Oblique Cropping Plane Sequence", retired: "", keyword: "ObliqueCroppingPlaneSequence" });
-	dicom_dictionary.insert(0x00701305, DicomDictElt { vr : "FD", vm: "4", name: "Plane", retired: "", keyword: "Plane" });
-	dicom_dictionary.insert(0x00701306, DicomDictElt { vr : "FD", vm: "3", name: "Plane Normal", retired: "", keyword: "PlaneNormal" });
-	dicom_dictionary.insert(0x00701309, DicomDictElt { vr : "US", vm: "1", name: "Cropping Specification Number", retired: "", keyword: "CroppingSpecificationNumber" });
-	dicom_dictionary.insert(0x00701501, DicomDictElt { vr : "CS", vm: "1", name: "Multi-Planar Reconstruction Style", retired: "", keyword: "MultiPlanarReconstructionStyle" });
-	dicom_dictionary.insert(0x00701502, DicomDictElt { vr : "CS", vm: "1", name: "MPR Thickness Type", retired: "", keyword: "MPRThicknessType" });
-	dicom_dictionary.insert(0x00701503, DicomDictElt { vr : "FD", vm: "1", name: "MPR Slab Thickness", retired: "", keyword: "MPRSlabThickness" });
-	dicom_dictionary.insert(0x00701505, DicomDictElt { vr : "FD", vm: "3", name: "MPR Top Left Hand Corner", retired: "", keyword: "MPRTopLeftHandCorner" });
-	dicom_dictionary.insert(0x00701507, DicomDictElt { vr : "FD", vm: "3", name: "MPR View Width Direction", retired: "", keyword: "MPRViewWidthDirection" });
-	dicom_dictionary.insert(0x00701508, DicomDictElt { vr : "FD", vm: "1", name: "MPR View Width", retired: "", keyword: "MPRViewWidth" });
-	dicom_dictionary.insert(0x0070150C, DicomDictElt { vr : "UL", vm: "1", name: "Number of Volumetric Curve Points", retired: "", keyword: "NumberOfVolumetricCurvePoints" });
-	dicom_dictionary.insert(0x0070150D, DicomDictElt { vr : "OD", vm: "1", name: "Volumetric Curve Points", retired: "", keyword: "VolumetricCurvePoints" });
-	dicom_dictionary.insert(0x00701511, DicomDictElt { vr : "FD", vm: "3", name: "MPR View Height Direction", retired: "", keyword: "MPRViewHeightDirection" });
-	dicom_dictionary.insert(0x00701512, DicomDictElt { vr : "FD", vm: "1", name: "MPR View Height", retired: "", keyword: "MPRViewHeight" });
-	dicom_dictionary.insert(0x00701801, DicomDictElt { vr : "SQ", vm: "1", name: "Presentation State Classification Component Sequence", retired: "", keyword: "PresentationStateClassificationComponentSequence" });
-	dicom_dictionary.insert(0x00701802, DicomDictElt { vr : "CS", vm: "1", name: "Component Type", retired: "", keyword: "ComponentType" });
-	dicom_dictionary.insert(0x00701803, DicomDictElt { vr : "SQ", vm: "1", name: "Component Input Sequence", retired: "", keyword: "ComponentInputSequence" });
-	dicom_dictionary.insert(0x00701804, DicomDictElt { vr : "US", vm: "1", name: "Volumetric Presentation Input Index", retired: "", keyword: "VolumetricPresentationInputIndex" });
-	dicom_dictionary.insert(0x00701805, DicomDictElt { vr : "SQ", vm: "1", name: "Presentation State Compositor Component Sequence", retired: "", keyword: "PresentationStateCompositorComponentSequence" });
-	dicom_dictionary.insert(0x00701806, DicomDictElt { vr : "SQ", vm: "1", name: "Weighting Transfer Function Sequence", retired: "", keyword: "WeightingTransferFunctionSequence" });
-	dicom_dictionary.insert(0x00701807, DicomDictElt { vr : "US", vm: "3", name: "Weighting Lookup Table Descriptor", retired: "", keyword: "WeightingLookupTableDescriptor" });
-	dicom_dictionary.insert(0x00701808, DicomDictElt { vr : "OB", vm: "1", name: "Weighting Lookup Table Data", retired: "", keyword: "WeightingLookupTableData" });
-	dicom_dictionary.insert(0x00701901, DicomDictElt { vr : "SQ", vm: "1", name: "Volumetric Annotation Sequence", retired: "", keyword: "VolumetricAnnotationSequence" });
-	dicom_dictionary.insert(0x00701903, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Structured Context Sequence", retired: "", keyword: "ReferencedStructuredContextSequence" });
-	dicom_dictionary.insert(0x00701904, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Content Item", retired: "", keyword: "ReferencedContentItem" });
-	dicom_dictionary.insert(0x00701905, DicomDictElt { vr : "SQ", vm: "1", name: "Volumetric Presentation Input Annotation Sequence", retired: "", keyword: "VolumetricPresentationInputAnnotationSequence" });
-	dicom_dictionary.insert(0x00701907, DicomDictElt { vr : "CS", vm: "1", name: "Annotation Clipping", retired: "", keyword: "AnnotationClipping" });
-	dicom_dictionary.insert(0x00701A01, DicomDictElt { vr : "CS", vm: "1", name: "Presentation Animation Style", retired: "", keyword: "PresentationAnimationStyle" });
-	dicom_dictionary.insert(0x00701A03, DicomDictElt { vr : "FD", vm: "1", name: "Recommended Animation Rate", retired: "", keyword: "RecommendedAnimationRate" });
-	dicom_dictionary.insert(0x00701A04, DicomDictElt { vr : "SQ", vm: "1", name: "Animation Curve Sequence", retired: "", keyword: "AnimationCurveSequence" });
-	dicom_dictionary.insert(0x00701A05, DicomDictElt { vr : "FD", vm: "1", name: "Animation Step Size", retired: "", keyword: "AnimationStepSize" });
-	dicom_dictionary.insert(0x00720002, DicomDictElt { vr : "SH", vm: "1", name: "Hanging Protocol Name", retired: "", keyword: "HangingProtocolName" });
-	dicom_dictionary.insert(0x00720004, DicomDictElt { vr : "LO", vm: "1", name: "Hanging Protocol Description", retired: "", keyword: "HangingProtocolDescription" });
-	dicom_dictionary.insert(0x00720006, DicomDictElt { vr : "CS", vm: "1", name: "Hanging Protocol Level", retired: "", keyword: "HangingProtocolLevel" });
-	dicom_dictionary.insert(0x00720008, DicomDictElt { vr : "LO", vm: "1", name: "Hanging Protocol Creator", retired: "", keyword: "HangingProtocolCreator" });
-	dicom_dictionary.insert(0x0072000A, DicomDictElt { vr : "DT", vm: "1", name: "Hanging Protocol Creation DateTime", retired: "", keyword: "HangingProtocolCreationDateTime" });
-	dicom_dictionary.insert(0x0072000C, DicomDictElt { vr : "SQ", vm: "1", name: "Hanging Protocol Definition Sequence", retired: "", keyword: "HangingProtocolDefinitionSequence" });
-	dicom_dictionary.insert(0x0072000E, DicomDictElt { vr : "SQ", vm: "1", name: "Hanging Protocol User Identification Code Sequence", retired: "", keyword: "HangingProtocolUserIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x00720010, DicomDictElt { vr : "LO", vm: "1", name: "Hanging Protocol User Group Name", retired: "", keyword: "HangingProtocolUserGroupName" });
-	dicom_dictionary.insert(0x00720012, DicomDictElt { vr : "SQ", vm: "1", name: "Source Hanging Protocol Sequence", retired: "", keyword: "SourceHangingProtocolSequence" });
-	dicom_dictionary.insert(0x00720014, DicomDictElt { vr : "US", vm: "1", name: "Number of Priors Referenced", retired: "", keyword: "NumberOfPriorsReferenced" });
-	dicom_dictionary.insert(0x00720020, DicomDictElt { vr : "SQ", vm: "1", name: "Image Sets Sequence", retired: "", keyword: "ImageSetsSequence" });
-	dicom_dictionary.insert(0x00720022, DicomDictElt { vr : "SQ", vm: "1", name: "Image Set Selector Sequence", retired: "", keyword: "ImageSetSelectorSequence" });
-	dicom_dictionary.insert(0x00720024, DicomDictElt { vr : "CS", vm: "1", name: "Image Set Selector Usage Flag", retired: "", keyword: "ImageSetSelectorUsageFlag" });
-	dicom_dictionary.insert(0x00720026, DicomDictElt { vr : "AT", vm: "1", name: "Selector Attribute", retired: "", keyword: "SelectorAttribute" });
-	dicom_dictionary.insert(0x00720028, DicomDictElt { vr : "US", vm: "1", name: "Selector Value Number", retired: "", keyword: "SelectorValueNumber" });
-	dicom_dictionary.insert(0x00720030, DicomDictElt { vr : "SQ", vm: "1", name: "Time Based Image Sets Sequence", retired: "", keyword: "TimeBasedImageSetsSequence" });
-	dicom_dictionary.insert(0x00720032, DicomDictElt { vr : "US", vm: "1", name: "Image Set Number", retired: "", keyword: "ImageSetNumber" });
-	dicom_dictionary.insert(0x00720034, DicomDictElt { vr : "CS", vm: "1", name: "Image Set Selector Category", retired: "", keyword: "ImageSetSelectorCategory" });
-	dicom_dictionary.insert(0x00720038, DicomDictElt { vr : "US", vm: "2", name: "Relative Time", retired: "", keyword: "RelativeTime" });
-	dicom_dictionary.insert(0x0072003A, DicomDictElt { vr : "CS", vm: "1", name: "Relative Time Units", retired: "", keyword: "RelativeTimeUnits" });
-	dicom_dictionary.insert(0x0072003C, DicomDictElt { vr : "SS", vm: "2", name: "Abstract Prior Value", retired: "", keyword: "AbstractPriorValue" });
-	dicom_dictionary.insert(0x0072003E, DicomDictElt { vr : "SQ", vm: "1", name: "Abstract Prior Code Sequence", retired: "", keyword: "AbstractPriorCodeSequence" });
-	dicom_dictionary.insert(0x00720040, DicomDictElt { vr : "LO", vm: "1", name: "Image Set Label", retired: "", keyword: "ImageSetLabel" });
-	dicom_dictionary.insert(0x00720050, DicomDictElt { vr : "CS", vm: "1", name: "Selector Attribute VR", retired: "", keyword: "SelectorAttributeVR" });
-	dicom_dictionary.insert(0x00720052, DicomDictElt { vr : "AT", vm: "1-n", name: "Selector Sequence Pointer", retired: "", keyword: "SelectorSequencePointer" });
-	dicom_dictionary.insert(0x00720054, DicomDictElt { vr : "LO", vm: "1-n", name: "Selector Sequence Pointer Private Creator", retired: "", keyword: "SelectorSequencePointerPrivateCreator" });
-	dicom_dictionary.insert(0x00720056, DicomDictElt { vr : "LO", vm: "1", name: "Selector Attribute Private Creator", retired: "", keyword: "SelectorAttributePrivateCreator" });
-	dicom_dictionary.insert(0x0072005E, DicomDictElt { vr : "AE", vm: "1-n", name: "Selector AE Value", retired: "", keyword: "SelectorAEValue" });
-	dicom_dictionary.insert(0x0072005F, DicomDictElt { vr : "AS", vm: "1-n", name: "Selector AS Value", retired: "", keyword: "SelectorASValue" });
-	dicom_dictionary.insert(0x00720060, DicomDictElt { vr : "AT", vm: "1-n", name: "Selector AT Value", retired: "", keyword: "SelectorATValue" });
-	dicom_dictionary.insert(0x00720061, DicomDictElt { vr : "DA", vm: "1-n", name: "Selector DA Value", retired: "", keyword: "SelectorDAValue" });
-	dicom_dictionary.insert(0x00720062, DicomDictElt { vr : "CS", vm: "1-n", name: "Selector CS Value", retired: "", keyword: "SelectorCSValue" });
-	dicom_dictionary.insert(0x00720063, DicomDictElt { vr : "DT", vm: "1-n", name: "Selector DT Value", retired: "", keyword: "SelectorDTValue" });
-	dicom_dictionary.insert(0x00720064, DicomDictElt { vr : "IS", vm: "1-n", name: "Selector IS Value", retired: "", keyword: "SelectorISValue" });
-	dicom_dictionary.insert(0x00720065, DicomDictElt { vr : "OB", vm: "1", name: "Selector OB Value", retired: "", keyword: "SelectorOBValue" });
-	dicom_dictionary.insert(0x00720066, DicomDictElt { vr : "LO", vm: "1-n", name: "Selector LO Value", retired: "", keyword: "SelectorLOValue" });
-	dicom_dictionary.insert(0x00720067, DicomDictElt { vr : "OF", vm: "1", name: "Selector OF Value", retired: "", keyword: "SelectorOFValue" });
-	dicom_dictionary.insert(0x00720068, DicomDictElt { vr : "LT", vm: "1", name: "Selector LT Value", retired: "", keyword: "SelectorLTValue" });
-	dicom_dictionary.insert(0x00720069, DicomDictElt { vr : "OW", vm: "1", name: "Selector OW Value", retired: "", keyword: "SelectorOWValue" });
-	dicom_dictionary.insert(0x0072006A, DicomDictElt { vr : "PN", vm: "1-n", name: "Selector PN Value", retired: "", keyword: "SelectorPNValue" });
-	dicom_dictionary.insert(0x0072006B, DicomDictElt { vr : "TM", vm: "1-n", name: "Selector TM Value", retired: "", keyword: "SelectorTMValue" });
-	dicom_dictionary.insert(0x0072006C, DicomDictElt { vr : "SH", vm: "1-n", name: "Selector SH Value", retired: "", keyword: "SelectorSHValue" });
-	dicom_dictionary.insert(0x0072006D, DicomDictElt { vr : "UN", vm: "1", name: "Selector UN Value", retired: "", keyword: "SelectorUNValue" });
-	dicom_dictionary.insert(0x0072006E, DicomDictElt { vr : "ST", vm: "1", name: "Selector ST Value", retired: "", keyword: "SelectorSTValue" });
-	dicom_dictionary.insert(0x0072006F, DicomDictElt { vr : "UC", vm: "1-n", name: "Selector UC Value", retired: "", keyword: "SelectorUCValue" });
-	dicom_dictionary.insert(0x00720070, DicomDictElt { vr : "UT", vm: "1", name: "Selector UT Value", retired: "", keyword: "SelectorUTValue" });
-	dicom_dictionary.insert(0x00720071, DicomDictElt { vr : "UR", vm: "1", name: "Selector UR Value", retired: "", keyword: "SelectorURValue" });
-	dicom_dictionary.insert(0x00720072, DicomDictElt { vr : "DS", vm: "1-n", name: "Selector DS Value", retired: "", keyword: "SelectorDSValue" });
-	dicom_dictionary.insert(0x00720073, DicomDictElt { vr : "OD", vm: "1", name: "Selector OD Value", retired: "", keyword: "SelectorODValue" });
-	dicom_dictionary.insert(0x00720074, DicomDictElt { vr : "FD", vm: "1-n", name: "Selector FD Value", retired: "", keyword: "SelectorFDValue" });
-	dicom_dictionary.insert(0x00720075, DicomDictElt { vr : "OL", vm: "1", name: "Selector OL Value", retired: "", keyword: "SelectorOLValue" });
-	dicom_dictionary.insert(0x00720076, DicomDictElt { vr : "FL", vm: "1-n", name: "Selector FL Value", retired: "", keyword: "SelectorFLValue" });
-	dicom_dictionary.insert(0x00720078, DicomDictElt { vr : "UL", vm: "1-n", name: "Selector UL Value", retired: "", keyword: "SelectorULValue" });
-	dicom_dictionary.insert(0x0072007A, DicomDictElt { vr : "US", vm: "1-n", name: "Selector US Value", retired: "", keyword: "SelectorUSValue" });
-	dicom_dictionary.insert(0x0072007C, DicomDictElt { vr : "SL", vm: "1-n", name: "Selector SL Value", retired: "", keyword: "SelectorSLValue" });
-	dicom_dictionary.insert(0x0072007E, DicomDictElt { vr : "SS", vm: "1-n", name: "Selector SS Value", retired: "", keyword: "SelectorSSValue" });
-	dicom_dictionary.insert(0x0072007F, DicomDictElt { vr : "UI", vm: "1-n", name: "Selector UI Value", retired: "", keyword: "SelectorUIValue" });
-	dicom_dictionary.insert(0x00720080, DicomDictElt { vr : "SQ", vm: "1", name: "Selector Code Sequence Value", retired: "", keyword: "SelectorCodeSequenceValue" });
-	dicom_dictionary.insert(0x00720100, DicomDictElt { vr : "US", vm: "1", name: "Number of Screens", retired: "", keyword: "NumberOfScreens" });
-	dicom_dictionary.insert(0x00720102, DicomDictElt { vr : "SQ", vm: "1", name: "Nominal Screen Definition Sequence", retired: "", keyword: "NominalScreenDefinitionSequence" });
-	dicom_dictionary.insert(0x00720104, DicomDictElt { vr : "US", vm: "1", name: "Number of Vertical Pixels", retired: "", keyword: "NumberOfVerticalPixels" });
-	dicom_dictionary.insert(0x00720106, DicomDictElt { vr : "US", vm: "1", name: "Number of Horizontal Pixels", retired: "", keyword: "NumberOfHorizontalPixels" });
-	dicom_dictionary.insert(0x00720108, DicomDictElt { vr : "FD", vm: "4", name: "Display Environment Spatial Position", retired: "", keyword: "DisplayEnvironmentSpatialPosition" });
-	dicom_dictionary.insert(0x0072010A, DicomDictElt { vr : "US", vm: "1", name: "Screen Minimum Grayscale Bit Depth", retired: "", keyword: "ScreenMinimumGrayscaleBitDepth" });
-	dicom_dictionary.insert(0x0072010C, DicomDictElt { vr : "US", vm: "1", name: "Screen Minimum Color Bit Depth", retired: "", keyword: "ScreenMinimumColorBitDepth" });
-	dicom_dictionary.insert(0x0072010E, DicomDictElt { vr : "US", vm: "1", name: "Application Maximum Repaint Time", retired: "", keyword: "ApplicationMaximumRepaintTime" });
-	dicom_dictionary.insert(0x00720200, DicomDictElt { vr : "SQ", vm: "1", name: "Display Sets Sequence", retired: "", keyword: "DisplaySetsSequence" });
-	dicom_dictionary.insert(0x00720202, DicomDictElt { vr : "US", vm: "1", name: "Display Set Number", retired: "", keyword: "DisplaySetNumber" });
-	dicom_dictionary.insert(0x00720203, DicomDictElt { vr : "LO", vm: "1", name: "Display Set Label", retired: "", keyword: "DisplaySetLabel" });
-	dicom_dictionary.insert(0x00720204, DicomDictElt { vr : "US", vm: "1", name: "Display Set Presentation Group", retired: "", keyword: "DisplaySetPresentationGroup" });
-	dicom_dictionary.insert(0x00720206, DicomDictElt { vr : "LO", vm: "1", name: "Display Set Presentation Group Description", retired: "", keyword: "DisplaySetPresentationGroupDescription" });
-	dicom_dictionary.insert(0x00720208, DicomDictElt { vr : "CS", vm: "1", name: "Partial Data Display Handling", retired: "", keyword: "PartialDataDisplayHandling" });
-	dicom_dictionary.insert(0x00720210, DicomDictElt { vr : "SQ", vm: "1", name: "Synchronized Scrolling Sequence", retired: "", keyword: "SynchronizedScrollingSequence" });
-	dicom_dictionary.insert(0x00720212, DicomDictElt { vr : "US", vm: "2-n", name: "Display Set Scrolling Group", retired: "", keyword: "DisplaySetScrollingGroup" });
-	dicom_dictionary.insert(0x00720214, DicomDictElt { vr : "SQ", vm: "1", name: "Navigation Indicator Sequence", retired: "", keyword: "NavigationIndicatorSequence" });
-	dicom_dictionary.insert(0x00720216, DicomDictElt { vr : "US", vm: "1", name: "Navigation Display Set", retired: "", keyword: "NavigationDisplaySet" });
-	dicom_dictionary.insert(0x00720218, DicomDictElt { vr : "US", vm: "1-n", name: "Reference Display Sets", retired: "", keyword: "ReferenceDisplaySets" });
-	dicom_dictionary.insert(0x00720300, DicomDictElt { vr : "SQ", vm: "1", name: "Image Boxes Sequence", retired: "", keyword: "ImageBoxesSequence" });
-	dicom_dictionary.insert(0x00720302, DicomDictElt { vr : "US", vm: "1", name: "Image Box Number", retired: "", keyword: "ImageBoxNumber" });
-	dicom_dictionary.insert(0x00720304, DicomDictElt { vr : "CS", vm: "1", name: "Image Box Layout Type", retired: "", keyword: "ImageBoxLayoutType" });
-	dicom_dictionary.insert(0x00720306, DicomDictElt { vr : "US", vm: "1", name: "Image Box Tile Horizontal Dimension", retired: "", keyword: "ImageBoxTileHorizontalDimension" });
-	dicom_dictionary.insert(0x00720308, DicomDictElt { vr : "US", vm: "1", name: "Image Box Tile Vertical Dimension", retired: "", keyword: "ImageBoxTileVerticalDimension" });
-	dicom_dictionary.insert(0x00720310, DicomDictElt { vr : "CS", vm: "1", name: "Image Box Scroll Direction", retired: "", keyword: "ImageBoxScrollDirection" });
-	dicom_dictionary.insert(0x00720312, DicomDictElt { vr : "CS", vm: "1", name: "Image Box Small Scroll Type", retired: "", keyword: "ImageBoxSmallScrollType" });
-	dicom_dictionary.insert(0x00720314, DicomDictElt { vr : "US", vm: "1", name: "Image Box Small Scroll Amount", retired: "", keyword: "ImageBoxSmallScrollAmount" });
-	dicom_dictionary.insert(0x00720316, DicomDictElt { vr : "CS", vm: "1", name: "Image Box Large Scroll Type", retired: "", keyword: "ImageBoxLargeScrollType" });
-	dicom_dictionary.insert(0x00720318, DicomDictElt { vr : "US", vm: "1", name: "Image Box Large Scroll Amount", retired: "", keyword: "ImageBoxLargeScrollAmount" });
-	dicom_dictionary.insert(0x00720320, DicomDictElt { vr : "US", vm: "1", name: "Image Box Overlap Priority", retired: "", keyword: "ImageBoxOverlapPriority" });
-	dicom_dictionary.insert(0x00720330, DicomDictElt { vr : "FD", vm: "1", name: "Cine Relative to Real-Time", retired: "", keyword: "CineRelativeToRealTime" });
-	dicom_dictionary.insert(0x00720400, DicomDictElt { vr : "SQ", vm: "1", name: "Filter Operations Sequence", retired: "", keyword: "FilterOperationsSequence" });
-	dicom_dictionary.insert(0x00720402, DicomDictElt { vr : "CS", vm: "1", name: "Filter-by Category", retired: "", keyword: "FilterByCategory" });
-	dicom_dictionary.insert(0x00720404, DicomDictElt { vr : "CS", vm: "1", name: "Filter-by Attribute Presence", retired: "", keyword: "FilterByAttributePresence" });
-	dicom_dictionary.insert(0x00720406, DicomDictElt { vr : "CS", vm: "1", name: "Filter-by Operator", retired: "", keyword: "FilterByOperator" });
-	dicom_dictionary.insert(0x00720420, DicomDictElt { vr : "US", vm: "3", name: "Structured Display Background CIELab Value", retired: "", keyword: "StructuredDisplayBackgroundCIELabValue" });
-	dicom_dictionary.insert(0x00720421, DicomDictElt { vr : "US", vm: "3", name: "Empty Image Box CIELab Value", retired: "", keyword: "EmptyImageBoxCIELabValue" });
-	dicom_dictionary.insert(0x00720422, DicomDictElt { vr : "SQ", vm: "1", name: "Structured Display Image Box Sequence", retired: "", keyword: "StructuredDisplayImageBoxSequence" });
-	dicom_dictionary.insert(0x00720424, DicomDictElt { vr : "SQ", vm: "1", name: "Structured Display Text Box Sequence", retired: "", keyword: "StructuredDisplayTextBoxSequence" });
-	dicom_dictionary.insert(0x00720427, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced First Frame Sequence", retired: "", keyword: "ReferencedFirstFrameSequence" });
-	dicom_dictionary.insert(0x00720430, DicomDictElt { vr : "SQ", vm: "1", name: "Image Box Synchronization Sequence", retired: "", keyword: "ImageBoxSynchronizationSequence" });
-	dicom_dictionary.insert(0x00720432, DicomDictElt { vr : "US", vm: "2-n", name: "Synchronized Image Box List", retired: "", keyword: "SynchronizedImageBoxList" });
-	dicom_dictionary.insert(0x00720434, DicomDictElt { vr : "CS", vm: "1", name: "Type of Synchronization", retired: "", keyword: "TypeOfSynchronization" });
-	dicom_dictionary.insert(0x00720500, DicomDictElt { vr : "CS", vm: "1", name: "Blending Operation Type", retired: "", keyword: "BlendingOperationType" });
-	dicom_dictionary.insert(0x00720510, DicomDictElt { vr : "CS", vm: "1", name: "Reformatting Operation Type", retired: "", keyword: "ReformattingOperationType" });
-	dicom_dictionary.insert(0x00720512, DicomDictElt { vr : "FD", vm: "1", name: "Reformatting Thickness", retired: "", keyword: "ReformattingThickness" });
-	dicom_dictionary.insert(0x00720514, DicomDictElt { vr : "FD", vm: "1", name: "Reformatting Interval", retired: "", keyword: "ReformattingInterval" });
-	dicom_dictionary.insert(0x00720516, DicomDictElt { vr : "CS", vm: "1", name: "Reformatting Operation Initial View Direction", retired: "", keyword: "ReformattingOperationInitialViewDirection" });
-	dicom_dictionary.insert(0x00720520, DicomDictElt { vr : "CS", vm: "1-n", name: "3D Rendering Type", retired: "", keyword: "ThreeDRenderingType" });
-	dicom_dictionary.insert(0x00720600, DicomDictElt { vr : "SQ", vm: "1", name: "Sorting Operations Sequence", retired: "", keyword: "SortingOperationsSequence" });
-	dicom_dictionary.insert(0x00720602, DicomDictElt { vr : "CS", vm: "1", name: "Sort-by Category", retired: "", keyword: "SortByCategory" });
-	dicom_dictionary.insert(0x00720604, DicomDictElt { vr : "CS", vm: "1", name: "Sorting Direction", retired: "", keyword: "SortingDirection" });
-	dicom_dictionary.insert(0x00720700, DicomDictElt { vr : "CS", vm: "2", name: "Display Set Patient Orientation", retired: "", keyword: "DisplaySetPatientOrientation" });
-	dicom_dictionary.insert(0x00720702, DicomDictElt { vr : "CS", vm: "1", name: "VOI Type", retired: "", keyword: "VOIType" });
-	dicom_dictionary.insert(0x00720704, DicomDictElt { vr : "CS", vm: "1", name: "Pseudo-Color Type", retired: "", keyword: "PseudoColorType" });
-	dicom_dictionary.insert(0x00720705, DicomDictElt { vr : "SQ", vm: "1", name: "Pseudo-Color Palette Instance Reference Sequence", retired: "", keyword: "PseudoColorPaletteInstanceReferenceSequence" });
-	dicom_dictionary.insert(0x00720706, DicomDictElt { vr : "CS", vm: "1", name: "Show Grayscale Inverted", retired: "", keyword: "ShowGrayscaleInverted" });
-	dicom_dictionary.insert(0x00720710, DicomDictElt { vr : "CS", vm: "1", name: "Show Image True Size Flag", retired: "", keyword: "ShowImageTrueSizeFlag" });
-	dicom_dictionary.insert(0x00720712, DicomDictElt { vr : "CS", vm: "1", name: "Show Graphic Annotation Flag", retired: "", keyword: "ShowGraphicAnnotationFlag" });
-	dicom_dictionary.insert(0x00720714, DicomDictElt { vr : "CS", vm: "1", name: "Show Patient Demographics Flag", retired: "", keyword: "ShowPatientDemographicsFlag" });
-	dicom_dictionary.insert(0x00720716, DicomDictElt { vr : "CS", vm: "1", name: "Show Acquisition Techniques Flag", retired: "", keyword: "ShowAcquisitionTechniquesFlag" });
-	dicom_dictionary.insert(0x00720717, DicomDictElt { vr : "CS", vm: "1", name: "Display Set Horizontal Justification", retired: "", keyword: "DisplaySetHorizontalJustification" });
-	dicom_dictionary.insert(0x00720718, DicomDictElt { vr : "CS", vm: "1", name: "Display Set Vertical Justification", retired: "", keyword: "DisplaySetVerticalJustification" });
-	dicom_dictionary.insert(0x00740120, DicomDictElt { vr : "FD", vm: "1", name: "Continuation Start Meterset", retired: "", keyword: "ContinuationStartMeterset" });
-	dicom_dictionary.insert(0x00740121, DicomDictElt { vr : "FD", vm: "1", name: "Continuation End Meterset", retired: "", keyword: "ContinuationEndMeterset" });
-	dicom_dictionary.insert(0x00741000, DicomDictElt { vr : "CS", vm: "1", name: "Procedure Step State", retired: "", keyword: "ProcedureStepState" });
-	dicom_dictionary.insert(0x00741002, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Step Progress Information Sequence", retired: "", keyword: "ProcedureStepProgressInformationSequence" });
-	dicom_dictionary.insert(0x00741004, DicomDictElt { vr : "DS", vm: "1", name: "Procedure Step Progress", retired: "", keyword: "ProcedureStepProgress" });
-	dicom_dictionary.insert(0x00741006, DicomDictElt { vr : "ST", vm: "1", name: "Procedure Step Progress Description", retired: "", keyword: "ProcedureStepProgressDescription" });
-	dicom_dictionary.insert(0x00741008, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Step Communications URI Sequence", retired: "", keyword: "ProcedureStepCommunicationsURISequence" });
-	dicom_dictionary.insert(0x0074100A, DicomDictElt { vr : "UR", vm: "1", name: "Contact URI", retired: "", keyword: "ContactURI" });
-	dicom_dictionary.insert(0x0074100C, DicomDictElt { vr : "LO", vm: "1", name: "Contact Display Name", retired: "", keyword: "ContactDisplayName" });
-	dicom_dictionary.insert(0x0074100E, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Step Discontinuation Reason Code Sequence", retired: "", keyword: "ProcedureStepDiscontinuationReasonCodeSequence" });
-	dicom_dictionary.insert(0x00741020, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Task Sequence", retired: "", keyword: "BeamTaskSequence" });
-	dicom_dictionary.insert(0x00741022, DicomDictElt { vr : "CS", vm: "1", name: "Beam Task Type", retired: "", keyword: "BeamTaskType" });
-	dicom_dictionary.insert(0x00741024, DicomDictElt { vr : "IS", vm: "1", name: "Beam Order Index (Trial)", retired: "Retired", keyword: "BeamOrderIndexTrial" });
-	dicom_dictionary.insert(0x00741025, DicomDictElt { vr : "CS", vm: "1", name: "Autosequence Flag", retired: "", keyword: "AutosequenceFlag" });
-	dicom_dictionary.insert(0x00741026, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Vertical Adjusted Position", retired: "", keyword: "TableTopVerticalAdjustedPosition" });
-	dicom_dictionary.insert(0x00741027, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Longitudinal Adjusted Position", retired: "", keyword: "TableTopLongitudinalAdjustedPosition" });
-	dicom_dictionary.insert(0x00741028, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Lateral Adjusted Position", retired: "", keyword: "TableTopLateralAdjustedPosition" });
-	dicom_dictionary.insert(0x0074102A, DicomDictElt { vr : "FD", vm: "1", name: "Patient Support Adjusted Angle", retired: "", keyword: "PatientSupportAdjustedAngle" });
-	dicom_dictionary.insert(0x0074102B, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Eccentric Adjusted Angle", retired: "", keyword: "TableTopEccentricAdjustedAngle" });
-	dicom_dictionary.insert(0x0074102C, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Pitch Adjusted Angle", retired: "", keyword: "TableTopPitchAdjustedAngle" });
-	dicom_dictionary.insert(0x0074102D, DicomDictElt { vr : "FD", vm: "1", name: "Table Top Roll Adjusted Angle", retired: "", keyword: "TableTopRollAdjustedAngle" });
-	dicom_dictionary.insert(0x00741030, DicomDictElt { vr : "SQ", vm: "1", name: "Delivery Verification Image Sequence", retired: "", keyword: "DeliveryVerificationImageSequence" });
-	dicom_dictionary.insert(0x00741032, DicomDictElt { vr : "CS", vm: "1", name: "Verification Image Timing", retired: "", keyword: "VerificationImageTiming" });
-	dicom_dictionary.insert(0x00741034, DicomDictElt { vr : "CS", vm: "1", name: "Double Exposure Flag", retired: "", keyword: "DoubleExposureFlag" });
-	dicom_dictionary.insert(0x00741036, DicomDictElt { vr : "CS", vm: "1", name: "Double Exposure Ordering", retired: "", keyword: "DoubleExposureOrdering" });
-	dicom_dictionary.insert(0x00741038, DicomDictElt { vr : "DS", vm: "1", name: "Double Exposure Meterset (Trial)", retired: "Retired", keyword: "DoubleExposureMetersetTrial" });
-	dicom_dictionary.insert(0x0074103A, DicomDictElt { vr : "DS", vm: "4", name: "Double Exposure Field Delta (Trial)", retired: "Retired", keyword: "DoubleExposureFieldDeltaTrial" });
-	dicom_dictionary.insert(0x00741040, DicomDictElt { vr : "SQ", vm: "1", name: "Related Reference RT Image Sequence", retired: "", keyword: "RelatedReferenceRTImageSequence" });
-	dicom_dictionary.insert(0x00741042, DicomDictElt { vr : "SQ", vm: "1", name: "General Machine Verification Sequence", retired: "", keyword: "GeneralMachineVerificationSequence" });
-	dicom_dictionary.insert(0x00741044, DicomDictElt { vr : "SQ", vm: "1", name: "Conventional Machine Verification Sequence", retired: "", keyword: "ConventionalMachineVerificationSequence" });
-	dicom_dictionary.insert(0x00741046, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Machine Verification Sequence", retired: "", keyword: "IonMachineVerificationSequence" });
-	dicom_dictionary.insert(0x00741048, DicomDictElt { vr : "SQ", vm: "1", name: "Failed Attributes Sequence", retired: "", keyword: "FailedAttributesSequence" });
-	dicom_dictionary.insert(0x0074104A, DicomDictElt { vr : "SQ", vm: "1", name: "Overridden Attributes Sequence", retired: "", keyword: "OverriddenAttributesSequence" });
-	dicom_dictionary.insert(0x0074104C, DicomDictElt { vr : "SQ", vm: "1", name: "Conventional Control Point Verification Sequence", retired: "", keyword: "ConventionalControlPointVerificationSequence" });
-	dicom_dictionary.insert(0x0074104E, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Control Point Verification Sequence", retired: "", keyword: "IonControlPointVerificationSequence" });
-	dicom_dictionary.insert(0x00741050, DicomDictElt { vr : "SQ", vm: "1", name: "Attribute Occurrence Sequence", retired: "", keyword: "AttributeOccurrenceSequence" });
-	dicom_dictionary.insert(0x00741052, DicomDictElt { vr : "AT", vm: "1", name: "Attribute Occurrence Pointer", retired: "", keyword: "AttributeOccurrencePointer" });
-	dicom_dictionary.insert(0x00741054, DicomDictElt { vr : "UL", vm: "1", name: "Attribute Item Selector", retired: "", keyword: "AttributeItemSelector" });
-	dicom_dictionary.insert(0x00741056, DicomDictElt { vr : "LO", vm: "1", name: "Attribute Occurrence Private Creator", retired: "", keyword: "AttributeOccurrencePrivateCreator" });
-	dicom_dictionary.insert(0x00741057, DicomDictElt { vr : "IS", vm: "1-n", name: "Selector Sequence Pointer Items", retired: "", keyword: "SelectorSequencePointerItems" });
-	dicom_dictionary.insert(0x00741200, DicomDictElt { vr : "CS", vm: "1", name: "Scheduled Procedure Step Priority", retired: "", keyword: "ScheduledProcedureStepPriority" });
-	dicom_dictionary.insert(0x00741202, DicomDictElt { vr : "LO", vm: "1", name: "Worklist Label", retired: "", keyword: "WorklistLabel" });
-	dicom_dictionary.insert(0x00741204, DicomDictElt { vr : "LO", vm: "1", name: "Procedure Step Label", retired: "", keyword: "ProcedureStepLabel" });
-	dicom_dictionary.insert(0x00741210, DicomDictElt { vr : "SQ", vm: "1", name: "Scheduled Processing Parameters Sequence", retired: "", keyword: "ScheduledProcessingParametersSequence" });
-	dicom_dictionary.insert(0x00741212, DicomDictElt { vr : "SQ", vm: "1", name: "Performed Processing Parameters Sequence", retired: "", keyword: "PerformedProcessingParametersSequence" });
-	dicom_dictionary.insert(0x00741216, DicomDictElt { vr : "SQ", vm: "1", name: "Unified Procedure Step Performed Procedure Sequence", retired: "", keyword: "UnifiedProcedureStepPerformedProcedureSequence" });
-	dicom_dictionary.insert(0x00741220, DicomDictElt { vr : "SQ", vm: "1", name: "Related Procedure Step Sequence", retired: "Retired", keyword: "RelatedProcedureStepSequence" });
-	dicom_dictionary.insert(0x00741222, DicomDictElt { vr : "LO", vm: "1", name: "Procedure Step Relationship Type", retired: "Retired", keyword: "ProcedureStepRelationshipType" });
-	dicom_dictionary.insert(0x00741224, DicomDictElt { vr : "SQ", vm: "1", name: "Replaced Procedure Step Sequence", retired: "", keyword: "ReplacedProcedureStepSequence" });
-	dicom_dictionary.insert(0x00741230, DicomDictElt { vr : "LO", vm: "1", name: "Deletion Lock", retired: "", keyword: "DeletionLock" });
-	dicom_dictionary.insert(0x00741234, DicomDictElt { vr : "AE", vm: "1", name: "Receiving AE", retired: "", keyword: "ReceivingAE" });
-	dicom_dictionary.insert(0x00741236, DicomDictElt { vr : "AE", vm: "1", name: "Requesting AE", retired: "", keyword: "RequestingAE" });
-	dicom_dictionary.insert(0x00741238, DicomDictElt { vr : "LT", vm: "1", name: "Reason for Cancellation", retired: "", keyword: "ReasonForCancellation" });
-	dicom_dictionary.insert(0x00741242, DicomDictElt { vr : "CS", vm: "1", name: "SCP Status", retired: "", keyword: "SCPStatus" });
-	dicom_dictionary.insert(0x00741244, DicomDictElt { vr : "CS", vm: "1", name: "Subscription List Status", retired: "", keyword: "SubscriptionListStatus" });
-	dicom_dictionary.insert(0x00741246, DicomDictElt { vr : "CS", vm: "1", name: "Unified Procedure Step List Status", retired: "", keyword: "UnifiedProcedureStepListStatus" });
-	dicom_dictionary.insert(0x00741324, DicomDictElt { vr : "UL", vm: "1", name: "Beam Order Index", retired: "", keyword: "BeamOrderIndex" });
-	dicom_dictionary.insert(0x00741338, DicomDictElt { vr : "FD", vm: "1", name: "Double Exposure Meterset", retired: "", keyword: "DoubleExposureMeterset" });
-	dicom_dictionary.insert(0x0074133A, DicomDictElt { vr : "FD", vm: "4", name: "Double Exposure Field Delta", retired: "", keyword: "DoubleExposureFieldDelta" });
-	dicom_dictionary.insert(0x00741401, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Task Sequence", retired: "", keyword: "BrachyTaskSequence" });
-	dicom_dictionary.insert(0x00741402, DicomDictElt { vr : "DS", vm: "1", name: "Continuation Start Total Reference Air Kerma", retired: "", keyword: "ContinuationStartTotalReferenceAirKerma" });
-	dicom_dictionary.insert(0x00741403, DicomDictElt { vr : "DS", vm: "1", name: "Continuation End Total Reference Air Kerma", retired: "", keyword: "ContinuationEndTotalReferenceAirKerma" });
-	dicom_dictionary.insert(0x00741404, DicomDictElt { vr : "IS", vm: "1", name: "Continuation Pulse Number", retired: "", keyword: "ContinuationPulseNumber" });
-	dicom_dictionary.insert(0x00741405, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Delivery Order Sequence", retired: "", keyword: "ChannelDeliveryOrderSequence" });
-	dicom_dictionary.insert(0x00741406, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Channel Number", retired: "", keyword: "ReferencedChannelNumber" });
-	dicom_dictionary.insert(0x00741407, DicomDictElt { vr : "DS", vm: "1", name: "Start Cumulative Time Weight", retired: "", keyword: "StartCumulativeTimeWeight" });
-	dicom_dictionary.insert(0x00741408, DicomDictElt { vr : "DS", vm: "1", name: "End Cumulative Time Weight", retired: "", keyword: "EndCumulativeTimeWeight" });
-	dicom_dictionary.insert(0x00741409, DicomDictElt { vr : "SQ", vm: "1", name: "Omitted Channel Sequence", retired: "", keyword: "OmittedChannelSequence" });
-	dicom_dictionary.insert(0x0074140A, DicomDictElt { vr : "CS", vm: "1", name: "Reason for Channel Omission", retired: "", keyword: "ReasonForChannelOmission" });
-	dicom_dictionary.insert(0x0074140B, DicomDictElt { vr : "LO", vm: "1", name: "Reason for Channel Omission Description", retired: "", keyword: "ReasonForChannelOmissionDescription" });
-	dicom_dictionary.insert(0x0074140C, DicomDictElt { vr : "IS", vm: "1", name: "Channel Delivery Order Index", retired: "", keyword: "ChannelDeliveryOrderIndex" });
-	dicom_dictionary.insert(0x0074140D, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Delivery Continuation Sequence", retired: "", keyword: "ChannelDeliveryContinuationSequence" });
-	dicom_dictionary.insert(0x0074140E, DicomDictElt { vr : "SQ", vm: "1", name: "Omitted Application Setup Sequence", retired: "", keyword: "OmittedApplicationSetupSequence" });
-	dicom_dictionary.insert(0x00760001, DicomDictElt { vr : "LO", vm: "1", name: "Implant Assembly Template Name", retired: "", keyword: "ImplantAssemblyTemplateName" });
-	dicom_dictionary.insert(0x00760003, DicomDictElt { vr : "LO", vm: "1", name: "Implant Assembly Template Issuer", retired: "", keyword: "ImplantAssemblyTemplateIssuer" });
-	dicom_dictionary.insert(0x00760006, DicomDictElt { vr : "LO", vm: "1", name: "Implant Assembly Template Version", retired: "", keyword: "ImplantAssemblyTemplateVersion" });
-	dicom_dictionary.insert(0x00760008, DicomDictElt { vr : "SQ", vm: "1", name: "Replaced Implant Assembly Template Sequence", retired: "", keyword: "ReplacedImplantAssemblyTemplateSequence" });
-	dicom_dictionary.insert(0x0076000A, DicomDictElt { vr : "CS", vm: "1", name: "Implant Assembly Template Type", retired: "", keyword: "ImplantAssemblyTemplateType" });
-	dicom_dictionary.insert(0x0076000C, DicomDictElt { vr : "SQ", vm: "1", name: "Original Implant Assembly Template Sequence", retired: "", keyword: "OriginalImplantAssemblyTemplateSequence" });
-	dicom_dictionary.insert(0x0076000E, DicomDictElt { vr : "SQ", vm: "1", name: "Derivation Implant Assembly Template Sequence", retired: "", keyword: "DerivationImplantAssemblyTemplateSequence" });
-	dicom_dictionary.insert(0x00760010, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Assembly Template Target Anatomy Sequence", retired: "", keyword: "ImplantAssemblyTemplateTargetAnatomySequence" });
-	dicom_dictionary.insert(0x00760020, DicomDictElt { vr : "SQ", vm: "1", name: "Procedure Type Code Sequence", retired: "", keyword: "ProcedureTypeCodeSequence" });
-	dicom_dictionary.insert(0x00760030, DicomDictElt { vr : "LO", vm: "1", name: "Surgical Technique", retired: "", keyword: "SurgicalTechnique" });
-	dicom_dictionary.insert(0x00760032, DicomDictElt { vr : "SQ", vm: "1", name: "Component Types Sequence", retired: "", keyword: "ComponentTypesSequence" });
-	dicom_dictionary.insert(0x00760034, DicomDictElt { vr : "CS", vm: "1", name: "Component Type Code Sequence", retired: "", keyword: "ComponentTypeCodeSequence" });
-	dicom_dictionary.insert(0x00760036, DicomDictElt { vr : "CS", vm: "1", name: "Exclusive Component Type", retired: "", keyword: "ExclusiveComponentType" });
-	dicom_dictionary.insert(0x00760038, DicomDictElt { vr : "CS", vm: "1", name: "Mandatory Component Type", retired: "", keyword: "MandatoryComponentType" });
-	dicom_dictionary.insert(0x00760040, DicomDictElt { vr : "SQ", vm: "1", name: "Component Sequence", retired: "", keyword: "ComponentSequence" });
-	dicom_dictionary.insert(0x00760055, DicomDictElt { vr : "US", vm: "1", name: "Component ID", retired: "", keyword: "ComponentID" });
-	dicom_dictionary.insert(0x00760060, DicomDictElt { vr : "SQ", vm: "1", name: "Component Assembly Sequence", retired: "", keyword: "ComponentAssemblySequence" });
-	dicom_dictionary.insert(0x00760070, DicomDictElt { vr : "US", vm: "1", name: "Component 1 Referenced ID", retired: "", keyword: "Component1ReferencedID" });
-	dicom_dictionary.insert(0x00760080, DicomDictElt { vr : "US", vm: "1", name: "Component 1 Referenced Mating Feature Set ID", retired: "", keyword: "Component1ReferencedMatingFeatureSetID" });
-	dicom_dictionary.insert(0x00760090, DicomDictElt { vr : "US", vm: "1", name: "Component 1 Referenced Mating Feature ID", retired: "", keyword: "Component1ReferencedMatingFeatureID" });
-	dicom_dictionary.insert(0x007600A0, DicomDictElt { vr : "US", vm: "1", name: "Component 2 Referenced ID", retired: "", keyword: "Component2ReferencedID" });
-	dicom_dictionary.insert(0x007600B0, DicomDictElt { vr : "US", vm: "1", name: "Component 2 Referenced Mating Feature Set ID", retired: "", keyword: "Component2ReferencedMatingFeatureSetID" });
-	dicom_dictionary.insert(0x007600C0, DicomDictElt { vr : "US", vm: "1", name: "Component 2 Referenced Mating Feature ID", retired: "", keyword: "Component2ReferencedMatingFeatureID" });
-	dicom_dictionary.insert(0x00780001, DicomDictElt { vr : "LO", vm: "1", name: "Implant Template Group Name", retired: "", keyword: "ImplantTemplateGroupName" });
-	dicom_dictionary.insert(0x00780010, DicomDictElt { vr : "ST", vm: "1", name: "Implant Template Group Description", retired: "", keyword: "ImplantTemplateGroupDescription" });
-	dicom_dictionary.insert(0x00780020, DicomDictElt { vr : "LO", vm: "1", name: "Implant Template Group Issuer", retired: "", keyword: "ImplantTemplateGroupIssuer" });
-	dicom_dictionary.insert(0x00780024, DicomDictElt { vr : "LO", vm: "1", name: "Implant Template Group Version", retired: "", keyword: "ImplantTemplateGroupVersion" });
-	dicom_dictionary.insert(0x00780026, DicomDictElt { vr : "SQ", vm: "1", name: "Replaced Implant Template Group Sequence", retired: "", keyword: "ReplacedImplantTemplateGroupSequence" });
-	dicom_dictionary.insert(0x00780028, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Template Group Target Anatomy Sequence", retired: "", keyword: "ImplantTemplateGroupTargetAnatomySequence" });
-	dicom_dictionary.insert(0x0078002A, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Template Group Members Sequence", retired: "", keyword: "ImplantTemplateGroupMembersSequence" });
-	dicom_dictionary.insert(0x0078002E, DicomDictElt { vr : "US", vm: "1", name: "Implant Template Group Member ID", retired: "", keyword: "ImplantTemplateGroupMemberID" });
-	dicom_dictionary.insert(0x00780050, DicomDictElt { vr : "FD", vm: "3", name: "3D Implant Template Group Member Matching Point", retired: "", keyword: "ThreeDImplantTemplateGroupMemberMatchingPoint" });
-	dicom_dictionary.insert(0x00780060, DicomDictElt { vr : "FD", vm: "9", name: "3D Implant Template Group Member Matching Axes", retired: "", keyword: "ThreeDImplantTemplateGroupMemberMatchingAxes" });
-	dicom_dictionary.insert(0x00780070, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Template Group Member Matching 2D Coordinates Sequence", retired: "", keyword: "ImplantTemplateGroupMemberMatching2DCoordinatesSequence" });
-	dicom_dictionary.insert(0x00780090, DicomDictElt { vr : "FD", vm: "2", name: "2D Implant Template Group Member Matching Point", retired: "", keyword: "TwoDImplantTemplateGroupMemberMatchingPoint" });
-	dicom_dictionary.insert(0x007800A0, DicomDictElt { vr : "FD", vm: "4", name: "2D Implant Template Group Member Matching Axes", retired: "", keyword: "TwoDImplantTemplateGroupMemberMatchingAxes" });
-	dicom_dictionary.insert(0x007800B0, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Template Group Variation Dimension Sequence", retired: "", keyword: "ImplantTemplateGroupVariationDimensionSequence" });
-	dicom_dictionary.insert(0x007800B2, DicomDictElt { vr : "LO", vm: "1", name: "Implant Template Group Variation Dimension Name", retired: "", keyword: "ImplantTemplateGroupVariationDimensionName" });
-	dicom_dictionary.insert(0x007800B4, DicomDictElt { vr : "SQ", vm: "1", name: "Implant Template Group Variation Dimension Rank Sequence", retired: "", keyword: "ImplantTemplateGroupVariationDimensionRankSequence" });
-	dicom_dictionary.insert(0x007800B6, DicomDictElt { vr : "US", vm: "1", name: "Referenced Implant Template Group Member ID", retired: "", keyword: "ReferencedImplantTemplateGroupMemberID" });
-	dicom_dictionary.insert(0x007800B8, DicomDictElt { vr : "US", vm: "1", name: "Implant Template Group Variation Dimension Rank", retired: "", keyword: "ImplantTemplateGroupVariationDimensionRank" });
-	dicom_dictionary.insert(0x00800001, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Scan Acquisition Type Code Sequence", retired: "", keyword: "SurfaceScanAcquisitionTypeCodeSequence" });
-	dicom_dictionary.insert(0x00800002, DicomDictElt { vr : "SQ", vm: "1", name: "Surface Scan Mode Code Sequence", retired: "", keyword: "SurfaceScanModeCodeSequence" });
-	dicom_dictionary.insert(0x00800003, DicomDictElt { vr : "SQ", vm: "1", name: "Registration Method Code Sequence", retired: "", keyword: "RegistrationMethodCodeSequence" });
-	dicom_dictionary.insert(0x00800004, DicomDictElt { vr : "FD", vm: "1", name: "Shot Duration Time", retired: "", keyword: "ShotDurationTime" });
-	dicom_dictionary.insert(0x00800005, DicomDictElt { vr : "FD", vm: "1", name: "Shot Offset Time", retired: "", keyword: "ShotOffsetTime" });
-	dicom_dictionary.insert(0x00800006, DicomDictElt { vr : "US", vm: "1-n", name: "Surface Point Presentation Value Data", retired: "", keyword: "SurfacePointPresentationValueData" });
-	dicom_dictionary.insert(0x00800007, DicomDictElt { vr : "US", vm: "3-3n", name: "Surface Point Color CIELab Value Data", retired: "", keyword: "SurfacePointColorCIELabValueData" });
-	dicom_dictionary.insert(0x00800008, DicomDictElt { vr : "SQ", vm: "1", name: "UV Mapping Sequence", retired: "", keyword: "UVMappingSequence" });
-	dicom_dictionary.insert(0x00800009, DicomDictElt { vr : "SH", vm: "1", name: "Texture Label", retired: "", keyword: "TextureLabel" });
-	dicom_dictionary.insert(0x00800010, DicomDictElt { vr : "OF", vm: "1-n", name: "U Value Data", retired: "", keyword: "UValueData" });
-	dicom_dictionary.insert(0x00800011, DicomDictElt { vr : "OF", vm: "1-n", name: "V Value Data", retired: "", keyword: "VValueData" });
-	dicom_dictionary.insert(0x00800012, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Texture Sequence", retired: "", keyword: "ReferencedTextureSequence" });
-	dicom_dictionary.insert(0x00800013, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Surface Data Sequence", retired: "", keyword: "ReferencedSurfaceDataSequence" });
-	dicom_dictionary.insert(0x00820001, DicomDictElt { vr : "CS", vm: "1", name: "Assessment Summary", retired: "", keyword: "AssessmentSummary" });
-	dicom_dictionary.insert(0x00820003, DicomDictElt { vr : "UT", vm: "1", name: "Assessment Summary Description", retired: "", keyword: "AssessmentSummaryDescription" });
-	dicom_dictionary.insert(0x00820004, DicomDictElt { vr : "SQ", vm: "1", name: "Assessed SOP Instance Sequence", retired: "", keyword: "AssessedSOPInstanceSequence" });
-	dicom_dictionary.insert(0x00820005, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Comparison SOP Instance Sequence", retired: "", keyword: "ReferencedComparisonSOPInstanceSequence" });
-	dicom_dictionary.insert(0x00820006, DicomDictElt { vr : "UL", vm: "1", name: "Number of Assessment Observations", retired: "", keyword: "NumberOfAssessmentObservations" });
-	dicom_dictionary.insert(0x00820007, DicomDictElt { vr : "SQ", vm: "1", name: "Assessment Observations Sequence", retired: "", keyword: "AssessmentObservationsSequence" });
-	dicom_dictionary.insert(0x00820008, DicomDictElt { vr : "CS", vm: "1", name: "Observation Significance", retired: "", keyword: "ObservationSignificance" });
-	dicom_dictionary.insert(0x0082000A, DicomDictElt { vr : "UT", vm: "1", name: "Observation Description", retired: "", keyword: "ObservationDescription" });
-	dicom_dictionary.insert(0x0082000C, DicomDictElt { vr : "SQ", vm: "1", name: "Structured Constraint Observation Sequence", retired: "", keyword: "StructuredContraintObservationSequence" });
-	dicom_dictionary.insert(0x00820010, DicomDictElt { vr : "SQ", vm: "1", name: "Assessed Attribute Value Sequence", retired: "", keyword: "AssessedAttributeValueSequence" });
-	dicom_dictionary.insert(0x00820016, DicomDictElt { vr : "LO", vm: "1", name: "Assessment Set ID", retired: "", keyword: "AssessmentSetID" });
-	dicom_dictionary.insert(0x00820017, DicomDictElt { vr : "SQ", vm: "1", name: "Assessment Requester Sequence", retired: "", keyword: "AssessmentRequesterSequence" });
-	dicom_dictionary.insert(0x00820018, DicomDictElt { vr : "LO", vm: "1", name: "Selector Attribute Name", retired: "", keyword: "SelectorAttributeName" });
-	dicom_dictionary.insert(0x00820019, DicomDictElt { vr : "LO", vm: "1", name: "Selector Attribute Keyword", retired: "", keyword: "SelectorAttributeKeyword" });
-	dicom_dictionary.insert(0x00820021, DicomDictElt { vr : "SQ", vm: "1", name: "Assessment Type Code Sequence", retired: "", keyword: "AssessmentTypeCodeSequence" });
-	dicom_dictionary.insert(0x00820022, DicomDictElt { vr : "SQ", vm: "1", name: "Observation Basis Code Sequence", retired: "", keyword: "ObservationBasisCodeSequence" });
-	dicom_dictionary.insert(0x00820023, DicomDictElt { vr : "LO", vm: "1", name: "Assessment Label", retired: "", keyword: "AssessmentLabel" });
-	dicom_dictionary.insert(0x00820032, DicomDictElt { vr : "CS", vm: "1", name: "Constraint Type", retired: "", keyword: "ConstraintType" });
-	dicom_dictionary.insert(0x00820033, DicomDictElt { vr : "UT", vm: "1", name: "Specification Selection Guidance", retired: "", keyword: "SpecificationSelectionGuidance" });
-	dicom_dictionary.insert(0x00820034, DicomDictElt { vr : "SQ", vm: "1", name: "Constraint Value Sequence", retired: "", keyword: "ConstraintValueSequence" });
-	dicom_dictionary.insert(0x00820035, DicomDictElt { vr : "SQ", vm: "1", name: "Recommended Default Value Sequence", retired: "", keyword: "RecommendedDefaultValueSequence" });
-	dicom_dictionary.insert(0x00820036, DicomDictElt { vr : "CS", vm: "1", name: "Constraint Violation Significance", retired: "", keyword: "ConstraintViolationSignificance" });
-	dicom_dictionary.insert(0x00820037, DicomDictElt { vr : "UT", vm: "1", name: "Constraint Violation Condition", retired: "", keyword: "ConstraintViolationCondition" });
-	dicom_dictionary.insert(0x00820038, DicomDictElt { vr : "CS", vm: "1", name: "Modifiable Constraint Flag", retired: "", keyword: "ModifiableConstraintFlag" });
-	dicom_dictionary.insert(0x00880130, DicomDictElt { vr : "SH", vm: "1", name: "Storage Media File-set ID", retired: "", keyword: "StorageMediaFileSetID" });
-	dicom_dictionary.insert(0x00880140, DicomDictElt { vr : "UI", vm: "1", name: "Storage Media File-set UID", retired: "", keyword: "StorageMediaFileSetUID" });
-	dicom_dictionary.insert(0x00880200, DicomDictElt { vr : "SQ", vm: "1", name: "Icon Image Sequence", retired: "", keyword: "IconImageSequence" });
-	dicom_dictionary.insert(0x00880904, DicomDictElt { vr : "LO", vm: "1", name: "Topic Title", retired: "Retired", keyword: "TopicTitle" });
-	dicom_dictionary.insert(0x00880906, DicomDictElt { vr : "ST", vm: "1", name: "Topic Subject", retired: "Retired", keyword: "TopicSubject" });
-	dicom_dictionary.insert(0x00880910, DicomDictElt { vr : "LO", vm: "1", name: "Topic Author", retired: "Retired", keyword: "TopicAuthor" });
-	dicom_dictionary.insert(0x00880912, DicomDictElt { vr : "LO", vm: "1-32", name: "Topic Keywords", retired: "Retired", keyword: "TopicKeywords" });
-	dicom_dictionary.insert(0x01000410, DicomDictElt { vr : "CS", vm: "1", name: "SOP Instance Status", retired: "", keyword: "SOPInstanceStatus" });
-	dicom_dictionary.insert(0x01000420, DicomDictElt { vr : "DT", vm: "1", name: "SOP Authorization DateTime", retired: "", keyword: "SOPAuthorizationDateTime" });
-	dicom_dictionary.insert(0x01000424, DicomDictElt { vr : "LT", vm: "1", name: "SOP Authorization Comment", retired: "", keyword: "SOPAuthorizationComment" });
-	dicom_dictionary.insert(0x01000426, DicomDictElt { vr : "LO", vm: "1", name: "Authorization Equipment Certification Number", retired: "", keyword: "AuthorizationEquipmentCertificationNumber" });
-	dicom_dictionary.insert(0x04000005, DicomDictElt { vr : "US", vm: "1", name: "MAC ID Number", retired: "", keyword: "MACIDNumber" });
-	dicom_dictionary.insert(0x04000010, DicomDictElt { vr : "UI", vm: "1", name: "MAC Calculation Transfer Syntax UID", retired: "", keyword: "MACCalculationTransferSyntaxUID" });
-	dicom_dictionary.insert(0x04000015, DicomDictElt { vr : "CS", vm: "1", name: "MAC Algorithm", retired: "", keyword: "MACAlgorithm" });
-	dicom_dictionary.insert(0x04000020, DicomDictElt { vr : "AT", vm: "1-n", name: "Data Elements Signed", retired: "", keyword: "DataElementsSigned" });
-	dicom_dictionary.insert(0x04000100, DicomDictElt { vr : "UI", vm: "1", name: "Digital Signature UID", retired: "", keyword: "DigitalSignatureUID" });
-	dicom_dictionary.insert(0x04000105, DicomDictElt { vr : "DT", vm: "1", name: "Digital Signature DateTime", retired: "", keyword: "DigitalSignatureDateTime" });
-	dicom_dictionary.insert(0x04000110, DicomDictElt { vr : "CS", vm: "1", name: "Certificate Type", retired: "", keyword: "CertificateType" });
-	dicom_dictionary.insert(0x04000115, DicomDictElt { vr : "OB", vm: "1", name: "Certificate of Signer", retired: "", keyword: "CertificateOfSigner" });
-	dicom_dictionary.insert(0x04000120, DicomDictElt { vr : "OB", vm: "1", name: "Signature", retired: "", keyword: "Signature" });
-	dicom_dictionary.insert(0x04000305, DicomDictElt { vr : "CS", vm: "1", name: "Certified Timestamp Type", retired: "", keyword: "CertifiedTimestampType" });
-	dicom_dictionary.insert(0x04000310, DicomDictElt { vr : "OB", vm: "1", name: "Certified Timestamp", retired: "", keyword: "CertifiedTimestamp" });
-	dicom_dictionary.insert(0x04000315, DicomDictElt { vr : "FL", vm: "1", name: "", retired: "Retired", keyword: "" });
-	dicom_dictionary.insert(0x04000401, DicomDictElt { vr : "SQ", vm: "1", name: "Digital Signature Purpose Code Sequence", retired: "", keyword: "DigitalSignaturePurposeCodeSequence" });
-	dicom_dictionary.insert(0x04000402, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Digital Signature Sequence", retired: "", keyword: "ReferencedDigitalSignatureSequence" });
-	dicom_dictionary.insert(0x04000403, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced SOP Instance MAC Sequence", retired: "", keyword: "ReferencedSOPInstanceMACSequence" });
-	dicom_dictionary.insert(0x04000404, DicomDictElt { vr : "OB", vm: "1", name: "MAC", retired: "", keyword: "MAC" });
-	dicom_dictionary.insert(0x04000500, DicomDictElt { vr : "SQ", vm: "1", name: "Encrypted Attributes Sequence", retired: "", keyword: "EncryptedAttributesSequence" });
-	dicom_dictionary.insert(0x04000510, DicomDictElt { vr : "UI", vm: "1", name: "Encrypted Content Transfer Syntax UID", retired: "", keyword: "EncryptedContentTransferSyntaxUID" });
-	dicom_dictionary.insert(0x04000520, DicomDictElt { vr : "OB", vm: "1", name: "Encrypted Content", retired: "", keyword: "EncryptedContent" });
-	dicom_dictionary.insert(0x04000550, DicomDictElt { vr : "SQ", vm: "1", name: "Modified Attributes Sequence", retired: "", keyword: "ModifiedAttributesSequence" });
-	dicom_dictionary.insert(0x04000561, DicomDictElt { vr : "SQ", vm: "1", name: "Original Attributes Sequence", retired: "", keyword: "OriginalAttributesSequence" });
-	dicom_dictionary.insert(0x04000562, DicomDictElt { vr : "DT", vm: "1", name: "Attribute Modification DateTime", retired: "", keyword: "AttributeModificationDateTime" });
-	dicom_dictionary.insert(0x04000563, DicomDictElt { vr : "LO", vm: "1", name: "Modifying System", retired: "", keyword: "ModifyingSystem" });
-	dicom_dictionary.insert(0x04000564, DicomDictElt { vr : "LO", vm: "1", name: "Source of Previous Values", retired: "", keyword: "SourceOfPreviousValues" });
-	dicom_dictionary.insert(0x04000565, DicomDictElt { vr : "CS", vm: "1", name: "Reason for the Attribute Modification", retired: "", keyword: "ReasonForTheAttributeModification" });
-	dicom_dictionary.insert(0x20000010, DicomDictElt { vr : "IS", vm: "1", name: "Number of Copies", retired: "", keyword: "NumberOfCopies" });
-	dicom_dictionary.insert(0x2000001E, DicomDictElt { vr : "SQ", vm: "1", name: "Printer Configuration Sequence", retired: "", keyword: "PrinterConfigurationSequence" });
-	dicom_dictionary.insert(0x20000020, DicomDictElt { vr : "CS", vm: "1", name: "Print Priority", retired: "", keyword: "PrintPriority" });
-	dicom_dictionary.insert(0x20000030, DicomDictElt { vr : "CS", vm: "1", name: "Medium Type", retired: "", keyword: "MediumType" });
-	dicom_dictionary.insert(0x20000040, DicomDictElt { vr : "CS", vm: "1", name: "Film Destination", retired: "", keyword: "FilmDestination" });
-	dicom_dictionary.insert(0x20000050, DicomDictElt { vr : "LO", vm: "1", name: "Film Session Label", retired: "", keyword: "FilmSessionLabel" });
-	dicom_dictionary.insert(0x20000060, DicomDictElt { vr : "IS", vm: "1", name: "Memory Allocation", retired: "", keyword: "MemoryAllocation" });
-	dicom_dictionary.insert(0x20000061, DicomDictElt { vr : "IS", vm: "1", name: "Maximum Memory Allocation", retired: "", keyword: "MaximumMemoryAllocation" });
-	dicom_dictionary.insert(0x20000062, DicomDictElt { vr : "CS", vm: "1", name: "Color Image Printing Flag", retired: "Retired", keyword: "ColorImagePrintingFlag" });
-	dicom_dictionary.insert(0x20000063, DicomDictElt { vr : "CS", vm: "1", name: "Collation Flag", retired: "Retired", keyword: "CollationFlag" });
-	dicom_dictionary.insert(0x20000065, DicomDictElt { vr : "CS", vm: "1", name: "Annotation Flag", retired: "Retired", keyword: "AnnotationFlag" });
-	dicom_dictionary.insert(0x20000067, DicomDictElt { vr : "CS", vm: "1", name: "Image Overlay Flag", retired: "Retired", keyword: "ImageOverlayFlag" });
-	dicom_dictionary.insert(0x20000069, DicomDictElt { vr : "CS", vm: "1", name: "Presentation LUT Flag", retired: "Retired", keyword: "PresentationLUTFlag" });
-	dicom_dictionary.insert(0x2000006A, DicomDictElt { vr : "CS", vm: "1", name: "Image Box Presentation LUT Flag", retired: "Retired", keyword: "ImageBoxPresentationLUTFlag" });
-	dicom_dictionary.insert(0x200000A0, DicomDictElt { vr : "US", vm: "1", name: "Memory Bit Depth", retired: "", keyword: "MemoryBitDepth" });
-	dicom_dictionary.insert(0x200000A1, DicomDictElt { vr : "US", vm: "1", name: "Printing Bit Depth", retired: "", keyword: "PrintingBitDepth" });
-	dicom_dictionary.insert(0x200000A2, DicomDictElt { vr : "SQ", vm: "1", name: "Media Installed Sequence", retired: "", keyword: "MediaInstalledSequence" });
-	dicom_dictionary.insert(0x200000A4, DicomDictElt { vr : "SQ", vm: "1", name: "Other Media Available Sequence", retired: "", keyword: "OtherMediaAvailableSequence" });
-	dicom_dictionary.insert(0x200000A8, DicomDictElt { vr : "SQ", vm: "1", name: "Supported Image Display Formats Sequence", retired: "", keyword: "SupportedImageDisplayFormatsSequence" });
-	dicom_dictionary.insert(0x20000500, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Film Box Sequence", retired: "", keyword: "ReferencedFilmBoxSequence" });
-	dicom_dictionary.insert(0x20000510, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Stored Print Sequence", retired: "Retired", keyword: "ReferencedStoredPrintSequence" });
-	dicom_dictionary.insert(0x20100010, DicomDictElt { vr : "ST", vm: "1", name: "Image Display Format", retired: "", keyword: "ImageDisplayFormat" });
-	dicom_dictionary.insert(0x20100030, DicomDictElt { vr : "CS", vm: "1", name: "Annotation Display Format ID", retired: "", keyword: "AnnotationDisplayFormatID" });
-	dicom_dictionary.insert(0x20100040, DicomDictElt { vr : "CS", vm: "1", name: "Film Orientation", retired: "", keyword: "FilmOrientation" });
-	dicom_dictionary.insert(0x20100050, DicomDictElt { vr : "CS", vm: "1", name: "Film Size ID", retired: "", keyword: "FilmSizeID" });
-	dicom_dictionary.insert(0x20100052, DicomDictElt { vr : "CS", vm: "1", name: "Printer Resolution ID", retired: "", keyword: "PrinterResolutionID" });
-	dicom_dictionary.insert(0x20100054, DicomDictElt { vr : "CS", vm: "1", name: "Default Printer Resolution ID", retired: "", keyword: "DefaultPrinterResolutionID" });
-	dicom_dictionary.insert(0x20100060, DicomDictElt { vr : "CS", vm: "1", name: "Magnification Type", retired: "", keyword: "MagnificationType" });
-	dicom_dictionary.insert(0x20100080, DicomDictElt { vr : "CS", vm: "1", name: "Smoothing Type", retired: "", keyword: "SmoothingType" });
-	dicom_dictionary.insert(0x201000A6, DicomDictElt { vr : "CS", vm: "1", name: "Default Magnification Type", retired: "", keyword: "DefaultMagnificationType" });
-	dicom_dictionary.insert(0x201000A7, DicomDictElt { vr : "CS", vm: "1-n", name: "Other Magnification Types Available", retired: "", keyword: "OtherMagnificationTypesAvailable" });
-	dicom_dictionary.insert(0x201000A8, DicomDictElt { vr : "CS", vm: "1", name: "Default Smoothing Type", retired: "", keyword: "DefaultSmoothingType" });
-	dicom_dictionary.insert(0x201000A9, DicomDictElt { vr : "CS", vm: "1-n", name: "Other Smoothing Types Available", retired: "", keyword: "OtherSmoothingTypesAvailable" });
-	dicom_dictionary.insert(0x20100100, DicomDictElt { vr : "CS", vm: "1", name: "Border Density", retired: "", keyword: "BorderDensity" });
-	dicom_dictionary.insert(0x20100110, DicomDictElt { vr : "CS", vm: "1", name: "Empty Image Density", retired: "", keyword: "EmptyImageDensity" });
-	dicom_dictionary.insert(0x20100120, DicomDictElt { vr : "US", vm: "1", name: "Min Density", retired: "", keyword: "MinDensity" });
-	dicom_dictionary.insert(0x20100130, DicomDictElt { vr : "US", vm: "1", name: "Max Density", retired: "", keyword: "MaxDensity" });
-	dicom_dictionary.insert(0x20100140, DicomDictElt { vr : "CS", vm: "1", name: "Trim", retired: "", keyword: "Trim" });
-	dicom_dictionary.insert(0x20100150, DicomDictElt { vr : "ST", vm: "1", name: "Configuration Information", retired: "", keyword: "ConfigurationInformation" });
-	dicom_dictionary.insert(0x20100152, DicomDictElt { vr : "LT", vm: "1", name: "Configuration Information Description", retired: "", keyword: "ConfigurationInformationDescription" });
-	dicom_dictionary.insert(0x20100154, DicomDictElt { vr : "IS", vm: "1", name: "Maximum Collated Films", retired: "", keyword: "MaximumCollatedFilms" });
-	dicom_dictionary.insert(0x2010015E, DicomDictElt { vr : "US", vm: "1", name: "Illumination", retired: "", keyword: "Illumination" });
-	dicom_dictionary.insert(0x20100160, DicomDictElt { vr : "US", vm: "1", name: "Reflected Ambient Light", retired: "", keyword: "ReflectedAmbientLight" });
-	dicom_dictionary.insert(0x20100376, DicomDictElt { vr : "DS", vm: "2", name: "Printer Pixel Spacing", retired: "", keyword: "PrinterPixelSpacing" });
-	dicom_dictionary.insert(0x20100500, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Film Session Sequence", retired: "", keyword: "ReferencedFilmSessionSequence" });
-	dicom_dictionary.insert(0x20100510, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Box Sequence", retired: "", keyword: "ReferencedImageBoxSequence" });
-	dicom_dictionary.insert(0x20100520, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Basic Annotation Box Sequence", retired: "", keyword: "ReferencedBasicAnnotationBoxSequence" });
-	dicom_dictionary.insert(0x20200010, DicomDictElt { vr : "US", vm: "1", name: "Image Box Position", retired: "", keyword: "ImageBoxPosition" });
-	dicom_dictionary.insert(0x20200020, DicomDictElt { vr : "CS", vm: "1", name: "Polarity", retired: "", keyword: "Polarity" });
-	dicom_dictionary.insert(0x20200030, DicomDictElt { vr : "DS", vm: "1", name: "Requested Image Size", retired: "", keyword: "RequestedImageSize" });
-	dicom_dictionary.insert(0x20200040, DicomDictElt { vr : "CS", vm: "1", name: "Requested Decimate/Crop Behavior", retired: "", keyword: "RequestedDecimateCropBehavior" });
-	dicom_dictionary.insert(0x20200050, DicomDictElt { vr : "CS", vm: "1", name: "Requested Resolution ID", retired: "", keyword: "RequestedResolutionID" });
-	dicom_dictionary.insert(0x202000A0, DicomDictElt { vr : "CS", vm: "1", name: "Requested Image Size Flag", retired: "", keyword: "RequestedImageSizeFlag" });
-	dicom_dictionary.insert(0x202000A2, DicomDictElt { vr : "CS", vm: "1", name: "Decimate/Crop Result", retired: "", keyword: "DecimateCropResult" });
-	dicom_dictionary.insert(0x20200110, DicomDictElt { vr : "SQ", vm: "1", name: "Basic Grayscale Image Sequence", retired: "", keyword: "BasicGrayscaleImageSequence" });
-	dicom_dictionary.insert(0x20200111, DicomDictElt { vr : "SQ", vm: "1", name: "Basic Color Image Sequence", retired: "", keyword: "BasicColorImageSequence" });
-	dicom_dictionary.insert(0x20200130, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Overlay Box Sequence", retired: "Retired", keyword: "ReferencedImageOverlayBoxSequence" });
-	dicom_dictionary.insert(0x20200140, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced VOI LUT Box Sequence", retired: "Retired", keyword: "ReferencedVOILUTBoxSequence" });
-	dicom_dictionary.insert(0x20300010, DicomDictElt { vr : "US", vm: "1", name: "Annotation Position", retired: "", keyword: "AnnotationPosition" });
-	dicom_dictionary.insert(0x20300020, DicomDictElt { vr : "LO", vm: "1", name: "Text String", retired: "", keyword: "TextString" });
-	dicom_dictionary.insert(0x20400010, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Overlay Plane Sequence", retired: "Retired", keyword: "ReferencedOverlayPlaneSequence" });
-	dicom_dictionary.insert(0x20400011, DicomDictElt { vr : "US", vm: "1-99", name: "Referenced Overlay Plane Groups", retired: "Retired", keyword: "ReferencedOverlayPlaneGroups" });
-	dicom_dictionary.insert(0x20400020, DicomDictElt { vr : "SQ", vm: "1", name: "Overlay Pixel Data Sequence", retired: "Retired", keyword: "OverlayPixelDataSequence" });
-	dicom_dictionary.insert(0x20400060, DicomDictElt { vr : "CS", vm: "1", name: "Overlay Magnification Type", retired: "Retired", keyword: "OverlayMagnificationType" });
-	dicom_dictionary.insert(0x20400070, DicomDictElt { vr : "CS", vm: "1", name: "Overlay Smoothing Type", retired: "Retired", keyword: "OverlaySmoothingType" });
-	dicom_dictionary.insert(0x20400072, DicomDictElt { vr : "CS", vm: "1", name: "Overlay or Image Magnification", retired: "Retired", keyword: "OverlayOrImageMagnification" });
-	dicom_dictionary.insert(0x20400074, DicomDictElt { vr : "US", vm: "1", name: "Magnify to Number of Columns", retired: "Retired", keyword: "MagnifyToNumberOfColumns" });
-	dicom_dictionary.insert(0x20400080, DicomDictElt { vr : "CS", vm: "1", name: "Overlay Foreground Density", retired: "Retired", keyword: "OverlayForegroundDensity" });
-	dicom_dictionary.insert(0x20400082, DicomDictElt { vr : "CS", vm: "1", name: "Overlay Background Density", retired: "Retired", keyword: "OverlayBackgroundDensity" });
-	dicom_dictionary.insert(0x20400090, DicomDictElt { vr : "CS", vm: "1", name: "Overlay Mode", retired: "Retired", keyword: "OverlayMode" });
-	dicom_dictionary.insert(0x20400100, DicomDictElt { vr : "CS", vm: "1", name: "Threshold Density", retired: "Retired", keyword: "ThresholdDensity" });
-	dicom_dictionary.insert(0x20400500, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Image Box Sequence (Retired)", retired: "Retired", keyword: "ReferencedImageBoxSequenceRetired" });
-	dicom_dictionary.insert(0x20500010, DicomDictElt { vr : "SQ", vm: "1", name: "Presentation LUT Sequence", retired: "", keyword: "PresentationLUTSequence" });
-	dicom_dictionary.insert(0x20500020, DicomDictElt { vr : "CS", vm: "1", name: "Presentation LUT Shape", retired: "", keyword: "PresentationLUTShape" });
-	dicom_dictionary.insert(0x20500500, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Presentation LUT Sequence", retired: "", keyword: "ReferencedPresentationLUTSequence" });
-	dicom_dictionary.insert(0x21000010, DicomDictElt { vr : "SH", vm: "1", name: "Print Job ID", retired: "Retired", keyword: "PrintJobID" });
-	dicom_dictionary.insert(0x21000020, DicomDictElt { vr : "CS", vm: "1", name: "Execution Status", retired: "", keyword: "ExecutionStatus" });
-	dicom_dictionary.insert(0x21000030, DicomDictElt { vr : "CS", vm: "1", name: "Execution Status Info", retired: "", keyword: "ExecutionStatusInfo" });
-	dicom_dictionary.insert(0x21000040, DicomDictElt { vr : "DA", vm: "1", name: "Creation Date", retired: "", keyword: "CreationDate" });
-	dicom_dictionary.insert(0x21000050, DicomDictElt { vr : "TM", vm: "1", name: "Creation Time", retired: "", keyword: "CreationTime" });
-	dicom_dictionary.insert(0x21000070, DicomDictElt { vr : "AE", vm: "1", name: "Originator", retired: "", keyword: "Originator" });
-	dicom_dictionary.insert(0x21000140, DicomDictElt { vr : "AE", vm: "1", name: "Destination AE", retired: "", keyword: "DestinationAE" });
-	dicom_dictionary.insert(0x21000160, DicomDictElt { vr : "SH", vm: "1", name: "Owner ID", retired: "", keyword: "OwnerID" });
-	dicom_dictionary.insert(0x21000170, DicomDictElt { vr : "IS", vm: "1", name: "Number of Films", retired: "", keyword: "NumberOfFilms" });
-	dicom_dictionary.insert(0x21000500, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Print Job Sequence (Pull Stored Print)", retired: "Retired", keyword: "ReferencedPrintJobSequencePullStoredPrint" });
-	dicom_dictionary.insert(0x21100010, DicomDictElt { vr : "CS", vm: "1", name: "Printer Status", retired: "", keyword: "PrinterStatus" });
-	dicom_dictionary.insert(0x21100020, DicomDictElt { vr : "CS", vm: "1", name: "Printer Status Info", retired: "", keyword: "PrinterStatusInfo" });
-	dicom_dictionary.insert(0x21100030, DicomDictElt { vr : "LO", vm: "1", name: "Printer Name", retired: "", keyword: "PrinterName" });
-	dicom_dictionary.insert(0x21100099, DicomDictElt { vr : "SH", vm: "1", name: "Print Queue ID", retired: "Retired", keyword: "PrintQueueID" });
-	dicom_dictionary.insert(0x21200010, DicomDictElt { vr : "CS", vm: "1", name: "Queue Status", retired: "Retired", keyword: "QueueStatus" });
-	dicom_dictionary.insert(0x21200050, DicomDictElt { vr : "SQ", vm: "1", name: "Print Job Description Sequence", retired: "Retired", keyword: "PrintJobDescriptionSequence" });
-	dicom_dictionary.insert(0x21200070, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Print Job Sequence", retired: "Retired", keyword: "ReferencedPrintJobSequence" });
-	dicom_dictionary.insert(0x21300010, DicomDictElt { vr : "SQ", vm: "1", name: "Print Management Capabilities Sequence", retired: "Retired", keyword: "PrintManagementCapabilitiesSequence" });
-	dicom_dictionary.insert(0x21300015, DicomDictElt { vr : "SQ", vm: "1", name: "Printer Characteristics Sequence", retired: "Retired", keyword: "PrinterCharacteristicsSequence" });
-	dicom_dictionary.insert(0x21300030, DicomDictElt { vr : "SQ", vm: "1", name: "Film Box Content Sequence", retired: "Retired", keyword: "FilmBoxContentSequence" });
-	dicom_dictionary.insert(0x21300040, DicomDictElt { vr : "SQ", vm: "1", name: "Image Box Content Sequence", retired: "Retired", keyword: "ImageBoxContentSequence" });
-	dicom_dictionary.insert(0x21300050, DicomDictElt { vr : "SQ", vm: "1", name: "Annotation Content Sequence", retired: "Retired", keyword: "AnnotationContentSequence" });
-	dicom_dictionary.insert(0x21300060, DicomDictElt { vr : "SQ", vm: "1", name: "Image Overlay Box Content Sequence", retired: "Retired", keyword: "ImageOverlayBoxContentSequence" });
-	dicom_dictionary.insert(0x21300080, DicomDictElt { vr : "SQ", vm: "1", name: "Presentation LUT Content Sequence", retired: "Retired", keyword: "PresentationLUTContentSequence" });
-	dicom_dictionary.insert(0x213000A0, DicomDictElt { vr : "SQ", vm: "1", name: "Proposed Study Sequence", retired: "Retired", keyword: "ProposedStudySequence" });
-	dicom_dictionary.insert(0x213000C0, DicomDictElt { vr : "SQ", vm: "1", name: "Original Image Sequence", retired: "Retired", keyword: "OriginalImageSequence" });
-	dicom_dictionary.insert(0x22000001, DicomDictElt { vr : "CS", vm: "1", name: "Label Using Information Extracted From Instances", retired: "", keyword: "LabelUsingInformationExtractedFromInstances" });
-	dicom_dictionary.insert(0x22000002, DicomDictElt { vr : "UT", vm: "1", name: "Label Text", retired: "", keyword: "LabelText" });
-	dicom_dictionary.insert(0x22000003, DicomDictElt { vr : "CS", vm: "1", name: "Label Style Selection", retired: "", keyword: "LabelStyleSelection" });
-	dicom_dictionary.insert(0x22000004, DicomDictElt { vr : "LT", vm: "1", name: "Media Disposition", retired: "", keyword: "MediaDisposition" });
-	dicom_dictionary.insert(0x22000005, DicomDictElt { vr : "LT", vm: "1", name: "Barcode Value", retired: "", keyword: "BarcodeValue" });
-	dicom_dictionary.insert(0x22000006, DicomDictElt { vr : "CS", vm: "1", name: "Barcode Symbology", retired: "", keyword: "BarcodeSymbology" });
-	dicom_dictionary.insert(0x22000007, DicomDictElt { vr : "CS", vm: "1", name: "Allow Media Splitting", retired: "", keyword: "AllowMediaSplitting" });
-	dicom_dictionary.insert(0x22000008, DicomDictElt { vr : "CS", vm: "1", name: "Include Non-DICOM Objects", retired: "", keyword: "IncludeNonDICOMObjects" });
-	dicom_dictionary.insert(0x22000009, DicomDictElt { vr : "CS", vm: "1", name: "Include Display Application", retired: "", keyword: "IncludeDisplayApplication" });
-	dicom_dictionary.insert(0x2200000A, DicomDictElt { vr : "CS", vm: "1", name: "Preserve Composite Instances After Media Creation", retired: "", keyword: "PreserveCompositeInstancesAfterMediaCreation" });
-	dicom_dictionary.insert(0x2200000B, DicomDictElt { vr : "US", vm: "1", name: "Total Number of Pieces of Media Created", retired: "", keyword: "TotalNumberOfPiecesOfMediaCreated" });
-	dicom_dictionary.insert(0x2200000C, DicomDictElt { vr : "LO", vm: "1", name: "Requested Media Application Profile", retired: "", keyword: "RequestedMediaApplicationProfile" });
-	dicom_dictionary.insert(0x2200000D, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Storage Media Sequence", retired: "", keyword: "ReferencedStorageMediaSequence" });
-	dicom_dictionary.insert(0x2200000E, DicomDictElt { vr : "AT", vm: "1-n", name: "Failure Attributes", retired: "", keyword: "FailureAttributes" });
-	dicom_dictionary.insert(0x2200000F, DicomDictElt { vr : "CS", vm: "1", name: "Allow Lossy Compression", retired: "", keyword: "AllowLossyCompression" });
-	dicom_dictionary.insert(0x22000020, DicomDictElt { vr : "CS", vm: "1", name: "Request Priority", retired: "", keyword: "RequestPriority" });
-	dicom_dictionary.insert(0x30020002, DicomDictElt { vr : "SH", vm: "1", name: "RT Image Label", retired: "", keyword: "RTImageLabel" });
-	dicom_dictionary.insert(0x30020003, DicomDictElt { vr : "LO", vm: "1", name: "RT Image Name", retired: "", keyword: "RTImageName" });
-	dicom_dictionary.insert(0x30020004, DicomDictElt { vr : "ST", vm: "1", name: "RT Image Description", retired: "", keyword: "RTImageDescription" });
-	dicom_dictionary.insert(0x3002000A, DicomDictElt { vr : "CS", vm: "1", name: "Reported Values Origin", retired: "", keyword: "ReportedValuesOrigin" });
-	dicom_dictionary.insert(0x3002000C, DicomDictElt { vr : "CS", vm: "1", name: "RT Image Plane", retired: "", keyword: "RTImagePlane" });
-	dicom_dictionary.insert(0x3002000D, DicomDictElt { vr : "DS", vm: "3", name: "X-Ray Image Receptor Translation", retired: "", keyword: "XRayImageReceptorTranslation" });
-	dicom_dictionary.insert(0x3002000E, DicomDictElt { vr : "DS", vm: "1", name: "X-Ray Image Receptor Angle", retired: "", keyword: "XRayImageReceptorAngle" });
-	dicom_dictionary.insert(0x30020010, DicomDictElt { vr : "DS", vm: "6", name: "RT Image Orientation", retired: "", keyword: "RTImageOrientation" });
-	dicom_dictionary.insert(0x30020011, DicomDictElt { vr : "DS", vm: "2", name: "Image Plane Pixel Spacing", retired: "", keyword: "ImagePlanePixelSpacing" });
-	dicom_dictionary.insert(0x30020012, DicomDictElt { vr : "DS", vm: "2", name: "RT Image Position", retired: "", keyword: "RTImagePosition" });
-	dicom_dictionary.insert(0x30020020, DicomDictElt { vr : "SH", vm: "1", name: "Radiation Machine Name", retired: "", keyword: "RadiationMachineName" });
-	dicom_dictionary.insert(0x30020022, DicomDictElt { vr : "DS", vm: "1", name: "Radiation Machine SAD", retired: "", keyword: "RadiationMachineSAD" });
-	dicom_dictionary.insert(0x30020024, DicomDictElt { vr : "DS", vm: "1", name: "Radiation Machine SSD", retired: "", keyword: "RadiationMachineSSD" });
-	dicom_dictionary.insert(0x30020026, DicomDictElt { vr : "DS", vm: "1", name: "RT Image SID", retired: "", keyword: "RTImageSID" });
-	dicom_dictionary.insert(0x30020028, DicomDictElt { vr : "DS", vm: "1", name: "Source to Reference Object Distance", retired: "", keyword: "SourceToReferenceObjectDistance" });
-	dicom_dictionary.insert(0x30020029, DicomDictElt { vr : "IS", vm: "1", name: "Fraction Number", retired: "", keyword: "FractionNumber" });
-	dicom_dictionary.insert(0x30020030, DicomDictElt { vr : "SQ", vm: "1", name: "Exposure Sequence", retired: "", keyword: "ExposureSequence" });
-	dicom_dictionary.insert(0x30020032, DicomDictElt { vr : "DS", vm: "1", name: "Meterset Exposure", retired: "", keyword: "MetersetExposure" });
-	dicom_dictionary.insert(0x30020034, DicomDictElt { vr : "DS", vm: "4", name: "Diaphragm Position", retired: "", keyword: "DiaphragmPosition" });
-	dicom_dictionary.insert(0x30020040, DicomDictElt { vr : "SQ", vm: "1", name: "Fluence Map Sequence", retired: "", keyword: "FluenceMapSequence" });
-	dicom_dictionary.insert(0x30020041, DicomDictElt { vr : "CS", vm: "1", name: "Fluence Data Source", retired: "", keyword: "FluenceDataSource" });
-	dicom_dictionary.insert(0x30020042, DicomDictElt { vr : "DS", vm: "1", name: "Fluence Data Scale", retired: "", keyword: "FluenceDataScale" });
-	dicom_dictionary.insert(0x30020050, DicomDictElt { vr : "SQ", vm: "1", name: "Primary Fluence Mode Sequence", retired: "", keyword: "PrimaryFluenceModeSequence" });
-	dicom_dictionary.insert(0x30020051, DicomDictElt { vr : "CS", vm: "1", name: "Fluence Mode", retired: "", keyword: "FluenceMode" });
-	dicom_dictionary.insert(0x30020052, DicomDictElt { vr : "SH", vm: "1", name: "Fluence Mode ID", retired: "", keyword: "FluenceModeID" });
-	dicom_dictionary.insert(0x30040001, DicomDictElt { vr : "CS", vm: "1", name: "DVH Type", retired: "", keyword: "DVHType" });
-	dicom_dictionary.insert(0x30040002, DicomDictElt { vr : "CS", vm: "1", name: "Dose Units", retired: "", keyword: "DoseUnits" });
-	dicom_dictionary.insert(0x30040004, DicomDictElt { vr : "CS", vm: "1", name: "Dose Type", retired: "", keyword: "DoseType" });
-	dicom_dictionary.insert(0x30040005, DicomDictElt { vr : "CS", vm: "1", name: "Spatial Transform of Dose", retired: "", keyword: "SpatialTransformOfDose" });
-	dicom_dictionary.insert(0x30040006, DicomDictElt { vr : "LO", vm: "1", name: "Dose Comment", retired: "", keyword: "DoseComment" });
-	dicom_dictionary.insert(0x30040008, DicomDictElt { vr : "DS", vm: "3", name: "Normalization Point", retired: "", keyword: "NormalizationPoint" });
-	dicom_dictionary.insert(0x3004000A, DicomDictElt { vr : "CS", vm: "1", name: "Dose Summation Type", retired: "", keyword: "DoseSummationType" });
-	dicom_dictionary.insert(0x3004000C, DicomDictElt { vr : "DS", vm: "2-n", name: "Grid Frame Offset Vector", retired: "", keyword: "GridFrameOffsetVector" });
-	dicom_dictionary.insert(0x3004000E, DicomDictElt { vr : "DS", vm: "1", name: "Dose Grid Scaling", retired: "", keyword: "DoseGridScaling" });
-	dicom_dictionary.insert(0x30040010, DicomDictElt { vr : "SQ", vm: "1", name: "RT Dose ROI Sequence", retired: "", keyword: "RTDoseROISequence" });
-	dicom_dictionary.insert(0x30040012, DicomDictElt { vr : "DS", vm: "1", name: "Dose Value", retired: "", keyword: "DoseValue" });
-	dicom_dictionary.insert(0x30040014, DicomDictElt { vr : "CS", vm: "1-3", name: "Tissue Heterogeneity Correction", retired: "", keyword: "TissueHeterogeneityCorrection" });
-	dicom_dictionary.insert(0x30040040, DicomDictElt { vr : "DS", vm: "3", name: "DVH Normalization Point", retired: "", keyword: "DVHNormalizationPoint" });
-	dicom_dictionary.insert(0x30040042, DicomDictElt { vr : "DS", vm: "1", name: "DVH Normalization Dose Value", retired: "", keyword: "DVHNormalizationDoseValue" });
-	dicom_dictionary.insert(0x30040050, DicomDictElt { vr : "SQ", vm: "1", name: "DVH Sequence", retired: "", keyword: "DVHSequence" });
-	dicom_dictionary.insert(0x30040052, DicomDictElt { vr : "DS", vm: "1", name: "DVH Dose Scaling", retired: "", keyword: "DVHDoseScaling" });
-	dicom_dictionary.insert(0x30040054, DicomDictElt { vr : "CS", vm: "1", name: "DVH Volume Units", retired: "", keyword: "DVHVolumeUnits" });
-	dicom_dictionary.insert(0x30040056, DicomDictElt { vr : "IS", vm: "1", name: "DVH Number of Bins", retired: "", keyword: "DVHNumberOfBins" });
-	dicom_dictionary.insert(0x30040058, DicomDictElt { vr : "DS", vm: "2-2n", name: "DVH Data", retired: "", keyword: "DVHData" });
-	dicom_dictionary.insert(0x30040060, DicomDictElt { vr : "SQ", vm: "1", name: "DVH Referenced ROI Sequence", retired: "", keyword: "DVHReferencedROISequence" });
-	dicom_dictionary.insert(0x30040062, DicomDictElt { vr : "CS", vm: "1", name: "DVH ROI Contribution Type", retired: "", keyword: "DVHROIContributionType" });
-	dicom_dictionary.insert(0x30040070, DicomDictElt { vr : "DS", vm: "1", name: "DVH Minimum Dose", retired: "", keyword: "DVHMinimumDose" });
-	dicom_dictionary.insert(0x30040072, DicomDictElt { vr : "DS", vm: "1", name: "DVH Maximum Dose", retired: "", keyword: "DVHMaximumDose" });
-	dicom_dictionary.insert(0x30040074, DicomDictElt { vr : "DS", vm: "1", name: "DVH Mean Dose", retired: "", keyword: "DVHMeanDose" });
-	dicom_dictionary.insert(0x30060002, DicomDictElt { vr : "SH", vm: "1", name: "Structure Set Label", retired: "", keyword: "StructureSetLabel" });
-	dicom_dictionary.insert(0x30060004, DicomDictElt { vr : "LO", vm: "1", name: "Structure Set Name", retired: "", keyword: "StructureSetName" });
-	dicom_dictionary.insert(0x30060006, DicomDictElt { vr : "ST", vm: "1", name: "Structure Set Description", retired: "", keyword: "StructureSetDescription" });
-	dicom_dictionary.insert(0x30060008, DicomDictElt { vr : "DA", vm: "1", name: "Structure Set Date", retired: "", keyword: "StructureSetDate" });
-	dicom_dictionary.insert(0x30060009, DicomDictElt { vr : "TM", vm: "1", name: "Structure Set Time", retired: "", keyword: "StructureSetTime" });
-	dicom_dictionary.insert(0x30060010, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Frame of Reference Sequence", retired: "", keyword: "ReferencedFrameOfReferenceSequence" });
-	dicom_dictionary.insert(0x30060012, DicomDictElt { vr : "SQ", vm: "1", name: "RT Referenced Study Sequence", retired: "", keyword: "RTReferencedStudySequence" });
-	dicom_dictionary.insert(0x30060014, DicomDictElt { vr : "SQ", vm: "1", name: "RT Referenced Series Sequence", retired: "", keyword: "RTReferencedSeriesSequence" });
-	dicom_dictionary.insert(0x30060016, DicomDictElt { vr : "SQ", vm: "1", name: "Contour Image Sequence", retired: "", keyword: "ContourImageSequence" });
-	dicom_dictionary.insert(0x30060018, DicomDictElt { vr : "SQ", vm: "1", name: "Predecessor Structure Set Sequence", retired: "", keyword: "PredecessorStructureSetSequence" });
-	dicom_dictionary.insert(0x30060020, DicomDictElt { vr : "SQ", vm: "1", name: "Structure Set ROI Sequence", retired: "", keyword: "StructureSetROISequence" });
-	dicom_dictionary.insert(0x30060022, DicomDictElt { vr : "IS", vm: "1", name: "ROI Number", retired: "", keyword: "ROINumber" });
-	dicom_dictionary.insert(0x30060024, DicomDictElt { vr : "UI", vm: "1", name: "Referenced Frame of Reference UID", retired: "", keyword: "ReferencedFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x30060026, DicomDictElt { vr : "LO", vm: "1", name: "ROI Name", retired: "", keyword: "ROIName" });
-	dicom_dictionary.insert(0x30060028, DicomDictElt { vr : "ST", vm: "1", name: "ROI Description", retired: "", keyword: "ROIDescription" });
-	dicom_dictionary.insert(0x3006002A, DicomDictElt { vr : "IS", vm: "3", name: "ROI Display Color", retired: "", keyword: "ROIDisplayColor" });
-	dicom_dictionary.insert(0x3006002C, DicomDictElt { vr : "DS", vm: "1", name: "ROI Volume", retired: "", keyword: "ROIVolume" });
-	dicom_dictionary.insert(0x30060030, DicomDictElt { vr : "SQ", vm: "1", name: "RT Related ROI Sequence", retired: "", keyword: "RTRelatedROISequence" });
-	dicom_dictionary.insert(0x30060033, DicomDictElt { vr : "CS", vm: "1", name: "RT ROI Relationship", retired: "", keyword: "RTROIRelationship" });
-	dicom_dictionary.insert(0x30060036, DicomDictElt { vr : "CS", vm: "1", name: "ROI Generation Algorithm", retired: "", keyword: "ROIGenerationAlgorithm" });
-	dicom_dictionary.insert(0x30060038, DicomDictElt { vr : "LO", vm: "1", name: "ROI Generation Description", retired: "", keyword: "ROIGenerationDescription" });
-	dicom_dictionary.insert(0x30060039, DicomDictElt { vr : "SQ", vm: "1", name: "ROI Contour Sequence", retired: "", keyword: "ROIContourSequence" });
-	dicom_dictionary.insert(0x30060040, DicomDictElt { vr : "SQ", vm: "1", name: "Contour Sequence", retired: "", keyword: "ContourSequence" });
-	dicom_dictionary.insert(0x30060042, DicomDictElt { vr : "CS", vm: "1", name: "Contour Geometric Type", retired: "", keyword: "ContourGeometricType" });
-	dicom_dictionary.insert(0x30060044, DicomDictElt { vr : "DS", vm: "1", name: "Contour Slab Thickness", retired: "", keyword: "ContourSlabThickness" });
-	dicom_dictionary.insert(0x30060045, DicomDictElt { vr : "DS", vm: "3", name: "Contour Offset Vector", retired: "", keyword: "ContourOffsetVector" });
-	dicom_dictionary.insert(0x30060046, DicomDictElt { vr : "IS", vm: "1", name: "Number of Contour Points", retired: "", keyword: "NumberOfContourPoints" });
-	dicom_dictionary.insert(0x30060048, DicomDictElt { vr : "IS", vm: "1", name: "Contour Number", retired: "", keyword: "ContourNumber" });
-	dicom_dictionary.insert(0x30060049, DicomDictElt { vr : "IS", vm: "1-n", name: "Attached Contours", retired: "", keyword: "AttachedContours" });
-	dicom_dictionary.insert(0x30060050, DicomDictElt { vr : "DS", vm: "3-3n", name: "Contour Data", retired: "", keyword: "ContourData" });
-	dicom_dictionary.insert(0x30060080, DicomDictElt { vr : "SQ", vm: "1", name: "RT ROI Observations Sequence", retired: "", keyword: "RTROIObservationsSequence" });
-	dicom_dictionary.insert(0x30060082, DicomDictElt { vr : "IS", vm: "1", name: "Observation Number", retired: "", keyword: "ObservationNumber" });
-	dicom_dictionary.insert(0x30060084, DicomDictElt { vr : "IS", vm: "1", name: "Referenced ROI Number", retired: "", keyword: "ReferencedROINumber" });
-	dicom_dictionary.insert(0x30060085, DicomDictElt { vr : "SH", vm: "1", name: "ROI Observation Label", retired: "", keyword: "ROIObservationLabel" });
-	dicom_dictionary.insert(0x30060086, DicomDictElt { vr : "SQ", vm: "1", name: "RT ROI Identification Code Sequence", retired: "", keyword: "RTROIIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x30060088, DicomDictElt { vr : "ST", vm: "1", name: "ROI Observation Description", retired: "", keyword: "ROIObservationDescription" });
-	dicom_dictionary.insert(0x300600A0, DicomDictElt { vr : "SQ", vm: "1", name: "Related RT ROI Observations Sequence", retired: "", keyword: "RelatedRTROIObservationsSequence" });
-	dicom_dictionary.insert(0x300600A4, DicomDictElt { vr : "CS", vm: "1", name: "RT ROI Interpreted Type", retired: "", keyword: "RTROIInterpretedType" });
-	dicom_dictionary.insert(0x300600A6, DicomDictElt { vr : "PN", vm: "1", name: "ROI Interpreter", retired: "", keyword: "ROIInterpreter" });
-	dicom_dictionary.insert(0x300600B0, DicomDictElt { vr : "SQ", vm: "1", name: "ROI Physical Properties Sequence", retired: "", keyword: "ROIPhysicalPropertiesSequence" });
-	dicom_dictionary.insert(0x300600B2, DicomDictElt { vr : "CS", vm: "1", name: "ROI Physical Property", retired: "", keyword: "ROIPhysicalProperty" });
-	dicom_dictionary.insert(0x300600B4, DicomDictElt { vr : "DS", vm: "1", name: "ROI Physical Property Value", retired: "", keyword: "ROIPhysicalPropertyValue" });
-	dicom_dictionary.insert(0x300600B6, DicomDictElt { vr : "SQ", vm: "1", name: "ROI Elemental Composition Sequence", retired: "", keyword: "ROIElementalCompositionSequence" });
-	dicom_dictionary.insert(0x300600B7, DicomDictElt { vr : "US", vm: "1", name: "ROI Elemental Composition Atomic Number", retired: "", keyword: "ROIElementalCompositionAtomicNumber" });
-	dicom_dictionary.insert(0x300600B8, DicomDictElt { vr : "FL", vm: "1", name: "ROI Elemental Composition Atomic Mass Fraction", retired: "", keyword: "ROIElementalCompositionAtomicMassFraction" });
-	dicom_dictionary.insert(0x300600B9, DicomDictElt { vr : "SQ", vm: "1", name: "Additional RT ROI Identification Code Sequence", retired: "Retired", keyword: "AdditionalRTROIIdentificationCodeSequence" });
-	dicom_dictionary.insert(0x300600C0, DicomDictElt { vr : "SQ", vm: "1", name: "Frame of Reference Relationship Sequence", retired: "Retired", keyword: "FrameOfReferenceRelationshipSequence" });
-	dicom_dictionary.insert(0x300600C2, DicomDictElt { vr : "UI", vm: "1", name: "Related Frame of Reference UID", retired: "Retired", keyword: "RelatedFrameOfReferenceUID" });
-	dicom_dictionary.insert(0x300600C4, DicomDictElt { vr : "CS", vm: "1", name: "Frame of Reference Transformation Type", retired: "Retired", keyword: "FrameOfReferenceTransformationType" });
-	dicom_dictionary.insert(0x300600C6, DicomDictElt { vr : "DS", vm: "16", name: "Frame of Reference Transformation Matrix", retired: "", keyword: "FrameOfReferenceTransformationMatrix" });
-	dicom_dictionary.insert(0x300600C8, DicomDictElt { vr : "LO", vm: "1", name: "Frame of Reference Transformation Comment", retired: "", keyword: "FrameOfReferenceTransformationComment" });
-	dicom_dictionary.insert(0x30080010, DicomDictElt { vr : "SQ", vm: "1", name: "Measured Dose Reference Sequence", retired: "", keyword: "MeasuredDoseReferenceSequence" });
-	dicom_dictionary.insert(0x30080012, DicomDictElt { vr : "ST", vm: "1", name: "Measured Dose Description", retired: "", keyword: "MeasuredDoseDescription" });
-	dicom_dictionary.insert(0x30080014, DicomDictElt { vr : "CS", vm: "1", name: "Measured Dose Type", retired: "", keyword: "MeasuredDoseType" });
-	dicom_dictionary.insert(0x30080016, DicomDictElt { vr : "DS", vm: "1", name: "Measured Dose Value", retired: "", keyword: "MeasuredDoseValue" });
-	dicom_dictionary.insert(0x30080020, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Session Beam Sequence", retired: "", keyword: "TreatmentSessionBeamSequence" });
-	dicom_dictionary.insert(0x30080021, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Session Ion Beam Sequence", retired: "", keyword: "TreatmentSessionIonBeamSequence" });
-	dicom_dictionary.insert(0x30080022, DicomDictElt { vr : "IS", vm: "1", name: "Current Fraction Number", retired: "", keyword: "CurrentFractionNumber" });
-	dicom_dictionary.insert(0x30080024, DicomDictElt { vr : "DA", vm: "1", name: "Treatment Control Point Date", retired: "", keyword: "TreatmentControlPointDate" });
-	dicom_dictionary.insert(0x30080025, DicomDictElt { vr : "TM", vm: "1", name: "Treatment Control Point Time", retired: "", keyword: "TreatmentControlPointTime" });
-	dicom_dictionary.insert(0x3008002A, DicomDictElt { vr : "CS", vm: "1", name: "Treatment Termination Status", retired: "", keyword: "TreatmentTerminationStatus" });
-	dicom_dictionary.insert(0x3008002B, DicomDictElt { vr : "SH", vm: "1", name: "Treatment Termination Code", retired: "", keyword: "TreatmentTerminationCode" });
-	dicom_dictionary.insert(0x3008002C, DicomDictElt { vr : "CS", vm: "1", name: "Treatment Verification Status", retired: "", keyword: "TreatmentVerificationStatus" });
-	dicom_dictionary.insert(0x30080030, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Treatment Record Sequence", retired: "", keyword: "ReferencedTreatmentRecordSequence" });
-	dicom_dictionary.insert(0x30080032, DicomDictElt { vr : "DS", vm: "1", name: "Specified Primary Meterset", retired: "", keyword: "SpecifiedPrimaryMeterset" });
-	dicom_dictionary.insert(0x30080033, DicomDictElt { vr : "DS", vm: "1", name: "Specified Secondary Meterset", retired: "", keyword: "SpecifiedSecondaryMeterset" });
-	dicom_dictionary.insert(0x30080036, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Primary Meterset", retired: "", keyword: "DeliveredPrimaryMeterset" });
-	dicom_dictionary.insert(0x30080037, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Secondary Meterset", retired: "", keyword: "DeliveredSecondaryMeterset" });
-	dicom_dictionary.insert(0x3008003A, DicomDictElt { vr : "DS", vm: "1", name: "Specified Treatment Time", retired: "", keyword: "SpecifiedTreatmentTime" });
-	dicom_dictionary.insert(0x3008003B, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Treatment Time", retired: "", keyword: "DeliveredTreatmentTime" });
-	dicom_dictionary.insert(0x30080040, DicomDictElt { vr : "SQ", vm: "1", name: "Control Point Delivery Sequence", retired: "", keyword: "ControlPointDeliverySequence" });
-	dicom_dictionary.insert(0x30080041, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Control Point Delivery Sequence", retired: "", keyword: "IonControlPointDeliverySequence" });
-	dicom_dictionary.insert(0x30080042, DicomDictElt { vr : "DS", vm: "1", name: "Specified Meterset", retired: "", keyword: "SpecifiedMeterset" });
-	dicom_dictionary.insert(0x30080044, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Meterset", retired: "", keyword: "DeliveredMeterset" });
-	dicom_dictionary.insert(0x30080045, DicomDictElt { vr : "FL", vm: "1", name: "Meterset Rate Set", retired: "", keyword: "MetersetRateSet" });
-	dicom_dictionary.insert(0x30080046, DicomDictElt { vr : "FL", vm: "1", name: "Meterset Rate Delivered", retired: "", keyword: "MetersetRateDelivered" });
-	dicom_dictionary.insert(0x30080047, DicomDictElt { vr : "FL", vm: "1-n", name: "Scan Spot Metersets Delivered", retired: "", keyword: "ScanSpotMetersetsDelivered" });
-	dicom_dictionary.insert(0x30080048, DicomDictElt { vr : "DS", vm: "1", name: "Dose Rate Delivered", retired: "", keyword: "DoseRateDelivered" });
-	dicom_dictionary.insert(0x30080050, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Summary Calculated Dose Reference Sequence", retired: "", keyword: "TreatmentSummaryCalculatedDoseReferenceSequence" });
-	dicom_dictionary.insert(0x30080052, DicomDictElt { vr : "DS", vm: "1", name: "Cumulative Dose to Dose Reference", retired: "", keyword: "CumulativeDoseToDoseReference" });
-	dicom_dictionary.insert(0x30080054, DicomDictElt { vr : "DA", vm: "1", name: "First Treatment Date", retired: "", keyword: "FirstTreatmentDate" });
-	dicom_dictionary.insert(0x30080056, DicomDictElt { vr : "DA", vm: "1", name: "Most Recent Treatment Date", retired: "", keyword: "MostRecentTreatmentDate" });
-	dicom_dictionary.insert(0x3008005A, DicomDictElt { vr : "IS", vm: "1", name: "Number of Fractions Delivered", retired: "", keyword: "NumberOfFractionsDelivered" });
-	dicom_dictionary.insert(0x30080060, DicomDictElt { vr : "SQ", vm: "1", name: "Override Sequence", retired: "", keyword: "OverrideSequence" });
-	dicom_dictionary.insert(0x30080061, DicomDictElt { vr : "AT", vm: "1", name: "Parameter Sequence Pointer", retired: "", keyword: "ParameterSequencePointer" });
-	dicom_dictionary.insert(0x30080062, DicomDictElt { vr : "AT", vm: "1", name: "Override Parameter Pointer", retired: "", keyword: "OverrideParameterPointer" });
-	dicom_dictionary.insert(0x30080063, DicomDictElt { vr : "IS", vm: "1", name: "Parameter Item Index", retired: "", keyword: "ParameterItemIndex" });
-	dicom_dictionary.insert(0x30080064, DicomDictElt { vr : "IS", vm: "1", name: "Measured Dose Reference Number", retired: "", keyword: "MeasuredDoseReferenceNumber" });
-	dicom_dictionary.insert(0x30080065, DicomDictElt { vr : "AT", vm: "1", name: "Parameter Pointer", retired: "", keyword: "ParameterPointer" });
-	dicom_dictionary.insert(0x30080066, DicomDictElt { vr : "ST", vm: "1", name: "Override Reason", retired: "", keyword: "OverrideReason" });
-	dicom_dictionary.insert(0x30080067, DicomDictElt { vr : "US", vm: "1", name: "Parameter Value Number", retired: "", keyword: "ParameterValueNumber" });
-	dicom_dictionary.insert(0x30080068, DicomDictElt { vr : "SQ", vm: "1", name: "Corrected Parameter Sequence", retired: "", keyword: "CorrectedParameterSequence" });
-	dicom_dictionary.insert(0x3008006A, DicomDictElt { vr : "FL", vm: "1", name: "Correction Value", retired: "", keyword: "CorrectionValue" });
-	dicom_dictionary.insert(0x30080070, DicomDictElt { vr : "SQ", vm: "1", name: "Calculated Dose Reference Sequence", retired: "", keyword: "CalculatedDoseReferenceSequence" });
-	dicom_dictionary.insert(0x30080072, DicomDictElt { vr : "IS", vm: "1", name: "Calculated Dose Reference Number", retired: "", keyword: "CalculatedDoseReferenceNumber" });
-	dicom_dictionary.insert(0x30080074, DicomDictElt { vr : "ST", vm: "1", name: "Calculated Dose Reference Description", retired: "", keyword: "CalculatedDoseReferenceDescription" });
-	dicom_dictionary.insert(0x30080076, DicomDictElt { vr : "DS", vm: "1", name: "Calculated Dose Reference Dose Value", retired: "", keyword: "CalculatedDoseReferenceDoseValue" });
-	dicom_dictionary.insert(0x30080078, DicomDictElt { vr : "DS", vm: "1", name: "Start Meterset", retired: "", keyword: "StartMeterset" });
-	dicom_dictionary.insert(0x3008007A, DicomDictElt { vr : "DS", vm: "1", name: "End Meterset", retired: "", keyword: "EndMeterset" });
-	dicom_dictionary.insert(0x30080080, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Measured Dose Reference Sequence", retired: "", keyword: "ReferencedMeasuredDoseReferenceSequence" });
-	dicom_dictionary.insert(0x30080082, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Measured Dose Reference Number", retired: "", keyword: "ReferencedMeasuredDoseReferenceNumber" });
-	dicom_dictionary.insert(0x30080090, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Calculated Dose Reference Sequence", retired: "", keyword: "ReferencedCalculatedDoseReferenceSequence" });
-	dicom_dictionary.insert(0x30080092, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Calculated Dose Reference Number", retired: "", keyword: "ReferencedCalculatedDoseReferenceNumber" });
-	dicom_dictionary.insert(0x300800A0, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Limiting Device Leaf Pairs Sequence", retired: "", keyword: "BeamLimitingDeviceLeafPairsSequence" });
-	dicom_dictionary.insert(0x300800B0, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Wedge Sequence", retired: "", keyword: "RecordedWedgeSequence" });
-	dicom_dictionary.insert(0x300800C0, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Compensator Sequence", retired: "", keyword: "RecordedCompensatorSequence" });
-	dicom_dictionary.insert(0x300800D0, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Block Sequence", retired: "", keyword: "RecordedBlockSequence" });
-	dicom_dictionary.insert(0x300800E0, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Summary Measured Dose Reference Sequence", retired: "", keyword: "TreatmentSummaryMeasuredDoseReferenceSequence" });
-	dicom_dictionary.insert(0x300800F0, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Snout Sequence", retired: "", keyword: "RecordedSnoutSequence" });
-	dicom_dictionary.insert(0x300800F2, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Range Shifter Sequence", retired: "", keyword: "RecordedRangeShifterSequence" });
-	dicom_dictionary.insert(0x300800F4, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Lateral Spreading Device Sequence", retired: "", keyword: "RecordedLateralSpreadingDeviceSequence" });
-	dicom_dictionary.insert(0x300800F6, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Range Modulator Sequence", retired: "", keyword: "RecordedRangeModulatorSequence" });
-	dicom_dictionary.insert(0x30080100, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Source Sequence", retired: "", keyword: "RecordedSourceSequence" });
-	dicom_dictionary.insert(0x30080105, DicomDictElt { vr : "LO", vm: "1", name: "Source Serial Number", retired: "", keyword: "SourceSerialNumber" });
-	dicom_dictionary.insert(0x30080110, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Session Application Setup Sequence", retired: "", keyword: "TreatmentSessionApplicationSetupSequence" });
-	dicom_dictionary.insert(0x30080116, DicomDictElt { vr : "CS", vm: "1", name: "Application Setup Check", retired: "", keyword: "ApplicationSetupCheck" });
-	dicom_dictionary.insert(0x30080120, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Brachy Accessory Device Sequence", retired: "", keyword: "RecordedBrachyAccessoryDeviceSequence" });
-	dicom_dictionary.insert(0x30080122, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Brachy Accessory Device Number", retired: "", keyword: "ReferencedBrachyAccessoryDeviceNumber" });
-	dicom_dictionary.insert(0x30080130, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Channel Sequence", retired: "", keyword: "RecordedChannelSequence" });
-	dicom_dictionary.insert(0x30080132, DicomDictElt { vr : "DS", vm: "1", name: "Specified Channel Total Time", retired: "", keyword: "SpecifiedChannelTotalTime" });
-	dicom_dictionary.insert(0x30080134, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Channel Total Time", retired: "", keyword: "DeliveredChannelTotalTime" });
-	dicom_dictionary.insert(0x30080136, DicomDictElt { vr : "IS", vm: "1", name: "Specified Number of Pulses", retired: "", keyword: "SpecifiedNumberOfPulses" });
-	dicom_dictionary.insert(0x30080138, DicomDictElt { vr : "IS", vm: "1", name: "Delivered Number of Pulses", retired: "", keyword: "DeliveredNumberOfPulses" });
-	dicom_dictionary.insert(0x3008013A, DicomDictElt { vr : "DS", vm: "1", name: "Specified Pulse Repetition Interval", retired: "", keyword: "SpecifiedPulseRepetitionInterval" });
-	dicom_dictionary.insert(0x3008013C, DicomDictElt { vr : "DS", vm: "1", name: "Delivered Pulse Repetition Interval", retired: "", keyword: "DeliveredPulseRepetitionInterval" });
-	dicom_dictionary.insert(0x30080140, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Source Applicator Sequence", retired: "", keyword: "RecordedSourceApplicatorSequence" });
-	dicom_dictionary.insert(0x30080142, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Source Applicator Number", retired: "", keyword: "ReferencedSourceApplicatorNumber" });
-	dicom_dictionary.insert(0x30080150, DicomDictElt { vr : "SQ", vm: "1", name: "Recorded Channel Shield Sequence", retired: "", keyword: "RecordedChannelShieldSequence" });
-	dicom_dictionary.insert(0x30080152, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Channel Shield Number", retired: "", keyword: "ReferencedChannelShieldNumber" });
-	dicom_dictionary.insert(0x30080160, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Control Point Delivered Sequence", retired: "", keyword: "BrachyControlPointDeliveredSequence" });
-	dicom_dictionary.insert(0x30080162, DicomDictElt { vr : "DA", vm: "1", name: "Safe Position Exit Date", retired: "", keyword: "SafePositionExitDate" });
-	dicom_dictionary.insert(0x30080164, DicomDictElt { vr : "TM", vm: "1", name: "Safe Position Exit Time", retired: "", keyword: "SafePositionExitTime" });
-	dicom_dictionary.insert(0x30080166, DicomDictElt { vr : "DA", vm: "1", name: "Safe Position Return Date", retired: "", keyword: "SafePositionReturnDate" });
-	dicom_dictionary.insert(0x30080168, DicomDictElt { vr : "TM", vm: "1", name: "Safe Position Return Time", retired: "", keyword: "SafePositionReturnTime" });
-	dicom_dictionary.insert(0x30080171, DicomDictElt { vr : "SQ", vm: "1", name: "Pulse Specific Brachy Control Point Delivered Sequence", retired: "", keyword: "PulseSpecificBrachyControlPointDeliveredSequence" });
-	dicom_dictionary.insert(0x30080172, DicomDictElt { vr : "US", vm: "1", name: "Pulse Number", retired: "", keyword: "PulseNumber" });
-	dicom_dictionary.insert(0x30080173, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Pulse Control Point Delivered Sequence", retired: "", keyword: "BrachyPulseControlPointDeliveredSequence" });
-	dicom_dictionary.insert(0x30080200, DicomDictElt { vr : "CS", vm: "1", name: "Current Treatment Status", retired: "", keyword: "CurrentTreatmentStatus" });
-	dicom_dictionary.insert(0x30080202, DicomDictElt { vr : "ST", vm: "1", name: "Treatment Status Comment", retired: "", keyword: "TreatmentStatusComment" });
-	dicom_dictionary.insert(0x30080220, DicomDictElt { vr : "SQ", vm: "1", name: "Fraction Group Summary Sequence", retired: "", keyword: "FractionGroupSummarySequence" });
-	dicom_dictionary.insert(0x30080223, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Fraction Number", retired: "", keyword: "ReferencedFractionNumber" });
-	dicom_dictionary.insert(0x30080224, DicomDictElt { vr : "CS", vm: "1", name: "Fraction Group Type", retired: "", keyword: "FractionGroupType" });
-	dicom_dictionary.insert(0x30080230, DicomDictElt { vr : "CS", vm: "1", name: "Beam Stopper Position", retired: "", keyword: "BeamStopperPosition" });
-	dicom_dictionary.insert(0x30080240, DicomDictElt { vr : "SQ", vm: "1", name: "Fraction Status Summary Sequence", retired: "", keyword: "FractionStatusSummarySequence" });
-	dicom_dictionary.insert(0x30080250, DicomDictElt { vr : "DA", vm: "1", name: "Treatment Date", retired: "", keyword: "TreatmentDate" });
-	dicom_dictionary.insert(0x30080251, DicomDictElt { vr : "TM", vm: "1", name: "Treatment Time", retired: "", keyword: "TreatmentTime" });
-	dicom_dictionary.insert(0x300A0002, DicomDictElt { vr : "SH", vm: "1", name: "RT Plan Label", retired: "", keyword: "RTPlanLabel" });
-	dicom_dictionary.insert(0x300A0003, DicomDictElt { vr : "LO", vm: "1", name: "RT Plan Name", retired: "", keyword: "RTPlanName" });
-	dicom_dictionary.insert(0x300A0004, DicomDictElt { vr : "ST", vm: "1", name: "RT Plan Description", retired: "", keyword: "RTPlanDescription" });
-	dicom_dictionary.insert(0x300A0006, DicomDictElt { vr : "DA", vm: "1", name: "RT Plan Date", retired: "", keyword: "RTPlanDate" });
-	dicom_dictionary.insert(0x300A0007, DicomDictElt { vr : "TM", vm: "1", name: "RT Plan Time", retired: "", keyword: "RTPlanTime" });
-	dicom_dictionary.insert(0x300A0009, DicomDictElt { vr : "LO", vm: "1-n", name: "Treatment Protocols", retired: "", keyword: "TreatmentProtocols" });
-	dicom_dictionary.insert(0x300A000A, DicomDictElt { vr : "CS", vm: "1", name: "Plan Intent", retired: "", keyword: "PlanIntent" });
-	dicom_dictionary.insert(0x300A000B, DicomDictElt { vr : "LO", vm: "1-n", name: "Treatment Sites", retired: "", keyword: "TreatmentSites" });
-	dicom_dictionary.insert(0x300A000C, DicomDictElt { vr : "CS", vm: "1", name: "RT Plan Geometry", retired: "", keyword: "RTPlanGeometry" });
-	dicom_dictionary.insert(0x300A000E, DicomDictElt { vr : "ST", vm: "1", name: "Prescription Description", retired: "", keyword: "PrescriptionDescription" });
-	dicom_dictionary.insert(0x300A0010, DicomDictElt { vr : "SQ", vm: "1", name: "Dose Reference Sequence", retired: "", keyword: "DoseReferenceSequence" });
-	dicom_dictionary.insert(0x300A0012, DicomDictElt { vr : "IS", vm: "1", name: "Dose Reference Number", retired: "", keyword: "DoseReferenceNumber" });
-	dicom_dictionary.insert(0x300A0013, DicomDictElt { vr : "UI", vm: "1", name: "Dose Reference UID", retired: "", keyword: "DoseReferenceUID" });
-	dicom_dictionary.insert(0x300A0014, DicomDictElt { vr : "CS", vm: "1", name: "Dose Reference Structure Type", retired: "", keyword: "DoseReferenceStructureType" });
-	dicom_dictionary.insert(0x300A0015, DicomDictElt { vr : "CS", vm: "1", name: "Nominal Beam Energy Unit", retired: "", keyword: "NominalBeamEnergyUnit" });
-	dicom_dictionary.insert(0x300A0016, DicomDictElt { vr : "LO", vm: "1", name: "Dose Reference Description", retired: "", keyword: "DoseReferenceDescription" });
-	dicom_dictionary.insert(0x300A0018, DicomDictElt { vr : "DS", vm: "3", name: "Dose Reference Point Coordinates", retired: "", keyword: "DoseReferencePointCoordinates" });
-	dicom_dictionary.insert(0x300A001A, DicomDictElt { vr : "DS", vm: "1", name: "Nominal Prior Dose", retired: "", keyword: "NominalPriorDose" });
-	dicom_dictionary.insert(0x300A0020, DicomDictElt { vr : "CS", vm: "1", name: "Dose Reference Type", retired: "", keyword: "DoseReferenceType" });
-	dicom_dictionary.insert(0x300A0021, DicomDictElt { vr : "DS", vm: "1", name: "Constraint Weight", retired: "", keyword: "ConstraintWeight" });
-	dicom_dictionary.insert(0x300A0022, DicomDictElt { vr : "DS", vm: "1", name: "Delivery Warning Dose", retired: "", keyword: "DeliveryWarningDose" });
-	dicom_dictionary.insert(0x300A0023, DicomDictElt { vr : "DS", vm: "1", name: "Delivery Maximum Dose", retired: "", keyword: "DeliveryMaximumDose" });
-	dicom_dictionary.insert(0x300A0025, DicomDictElt { vr : "DS", vm: "1", name: "Target Minimum Dose", retired: "", keyword: "TargetMinimumDose" });
-	dicom_dictionary.insert(0x300A0026, DicomDictElt { vr : "DS", vm: "1", name: "Target Prescription Dose", retired: "", keyword: "TargetPrescriptionDose" });
-	dicom_dictionary.insert(0x300A0027, DicomDictElt { vr : "DS", vm: "1", name: "Target Maximum Dose", retired: "", keyword: "TargetMaximumDose" });
-	dicom_dictionary.insert(0x300A0028, DicomDictElt { vr : "DS", vm: "1", name: "Target Underdose Volume Fraction", retired: "", keyword: "TargetUnderdoseVolumeFraction" });
-	dicom_dictionary.insert(0x300A002A, DicomDictElt { vr : "DS", vm: "1", name: "Organ at Risk Full-volume Dose", retired: "", keyword: "OrganAtRiskFullVolumeDose" });
-	dicom_dictionary.insert(0x300A002B, DicomDictElt { vr : "DS", vm: "1", name: "Organ at Risk Limit Dose", retired: "", keyword: "OrganAtRiskLimitDose" });
-	dicom_dictionary.insert(0x300A002C, DicomDictElt { vr : "DS", vm: "1", name: "Organ at Risk Maximum Dose", retired: "", keyword: "OrganAtRiskMaximumDose" });
-	dicom_dictionary.insert(0x300A002D, DicomDictElt { vr : "DS", vm: "1", name: "Organ at Risk Overdose Volume Fraction", retired: "", keyword: "OrganAtRiskOverdoseVolumeFraction" });
-	dicom_dictionary.insert(0x300A0040, DicomDictElt { vr : "SQ", vm: "1", name: "Tolerance Table Sequence", retired: "", keyword: "ToleranceTableSequence" });
-	dicom_dictionary.insert(0x300A0042, DicomDictElt { vr : "IS", vm: "1", name: "Tolerance Table Number", retired: "", keyword: "ToleranceTableNumber" });
-	dicom_dictionary.insert(0x300A0043, DicomDictElt { vr : "SH", vm: "1", name: "Tolerance Table Label", retired: "", keyword: "ToleranceTableLabel" });
-	dicom_dictionary.insert(0x300A0044, DicomDictElt { vr : "DS", vm: "1", name: "Gantry Angle Tolerance", retired: "", keyword: "GantryAngleTolerance" });
-	dicom_dictionary.insert(0x300A0046, DicomDictElt { vr : "DS", vm: "1", name: "Beam Limiting Device Angle Tolerance", retired: "", keyword: "BeamLimitingDeviceAngleTolerance" });
-	dicom_dictionary.insert(0x300A0048, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Limiting Device Tolerance Sequence", retired: "", keyword: "BeamLimitingDeviceToleranceSequence" });
-	dicom_dictionary.insert(0x300A004A, DicomDictElt { vr : "DS", vm: "1", name: "Beam Limiting Device Position Tolerance", retired: "", keyword: "BeamLimitingDevicePositionTolerance" });
-	dicom_dictionary.insert(0x300A004B, DicomDictElt { vr : "FL", vm: "1", name: "Snout Position Tolerance", retired: "", keyword: "SnoutPositionTolerance" });
-	dicom_dictionary.insert(0x300A004C, DicomDictElt { vr : "DS", vm: "1", name: "Patient Support Angle Tolerance", retired: "", keyword: "PatientSupportAngleTolerance" });
-	dicom_dictionary.insert(0x300A004E, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Eccentric Angle Tolerance", retired: "", keyword: "TableTopEccentricAngleTolerance" });
-	dicom_dictionary.insert(0x300A004F, DicomDictElt { vr : "FL", vm: "1", name: "Table Top Pitch Angle Tolerance", retired: "", keyword: "TableTopPitchAngleTolerance" });
-	dicom_dictionary.insert(0x300A0050, DicomDictElt { vr : "FL", vm: "1", name: "Table Top Roll Angle Tolerance", retired: "", keyword: "TableTopRollAngleTolerance" });
-	dicom_dictionary.insert(0x300A0051, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Vertical Position Tolerance", retired: "", keyword: "TableTopVerticalPositionTolerance" });
-	dicom_dictionary.insert(0x300A0052, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Longitudinal Position Tolerance", retired: "", keyword: "TableTopLongitudinalPositionTolerance" });
-	dicom_dictionary.insert(0x300A0053, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Lateral Position Tolerance", retired: "", keyword: "TableTopLateralPositionTolerance" });
-	dicom_dictionary.insert(0x300A0055, DicomDictElt { vr : "CS", vm: "1", name: "RT Plan Relationship", retired: "", keyword: "RTPlanRelationship" });
-	dicom_dictionary.insert(0x300A0070, DicomDictElt { vr : "SQ", vm: "1", name: "Fraction Group Sequence", retired: "", keyword: "FractionGroupSequence" });
-	dicom_dictionary.insert(0x300A0071, DicomDictElt { vr : "IS", vm: "1", name: "Fraction Group Number", retired: "", keyword: "FractionGroupNumber" });
-	dicom_dictionary.insert(0x300A0072, DicomDictElt { vr : "LO", vm: "1", name: "Fraction Group Description", retired: "", keyword: "FractionGroupDescription" });
-	dicom_dictionary.insert(0x300A0078, DicomDictElt { vr : "IS", vm: "1", name: "Number of Fractions Planned", retired: "", keyword: "NumberOfFractionsPlanned" });
-	dicom_dictionary.insert(0x300A0079, DicomDictElt { vr : "IS", vm: "1", name: "Number of Fraction Pattern Digits Per Day", retired: "", keyword: "NumberOfFractionPatternDigitsPerDay" });
-	dicom_dictionary.insert(0x300A007A, DicomDictElt { vr : "IS", vm: "1", name: "Repeat Fraction Cycle Length", retired: "", keyword: "RepeatFractionCycleLength" });
-	dicom_dictionary.insert(0x300A007B, DicomDictElt { vr : "LT", vm: "1", name: "Fraction Pattern", retired: "", keyword: "FractionPattern" });
-	dicom_dictionary.insert(0x300A0080, DicomDictElt { vr : "IS", vm: "1", name: "Number of Beams", retired: "", keyword: "NumberOfBeams" });
-	dicom_dictionary.insert(0x300A0082, DicomDictElt { vr : "DS", vm: "3", name: "Beam Dose Specification Point", retired: "", keyword: "BeamDoseSpecificationPoint" });
-	dicom_dictionary.insert(0x300A0084, DicomDictElt { vr : "DS", vm: "1", name: "Beam Dose", retired: "", keyword: "BeamDose" });
-	dicom_dictionary.insert(0x300A0086, DicomDictElt { vr : "DS", vm: "1", name: "Beam Meterset", retired: "", keyword: "BeamMeterset" });
-	dicom_dictionary.insert(0x300A0088, DicomDictElt { vr : "FL", vm: "1", name: "Beam Dose Point Depth", retired: "Retired", keyword: "BeamDosePointDepth" });
-	dicom_dictionary.insert(0x300A0089, DicomDictElt { vr : "FL", vm: "1", name: "Beam Dose Point Equivalent Depth", retired: "Retired", keyword: "BeamDosePointEquivalentDepth" });
-	dicom_dictionary.insert(0x300A008A, DicomDictElt { vr : "FL", vm: "1", name: "Beam Dose Point SSD", retired: "Retired", keyword: "BeamDosePointSSD" });
-	dicom_dictionary.insert(0x300A008B, DicomDictElt { vr : "CS", vm: "1", name: "Beam Dose Meaning", retired: "", keyword: "BeamDoseMeaning" });
-	dicom_dictionary.insert(0x300A008C, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Dose Verification Control Point Sequence", retired: "", keyword: "BeamDoseVerificationControlPointSequence" });
-	dicom_dictionary.insert(0x300A008D, DicomDictElt { vr : "FL", vm: "1", name: "Average Beam Dose Point Depth", retired: "", keyword: "AverageBeamDosePointDepth" });
-	dicom_dictionary.insert(0x300A008E, DicomDictElt { vr : "FL", vm: "1", name: "Average Beam Dose Point Equivalent Depth", retired: "", keyword: "AverageBeamDosePointEquivalentDepth" });
-	dicom_dictionary.insert(0x300A008F, DicomDictElt { vr : "FL", vm: "1", name: "Average Beam Dose Point SSD", retired: "", keyword: "AverageBeamDosePointSSD" });
-	dicom_dictionary.insert(0x300A0090, DicomDictElt { vr : "CS", vm: "1", name: "Beam Dose Type", retired: "", keyword: "BeamDoseType" });
-	dicom_dictionary.insert(0x300A0091, DicomDictElt { vr : "DS", vm: "1", name: "Alternate Beam Dose", retired: "", keyword: "AlternateBeamDose" });
-	dicom_dictionary.insert(0x300A0092, DicomDictElt { vr : "CS", vm: "1", name: "Alternate Beam Dose Type", retired: "", keyword: "AlternateBeamDoseType" });
-	dicom_dictionary.insert(0x300A00A0, DicomDictElt { vr : "IS", vm: "1", name: "Number of Brachy Application Setups", retired: "", keyword: "NumberOfBrachyApplicationSetups" });
-	dicom_dictionary.insert(0x300A00A2, DicomDictElt { vr : "DS", vm: "3", name: "Brachy Application Setup Dose Specification Point", retired: "", keyword: "BrachyApplicationSetupDoseSpecificationPoint" });
-	dicom_dictionary.insert(0x300A00A4, DicomDictElt { vr : "DS", vm: "1", name: "Brachy Application Setup Dose", retired: "", keyword: "BrachyApplicationSetupDose" });
-	dicom_dictionary.insert(0x300A00B0, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Sequence", retired: "", keyword: "BeamSequence" });
-	dicom_dictionary.insert(0x300A00B2, DicomDictElt { vr : "SH", vm: "1", name: "Treatment Machine Name", retired: "", keyword: "TreatmentMachineName" });
-	dicom_dictionary.insert(0x300A00B3, DicomDictElt { vr : "CS", vm: "1", name: "Primary Dosimeter Unit", retired: "", keyword: "PrimaryDosimeterUnit" });
-	dicom_dictionary.insert(0x300A00B4, DicomDictElt { vr : "DS", vm: "1", name: "Source-Axis Distance", retired: "", keyword: "SourceAxisDistance" });
-	dicom_dictionary.insert(0x300A00B6, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Limiting Device Sequence", retired: "", keyword: "BeamLimitingDeviceSequence" });
-	dicom_dictionary.insert(0x300A00B8, DicomDictElt { vr : "CS", vm: "1", name: "RT Beam Limiting Device Type", retired: "", keyword: "RTBeamLimitingDeviceType" });
-	dicom_dictionary.insert(0x300A00BA, DicomDictElt { vr : "DS", vm: "1", name: "Source to Beam Limiting Device Distance", retired: "", keyword: "SourceToBeamLimitingDeviceDistance" });
-	dicom_dictionary.insert(0x300A00BB, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Beam Limiting Device Distance", retired: "", keyword: "IsocenterToBeamLimitingDeviceDistance" });
-	dicom_dictionary.insert(0x300A00BC, DicomDictElt { vr : "IS", vm: "1", name: "Number of Leaf/Jaw Pairs", retired: "", keyword: "NumberOfLeafJawPairs" });
-	dicom_dictionary.insert(0x300A00BE, DicomDictElt { vr : "DS", vm: "3-n", name: "Leaf Position Boundaries", retired: "", keyword: "LeafPositionBoundaries" });
-	dicom_dictionary.insert(0x300A00C0, DicomDictElt { vr : "IS", vm: "1", name: "Beam Number", retired: "", keyword: "BeamNumber" });
-	dicom_dictionary.insert(0x300A00C2, DicomDictElt { vr : "LO", vm: "1", name: "Beam Name", retired: "", keyword: "BeamName" });
-	dicom_dictionary.insert(0x300A00C3, DicomDictElt { vr : "ST", vm: "1", name: "Beam Description", retired: "", keyword: "BeamDescription" });
-	dicom_dictionary.insert(0x300A00C4, DicomDictElt { vr : "CS", vm: "1", name: "Beam Type", retired: "", keyword: "BeamType" });
-	dicom_dictionary.insert(0x300A00C5, DicomDictElt { vr : "FD", vm: "1", name: "Beam Delivery Duration Limit", retired: "", keyword: "BeamDeliveryDurationLimit" });
-	dicom_dictionary.insert(0x300A00C6, DicomDictElt { vr : "CS", vm: "1", name: "Radiation Type", retired: "", keyword: "RadiationType" });
-	dicom_dictionary.insert(0x300A00C7, DicomDictElt { vr : "CS", vm: "1", name: "High-Dose Technique Type", retired: "", keyword: "HighDoseTechniqueType" });
-	dicom_dictionary.insert(0x300A00C8, DicomDictElt { vr : "IS", vm: "1", name: "Reference Image Number", retired: "", keyword: "ReferenceImageNumber" });
-	dicom_dictionary.insert(0x300A00CA, DicomDictElt { vr : "SQ", vm: "1", name: "Planned Verification Image Sequence", retired: "", keyword: "PlannedVerificationImageSequence" });
-	dicom_dictionary.insert(0x300A00CC, DicomDictElt { vr : "LO", vm: "1-n", name: "Imaging Device-Specific Acquisition Parameters", retired: "", keyword: "ImagingDeviceSpecificAcquisitionParameters" });
-	dicom_dictionary.insert(0x300A00CE, DicomDictElt { vr : "CS", vm: "1", name: "Treatment Delivery Type", retired: "", keyword: "TreatmentDeliveryType" });
-	dicom_dictionary.insert(0x300A00D0, DicomDictElt { vr : "IS", vm: "1", name: "Number of Wedges", retired: "", keyword: "NumberOfWedges" });
-	dicom_dictionary.insert(0x300A00D1, DicomDictElt { vr : "SQ", vm: "1", name: "Wedge Sequence", retired: "", keyword: "WedgeSequence" });
-	dicom_dictionary.insert(0x300A00D2, DicomDictElt { vr : "IS", vm: "1", name: "Wedge Number", retired: "", keyword: "WedgeNumber" });
-	dicom_dictionary.insert(0x300A00D3, DicomDictElt { vr : "CS", vm: "1", name: "Wedge Type", retired: "", keyword: "WedgeType" });
-	dicom_dictionary.insert(0x300A00D4, DicomDictElt { vr : "SH", vm: "1", name: "Wedge ID", retired: "", keyword: "WedgeID" });
-	dicom_dictionary.insert(0x300A00D5, DicomDictElt { vr : "IS", vm: "1", name: "Wedge Angle", retired: "", keyword: "WedgeAngle" });
-	dicom_dictionary.insert(0x300A00D6, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Factor", retired: "", keyword: "WedgeFactor" });
-	dicom_dictionary.insert(0x300A00D7, DicomDictElt { vr : "FL", vm: "1", name: "Total Wedge Tray Water-Equivalent Thickness", retired: "", keyword: "TotalWedgeTrayWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A00D8, DicomDictElt { vr : "DS", vm: "1", name: "Wedge Orientation", retired: "", keyword: "WedgeOrientation" });
-	dicom_dictionary.insert(0x300A00D9, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Wedge Tray Distance", retired: "", keyword: "IsocenterToWedgeTrayDistance" });
-	dicom_dictionary.insert(0x300A00DA, DicomDictElt { vr : "DS", vm: "1", name: "Source to Wedge Tray Distance", retired: "", keyword: "SourceToWedgeTrayDistance" });
-	dicom_dictionary.insert(0x300A00DB, DicomDictElt { vr : "FL", vm: "1", name: "Wedge Thin Edge Position", retired: "", keyword: "WedgeThinEdgePosition" });
-	dicom_dictionary.insert(0x300A00DC, DicomDictElt { vr : "SH", vm: "1", name: "Bolus ID", retired: "", keyword: "BolusID" });
-	dicom_dictionary.insert(0x300A00DD, DicomDictElt { vr : "ST", vm: "1", name: "Bolus Description", retired: "", keyword: "BolusDescription" });
-	dicom_dictionary.insert(0x300A00DE, DicomDictElt { vr : "DS", vm: "1", name: "Effective Wedge Angle", retired: "", keyword: "EffectiveWedgeAngle" });
-	dicom_dictionary.insert(0x300A00E0, DicomDictElt { vr : "IS", vm: "1", name: "Number of Compensators", retired: "", keyword: "NumberOfCompensators" });
-	dicom_dictionary.insert(0x300A00E1, DicomDictElt { vr : "SH", vm: "1", name: "Material ID", retired: "", keyword: "MaterialID" });
-	dicom_dictionary.insert(0x300A00E2, DicomDictElt { vr : "DS", vm: "1", name: "Total Compensator Tray Factor", retired: "", keyword: "TotalCompensatorTrayFactor" });
-	dicom_dictionary.insert(0x300A00E3, DicomDictElt { vr : "SQ", vm: "1", name: "Compensator Sequence", retired: "", keyword: "CompensatorSequence" });
-	dicom_dictionary.insert(0x300A00E4, DicomDictElt { vr : "IS", vm: "1", name: "Compensator Number", retired: "", keyword: "CompensatorNumber" });
-	dicom_dictionary.insert(0x300A00E5, DicomDictElt { vr : "SH", vm: "1", name: "Compensator ID", retired: "", keyword: "CompensatorID" });
-	dicom_dictionary.insert(0x300A00E6, DicomDictElt { vr : "DS", vm: "1", name: "Source to Compensator Tray Distance", retired: "", keyword: "SourceToCompensatorTrayDistance" });
-	dicom_dictionary.insert(0x300A00E7, DicomDictElt { vr : "IS", vm: "1", name: "Compensator Rows", retired: "", keyword: "CompensatorRows" });
-	dicom_dictionary.insert(0x300A00E8, DicomDictElt { vr : "IS", vm: "1", name: "Compensator Columns", retired: "", keyword: "CompensatorColumns" });
-	dicom_dictionary.insert(0x300A00E9, DicomDictElt { vr : "DS", vm: "2", name: "Compensator Pixel Spacing", retired: "", keyword: "CompensatorPixelSpacing" });
-	dicom_dictionary.insert(0x300A00EA, DicomDictElt { vr : "DS", vm: "2", name: "Compensator Position", retired: "", keyword: "CompensatorPosition" });
-	dicom_dictionary.insert(0x300A00EB, DicomDictElt { vr : "DS", vm: "1-n", name: "Compensator Transmission Data", retired: "", keyword: "CompensatorTransmissionData" });
-	dicom_dictionary.insert(0x300A00EC, DicomDictElt { vr : "DS", vm: "1-n", name: "Compensator Thickness Data", retired: "", keyword: "CompensatorThicknessData" });
-	dicom_dictionary.insert(0x300A00ED, DicomDictElt { vr : "IS", vm: "1", name: "Number of Boli", retired: "", keyword: "NumberOfBoli" });
-	dicom_dictionary.insert(0x300A00EE, DicomDictElt { vr : "CS", vm: "1", name: "Compensator Type", retired: "", keyword: "CompensatorType" });
-	dicom_dictionary.insert(0x300A00EF, DicomDictElt { vr : "SH", vm: "1", name: "Compensator Tray ID", retired: "", keyword: "CompensatorTrayID" });
-	dicom_dictionary.insert(0x300A00F0, DicomDictElt { vr : "IS", vm: "1", name: "Number of Blocks", retired: "", keyword: "NumberOfBlocks" });
-	dicom_dictionary.insert(0x300A00F2, DicomDictElt { vr : "DS", vm: "1", name: "Total Block Tray Factor", retired: "", keyword: "TotalBlockTrayFactor" });
-	dicom_dictionary.insert(0x300A00F3, DicomDictElt { vr : "FL", vm: "1", name: "Total Block Tray Water-Equivalent Thickness", retired: "", keyword: "TotalBlockTrayWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A00F4, DicomDictElt { vr : "SQ", vm: "1", name: "Block Sequence", retired: "", keyword: "BlockSequence" });
-	dicom_dictionary.insert(0x300A00F5, DicomDictElt { vr : "SH", vm: "1", name: "Block Tray ID", retired: "", keyword: "BlockTrayID" });
-	dicom_dictionary.insert(0x300A00F6, DicomDictElt { vr : "DS", vm: "1", name: "Source to Block Tray Distance", retired: "", keyword: "SourceToBlockTrayDistance" });
-	dicom_dictionary.insert(0x300A00F7, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Block Tray Distance", retired: "", keyword: "IsocenterToBlockTrayDistance" });
-	dicom_dictionary.insert(0x300A00F8, DicomDictElt { vr : "CS", vm: "1", name: "Block Type", retired: "", keyword: "BlockType" });
-	dicom_dictionary.insert(0x300A00F9, DicomDictElt { vr : "LO", vm: "1", name: "Accessory Code", retired: "", keyword: "AccessoryCode" });
-	dicom_dictionary.insert(0x300A00FA, DicomDictElt { vr : "CS", vm: "1", name: "Block Divergence", retired: "", keyword: "BlockDivergence" });
-	dicom_dictionary.insert(0x300A00FB, DicomDictElt { vr : "CS", vm: "1", name: "Block Mounting Position", retired: "", keyword: "BlockMountingPosition" });
-	dicom_dictionary.insert(0x300A00FC, DicomDictElt { vr : "IS", vm: "1", name: "Block Number", retired: "", keyword: "BlockNumber" });
-	dicom_dictionary.insert(0x300A00FE, DicomDictElt { vr : "LO", vm: "1", name: "Block Name", retired: "", keyword: "BlockName" });
-	dicom_dictionary.insert(0x300A0100, DicomDictElt { vr : "DS", vm: "1", name: "Block Thickness", retired: "", keyword: "BlockThickness" });
-	dicom_dictionary.insert(0x300A0102, DicomDictElt { vr : "DS", vm: "1", name: "Block Transmission", retired: "", keyword: "BlockTransmission" });
-	dicom_dictionary.insert(0x300A0104, DicomDictElt { vr : "IS", vm: "1", name: "Block Number of Points", retired: "", keyword: "BlockNumberOfPoints" });
-	dicom_dictionary.insert(0x300A0106, DicomDictElt { vr : "DS", vm: "2-2n", name: "Block Data", retired: "", keyword: "BlockData" });
-	dicom_dictionary.insert(0x300A0107, DicomDictElt { vr : "SQ", vm: "1", name: "Applicator Sequence", retired: "", keyword: "ApplicatorSequence" });
-	dicom_dictionary.insert(0x300A0108, DicomDictElt { vr : "SH", vm: "1", name: "Applicator ID", retired: "", keyword: "ApplicatorID" });
-	dicom_dictionary.insert(0x300A0109, DicomDictElt { vr : "CS", vm: "1", name: "Applicator Type", retired: "", keyword: "ApplicatorType" });
-	dicom_dictionary.insert(0x300A010A, DicomDictElt { vr : "LO", vm: "1", name: "Applicator Description", retired: "", keyword: "ApplicatorDescription" });
-	dicom_dictionary.insert(0x300A010C, DicomDictElt { vr : "DS", vm: "1", name: "Cumulative Dose Reference Coefficient", retired: "", keyword: "CumulativeDoseReferenceCoefficient" });
-	dicom_dictionary.insert(0x300A010E, DicomDictElt { vr : "DS", vm: "1", name: "Final Cumulative Meterset Weight", retired: "", keyword: "FinalCumulativeMetersetWeight" });
-	dicom_dictionary.insert(0x300A0110, DicomDictElt { vr : "IS", vm: "1", name: "Number of Control Points", retired: "", keyword: "NumberOfControlPoints" });
-	dicom_dictionary.insert(0x300A0111, DicomDictElt { vr : "SQ", vm: "1", name: "Control Point Sequence", retired: "", keyword: "ControlPointSequence" });
-	dicom_dictionary.insert(0x300A0112, DicomDictElt { vr : "IS", vm: "1", name: "Control Point Index", retired: "", keyword: "ControlPointIndex" });
-	dicom_dictionary.insert(0x300A0114, DicomDictElt { vr : "DS", vm: "1", name: "Nominal Beam Energy", retired: "", keyword: "NominalBeamEnergy" });
-	dicom_dictionary.insert(0x300A0115, DicomDictElt { vr : "DS", vm: "1", name: "Dose Rate Set", retired: "", keyword: "DoseRateSet" });
-	dicom_dictionary.insert(0x300A0116, DicomDictElt { vr : "SQ", vm: "1", name: "Wedge Position Sequence", retired: "", keyword: "WedgePositionSequence" });
-	dicom_dictionary.insert(0x300A0118, DicomDictElt { vr : "CS", vm: "1", name: "Wedge Position", retired: "", keyword: "WedgePosition" });
-	dicom_dictionary.insert(0x300A011A, DicomDictElt { vr : "SQ", vm: "1", name: "Beam Limiting Device Position Sequence", retired: "", keyword: "BeamLimitingDevicePositionSequence" });
-	dicom_dictionary.insert(0x300A011C, DicomDictElt { vr : "DS", vm: "2-2n", name: "Leaf/Jaw Positions", retired: "", keyword: "LeafJawPositions" });
-	dicom_dictionary.insert(0x300A011E, DicomDictElt { vr : "DS", vm: "1", name: "Gantry Angle", retired: "", keyword: "GantryAngle" });
-	dicom_dictionary.insert(0x300A011F, DicomDictElt { vr : "CS", vm: "1", name: "Gantry Rotation Direction", retired: "", keyword: "GantryRotationDirection" });
-	dicom_dictionary.insert(0x300A0120, DicomDictElt { vr : "DS", vm: "1", name: "Beam Limiting Device Angle", retired: "", keyword: "BeamLimitingDeviceAngle" });
-	dicom_dictionary.insert(0x300A0121, DicomDictElt { vr : "CS", vm: "1", name: "Beam Limiting Device Rotation Direction", retired: "", keyword: "BeamLimitingDeviceRotationDirection" });
-	dicom_dictionary.insert(0x300A0122, DicomDictElt { vr : "DS", vm: "1", name: "Patient Support Angle", retired: "", keyword: "PatientSupportAngle" });
-	dicom_dictionary.insert(0x300A0123, DicomDictElt { vr : "CS", vm: "1", name: "Patient Support Rotation Direction", retired: "", keyword: "PatientSupportRotationDirection" });
-	dicom_dictionary.insert(0x300A0124, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Eccentric Axis Distance", retired: "", keyword: "TableTopEccentricAxisDistance" });
-	dicom_dictionary.insert(0x300A0125, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Eccentric Angle", retired: "", keyword: "TableTopEccentricAngle" });
-	dicom_dictionary.insert(0x300A0126, DicomDictElt { vr : "CS", vm: "1", name: "Table Top Eccentric Rotation Direction", retired: "", keyword: "TableTopEccentricRotationDirection" });
-	dicom_dictionary.insert(0x300A0128, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Vertical Position", retired: "", keyword: "TableTopVerticalPosition" });
-	dicom_dictionary.insert(0x300A0129, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Longitudinal Position", retired: "", keyword: "TableTopLongitudinalPosition" });
-	dicom_dictionary.insert(0x300A012A, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Lateral Position", retired: "", keyword: "TableTopLateralPosition" });
-	dicom_dictionary.insert(0x300A012C, DicomDictElt { vr : "DS", vm: "3", name: "Isocenter Position", retired: "", keyword: "IsocenterPosition" });
-	dicom_dictionary.insert(0x300A012E, DicomDictElt { vr : "DS", vm: "3", name: "Surface Entry Point", retired: "", keyword: "SurfaceEntryPoint" });
-	dicom_dictionary.insert(0x300A0130, DicomDictElt { vr : "DS", vm: "1", name: "Source to Surface Distance", retired: "", keyword: "SourceToSurfaceDistance" });
-	dicom_dictionary.insert(0x300A0131, DicomDictElt { vr : "FL", vm: "1", name: "Average Beam Dose Point Source to External Contour Distance", retired: "", keyword: "AverageBeamDosePointSourceToExternalContourDistance" });
-	dicom_dictionary.insert(0x300A0132, DicomDictElt { vr : "FL", vm: "1", name: "Source to External Contour Distance", retired: "", keyword: "SourceToExternalContourDistance" });
-	dicom_dictionary.insert(0x300A0133, DicomDictElt { vr : "FL", vm: "3", name: "External Contour Entry Point", retired: "", keyword: "ExternalContourEntryPoint" });
-	dicom_dictionary.insert(0x300A0134, DicomDictElt { vr : "DS", vm: "1", name: "Cumulative Meterset Weight", retired: "", keyword: "CumulativeMetersetWeight" });
-	dicom_dictionary.insert(0x300A0140, DicomDictElt { vr : "FL", vm: "1", name: "Table Top Pitch Angle", retired: "", keyword: "TableTopPitchAngle" });
-	dicom_dictionary.insert(0x300A0142, DicomDictElt { vr : "CS", vm: "1", name: "Table Top Pitch Rotation Direction", retired: "", keyword: "TableTopPitchRotationDirection" });
-	dicom_dictionary.insert(0x300A0144, DicomDictElt { vr : "FL", vm: "1", name: "Table Top Roll Angle", retired: "", keyword: "TableTopRollAngle" });
-	dicom_dictionary.insert(0x300A0146, DicomDictElt { vr : "CS", vm: "1", name: "Table Top Roll Rotation Direction", retired: "", keyword: "TableTopRollRotationDirection" });
-	dicom_dictionary.insert(0x300A0148, DicomDictElt { vr : "FL", vm: "1", name: "Head Fixation Angle", retired: "", keyword: "HeadFixationAngle" });
-	dicom_dictionary.insert(0x300A014A, DicomDictElt { vr : "FL", vm: "1", name: "Gantry Pitch Angle", retired: "", keyword: "GantryPitchAngle" });
-	dicom_dictionary.insert(0x300A014C, DicomDictElt { vr : "CS", vm: "1", name: "Gantry Pitch Rotation Direction", retired: "", keyword: "GantryPitchRotationDirection" });
-	dicom_dictionary.insert(0x300A014E, DicomDictElt { vr : "FL", vm: "1", name: "Gantry Pitch Angle Tolerance", retired: "", keyword: "GantryPitchAngleTolerance" });
-	dicom_dictionary.insert(0x300A0150, DicomDictElt { vr : "CS", vm: "1", name: "Fixation Eye", retired: "", keyword: "FixationEye" });
-	dicom_dictionary.insert(0x300A0151, DicomDictElt { vr : "DS", vm: "1", name: "Chair Head Frame Position", retired: "", keyword: "ChairHeadFramePosition" });
-	dicom_dictionary.insert(0x300A0152, DicomDictElt { vr : "DS", vm: "1", name: "Head Fixation Angle Tolerance", retired: "", keyword: "HeadFixationAngleTolerance" });
-	dicom_dictionary.insert(0x300A0153, DicomDictElt { vr : "DS", vm: "1", name: "Chair Head Frame Position Tolerance", retired: "", keyword: "ChairHeadFramePositionTolerance" });
-	dicom_dictionary.insert(0x300A0154, DicomDictElt { vr : "DS", vm: "1", name: "Fixation Light Azimuthal Angle Tolerance", retired: "", keyword: "FixationLightAzimuthalAngleTolerance" });
-	dicom_dictionary.insert(0x300A0155, DicomDictElt { vr : "DS", vm: "1", name: "Fixation Light Polar Angle Tolerance", retired: "", keyword: "FixationLightPolarAngleTolerance" });
-	dicom_dictionary.insert(0x300A0180, DicomDictElt { vr : "SQ", vm: "1", name: "Patient Setup Sequence", retired: "", keyword: "PatientSetupSequence" });
-	dicom_dictionary.insert(0x300A0182, DicomDictElt { vr : "IS", vm: "1", name: "Patient Setup Number", retired: "", keyword: "PatientSetupNumber" });
-	dicom_dictionary.insert(0x300A0183, DicomDictElt { vr : "LO", vm: "1", name: "Patient Setup Label", retired: "", keyword: "PatientSetupLabel" });
-	dicom_dictionary.insert(0x300A0184, DicomDictElt { vr : "LO", vm: "1", name: "Patient Additional Position", retired: "", keyword: "PatientAdditionalPosition" });
-	dicom_dictionary.insert(0x300A0190, DicomDictElt { vr : "SQ", vm: "1", name: "Fixation Device Sequence", retired: "", keyword: "FixationDeviceSequence" });
-	dicom_dictionary.insert(0x300A0192, DicomDictElt { vr : "CS", vm: "1", name: "Fixation Device Type", retired: "", keyword: "FixationDeviceType" });
-	dicom_dictionary.insert(0x300A0194, DicomDictElt { vr : "SH", vm: "1", name: "Fixation Device Label", retired: "", keyword: "FixationDeviceLabel" });
-	dicom_dictionary.insert(0x300A0196, DicomDictElt { vr : "ST", vm: "1", name: "Fixation Device Description", retired: "", keyword: "FixationDeviceDescription" });
-	dicom_dictionary.insert(0x300A0198, DicomDictElt { vr : "SH", vm: "1", name: "Fixation Device Position", retired: "", keyword: "FixationDevicePosition" });
-	dicom_dictionary.insert(0x300A0199, DicomDictElt { vr : "FL", vm: "1", name: "Fixation Device Pitch Angle", retired: "", keyword: "FixationDevicePitchAngle" });
-	dicom_dictionary.insert(0x300A019A, DicomDictElt { vr : "FL", vm: "1", name: "Fixation Device Roll Angle", retired: "", keyword: "FixationDeviceRollAngle" });
-	dicom_dictionary.insert(0x300A01A0, DicomDictElt { vr : "SQ", vm: "1", name: "Shielding Device Sequence", retired: "", keyword: "ShieldingDeviceSequence" });
-	dicom_dictionary.insert(0x300A01A2, DicomDictElt { vr : "CS", vm: "1", name: "Shielding Device Type", retired: "", keyword: "ShieldingDeviceType" });
-	dicom_dictionary.insert(0x300A01A4, DicomDictElt { vr : "SH", vm: "1", name: "Shielding Device Label", retired: "", keyword: "ShieldingDeviceLabel" });
-	dicom_dictionary.insert(0x300A01A6, DicomDictElt { vr : "ST", vm: "1", name: "Shielding Device Description", retired: "", keyword: "ShieldingDeviceDescription" });
-	dicom_dictionary.insert(0x300A01A8, DicomDictElt { vr : "SH", vm: "1", name: "Shielding Device Position", retired: "", keyword: "ShieldingDevicePosition" });
-	dicom_dictionary.insert(0x300A01B0, DicomDictElt { vr : "CS", vm: "1", name: "Setup Technique", retired: "", keyword: "SetupTechnique" });
-	dicom_dictionary.insert(0x300A01B2, DicomDictElt { vr : "ST", vm: "1", name: "Setup Technique Description", retired: "", keyword: "SetupTechniqueDescription" });
-	dicom_dictionary.insert(0x300A01B4, DicomDictElt { vr : "SQ", vm: "1", name: "Setup Device Sequence", retired: "", keyword: "SetupDeviceSequence" });
-	dicom_dictionary.insert(0x300A01B6, DicomDictElt { vr : "CS", vm: "1", name: "Setup Device Type", retired: "", keyword: "SetupDeviceType" });
-	dicom_dictionary.insert(0x300A01B8, DicomDictElt { vr : "SH", vm: "1", name: "Setup Device Label", retired: "", keyword: "SetupDeviceLabel" });
-	dicom_dictionary.insert(0x300A01BA, DicomDictElt { vr : "ST", vm: "1", name: "Setup Device Description", retired: "", keyword: "SetupDeviceDescription" });
-	dicom_dictionary.insert(0x300A01BC, DicomDictElt { vr : "DS", vm: "1", name: "Setup Device Parameter", retired: "", keyword: "SetupDeviceParameter" });
-	dicom_dictionary.insert(0x300A01D0, DicomDictElt { vr : "ST", vm: "1", name: "Setup Reference Description", retired: "", keyword: "SetupReferenceDescription" });
-	dicom_dictionary.insert(0x300A01D2, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Vertical Setup Displacement", retired: "", keyword: "TableTopVerticalSetupDisplacement" });
-	dicom_dictionary.insert(0x300A01D4, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Longitudinal Setup Displacement", retired: "", keyword: "TableTopLongitudinalSetupDisplacement" });
-	dicom_dictionary.insert(0x300A01D6, DicomDictElt { vr : "DS", vm: "1", name: "Table Top Lateral Setup Displacement", retired: "", keyword: "TableTopLateralSetupDisplacement" });
-	dicom_dictionary.insert(0x300A0200, DicomDictElt { vr : "CS", vm: "1", name: "Brachy Treatment Technique", retired: "", keyword: "BrachyTreatmentTechnique" });
-	dicom_dictionary.insert(0x300A0202, DicomDictElt { vr : "CS", vm: "1", name: "Brachy Treatment Type", retired: "", keyword: "BrachyTreatmentType" });
-	dicom_dictionary.insert(0x300A0206, DicomDictElt { vr : "SQ", vm: "1", name: "Treatment Machine Sequence", retired: "", keyword: "TreatmentMachineSequence" });
-	dicom_dictionary.insert(0x300A0210, DicomDictElt { vr : "SQ", vm: "1", name: "Source Sequence", retired: "", keyword: "SourceSequence" });
-	dicom_dictionary.insert(0x300A0212, DicomDictElt { vr : "IS", vm: "1", name: "Source Number", retired: "", keyword: "SourceNumber" });
-	dicom_dictionary.insert(0x300A0214, DicomDictElt { vr : "CS", vm: "1", name: "Source Type", retired: "", keyword: "SourceType" });
-	dicom_dictionary.insert(0x300A0216, DicomDictElt { vr : "LO", vm: "1", name: "Source Manufacturer", retired: "", keyword: "SourceManufacturer" });
-	dicom_dictionary.insert(0x300A0218, DicomDictElt { vr : "DS", vm: "1", name: "Active Source Diameter", retired: "", keyword: "ActiveSourceDiameter" });
-	dicom_dictionary.insert(0x300A021A, DicomDictElt { vr : "DS", vm: "1", name: "Active Source Length", retired: "", keyword: "ActiveSourceLength" });
-	dicom_dictionary.insert(0x300A021B, DicomDictElt { vr : "SH", vm: "1", name: "Source Model ID", retired: "", keyword: "SourceModelID" });
-	dicom_dictionary.insert(0x300A021C, DicomDictElt { vr : "LO", vm: "1", name: "Source Description", retired: "", keyword: "SourceDescription" });
-	dicom_dictionary.insert(0x300A0222, DicomDictElt { vr : "DS", vm: "1", name: "Source Encapsulation Nominal Thickness", retired: "", keyword: "SourceEncapsulationNominalThickness" });
-	dicom_dictionary.insert(0x300A0224, DicomDictElt { vr : "DS", vm: "1", name: "Source Encapsulation Nominal Transmission", retired: "", keyword: "SourceEncapsulationNominalTransmission" });
-	dicom_dictionary.insert(0x300A0226, DicomDictElt { vr : "LO", vm: "1", name: "Source Isotope Name", retired: "", keyword: "SourceIsotopeName" });
-	dicom_dictionary.insert(0x300A0228, DicomDictElt { vr : "DS", vm: "1", name: "Source Isotope Half Life", retired: "", keyword: "SourceIsotopeHalfLife" });
-	dicom_dictionary.insert(0x300A0229, DicomDictElt { vr : "CS", vm: "1", name: "Source Strength Units", retired: "", keyword: "SourceStrengthUnits" });
-	dicom_dictionary.insert(0x300A022A, DicomDictElt { vr : "DS", vm: "1", name: "Reference Air Kerma Rate", retired: "", keyword: "ReferenceAirKermaRate" });
-	dicom_dictionary.insert(0x300A022B, DicomDictElt { vr : "DS", vm: "1", name: "Source Strength", retired: "", keyword: "SourceStrength" });
-	dicom_dictionary.insert(0x300A022C, DicomDictElt { vr : "DA", vm: "1", name: "Source Strength Reference Date", retired: "", keyword: "SourceStrengthReferenceDate" });
-	dicom_dictionary.insert(0x300A022E, DicomDictElt { vr : "TM", vm: "1", name: "Source Strength Reference Time", retired: "", keyword: "SourceStrengthReferenceTime" });
-	dicom_dictionary.insert(0x300A0230, DicomDictElt { vr : "SQ", vm: "1", name: "Application Setup Sequence", retired: "", keyword: "ApplicationSetupSequence" });
-	dicom_dictionary.insert(0x300A0232, DicomDictElt { vr : "CS", vm: "1", name: "Application Setup Type", retired: "", keyword: "ApplicationSetupType" });
-	dicom_dictionary.insert(0x300A0234, DicomDictElt { vr : "IS", vm: "1", name: "Application Setup Number", retired: "", keyword: "ApplicationSetupNumber" });
-	dicom_dictionary.insert(0x300A0236, DicomDictElt { vr : "LO", vm: "1", name: "Application Setup Name", retired: "", keyword: "ApplicationSetupName" });
-	dicom_dictionary.insert(0x300A0238, DicomDictElt { vr : "LO", vm: "1", name: "Application Setup Manufacturer", retired: "", keyword: "ApplicationSetupManufacturer" });
-	dicom_dictionary.insert(0x300A0240, DicomDictElt { vr : "IS", vm: "1", name: "Template Number", retired: "", keyword: "TemplateNumber" });
-	dicom_dictionary.insert(0x300A0242, DicomDictElt { vr : "SH", vm: "1", name: "Template Type", retired: "", keyword: "TemplateType" });
-	dicom_dictionary.insert(0x300A0244, DicomDictElt { vr : "LO", vm: "1", name: "Template Name", retired: "", keyword: "TemplateName" });
-	dicom_dictionary.insert(0x300A0250, DicomDictElt { vr : "DS", vm: "1", name: "Total Reference Air Kerma", retired: "", keyword: "TotalReferenceAirKerma" });
-	dicom_dictionary.insert(0x300A0260, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Accessory Device Sequence", retired: "", keyword: "BrachyAccessoryDeviceSequence" });
-	dicom_dictionary.insert(0x300A0262, DicomDictElt { vr : "IS", vm: "1", name: "Brachy Accessory Device Number", retired: "", keyword: "BrachyAccessoryDeviceNumber" });
-	dicom_dictionary.insert(0x300A0263, DicomDictElt { vr : "SH", vm: "1", name: "Brachy Accessory Device ID", retired: "", keyword: "BrachyAccessoryDeviceID" });
-	dicom_dictionary.insert(0x300A0264, DicomDictElt { vr : "CS", vm: "1", name: "Brachy Accessory Device Type", retired: "", keyword: "BrachyAccessoryDeviceType" });
-	dicom_dictionary.insert(0x300A0266, DicomDictElt { vr : "LO", vm: "1", name: "Brachy Accessory Device Name", retired: "", keyword: "BrachyAccessoryDeviceName" });
-	dicom_dictionary.insert(0x300A026A, DicomDictElt { vr : "DS", vm: "1", name: "Brachy Accessory Device Nominal Thickness", retired: "", keyword: "BrachyAccessoryDeviceNominalThickness" });
-	dicom_dictionary.insert(0x300A026C, DicomDictElt { vr : "DS", vm: "1", name: "Brachy Accessory Device Nominal Transmission", retired: "", keyword: "BrachyAccessoryDeviceNominalTransmission" });
-	dicom_dictionary.insert(0x300A0280, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Sequence", retired: "", keyword: "ChannelSequence" });
-	dicom_dictionary.insert(0x300A0282, DicomDictElt { vr : "IS", vm: "1", name: "Channel Number", retired: "", keyword: "ChannelNumber" });
-	dicom_dictionary.insert(0x300A0284, DicomDictElt { vr : "DS", vm: "1", name: "Channel Length", retired: "", keyword: "ChannelLength" });
-	dicom_dictionary.insert(0x300A0286, DicomDictElt { vr : "DS", vm: "1", name: "Channel Total Time", retired: "", keyword: "ChannelTotalTime" });
-	dicom_dictionary.insert(0x300A0288, DicomDictElt { vr : "CS", vm: "1", name: "Source Movement Type", retired: "", keyword: "SourceMovementType" });
-	dicom_dictionary.insert(0x300A028A, DicomDictElt { vr : "IS", vm: "1", name: "Number of Pulses", retired: "", keyword: "NumberOfPulses" });
-	dicom_dictionary.insert(0x300A028C, DicomDictElt { vr : "DS", vm: "1", name: "Pulse Repetition Interval", retired: "", keyword: "PulseRepetitionInterval" });
-	dicom_dictionary.insert(0x300A0290, DicomDictElt { vr : "IS", vm: "1", name: "Source Applicator Number", retired: "", keyword: "SourceApplicatorNumber" });
-	dicom_dictionary.insert(0x300A0291, DicomDictElt { vr : "SH", vm: "1", name: "Source Applicator ID", retired: "", keyword: "SourceApplicatorID" });
-	dicom_dictionary.insert(0x300A0292, DicomDictElt { vr : "CS", vm: "1", name: "Source Applicator Type", retired: "", keyword: "SourceApplicatorType" });
-	dicom_dictionary.insert(0x300A0294, DicomDictElt { vr : "LO", vm: "1", name: "Source Applicator Name", retired: "", keyword: "SourceApplicatorName" });
-	dicom_dictionary.insert(0x300A0296, DicomDictElt { vr : "DS", vm: "1", name: "Source Applicator Length", retired: "", keyword: "SourceApplicatorLength" });
-	dicom_dictionary.insert(0x300A0298, DicomDictElt { vr : "LO", vm: "1", name: "Source Applicator Manufacturer", retired: "", keyword: "SourceApplicatorManufacturer" });
-	dicom_dictionary.insert(0x300A029C, DicomDictElt { vr : "DS", vm: "1", name: "Source Applicator Wall Nominal Thickness", retired: "", keyword: "SourceApplicatorWallNominalThickness" });
-	dicom_dictionary.insert(0x300A029E, DicomDictElt { vr : "DS", vm: "1", name: "Source Applicator Wall Nominal Transmission", retired: "", keyword: "SourceApplicatorWallNominalTransmission" });
-	dicom_dictionary.insert(0x300A02A0, DicomDictElt { vr : "DS", vm: "1", name: "Source Applicator Step Size", retired: "", keyword: "SourceApplicatorStepSize" });
-	dicom_dictionary.insert(0x300A02A2, DicomDictElt { vr : "IS", vm: "1", name: "Transfer Tube Number", retired: "", keyword: "TransferTubeNumber" });
-	dicom_dictionary.insert(0x300A02A4, DicomDictElt { vr : "DS", vm: "1", name: "Transfer Tube Length", retired: "", keyword: "TransferTubeLength" });
-	dicom_dictionary.insert(0x300A02B0, DicomDictElt { vr : "SQ", vm: "1", name: "Channel Shield Sequence", retired: "", keyword: "ChannelShieldSequence" });
-	dicom_dictionary.insert(0x300A02B2, DicomDictElt { vr : "IS", vm: "1", name: "Channel Shield Number", retired: "", keyword: "ChannelShieldNumber" });
-	dicom_dictionary.insert(0x300A02B3, DicomDictElt { vr : "SH", vm: "1", name: "Channel Shield ID", retired: "", keyword: "ChannelShieldID" });
-	dicom_dictionary.insert(0x300A02B4, DicomDictElt { vr : "LO", vm: "1", name: "Channel Shield Name", retired: "", keyword: "ChannelShieldName" });
-	dicom_dictionary.insert(0x300A02B8, DicomDictElt { vr : "DS", vm: "1", name: "Channel Shield Nominal Thickness", retired: "", keyword: "ChannelShieldNominalThickness" });
-	dicom_dictionary.insert(0x300A02BA, DicomDictElt { vr : "DS", vm: "1", name: "Channel Shield Nominal Transmission", retired: "", keyword: "ChannelShieldNominalTransmission" });
-	dicom_dictionary.insert(0x300A02C8, DicomDictElt { vr : "DS", vm: "1", name: "Final Cumulative Time Weight", retired: "", keyword: "FinalCumulativeTimeWeight" });
-	dicom_dictionary.insert(0x300A02D0, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Control Point Sequence", retired: "", keyword: "BrachyControlPointSequence" });
-	dicom_dictionary.insert(0x300A02D2, DicomDictElt { vr : "DS", vm: "1", name: "Control Point Relative Position", retired: "", keyword: "ControlPointRelativePosition" });
-	dicom_dictionary.insert(0x300A02D4, DicomDictElt { vr : "DS", vm: "3", name: "Control Point 3D Position", retired: "", keyword: "ControlPoint3DPosition" });
-	dicom_dictionary.insert(0x300A02D6, DicomDictElt { vr : "DS", vm: "1", name: "Cumulative Time Weight", retired: "", keyword: "CumulativeTimeWeight" });
-	dicom_dictionary.insert(0x300A02E0, DicomDictElt { vr : "CS", vm: "1", name: "Compensator Divergence", retired: "", keyword: "CompensatorDivergence" });
-	dicom_dictionary.insert(0x300A02E1, DicomDictElt { vr : "CS", vm: "1", name: "Compensator Mounting Position", retired: "", keyword: "CompensatorMountingPosition" });
-	dicom_dictionary.insert(0x300A02E2, DicomDictElt { vr : "DS", vm: "1-n", name: "Source to Compensator Distance", retired: "", keyword: "SourceToCompensatorDistance" });
-	dicom_dictionary.insert(0x300A02E3, DicomDictElt { vr : "FL", vm: "1", name: "Total Compensator Tray Water-Equivalent Thickness", retired: "", keyword: "TotalCompensatorTrayWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A02E4, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Compensator Tray Distance", retired: "", keyword: "IsocenterToCompensatorTrayDistance" });
-	dicom_dictionary.insert(0x300A02E5, DicomDictElt { vr : "FL", vm: "1", name: "Compensator Column Offset", retired: "", keyword: "CompensatorColumnOffset" });
-	dicom_dictionary.insert(0x300A02E6, DicomDictElt { vr : "FL", vm: "1-n", name: "Isocenter to Compensator Distances", retired: "", keyword: "IsocenterToCompensatorDistances" });
-	dicom_dictionary.insert(0x300A02E7, DicomDictElt { vr : "FL", vm: "1", name: "Compensator Relative Stopping Power Ratio", retired: "", keyword: "CompensatorRelativeStoppingPowerRatio" });
-	dicom_dictionary.insert(0x300A02E8, DicomDictElt { vr : "FL", vm: "1", name: "Compensator Milling Tool Diameter", retired: "", keyword: "CompensatorMillingToolDiameter" });
-	dicom_dictionary.insert(0x300A02EA, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Range Compensator Sequence", retired: "", keyword: "IonRangeCompensatorSequence" });
-	dicom_dictionary.insert(0x300A02EB, DicomDictElt { vr : "LT", vm: "1", name: "Compensator Description", retired: "", keyword: "CompensatorDescription" });
-	dicom_dictionary.insert(0x300A0302, DicomDictElt { vr : "IS", vm: "1", name: "Radiation Mass Number", retired: "", keyword: "RadiationMassNumber" });
-	dicom_dictionary.insert(0x300A0304, DicomDictElt { vr : "IS", vm: "1", name: "Radiation Atomic Number", retired: "", keyword: "RadiationAtomicNumber" });
-	dicom_dictionary.insert(0x300A0306, DicomDictElt { vr : "SS", vm: "1", name: "Radiation Charge State", retired: "", keyword: "RadiationChargeState" });
-	dicom_dictionary.insert(0x300A0308, DicomDictElt { vr : "CS", vm: "1", name: "Scan Mode", retired: "", keyword: "ScanMode" });
-	dicom_dictionary.insert(0x300A0309, DicomDictElt { vr : "CS", vm: "1", name: "Modulated Scan Mode Type", retired: "", keyword: "ModulatedScanModeType" });
-	dicom_dictionary.insert(0x300A030A, DicomDictElt { vr : "FL", vm: "2", name: "Virtual Source-Axis Distances", retired: "", keyword: "VirtualSourceAxisDistances" });
-	dicom_dictionary.insert(0x300A030C, DicomDictElt { vr : "SQ", vm: "1", name: "Snout Sequence", retired: "", keyword: "SnoutSequence" });
-	dicom_dictionary.insert(0x300A030D, DicomDictElt { vr : "FL", vm: "1", name: "Snout Position", retired: "", keyword: "SnoutPosition" });
-	dicom_dictionary.insert(0x300A030F, DicomDictElt { vr : "SH", vm: "1", name: "Snout ID", retired: "", keyword: "SnoutID" });
-	dicom_dictionary.insert(0x300A0312, DicomDictElt { vr : "IS", vm: "1", name: "Number of Range Shifters", retired: "", keyword: "NumberOfRangeShifters" });
-	dicom_dictionary.insert(0x300A0314, DicomDictElt { vr : "SQ", vm: "1", name: "Range Shifter Sequence", retired: "", keyword: "RangeShifterSequence" });
-	dicom_dictionary.insert(0x300A0316, DicomDictElt { vr : "IS", vm: "1", name: "Range Shifter Number", retired: "", keyword: "RangeShifterNumber" });
-	dicom_dictionary.insert(0x300A0318, DicomDictElt { vr : "SH", vm: "1", name: "Range Shifter ID", retired: "", keyword: "RangeShifterID" });
-	dicom_dictionary.insert(0x300A0320, DicomDictElt { vr : "CS", vm: "1", name: "Range Shifter Type", retired: "", keyword: "RangeShifterType" });
-	dicom_dictionary.insert(0x300A0322, DicomDictElt { vr : "LO", vm: "1", name: "Range Shifter Description", retired: "", keyword: "RangeShifterDescription" });
-	dicom_dictionary.insert(0x300A0330, DicomDictElt { vr : "IS", vm: "1", name: "Number of Lateral Spreading Devices", retired: "", keyword: "NumberOfLateralSpreadingDevices" });
-	dicom_dictionary.insert(0x300A0332, DicomDictElt { vr : "SQ", vm: "1", name: "Lateral Spreading Device Sequence", retired: "", keyword: "LateralSpreadingDeviceSequence" });
-	dicom_dictionary.insert(0x300A0334, DicomDictElt { vr : "IS", vm: "1", name: "Lateral Spreading Device Number", retired: "", keyword: "LateralSpreadingDeviceNumber" });
-	dicom_dictionary.insert(0x300A0336, DicomDictElt { vr : "SH", vm: "1", name: "Lateral Spreading Device ID", retired: "", keyword: "LateralSpreadingDeviceID" });
-	dicom_dictionary.insert(0x300A0338, DicomDictElt { vr : "CS", vm: "1", name: "Lateral Spreading Device Type", retired: "", keyword: "LateralSpreadingDeviceType" });
-	dicom_dictionary.insert(0x300A033A, DicomDictElt { vr : "LO", vm: "1", name: "Lateral Spreading Device Description", retired: "", keyword: "LateralSpreadingDeviceDescription" });
-	dicom_dictionary.insert(0x300A033C, DicomDictElt { vr : "FL", vm: "1", name: "Lateral Spreading Device Water Equivalent Thickness", retired: "", keyword: "LateralSpreadingDeviceWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A0340, DicomDictElt { vr : "IS", vm: "1", name: "Number of Range Modulators", retired: "", keyword: "NumberOfRangeModulators" });
-	dicom_dictionary.insert(0x300A0342, DicomDictElt { vr : "SQ", vm: "1", name: "Range Modulator Sequence", retired: "", keyword: "RangeModulatorSequence" });
-	dicom_dictionary.insert(0x300A0344, DicomDictElt { vr : "IS", vm: "1", name: "Range Modulator Number", retired: "", keyword: "RangeModulatorNumber" });
-	dicom_dictionary.insert(0x300A0346, DicomDictElt { vr : "SH", vm: "1", name: "Range Modulator ID", retired: "", keyword: "RangeModulatorID" });
-	dicom_dictionary.insert(0x300A0348, DicomDictElt { vr : "CS", vm: "1", name: "Range Modulator Type", retired: "", keyword: "RangeModulatorType" });
-	dicom_dictionary.insert(0x300A034A, DicomDictElt { vr : "LO", vm: "1", name: "Range Modulator Description", retired: "", keyword: "RangeModulatorDescription" });
-	dicom_dictionary.insert(0x300A034C, DicomDictElt { vr : "SH", vm: "1", name: "Beam Current Modulation ID", retired: "", keyword: "BeamCurrentModulationID" });
-	dicom_dictionary.insert(0x300A0350, DicomDictElt { vr : "CS", vm: "1", name: "Patient Support Type", retired: "", keyword: "PatientSupportType" });
-	dicom_dictionary.insert(0x300A0352, DicomDictElt { vr : "SH", vm: "1", name: "Patient Support ID", retired: "", keyword: "PatientSupportID" });
-	dicom_dictionary.insert(0x300A0354, DicomDictElt { vr : "LO", vm: "1", name: "Patient Support Accessory Code", retired: "", keyword: "PatientSupportAccessoryCode" });
-	dicom_dictionary.insert(0x300A0355, DicomDictElt { vr : "LO", vm: "1", name: "Tray Accessory Code", retired: "", keyword: "TrayAccessoryCode" });
-	dicom_dictionary.insert(0x300A0356, DicomDictElt { vr : "FL", vm: "1", name: "Fixation Light Azimuthal Angle", retired: "", keyword: "FixationLightAzimuthalAngle" });
-	dicom_dictionary.insert(0x300A0358, DicomDictElt { vr : "FL", vm: "1", name: "Fixation Light Polar Angle", retired: "", keyword: "FixationLightPolarAngle" });
-	dicom_dictionary.insert(0x300A035A, DicomDictElt { vr : "FL", vm: "1", name: "Meterset Rate", retired: "", keyword: "MetersetRate" });
-	dicom_dictionary.insert(0x300A0360, DicomDictElt { vr : "SQ", vm: "1", name: "Range Shifter Settings Sequence", retired: "", keyword: "RangeShifterSettingsSequence" });
-	dicom_dictionary.insert(0x300A0362, DicomDictElt { vr : "LO", vm: "1", name: "Range Shifter Setting", retired: "", keyword: "RangeShifterSetting" });
-	dicom_dictionary.insert(0x300A0364, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Range Shifter Distance", retired: "", keyword: "IsocenterToRangeShifterDistance" });
-	dicom_dictionary.insert(0x300A0366, DicomDictElt { vr : "FL", vm: "1", name: "Range Shifter Water Equivalent Thickness", retired: "", keyword: "RangeShifterWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A0370, DicomDictElt { vr : "SQ", vm: "1", name: "Lateral Spreading Device Settings Sequence", retired: "", keyword: "LateralSpreadingDeviceSettingsSequence" });
-	dicom_dictionary.insert(0x300A0372, DicomDictElt { vr : "LO", vm: "1", name: "Lateral Spreading Device Setting", retired: "", keyword: "LateralSpreadingDeviceSetting" });
-	dicom_dictionary.insert(0x300A0374, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Lateral Spreading Device Distance", retired: "", keyword: "IsocenterToLateralSpreadingDeviceDistance" });
-	dicom_dictionary.insert(0x300A0380, DicomDictElt { vr : "SQ", vm: "1", name: "Range Modulator Settings Sequence", retired: "", keyword: "RangeModulatorSettingsSequence" });
-	dicom_dictionary.insert(0x300A0382, DicomDictElt { vr : "FL", vm: "1", name: "Range Modulator Gating Start Value", retired: "", keyword: "RangeModulatorGatingStartValue" });
-	dicom_dictionary.insert(0x300A0384, DicomDictElt { vr : "FL", vm: "1", name: "Range Modulator Gating Stop Value", retired: "", keyword: "RangeModulatorGatingStopValue" });
-	dicom_dictionary.insert(0x300A0386, DicomDictElt { vr : "FL", vm: "1", name: "Range Modulator Gating Start Water Equivalent Thickness", retired: "", keyword: "RangeModulatorGatingStartWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A0388, DicomDictElt { vr : "FL", vm: "1", name: "Range Modulator Gating Stop Water Equivalent Thickness", retired: "", keyword: "RangeModulatorGatingStopWaterEquivalentThickness" });
-	dicom_dictionary.insert(0x300A038A, DicomDictElt { vr : "FL", vm: "1", name: "Isocenter to Range Modulator Distance", retired: "", keyword: "IsocenterToRangeModulatorDistance" });
-	dicom_dictionary.insert(0x300A038F, DicomDictElt { vr : "FL", vm: "1-n", name: "Scan Spot Time Offset", retired: "", keyword: "ScanSpotTimeOffset" });
-	dicom_dictionary.insert(0x300A0390, DicomDictElt { vr : "SH", vm: "1", name: "Scan Spot Tune ID", retired: "", keyword: "ScanSpotTuneID" });
-	dicom_dictionary.insert(0x300A0391, DicomDictElt { vr : "IS", vm: "1-n", name: "Scan Spot Prescribed Indices", retired: "", keyword: "ScanSpotPrescribedIndices" });
-	dicom_dictionary.insert(0x300A0392, DicomDictElt { vr : "IS", vm: "1", name: "Number of Scan Spot Positions", retired: "", keyword: "NumberOfScanSpotPositions" });
-	dicom_dictionary.insert(0x300A0393, DicomDictElt { vr : "CS", vm: "1", name: "Scan Spot Reordered", retired: "", keyword: "ScanSpotReordered" });
-	dicom_dictionary.insert(0x300A0394, DicomDictElt { vr : "FL", vm: "1-n", name: "Scan Spot Position Map", retired: "", keyword: "ScanSpotPositionMap" });
-	dicom_dictionary.insert(0x300A0395, DicomDictElt { vr : "CS", vm: "1", name: "Scan Spot Reordering Allowed", retired: "", keyword: "ScanSpotReorderingAllowed" });
-	dicom_dictionary.insert(0x300A0396, DicomDictElt { vr : "FL", vm: "1-n", name: "Scan Spot Meterset Weights", retired: "", keyword: "ScanSpotMetersetWeights" });
-	dicom_dictionary.insert(0x300A0398, DicomDictElt { vr : "FL", vm: "2", name: "Scanning Spot Size", retired: "", keyword: "ScanningSpotSize" });
-	dicom_dictionary.insert(0x300A039A, DicomDictElt { vr : "IS", vm: "1", name: "Number of Paintings", retired: "", keyword: "NumberOfPaintings" });
-	dicom_dictionary.insert(0x300A03A0, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Tolerance Table Sequence", retired: "", keyword: "IonToleranceTableSequence" });
-	dicom_dictionary.insert(0x300A03A2, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Beam Sequence", retired: "", keyword: "IonBeamSequence" });
-	dicom_dictionary.insert(0x300A03A4, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Beam Limiting Device Sequence", retired: "", keyword: "IonBeamLimitingDeviceSequence" });
-	dicom_dictionary.insert(0x300A03A6, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Block Sequence", retired: "", keyword: "IonBlockSequence" });
-	dicom_dictionary.insert(0x300A03A8, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Control Point Sequence", retired: "", keyword: "IonControlPointSequence" });
-	dicom_dictionary.insert(0x300A03AA, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Wedge Sequence", retired: "", keyword: "IonWedgeSequence" });
-	dicom_dictionary.insert(0x300A03AC, DicomDictElt { vr : "SQ", vm: "1", name: "Ion Wedge Position Sequence", retired: "", keyword: "IonWedgePositionSequence" });
-	dicom_dictionary.insert(0x300A0401, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Setup Image Sequence", retired: "", keyword: "ReferencedSetupImageSequence" });
-	dicom_dictionary.insert(0x300A0402, DicomDictElt { vr : "ST", vm: "1", name: "Setup Image Comment", retired: "", keyword: "SetupImageComment" });
-	dicom_dictionary.insert(0x300A0410, DicomDictElt { vr : "SQ", vm: "1", name: "Motion Synchronization Sequence", retired: "", keyword: "MotionSynchronizationSequence" });
-	dicom_dictionary.insert(0x300A0412, DicomDictElt { vr : "FL", vm: "3", name: "Control Point Orientation", retired: "", keyword: "ControlPointOrientation" });
-	dicom_dictionary.insert(0x300A0420, DicomDictElt { vr : "SQ", vm: "1", name: "General Accessory Sequence", retired: "", keyword: "GeneralAccessorySequence" });
-	dicom_dictionary.insert(0x300A0421, DicomDictElt { vr : "SH", vm: "1", name: "General Accessory ID", retired: "", keyword: "GeneralAccessoryID" });
-	dicom_dictionary.insert(0x300A0422, DicomDictElt { vr : "ST", vm: "1", name: "General Accessory Description", retired: "", keyword: "GeneralAccessoryDescription" });
-	dicom_dictionary.insert(0x300A0423, DicomDictElt { vr : "CS", vm: "1", name: "General Accessory Type", retired: "", keyword: "GeneralAccessoryType" });
-	dicom_dictionary.insert(0x300A0424, DicomDictElt { vr : "IS", vm: "1", name: "General Accessory Number", retired: "", keyword: "GeneralAccessoryNumber" });
-	dicom_dictionary.insert(0x300A0425, DicomDictElt { vr : "FL", vm: "1", name: "Source to General Accessory Distance", retired: "", keyword: "SourceToGeneralAccessoryDistance" });
-	dicom_dictionary.insert(0x300A0431, DicomDictElt { vr : "SQ", vm: "1", name: "Applicator Geometry Sequence", retired: "", keyword: "ApplicatorGeometrySequence" });
-	dicom_dictionary.insert(0x300A0432, DicomDictElt { vr : "CS", vm: "1", name: "Applicator Aperture Shape", retired: "", keyword: "ApplicatorApertureShape" });
-	dicom_dictionary.insert(0x300A0433, DicomDictElt { vr : "FL", vm: "1", name: "Applicator Opening", retired: "", keyword: "ApplicatorOpening" });
-	dicom_dictionary.insert(0x300A0434, DicomDictElt { vr : "FL", vm: "1", name: "Applicator Opening X", retired: "", keyword: "ApplicatorOpeningX" });
-	dicom_dictionary.insert(0x300A0435, DicomDictElt { vr : "FL", vm: "1", name: "Applicator Opening Y", retired: "", keyword: "ApplicatorOpeningY" });
-	dicom_dictionary.insert(0x300A0436, DicomDictElt { vr : "FL", vm: "1", name: "Source to Applicator Mounting Position Distance", retired: "", keyword: "SourceToApplicatorMountingPositionDistance" });
-	dicom_dictionary.insert(0x300A0440, DicomDictElt { vr : "IS", vm: "1", name: "Number of Block Slab Items", retired: "", keyword: "NumberOfBlockSlabItems" });
-	dicom_dictionary.insert(0x300A0441, DicomDictElt { vr : "SQ", vm: "1", name: "Block Slab Sequence", retired: "", keyword: "BlockSlabSequence" });
-	dicom_dictionary.insert(0x300A0442, DicomDictElt { vr : "DS", vm: "1", name: "Block Slab Thickness", retired: "", keyword: "BlockSlabThickness" });
-	dicom_dictionary.insert(0x300A0443, DicomDictElt { vr : "US", vm: "1", name: "Block Slab Number", retired: "", keyword: "BlockSlabNumber" });
-	dicom_dictionary.insert(0x300A0450, DicomDictElt { vr : "SQ", vm: "1", name: "Device Motion Control Sequence", retired: "", keyword: "DeviceMotionControlSequence" });
-	dicom_dictionary.insert(0x300A0451, DicomDictElt { vr : "CS", vm: "1", name: "Device Motion Execution Mode", retired: "", keyword: "DeviceMotionExecutionMode" });
-	dicom_dictionary.insert(0x300A0452, DicomDictElt { vr : "CS", vm: "1", name: "Device Motion Observation Mode", retired: "", keyword: "DeviceMotionObservationMode" });
-	dicom_dictionary.insert(0x300A0453, DicomDictElt { vr : "SQ", vm: "1", name: "Device Motion Parameter Code Sequence", retired: "", keyword: "DeviceMotionParameterCodeSequence" });
-	dicom_dictionary.insert(0x300A0501, DicomDictElt { vr : "FL", vm: "1", name: "Distal Depth Fraction", retired: "", keyword: "DistalDepthFraction" });
-	dicom_dictionary.insert(0x300A0502, DicomDictElt { vr : "FL", vm: "1", name: "Distal Depth", retired: "", keyword: "DistalDepth" });
-	dicom_dictionary.insert(0x300A0503, DicomDictElt { vr : "FL", vm: "2", name: "Nominal Range Modulation Fractions", retired: "", keyword: "NominalRangeModulationFractions" });
-	dicom_dictionary.insert(0x300A0504, DicomDictElt { vr : "FL", vm: "2", name: "Nominal Range Modulated Region Depths", retired: "", keyword: "NominalRangeModulatedRegionDepths" });
-	dicom_dictionary.insert(0x300A0505, DicomDictElt { vr : "SQ", vm: "1", name: "Depth Dose Parameters Sequence", retired: "", keyword: "DepthDoseParametersSequence" });
-	dicom_dictionary.insert(0x300A0506, DicomDictElt { vr : "SQ", vm: "1", name: "Delivered Depth Dose Parameters Sequence", retired: "", keyword: "DeliveredDepthDoseParametersSequence" });
-	dicom_dictionary.insert(0x300A0507, DicomDictElt { vr : "FL", vm: "1", name: "Delivered Distal Depth Fraction", retired: "", keyword: "DeliveredDistalDepthFraction" });
-	dicom_dictionary.insert(0x300A0508, DicomDictElt { vr : "FL", vm: "1", name: "Delivered Distal Depth", retired: "", keyword: "DeliveredDistalDepth" });
-	dicom_dictionary.insert(0x300A0509, DicomDictElt { vr : "FL", vm: "2", name: "Delivered Nominal Range Modulation Fractions", retired: "", keyword: "DeliveredNominalRangeModulationFractions" });
-	dicom_dictionary.insert(0x300A0510, DicomDictElt { vr : "FL", vm: "2", name: "Delivered Nominal Range Modulated Region Depths", retired: "", keyword: "DeliveredNominalRangeModulatedRegionDepths" });
-	dicom_dictionary.insert(0x300A0511, DicomDictElt { vr : "CS", vm: "1", name: "Delivered Reference Dose Definition", retired: "", keyword: "DeliveredReferenceDoseDefinition" });
-	dicom_dictionary.insert(0x300A0512, DicomDictElt { vr : "CS", vm: "1", name: "Reference Dose Definition", retired: "", keyword: "ReferenceDoseDefinition" });
-	dicom_dictionary.insert(0x300C0002, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced RT Plan Sequence", retired: "", keyword: "ReferencedRTPlanSequence" });
-	dicom_dictionary.insert(0x300C0004, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Beam Sequence", retired: "", keyword: "ReferencedBeamSequence" });
-	dicom_dictionary.insert(0x300C0006, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Beam Number", retired: "", keyword: "ReferencedBeamNumber" });
-	dicom_dictionary.insert(0x300C0007, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Reference Image Number", retired: "", keyword: "ReferencedReferenceImageNumber" });
-	dicom_dictionary.insert(0x300C0008, DicomDictElt { vr : "DS", vm: "1", name: "Start Cumulative Meterset Weight", retired: "", keyword: "StartCumulativeMetersetWeight" });
-	dicom_dictionary.insert(0x300C0009, DicomDictElt { vr : "DS", vm: "1", name: "End Cumulative Meterset Weight", retired: "", keyword: "EndCumulativeMetersetWeight" });
-	dicom_dictionary.insert(0x300C000A, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Brachy Application Setup Sequence", retired: "", keyword: "ReferencedBrachyApplicationSetupSequence" });
-	dicom_dictionary.insert(0x300C000C, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Brachy Application Setup Number", retired: "", keyword: "ReferencedBrachyApplicationSetupNumber" });
-	dicom_dictionary.insert(0x300C000E, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Source Number", retired: "", keyword: "ReferencedSourceNumber" });
-	dicom_dictionary.insert(0x300C0020, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Fraction Group Sequence", retired: "", keyword: "ReferencedFractionGroupSequence" });
-	dicom_dictionary.insert(0x300C0022, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Fraction Group Number", retired: "", keyword: "ReferencedFractionGroupNumber" });
-	dicom_dictionary.insert(0x300C0040, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Verification Image Sequence", retired: "", keyword: "ReferencedVerificationImageSequence" });
-	dicom_dictionary.insert(0x300C0042, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Reference Image Sequence", retired: "", keyword: "ReferencedReferenceImageSequence" });
-	dicom_dictionary.insert(0x300C0050, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Dose Reference Sequence", retired: "", keyword: "ReferencedDoseReferenceSequence" });
-	dicom_dictionary.insert(0x300C0051, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Dose Reference Number", retired: "", keyword: "ReferencedDoseReferenceNumber" });
-	dicom_dictionary.insert(0x300C0055, DicomDictElt { vr : "SQ", vm: "1", name: "Brachy Referenced Dose Reference Sequence", retired: "", keyword: "BrachyReferencedDoseReferenceSequence" });
-	dicom_dictionary.insert(0x300C0060, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Structure Set Sequence", retired: "", keyword: "ReferencedStructureSetSequence" });
-	dicom_dictionary.insert(0x300C006A, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Patient Setup Number", retired: "", keyword: "ReferencedPatientSetupNumber" });
-	dicom_dictionary.insert(0x300C0080, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Dose Sequence", retired: "", keyword: "ReferencedDoseSequence" });
-	dicom_dictionary.insert(0x300C00A0, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Tolerance Table Number", retired: "", keyword: "ReferencedToleranceTableNumber" });
-	dicom_dictionary.insert(0x300C00B0, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Bolus Sequence", retired: "", keyword: "ReferencedBolusSequence" });
-	dicom_dictionary.insert(0x300C00C0, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Wedge Number", retired: "", keyword: "ReferencedWedgeNumber" });
-	dicom_dictionary.insert(0x300C00D0, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Compensator Number", retired: "", keyword: "ReferencedCompensatorNumber" });
-	dicom_dictionary.insert(0x300C00E0, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Block Number", retired: "", keyword: "ReferencedBlockNumber" });
-	dicom_dictionary.insert(0x300C00F0, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Control Point Index", retired: "", keyword: "ReferencedControlPointIndex" });
-	dicom_dictionary.insert(0x300C00F2, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Control Point Sequence", retired: "", keyword: "ReferencedControlPointSequence" });
-	dicom_dictionary.insert(0x300C00F4, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Start Control Point Index", retired: "", keyword: "ReferencedStartControlPointIndex" });
-	dicom_dictionary.insert(0x300C00F6, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Stop Control Point Index", retired: "", keyword: "ReferencedStopControlPointIndex" });
-	dicom_dictionary.insert(0x300C0100, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Range Shifter Number", retired: "", keyword: "ReferencedRangeShifterNumber" });
-	dicom_dictionary.insert(0x300C0102, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Lateral Spreading Device Number", retired: "", keyword: "ReferencedLateralSpreadingDeviceNumber" });
-	dicom_dictionary.insert(0x300C0104, DicomDictElt { vr : "IS", vm: "1", name: "Referenced Range Modulator Number", retired: "", keyword: "ReferencedRangeModulatorNumber" });
-	dicom_dictionary.insert(0x300C0111, DicomDictElt { vr : "SQ", vm: "1", name: "Omitted Beam Task Sequence", retired: "", keyword: "OmittedBeamTaskSequence" });
-	dicom_dictionary.insert(0x300C0112, DicomDictElt { vr : "CS", vm: "1", name: "Reason for Omission", retired: "", keyword: "ReasonForOmission" });
-	dicom_dictionary.insert(0x300C0113, DicomDictElt { vr : "LO", vm: "1", name: "Reason for Omission Description", retired: "", keyword: "ReasonForOmissionDescription" });
-	dicom_dictionary.insert(0x300E0002, DicomDictElt { vr : "CS", vm: "1", name: "Approval Status", retired: "", keyword: "ApprovalStatus" });
-	dicom_dictionary.insert(0x300E0004, DicomDictElt { vr : "DA", vm: "1", name: "Review Date", retired: "", keyword: "ReviewDate" });
-	dicom_dictionary.insert(0x300E0005, DicomDictElt { vr : "TM", vm: "1", name: "Review Time", retired: "", keyword: "ReviewTime" });
-	dicom_dictionary.insert(0x300E0008, DicomDictElt { vr : "PN", vm: "1", name: "Reviewer Name", retired: "", keyword: "ReviewerName" });
-	dicom_dictionary.insert(0x40000010, DicomDictElt { vr : "LT", vm: "1", name: "Arbitrary", retired: "Retired", keyword: "Arbitrary" });
-	dicom_dictionary.insert(0x40004000, DicomDictElt { vr : "LT", vm: "1", name: "Text Comments", retired: "Retired", keyword: "TextComments" });
-	dicom_dictionary.insert(0x40080040, DicomDictElt { vr : "SH", vm: "1", name: "Results ID", retired: "Retired", keyword: "ResultsID" });
-	dicom_dictionary.insert(0x40080042, DicomDictElt { vr : "LO", vm: "1", name: "Results ID Issuer", retired: "Retired", keyword: "ResultsIDIssuer" });
-	dicom_dictionary.insert(0x40080050, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced Interpretation Sequence", retired: "Retired", keyword: "ReferencedInterpretationSequence" });
-	dicom_dictionary.insert(0x400800FF, DicomDictElt { vr : "CS", vm: "1", name: "Report Production Status (Trial)", retired: "Retired", keyword: "ReportProductionStatusTrial" });
-	dicom_dictionary.insert(0x40080100, DicomDictElt { vr : "DA", vm: "1", name: "Interpretation Recorded Date", retired: "Retired", keyword: "InterpretationRecordedDate" });
-	dicom_dictionary.insert(0x40080101, DicomDictElt { vr : "TM", vm: "1", name: "Interpretation Recorded Time", retired: "Retired", keyword: "InterpretationRecordedTime" });
-	dicom_dictionary.insert(0x40080102, DicomDictElt { vr : "PN", vm: "1", name: "Interpretation Recorder", retired: "Retired", keyword: "InterpretationRecorder" });
-	dicom_dictionary.insert(0x40080103, DicomDictElt { vr : "LO", vm: "1", name: "Reference to Recorded Sound", retired: "Retired", keyword: "ReferenceToRecordedSound" });
-	dicom_dictionary.insert(0x40080108, DicomDictElt { vr : "DA", vm: "1", name: "Interpretation Transcription Date", retired: "Retired", keyword: "InterpretationTranscriptionDate" });
-	dicom_dictionary.insert(0x40080109, DicomDictElt { vr : "TM", vm: "1", name: "Interpretation Transcription Time", retired: "Retired", keyword: "InterpretationTranscriptionTime" });
-	dicom_dictionary.insert(0x4008010A, DicomDictElt { vr : "PN", vm: "1", name: "Interpretation Transcriber", retired: "Retired", keyword: "InterpretationTranscriber" });
-	dicom_dictionary.insert(0x4008010B, DicomDictElt { vr : "ST", vm: "1", name: "Interpretation Text", retired: "Retired", keyword: "InterpretationText" });
-	dicom_dictionary.insert(0x4008010C, DicomDictElt { vr : "PN", vm: "1", name: "Interpretation Author", retired: "Retired", keyword: "InterpretationAuthor" });
-	dicom_dictionary.insert(0x40080111, DicomDictElt { vr : "SQ", vm: "1", name: "Interpretation Approver Sequence", retired: "Retired", keyword: "InterpretationApproverSequence" });
-	dicom_dictionary.insert(0x40080112, DicomDictElt { vr : "DA", vm: "1", name: "Interpretation Approval Date", retired: "Retired", keyword: "InterpretationApprovalDate" });
-	dicom_dictionary.insert(0x40080113, DicomDictElt { vr : "TM", vm: "1", name: "Interpretation Approval Time", retired: "Retired", keyword: "InterpretationApprovalTime" });
-	dicom_dictionary.insert(0x40080114, DicomDictElt { vr : "PN", vm: "1", name: "Physician Approving Interpretation", retired: "Retired", keyword: "PhysicianApprovingInterpretation" });
-	dicom_dictionary.insert(0x40080115, DicomDictElt { vr : "LT", vm: "1", name: "Interpretation Diagnosis Description", retired: "Retired", keyword: "InterpretationDiagnosisDescription" });
-	dicom_dictionary.insert(0x40080117, DicomDictElt { vr : "SQ", vm: "1", name: "Interpretation Diagnosis Code Sequence", retired: "Retired", keyword: "InterpretationDiagnosisCodeSequence" });
-	dicom_dictionary.insert(0x40080118, DicomDictElt { vr : "SQ", vm: "1", name: "Results Distribution List Sequence", retired: "Retired", keyword: "ResultsDistributionListSequence" });
-	dicom_dictionary.insert(0x40080119, DicomDictElt { vr : "PN", vm: "1", name: "Distribution Name", retired: "Retired", keyword: "DistributionName" });
-	dicom_dictionary.insert(0x4008011A, DicomDictElt { vr : "LO", vm: "1", name: "Distribution Address", retired: "Retired", keyword: "DistributionAddress" });
-	dicom_dictionary.insert(0x40080200, DicomDictElt { vr : "SH", vm: "1", name: "Interpretation ID", retired: "Retired", keyword: "InterpretationID" });
-	dicom_dictionary.insert(0x40080202, DicomDictElt { vr : "LO", vm: "1", name: "Interpretation ID Issuer", retired: "Retired", keyword: "InterpretationIDIssuer" });
-	dicom_dictionary.insert(0x40080210, DicomDictElt { vr : "CS", vm: "1", name: "Interpretation Type ID", retired: "Retired", keyword: "InterpretationTypeID" });
-	dicom_dictionary.insert(0x40080212, DicomDictElt { vr : "CS", vm: "1", name: "Interpretation Status ID", retired: "Retired", keyword: "InterpretationStatusID" });
-	dicom_dictionary.insert(0x40080300, DicomDictElt { vr : "ST", vm: "1", name: "Impressions", retired: "Retired", keyword: "Impressions" });
-	dicom_dictionary.insert(0x40084000, DicomDictElt { vr : "ST", vm: "1", name: "Results Comments", retired: "Retired", keyword: "ResultsComments" });
-	dicom_dictionary.insert(0x40100001, DicomDictElt { vr : "CS", vm: "1", name: "Low Energy Detectors", retired: "", keyword: "LowEnergyDetectors" });
-	dicom_dictionary.insert(0x40100002, DicomDictElt { vr : "CS", vm: "1", name: "High Energy Detectors", retired: "", keyword: "HighEnergyDetectors" });
-	dicom_dictionary.insert(0x40100004, DicomDictElt { vr : "SQ", vm: "1", name: "Detector Geometry Sequence", retired: "", keyword: "DetectorGeometrySequence" });
-	dicom_dictionary.insert(0x40101001, DicomDictElt { vr : "SQ", vm: "1", name: "Threat ROI Voxel Sequence", retired: "", keyword: "ThreatROIVoxelSequence" });
-	dicom_dictionary.insert(0x40101004, DicomDictElt { vr : "FL", vm: "3", name: "Threat ROI Base", retired: "", keyword: "ThreatROIBase" });
-	dicom_dictionary.insert(0x40101005, DicomDictElt { vr : "FL", vm: "3", name: "Threat ROI Extents", retired: "", keyword: "ThreatROIExtents" });
-	dicom_dictionary.insert(0x40101006, DicomDictElt { vr : "OB", vm: "1", name: "Threat ROI Bitmap", retired: "", keyword: "ThreatROIBitmap" });
-	dicom_dictionary.insert(0x40101007, DicomDictElt { vr : "SH", vm: "1", name: "Route Segment ID", retired: "", keyword: "RouteSegmentID" });
-	dicom_dictionary.insert(0x40101008, DicomDictElt { vr : "CS", vm: "1", name: "Gantry Type", retired: "", keyword: "GantryType" });
-	dicom_dictionary.insert(0x40101009, DicomDictElt { vr : "CS", vm: "1", name: "OOI Owner Type", retired: "", keyword: "OOIOwnerType" });
-	dicom_dictionary.insert(0x4010100A, DicomDictElt { vr : "SQ", vm: "1", name: "Route Segment Sequence", retired: "", keyword: "RouteSegmentSequence" });
-	dicom_dictionary.insert(0x40101010, DicomDictElt { vr : "US", vm: "1", name: "Potential Threat Object ID", retired: "", keyword: "PotentialThreatObjectID" });
-	dicom_dictionary.insert(0x40101011, DicomDictElt { vr : "SQ", vm: "1", name: "Threat Sequence", retired: "", keyword: "ThreatSequence" });
-	dicom_dictionary.insert(0x40101012, DicomDictElt { vr : "CS", vm: "1", name: "Threat Category", retired: "", keyword: "ThreatCategory" });
-	dicom_dictionary.insert(0x40101013, DicomDictElt { vr : "LT", vm: "1", name: "Threat Category Description", retired: "", keyword: "ThreatCategoryDescription" });
-	dicom_dictionary.insert(0x40101014, DicomDictElt { vr : "CS", vm: "1", name: "ATD Ability Assessment", retired: "", keyword: "ATDAbilityAssessment" });
-	dicom_dictionary.insert(0x40101015, DicomDictElt { vr : "CS", vm: "1", name: "ATD Assessment Flag", retired: "", keyword: "ATDAssessmentFlag" });
-	dicom_dictionary.insert(0x40101016, DicomDictElt { vr : "FL", vm: "1", name: "ATD Assessment Probability", retired: "", keyword: "ATDAssessmentProbability" });
-	dicom_dictionary.insert(0x40101017, DicomDictElt { vr : "FL", vm: "1", name: "Mass", retired: "", keyword: "Mass" });
-	dicom_dictionary.insert(0x40101018, DicomDictElt { vr : "FL", vm: "1", name: "Density", retired: "", keyword: "Density" });
-	dicom_dictionary.insert(0x40101019, DicomDictElt { vr : "FL", vm: "1", name: "Z Effective", retired: "", keyword: "ZEffective" });
-	dicom_dictionary.insert(0x4010101A, DicomDictElt { vr : "SH", vm: "1", name: "Boarding Pass ID", retired: "", keyword: "BoardingPassID" });
-	dicom_dictionary.insert(0x4010101B, DicomDictElt { vr : "FL", vm: "3", name: "Center of Mass", retired: "", keyword: "CenterOfMass" });
-	dicom_dictionary.insert(0x4010101C, DicomDictElt { vr : "FL", vm: "3", name: "Center of PTO", retired: "", keyword: "CenterOfPTO" });
-	dicom_dictionary.insert(0x4010101D, DicomDictElt { vr : "FL", vm: "6-n", name: "Bounding Polygon", retired: "", keyword: "BoundingPolygon" });
-	dicom_dictionary.insert(0x4010101E, DicomDictElt { vr : "SH", vm: "1", name: "Route Segment Start Location ID", retired: "", keyword: "RouteSegmentStartLocationID" });
-	dicom_dictionary.insert(0x4010101F, DicomDictElt { vr : "SH", vm: "1", name: "Route Segment End Location ID", retired: "", keyword: "RouteSegmentEndLocationID" });
-	dicom_dictionary.insert(0x40101020, DicomDictElt { vr : "CS", vm: "1", name: "Route Segment Location ID Type", retired: "", keyword: "RouteSegmentLocationIDType" });
-	dicom_dictionary.insert(0x40101021, DicomDictElt { vr : "CS", vm: "1-n", name: "Abort Reason", retired: "", keyword: "AbortReason" });
-	dicom_dictionary.insert(0x40101023, DicomDictElt { vr : "FL", vm: "1", name: "Volume of PTO", retired: "", keyword: "VolumeOfPTO" });
-	dicom_dictionary.insert(0x40101024, DicomDictElt { vr : "CS", vm: "1", name: "Abort Flag", retired: "", keyword: "AbortFlag" });
-	dicom_dictionary.insert(0x40101025, DicomDictElt { vr : "DT", vm: "1", name: "Route Segment Start Time", retired: "", keyword: "RouteSegmentStartTime" });
-	dicom_dictionary.insert(0x40101026, DicomDictElt { vr : "DT", vm: "1", name: "Route Segment End Time", retired: "", keyword: "RouteSegmentEndTime" });
-	dicom_dictionary.insert(0x40101027, DicomDictElt { vr : "CS", vm: "1", name: "TDR Type", retired: "", keyword: "TDRType" });
-	dicom_dictionary.insert(0x40101028, DicomDictElt { vr : "CS", vm: "1", name: "International Route Segment", retired: "", keyword: "InternationalRouteSegment" });
-	dicom_dictionary.insert(0x40101029, DicomDictElt { vr : "LO", vm: "1-n", name: "Threat Detection Algorithm and Version", retired: "", keyword: "ThreatDetectionAlgorithmandVersion" });
-	dicom_dictionary.insert(0x4010102A, DicomDictElt { vr : "SH", vm: "1", name: "Assigned Location", retired: "", keyword: "AssignedLocation" });
-	dicom_dictionary.insert(0x4010102B, DicomDictElt { vr : "DT", vm: "1", name: "Alarm Decision Time", retired: "", keyword: "AlarmDecisionTime" });
-	dicom_dictionary.insert(0x40101031, DicomDictElt { vr : "CS", vm: "1", name: "Alarm Decision", retired: "", keyword: "AlarmDecision" });
-	dicom_dictionary.insert(0x40101033, DicomDictElt { vr : "US", vm: "1", name: "Number of Total Objects", retired: "", keyword: "NumberOfTotalObjects" });
-	dicom_dictionary.insert(0x40101034, DicomDictElt { vr : "US", vm: "1", name: "Number of Alarm Objects", retired: "", keyword: "NumberOfAlarmObjects" });
-	dicom_dictionary.insert(0x40101037, DicomDictElt { vr : "SQ", vm: "1", name: "PTO Representation Sequence", retired: "", keyword: "PTORepresentationSequence" });
-	dicom_dictionary.insert(0x40101038, DicomDictElt { vr : "SQ", vm: "1", name: "ATD Assessment Sequence", retired: "", keyword: "ATDAssessmentSequence" });
-	dicom_dictionary.insert(0x40101039, DicomDictElt { vr : "CS", vm: "1", name: "TIP Type", retired: "", keyword: "TIPType" });
-	dicom_dictionary.insert(0x4010103A, DicomDictElt { vr : "CS", vm: "1", name: "DICOS Version", retired: "", keyword: "DICOSVersion" });
-	dicom_dictionary.insert(0x40101041, DicomDictElt { vr : "DT", vm: "1", name: "OOI Owner Creation Time", retired: "", keyword: "OOIOwnerCreationTime" });
-	dicom_dictionary.insert(0x40101042, DicomDictElt { vr : "CS", vm: "1", name: "OOI Type", retired: "", keyword: "OOIType" });
-	dicom_dictionary.insert(0x40101043, DicomDictElt { vr : "FL", vm: "3", name: "OOI Size", retired: "", keyword: "OOISize" });
-	dicom_dictionary.insert(0x40101044, DicomDictElt { vr : "CS", vm: "1", name: "Acquisition Status", retired: "", keyword: "AcquisitionStatus" });
-	dicom_dictionary.insert(0x40101045, DicomDictElt { vr : "SQ", vm: "1", name: "Basis Materials Code Sequence", retired: "", keyword: "BasisMaterialsCodeSequence" });
-	dicom_dictionary.insert(0x40101046, DicomDictElt { vr : "CS", vm: "1", name: "Phantom Type", retired: "", keyword: "PhantomType" });
-	dicom_dictionary.insert(0x40101047, DicomDictElt { vr : "SQ", vm: "1", name: "OOI Owner Sequence", retired: "", keyword: "OOIOwnerSequence" });
-	dicom_dictionary.insert(0x40101048, DicomDictElt { vr : "CS", vm: "1", name: "Scan Type", retired: "", keyword: "ScanType" });
-	dicom_dictionary.insert(0x40101051, DicomDictElt { vr : "LO", vm: "1", name: "Itinerary ID", retired: "", keyword: "ItineraryID" });
-	dicom_dictionary.insert(0x40101052, DicomDictElt { vr : "SH", vm: "1", name: "Itinerary ID Type", retired: "", keyword: "ItineraryIDType" });
-	dicom_dictionary.insert(0x40101053, DicomDictElt { vr : "LO", vm: "1", name: "Itinerary ID Assigning Authority", retired: "", keyword: "ItineraryIDAssigningAuthority" });
-	dicom_dictionary.insert(0x40101054, DicomDictElt { vr : "SH", vm: "1", name: "Route ID", retired: "", keyword: "RouteID" });
-	dicom_dictionary.insert(0x40101055, DicomDictElt { vr : "SH", vm: "1", name: "Route ID Assigning Authority", retired: "", keyword: "RouteIDAssigningAuthority" });
-	dicom_dictionary.insert(0x40101056, DicomDictElt { vr : "CS", vm: "1", name: "Inbound Arrival Type", retired: "", keyword: "InboundArrivalType" });
-	dicom_dictionary.insert(0x40101058, DicomDictElt { vr : "SH", vm: "1", name: "Carrier ID", retired: "", keyword: "CarrierID" });
-	dicom_dictionary.insert(0x40101059, DicomDictElt { vr : "CS", vm: "1", name: "Carrier ID Assigning Authority", retired: "", keyword: "CarrierIDAssigningAuthority" });
-	dicom_dictionary.insert(0x40101060, DicomDictElt { vr : "FL", vm: "3", name: "Source Orientation", retired: "", keyword: "SourceOrientation" });
-	dicom_dictionary.insert(0x40101061, DicomDictElt { vr : "FL", vm: "3", name: "Source Position", retired: "", keyword: "SourcePosition" });
-	dicom_dictionary.insert(0x40101062, DicomDictElt { vr : "FL", vm: "1", name: "Belt Height", retired: "", keyword: "BeltHeight" });
-	dicom_dictionary.insert(0x40101064, DicomDictElt { vr : "SQ", vm: "1", name: "Algorithm Routing Code Sequence", retired: "", keyword: "AlgorithmRoutingCodeSequence" });
-	dicom_dictionary.insert(0x40101067, DicomDictElt { vr : "CS", vm: "1", name: "Transport Classification", retired: "", keyword: "TransportClassification" });
-	dicom_dictionary.insert(0x40101068, DicomDictElt { vr : "LT", vm: "1", name: "OOI Type Descriptor", retired: "", keyword: "OOITypeDescriptor" });
-	dicom_dictionary.insert(0x40101069, DicomDictElt { vr : "FL", vm: "1", name: "Total Processing Time", retired: "", keyword: "TotalProcessingTime" });
-	dicom_dictionary.insert(0x4010106C, DicomDictElt { vr : "OB", vm: "1", name: "Detector Calibration Data", retired: "", keyword: "DetectorCalibrationData" });
-	dicom_dictionary.insert(0x4010106D, DicomDictElt { vr : "CS", vm: "1", name: "Additional Screening Performed", retired: "", keyword: "AdditionalScreeningPerformed" });
-	dicom_dictionary.insert(0x4010106E, DicomDictElt { vr : "CS", vm: "1", name: "Additional Inspection Selection Criteria", retired: "", keyword: "AdditionalInspectionSelectionCriteria" });
-	dicom_dictionary.insert(0x4010106F, DicomDictElt { vr : "SQ", vm: "1", name: "Additional Inspection Method Sequence", retired: "", keyword: "AdditionalInspectionMethodSequence" });
-	dicom_dictionary.insert(0x40101070, DicomDictElt { vr : "CS", vm: "1", name: "AIT Device Type", retired: "", keyword: "AITDeviceType" });
-	dicom_dictionary.insert(0x40101071, DicomDictElt { vr : "SQ", vm: "1", name: "QR Measurements Sequence", retired: "", keyword: "QRMeasurementsSequence" });
-	dicom_dictionary.insert(0x40101072, DicomDictElt { vr : "SQ", vm: "1", name: "Target Material Sequence", retired: "", keyword: "TargetMaterialSequence" });
-	dicom_dictionary.insert(0x40101073, DicomDictElt { vr : "FD", vm: "1", name: "SNR Threshold", retired: "", keyword: "SNRThreshold" });
-	dicom_dictionary.insert(0x40101075, DicomDictElt { vr : "DS", vm: "1", name: "Image Scale Representation", retired: "", keyword: "ImageScaleRepresentation" });
-	dicom_dictionary.insert(0x40101076, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced PTO Sequence", retired: "", keyword: "ReferencedPTOSequence" });
-	dicom_dictionary.insert(0x40101077, DicomDictElt { vr : "SQ", vm: "1", name: "Referenced TDR Instance Sequence", retired: "", keyword: "ReferencedTDRInstanceSequence" });
-	dicom_dictionary.insert(0x40101078, DicomDictElt { vr : "ST", vm: "1", name: "PTO Location Description", retired: "", keyword: "PTOLocationDescription" });
-	dicom_dictionary.insert(0x40101079, DicomDictElt { vr : "SQ", vm: "1", name: "Anomaly Locator Indicator Sequence", retired: "", keyword: "AnomalyLocatorIndicatorSequence" });
-	dicom_dictionary.insert(0x4010107A, DicomDictElt { vr : "FL", vm: "3", name: "Anomaly Locator Indicator", retired: "", keyword: "AnomalyLocatorIndicator" });
-	dicom_dictionary.insert(0x4010107B, DicomDictElt { vr : "SQ", vm: "1", name: "PTO Region Sequence", retired: "", keyword: "PTORegionSequence" });
-	dicom_dictionary.insert(0x4010107C, DicomDictElt { vr : "CS", vm: "1", name: "Inspection Selection Criteria", retired: "", keyword: "InspectionSelectionCriteria" });
-	dicom_dictionary.insert(0x4010107D, DicomDictElt { vr : "SQ", vm: "1", name: "Secondary Inspection Method Sequence", retired: "", keyword: "SecondaryInspectionMethodSequence" });
-	dicom_dictionary.insert(0x4010107E, DicomDictElt { vr : "DS", vm: "6", name: "PRCS to RCS Orientation", retired: "", keyword: "PRCSToRCSOrientation" });
-	dicom_dictionary.insert(0x4FFE0001, DicomDictElt { vr : "SQ", vm: "1", name: "MAC Parameters Sequence", retired: "", keyword: "MACParametersSequence" });
-	dicom_dictionary.insert(0x52009229, DicomDictElt { vr : "SQ", vm: "1", name: "Shared Functional Groups Sequence", retired: "", keyword: "SharedFunctionalGroupsSequence" });
-	dicom_dictionary.insert(0x52009230, DicomDictElt { vr : "SQ", vm: "1", name: "Per-frame Functional Groups Sequence", retired: "", keyword: "PerFrameFunctionalGroupsSequence" });
-	dicom_dictionary.insert(0x54000100, DicomDictElt { vr : "SQ", vm: "1", name: "Waveform Sequence", retired: "", keyword: "WaveformSequence" });
-	dicom_dictionary.insert(0x54000110, DicomDictElt { vr : "OB or OW", vm: "1", name: "Channel Minimum Value", retired: "", keyword: "ChannelMinimumValue" });
-	dicom_dictionary.insert(0x54000112, DicomDictElt { vr : "OB or OW", vm: "1", name: "Channel Maximum Value", retired: "", keyword: "ChannelMaximumValue" });
-	dicom_dictionary.insert(0x54001004, DicomDictElt { vr : "US", vm: "1", name: "Waveform Bits Allocated", retired: "", keyword: "WaveformBitsAllocated" });
-	dicom_dictionary.insert(0x54001006, DicomDictElt { vr : "CS", vm: "1", name: "Waveform Sample Interpretation", retired: "", keyword: "WaveformSampleInterpretation" });
-	dicom_dictionary.insert(0x5400100A, DicomDictElt { vr : "OB or OW", vm: "1", name: "Waveform Padding Value", retired: "", keyword: "WaveformPaddingValue" });
-	dicom_dictionary.insert(0x54001010, DicomDictElt { vr : "OB or OW", vm: "1", name: "Waveform Data", retired: "", keyword: "WaveformData" });
-	dicom_dictionary.insert(0x56000010, DicomDictElt { vr : "OF", vm: "1", name: "First Order Phase Correction Angle", retired: "", keyword: "FirstOrderPhaseCorrectionAngle" });
-	dicom_dictionary.insert(0x56000020, DicomDictElt { vr : "OF", vm: "1", name: "Spectroscopy Data", retired: "", keyword: "SpectroscopyData" });
-	dicom_dictionary.insert(0x7FE00008, DicomDictElt { vr : "OF", vm: "1", name: "Float Pixel Data", retired: "", keyword: "FloatPixelData" });
-	dicom_dictionary.insert(0x7FE00009, DicomDictElt { vr : "OD", vm: "1", name: "Double Float Pixel Data", retired: "", keyword: "DoubleFloatPixelData" });
-	dicom_dictionary.insert(0x7FE00010, DicomDictElt { vr : "OB or OW", vm: "1", name: "Pixel Data", retired: "", keyword: "PixelData" });
-	dicom_dictionary.insert(0x7FE00020, DicomDictElt { vr : "OW", vm: "1", name: "Coefficients SDVN", retired: "Retired", keyword: "CoefficientsSDVN" });
-	dicom_dictionary.insert(0x7FE00030, DicomDictElt { vr : "OW", vm: "1", name: "Coefficients SDHN", retired: "Retired", keyword: "CoefficientsSDHN" });
-	dicom_dictionary.insert(0x7FE00040, DicomDictElt { vr : "OW", vm: "1", name: "Coefficients SDDN", retired: "Retired", keyword: "CoefficientsSDDN" });
-	dicom_dictionary.insert(0xFFFAFFFA, DicomDictElt { vr : "SQ", vm: "1", name: "Digital Signatures Sequence", retired: "", keyword: "DigitalSignaturesSequence" });
-	dicom_dictionary.insert(0xFFFCFFFC, DicomDictElt { vr : "OB", vm: "1", name: "Data Set Trailing Padding", retired: "", keyword: "DataSetTrailingPadding" });
-	dicom_dictionary.insert(0xFFFEE000, DicomDictElt { vr : "NONE", vm: "1", name: "Item", retired: "", keyword: "Item" });
-	dicom_dictionary.insert(0xFFFEE00D, DicomDictElt { vr : "NONE", vm: "1", name: "Item Delimitation Item", retired: "", keyword: "ItemDelimitationItem" });
-	dicom_dictionary.insert(0xFFFEE0DD, DicomDictElt { vr : "NONE", vm: "1", name: "Sequence Delimitation Item", retired: "", keyword: "SequenceDelimitationItem" });
-	dicom_dictionary
+/// Dictionary entry for a tag, falling back to the repeating group masks (50xx, 60xx, ...).
+pub fn lookup_tag(tag: u32) -> Option<&'static DicomDictElt<'static>> {
+    DICOM_DICTIONARY.get(&tag).or_else(|| REPEATERS_DICTIONARY.iter()
+                                             .find(|&&(mask, value, _)| tag & mask == value)
+                                             .map(|&(_, _, ref elt)| elt))
+}
+
+/// Tag for a keyword, e.g. "PatientName" is 0x00100010. Repeating group keywords give
+/// the first group, e.g. "OverlayRows" is 0x60000010.
+pub fn lookup_keyword(keyword: &str) -> Option<u32> {
+    DICOM_KEYWORDS.get(keyword).cloned().or_else(|| lookup_repeater_keyword(keyword).map(|(_, value)| value))
+}
+
+/// (mask, value) of a repeating group keyword: the tags it names are those with
+/// `tag & mask == value`.
+pub fn lookup_repeater_keyword(keyword: &str) -> Option<(u32, u32)> {
+    REPEATERS_DICTIONARY.iter().find(|&&(_, _, ref elt)| elt.keyword == keyword).map(|&(mask, value, _)| (mask, value))
 }
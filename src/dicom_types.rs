@@ -1,5 +1,14 @@
-use std::collections::HashMap;
-use std::ops::Index;
+use std::collections::{HashMap, BTreeMap};
+use std::collections::btree_map;
+
+use dicom_dict::{lookup_keyword, lookup_repeater_keyword};
+use uids::lookup_uid;
+use person_name::PersonName;
+use color::{ColorSpace, DcmColorImg};
+use palette::Palette;
+use pixels::DcmPixels;
+use tags;
+use error::{DicomError, Result};
 
 #[derive(Debug)]
 pub struct DicomDictElt<'a> {
@@ -10,28 +19,114 @@ pub struct DicomDictElt<'a> {
     pub keyword: &'a str,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DicomDataElt {
+    pub tag: u32,
+    pub vr: String,
+    pub value: DicomElt,
+}
+
+/// A dictionary tag with its VR and VM, as found in the `tags` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomTag {
+    pub tag: u32,
+    pub vr: &'static str,
+    pub vm: &'static str,
+    pub keyword: &'static str,
+}
+
+/// Anything that names an element: a raw tag such as `0x00281053` or a `tags::` constant.
+pub trait ToTag {
+    fn to_tag(&self) -> u32;
+}
+
+impl ToTag for u32 {
+    fn to_tag(&self) -> u32 { *self }
+}
+
+impl ToTag for DicomTag {
+    fn to_tag(&self) -> u32 { self.tag }
+}
+
+/// The kind of a registered UID, from the "UID Type" column of PS3.6 Table A-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UidType {
+    TransferSyntax,
+    SopClass,
+    MetaSopClass,
+    WellKnownSopInstance,
+    WellKnownFrameOfReference,
+    ApplicationContextName,
+    CodingScheme,
+    Other,
+}
+
+/// An entry of the PS3.6 Annex A UID registry.
+#[derive(Debug, PartialEq)]
+pub struct DicomUid {
+    pub uid: &'static str,
+    /// The name without the "(Retired)" suffix, e.g. "Explicit VR Big Endian"
+    pub name: &'static str,
+    pub keyword: &'static str,
+    pub uid_type: UidType,
+    pub retired: bool,
+    /// The part of the standard defining it, e.g. "PS3.5"
+    pub part: &'static str,
+}
+
+/// Every element of a dataset (or sequence item), kept in tag order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Dataset {
+    elements: BTreeMap<u32, DicomDataElt>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DicomSlice {
-    pub keydict: DicomKwEltDict,
+    pub dataset: Dataset,
+    pub transfer_syntax: TransferSyntax,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TransferSyntax {
+    ImplicitVRLittleEndian,
+    ExplicitVRLittleEndian,
+    DeflatedExplicitVRLittleEndian,
+    ExplicitVRBigEndian,
+    /// Any of the compressed syntaxes; the dataset itself is explicit VR little endian
+    Encapsulated(String),
+}
+
+/// Decoded pixel samples: `zr` frames one after another, each `yr` rows of `xr` columns
+/// with `samples` values per pixel.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct DcmImg16 {
+pub struct DcmImg<T> {
     pub xr : usize,
     pub yr : usize,
     pub zr : usize,
-    pub data : Vec<i16>,
+    pub samples : usize,
+    pub data : Vec<T>,
 }
 
+/// Encapsulated pixel data as stored: the Basic Offset Table and the compressed fragments.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct DcmImg8 {
+pub struct DcmFragments {
     pub xr : usize,
     pub yr : usize,
     pub zr : usize,
-    pub data : Vec<u8>,
+    pub samples : usize,
+    /// Offset of each frame's first fragment item, counted from the first fragment item
+    pub offsets : Vec<u32>,
+    pub fragments : Vec<Vec<u8>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub type DcmImg16 = DcmImg<i16>;
+pub type DcmImgU16 = DcmImg<u16>;
+pub type DcmImg8 = DcmImg<u8>;
+pub type DcmImgI8 = DcmImg<i8>;
+pub type DcmImg32 = DcmImg<i32>;
+pub type DcmImgU32 = DcmImg<u32>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DicomScan {
     pub slice_data: Vec<DicomSlice>,
     pub image: DcmImg16
@@ -44,60 +139,384 @@ pub enum DicomElt {
     UInt16s(Vec<u16>),
     Int32s(Vec<i32>),
     UInt32s(Vec<u32>),
+    Int64s(Vec<i64>),
+    UInt64s(Vec<u64>),
     Float64s(Vec<f64>),
     Float32s(Vec<f32>),
-    Seq(Vec<DicomElt>),
+    Seq(Vec<Dataset>),
     String(String),
     Bytes(Vec<u8>),
     Image16(DcmImg16),
+    ImageU16(DcmImgU16),
     Image8(DcmImg8),
+    ImageI8(DcmImgI8),
+    Image32(DcmImg32),
+    ImageU32(DcmImgU32),
+    Pixels(DcmPixels),
+    Fragments(DcmFragments),
     Empty,
 }
 
-pub type DicomDict<'a> = HashMap<u32, DicomDictElt<'a>>;
-pub type DicomGeltEltDict = HashMap<u32, DicomElt>;
-pub type DicomKwEltDict = HashMap<String, DicomElt>;
+/// The frames of a slice's PixelData, in order.
+pub struct Frames<'a> {
+    slice: &'a DicomSlice,
+    next: usize,
+    count: usize,
+}
+
+/// Private creator -> entries keyed by tag with the block byte cleared, e.g. 0x00290010 for (0029,xx10)
+pub type PrivateDict<'a> = HashMap<String, HashMap<u32, DicomDictElt<'a>>>;
+
+impl<T: Clone> DcmImg<T> {
+    /// Frame `n` as an image of its own.
+    pub fn frame(&self, n: usize) -> Option<DcmImg<T>> {
+        let len = self.xr * self.yr * self.samples;
+        if n >= self.zr { return None; }
+        self.data.get(n*len..(n + 1)*len)
+            .map(|data| DcmImg { xr: self.xr, yr: self.yr, zr: 1, samples: self.samples, data: data.to_vec() })
+    }
+}
+
+impl DcmFragments {
+    /// The compressed bytes of frame `n`, located through `extended_offsets` (the Extended
+    /// Offset Table) if given, else the Basic Offset Table. Without either, frames can only be
+    /// told apart when there is one frame or one fragment per frame.
+    pub fn frame(&self, n: usize, extended_offsets: Option<&[u64]>) -> Option<Vec<u8>> {
+        if n >= self.zr { return None; }
+        let offsets: Vec<u64> = match extended_offsets {
+            Some(offsets) => offsets.to_vec(),
+            None => self.offsets.iter().map(|&o| o as u64).collect(),
+        };
+        let (first, last) = if !offsets.is_empty() {
+            let index = |offset: u64| {
+                let mut pos = 0;
+                self.fragments.iter().position(|f| { let found = pos == offset; pos += 8 + f.len() as u64; found })
+            };
+            let first = index(*offsets.get(n)?)?;
+            let last = match offsets.get(n + 1) {
+                Some(&offset) => index(offset)?,
+                None => self.fragments.len(),
+            };
+            (first, last)
+        } else if self.zr == 1 {
+            (0, self.fragments.len())
+        } else if self.fragments.len() == self.zr {
+            (n, n + 1)
+        } else {
+            return None;
+        };
+        if first >= last { return None; }
+        Some(self.fragments[first..last].concat())
+    }
+}
+
+impl DicomElt {
+    /// The items of a sequence, or `None` if this isn't one.
+    pub fn items(&self) -> Option<&[Dataset]> {
+        match *self {
+            DicomElt::Seq(ref items) => Some(items),
+            _ => None,
+        }
+    }
 
-impl Index<String> for DicomSlice {
-    type Output = DicomElt;
+    pub fn item(&self, n: usize) -> Option<&Dataset> {
+        self.items().and_then(|items| items.get(n))
+    }
 
-    fn index<'a>(&'a self, key: String) -> &'a DicomElt {
-        &self.keydict[&key]
+    /// The number of frames of a PixelData value, or `None` if this isn't one.
+    pub fn number_of_frames(&self) -> Option<usize> {
+        match *self {
+            DicomElt::Image16(ref img) => Some(img.zr),
+            DicomElt::ImageU16(ref img) => Some(img.zr),
+            DicomElt::Image8(ref img) => Some(img.zr),
+            DicomElt::ImageI8(ref img) => Some(img.zr),
+            DicomElt::Image32(ref img) => Some(img.zr),
+            DicomElt::ImageU32(ref img) => Some(img.zr),
+            DicomElt::Pixels(ref p) => Some(p.zr),
+            DicomElt::Fragments(ref f) => Some(f.zr),
+            _ => None,
+        }
+    }
+
+    /// Native PixelData decoded in full; already decoded images are returned as they are.
+    pub fn decode(&self) -> Result<DicomElt> {
+        match *self {
+            DicomElt::Pixels(ref p) => p.decode(),
+            DicomElt::Image16(_) | DicomElt::ImageU16(_) | DicomElt::Image8(_) |
+            DicomElt::ImageI8(_) | DicomElt::Image32(_) | DicomElt::ImageU32(_) => Ok(self.clone()),
+            _ => Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
+        }
+    }
+
+    /// Frame `n` of native pixel data, as an image with one frame.
+    pub fn frame(&self, n: usize) -> Result<DicomElt> {
+        let frame = match *self {
+            DicomElt::Pixels(ref p) => return p.frame(n),
+            DicomElt::Image16(ref img) => img.frame(n).map(DicomElt::Image16),
+            DicomElt::ImageU16(ref img) => img.frame(n).map(DicomElt::ImageU16),
+            DicomElt::Image8(ref img) => img.frame(n).map(DicomElt::Image8),
+            DicomElt::ImageI8(ref img) => img.frame(n).map(DicomElt::ImageI8),
+            DicomElt::Image32(ref img) => img.frame(n).map(DicomElt::Image32),
+            DicomElt::ImageU32(ref img) => img.frame(n).map(DicomElt::ImageU32),
+            _ => return Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
+        };
+        frame.ok_or(DicomError::NoFrame(n))
+    }
+
+    /// 16 bit native pixel data as signed samples. Unsigned data is converted when every
+    /// value fits in an i16; otherwise, or for other pixel types, `UnexpectedType`.
+    pub fn to_image16(&self) -> Result<DcmImg16> {
+        match *self {
+            DicomElt::Image16(ref img) => Ok(img.clone()),
+            DicomElt::ImageU16(ref img) if img.data.iter().all(|&p| p <= i16::MAX as u16) =>
+                Ok(DcmImg { xr: img.xr, yr: img.yr, zr: img.zr, samples: img.samples,
+                            data: img.data.iter().map(|&p| p as i16).collect() }),
+            DicomElt::Pixels(ref p) => p.decode()?.to_image16(),
+            _ => Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
+        }
+    }
+
+    /// The names of a PN value, or `None` if this isn't a string.
+    pub fn person_names(&self) -> Option<Vec<PersonName>> {
+        match *self {
+            DicomElt::String(ref s) => Some(PersonName::parse_all(s)),
+            _ => None,
+        }
+    }
+}
+
+impl Dataset {
+    pub fn new() -> Self {
+        Dataset::default()
+    }
+
+    /// Add an element. Returns the element previously stored under the same tag, if any.
+    pub fn insert(&mut self, elt: DicomDataElt) -> Option<DicomDataElt> {
+        self.elements.insert(elt.tag, elt)
+    }
+
+    pub fn remove<T: ToTag>(&mut self, tag: T) -> Option<DicomDataElt> {
+        self.elements.remove(&tag.to_tag())
+    }
+
+    pub fn contains<T: ToTag>(&self, tag: T) -> bool {
+        self.elements.contains_key(&tag.to_tag())
+    }
+
+    pub fn get<T: ToTag>(&self, tag: T) -> Option<&DicomDataElt> {
+        self.elements.get(&tag.to_tag())
+    }
+
+    /// A repeating group keyword such as "OverlayRows" finds the lowest group present.
+    pub fn get_by_keyword(&self, keyword: &str) -> Option<&DicomDataElt> {
+        match lookup_repeater_keyword(keyword) {
+            Some((mask, value)) => self.iter().find(|elt| elt.tag & mask == value),
+            None => lookup_keyword(keyword).and_then(|tag| self.elements.get(&tag)),
+        }
+    }
+
+    /// The private creator owning a private element, e.g. "SIEMENS CSA HEADER" for (0029,1010)
+    /// when (0029,0010) holds that string.
+    pub fn private_creator(&self, tag: u32) -> Option<&str> {
+        let (grp, elt) = (tag >> 16, tag & 0xffff);
+        if grp % 2 == 0 || grp <= 0x0008 || elt < 0x1000 { return None; }
+        match self.get((grp << 16) | (elt >> 8)).map(|e| &e.value) {
+            Some(&DicomElt::String(ref creator)) => Some(creator.trim_matches(|c| c == ' ' || c == '\0')),
+            _ => None,
+        }
+    }
+
+    /// Look up a private element by creator, wherever its block was reserved in `group`.
+    /// `elt` is the low byte of the element number, e.g. 0x10 for (0029,xx10).
+    pub fn get_private(&self, creator: &str, group: u16, elt: u8) -> Option<&DicomDataElt> {
+        let group = group as u32;
+        for block in 0x10..0x100 {
+            if let Some(&DicomElt::String(ref c)) = self.get((group << 16) | block).map(|e| &e.value) {
+                if c.trim_matches(|c| c == ' ' || c == '\0') == creator {
+                    return self.get((group << 16) | (block << 8) | elt as u32);
+                }
+            }
+        }
+        None
+    }
+
+    /// Elements in ascending tag order.
+    pub fn iter<'a>(&'a self) -> btree_map::Values<'a, u32, DicomDataElt> {
+        self.elements.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+impl TransferSyntax {
+    pub fn from_uid(uid: &str) -> TransferSyntax {
+        match uid.trim_matches(|c| c == '\0' || c == ' ') {
+            "1.2.840.10008.1.2" => TransferSyntax::ImplicitVRLittleEndian,
+            "1.2.840.10008.1.2.1" => TransferSyntax::ExplicitVRLittleEndian,
+            "1.2.840.10008.1.2.1.99" => TransferSyntax::DeflatedExplicitVRLittleEndian,
+            "1.2.840.10008.1.2.2" => TransferSyntax::ExplicitVRBigEndian,
+            uid => TransferSyntax::Encapsulated(uid.to_string()),
+        }
+    }
+
+    pub fn uid(&self) -> &str {
+        match *self {
+            TransferSyntax::ImplicitVRLittleEndian => "1.2.840.10008.1.2",
+            TransferSyntax::ExplicitVRLittleEndian => "1.2.840.10008.1.2.1",
+            TransferSyntax::DeflatedExplicitVRLittleEndian => "1.2.840.10008.1.2.1.99",
+            TransferSyntax::ExplicitVRBigEndian => "1.2.840.10008.1.2.2",
+            TransferSyntax::Encapsulated(ref uid) => uid,
+        }
+    }
+
+    /// The registered name, e.g. "JPEG 2000 Image Compression", if the UID is known.
+    pub fn name(&self) -> Option<&'static str> {
+        lookup_uid(self.uid()).map(|uid| uid.name)
+    }
+
+    pub fn is_explicit_vr(&self) -> bool { *self != TransferSyntax::ImplicitVRLittleEndian }
+
+    pub fn is_big_endian(&self) -> bool { *self == TransferSyntax::ExplicitVRBigEndian }
+
+    pub fn is_deflated(&self) -> bool { *self == TransferSyntax::DeflatedExplicitVRLittleEndian }
+
+    pub fn is_encapsulated(&self) -> bool {
+        match *self {
+            TransferSyntax::Encapsulated(_) => true,
+            _ => false,
+        }
     }
 }
 
 impl DicomSlice {
-    pub fn pos(&self) -> f64 {
-        match self["ImagePositionPatient".to_owned()] {
-            DicomElt::Float64s(ref v) => v[2],
-            _ =>  panic!("no patient position"),
+    pub fn transfer_syntax(&self) -> &TransferSyntax {
+        &self.transfer_syntax
+    }
+
+    fn get(&self, tag: DicomTag) -> Result<&DicomElt> {
+        self.dataset.get(tag).map(|elt| &elt.value).ok_or_else(|| DicomError::MissingElement(tag.keyword.to_string()))
+    }
+
+    pub fn get_by_keyword(&self, keyword: &str) -> Result<&DicomElt> {
+        self.dataset.get_by_keyword(keyword).map(|elt| &elt.value)
+            .ok_or_else(|| DicomError::MissingElement(keyword.to_string()))
+    }
+
+    pub fn pos(&self) -> Result<f64> {
+        match *self.get(tags::IMAGE_POSITION_PATIENT)? {
+            DicomElt::Float64s(ref v) if v.len() > 2 => Ok(v[2]),
+            _ => Err(DicomError::UnexpectedType(tags::IMAGE_POSITION_PATIENT.keyword.to_string())),
+        }
+    }
+
+    /// 16 bit PixelData as signed samples, see `DicomElt::to_image16`.
+    pub fn pixel_data(&self) -> Result<DcmImg16> {
+        self.get(tags::PIXEL_DATA)?.to_image16()
+    }
+
+    /// All of PixelData decoded, see `DicomElt::decode`. Use `frame` to decode one frame
+    /// of a multi-frame image.
+    pub fn image(&self) -> Result<DicomElt> {
+        self.get(tags::PIXEL_DATA)?.decode()
+    }
+
+    pub fn number_of_frames(&self) -> Result<usize> {
+        self.get(tags::PIXEL_DATA)?.number_of_frames()
+            .ok_or_else(|| DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string()))
+    }
+
+    /// Frame `n` of PixelData: an image with one frame for native data, or the frame's
+    /// compressed bytes as `DicomElt::Bytes` for encapsulated data.
+    pub fn frame(&self, n: usize) -> Result<DicomElt> {
+        match *self.get(tags::PIXEL_DATA)? {
+            DicomElt::Fragments(ref f) => {
+                let extended = match self.dataset.get(tags::EXTENDED_OFFSET_TABLE).map(|elt| &elt.value) {
+                    Some(&DicomElt::UInt64s(ref offsets)) => Some(&offsets[..]),
+                    _ => None,
+                };
+                f.frame(n, extended).map(DicomElt::Bytes).ok_or(DicomError::NoFrame(n))
+            },
+            ref pixels => pixels.frame(n),
+        }
+    }
+
+    pub fn frames<'a>(&'a self) -> Result<Frames<'a>> {
+        Ok(Frames { slice: self, next: 0, count: self.number_of_frames()? })
+    }
+
+    /// PixelData as a color image, for RGB and YBR photometric interpretations.
+    pub fn color_image(&self) -> Result<DcmColorImg> {
+        let color = match *self.get(tags::PHOTOMETRIC_INTERPRETATION)? {
+            DicomElt::String(ref photometric) => ColorSpace::from_photometric(photometric),
+            _ => None,
+        }.ok_or_else(|| DicomError::UnexpectedType(tags::PHOTOMETRIC_INTERPRETATION.keyword.to_string()))?;
+        let planar = match self.dataset.get(tags::PLANAR_CONFIGURATION).map(|elt| &elt.value) {
+            Some(&DicomElt::UInt16s(ref v)) => v.first() == Some(&1),
+            _ => false,
+        };
+        match self.image()? {
+            DicomElt::Image8(img) if img.samples == 3 =>
+                Ok(DcmColorImg { xr: img.xr, yr: img.yr, zr: img.zr, color: color, planar: planar, data: img.data }),
+            _ => Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
         }
     }
 
-    pub fn pixel_data(&self) -> &DcmImg16 {
-        match self["PixelData".to_owned()] {
-            DicomElt::Image16(ref v) => v,
-            _ =>  panic!("unexpected image type"),
+    pub fn palette(&self) -> Result<Palette> {
+        Palette::new(&self.dataset)
+    }
+
+    /// PixelData as interleaved 8 bit RGB, for color and PALETTE COLOR images.
+    pub fn rgb(&self) -> Result<DcmImg8> {
+        let palette = match *self.get(tags::PHOTOMETRIC_INTERPRETATION)? {
+            DicomElt::String(ref photometric) => photometric.trim_matches(|c| c == ' ' || c == '\0') == "PALETTE COLOR",
+            _ => false,
+        };
+        if !palette {
+            return self.color_image()?.to_rgb().ok_or_else(|| DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string()));
+        }
+        let palette = self.palette()?;
+        match self.image()? {
+            DicomElt::Image8(ref img) => Ok(palette.apply(img)),
+            DicomElt::ImageU16(ref img) => Ok(palette.apply(img)),
+            DicomElt::Image16(ref img) => Ok(palette.apply(img)),
+            _ => Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
         }
     }
-    pub fn slope(&self) -> f64 {
-        match self["RescaleSlope".to_owned()] {
-            DicomElt::Float64s(ref v) => v[0],
-            _ => panic!("unknown slope type"),
+
+    pub fn slope(&self) -> Result<f64> {
+        match *self.get(tags::RESCALE_SLOPE)? {
+            DicomElt::Float64s(ref v) if !v.is_empty() => Ok(v[0]),
+            _ => Err(DicomError::UnexpectedType(tags::RESCALE_SLOPE.keyword.to_string())),
         }
     }
-    pub fn intercept(&self) -> i16 {
-        match self["RescaleIntercept".to_owned()] {
-            DicomElt::UInt32s(ref v)  => v[0] as i16,
-            DicomElt::Float64s(ref v) => v[0] as i16,
-            _ => panic!("unknown intercept type"),
+    pub fn intercept(&self) -> Result<i16> {
+        match *self.get(tags::RESCALE_INTERCEPT)? {
+            DicomElt::UInt32s(ref v) if !v.is_empty() => Ok(v[0] as i16),
+            DicomElt::Float64s(ref v) if !v.is_empty() => Ok(v[0] as i16),
+            _ => Err(DicomError::UnexpectedType(tags::RESCALE_INTERCEPT.keyword.to_string())),
         }
     }
-    pub fn thickness(&self) -> f64 {
-        match self["SliceThickness".to_owned()] {
-            DicomElt::Float64s(ref v) => v[0],
-            _ => 0.0,
+    pub fn thickness(&self) -> Result<f64> {
+        match self.dataset.get(tags::SLICE_THICKNESS).map(|elt| &elt.value) {
+            Some(&DicomElt::Float64s(ref v)) if !v.is_empty() => Ok(v[0]),
+            Some(&DicomElt::Float64s(_)) | Some(&DicomElt::Empty) | None => Ok(0.0),
+            Some(_) => Err(DicomError::UnexpectedType(tags::SLICE_THICKNESS.keyword.to_string())),
         }
     }
 }
 
+impl<'a> Iterator for Frames<'a> {
+    type Item = Result<DicomElt>;
+
+    fn next(&mut self) -> Option<Result<DicomElt>> {
+        if self.next >= self.count { return None; }
+        self.next += 1;
+        Some(self.slice.frame(self.next - 1))
+    }
+}
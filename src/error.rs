@@ -0,0 +1,82 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+#[derive(Debug)]
+pub enum DicomError {
+    Io(io::Error),
+    /// No "DICM" prefix after the preamble
+    BadMagic { offset: usize },
+    /// VR not recognized, or not in the dictionary for an implicit VR element
+    BadVR { tag: u32, offset: usize, vr: String },
+    /// An item or delimitation tag was expected but something else was found
+    ExpectedItem { tag: u32, offset: usize, found: u32 },
+    /// The value bytes of an element could not be decoded
+    BadValue { tag: u32, offset: usize, reason: String },
+    /// The data ends before the `needed` bytes starting at `offset` could be read
+    Truncated { tag: u32, offset: usize, needed: usize },
+    /// The same tag appeared twice in one dataset
+    DuplicateTag { tag: u32, offset: usize },
+    /// A keyword looked up on a slice is not present
+    MissingElement(String),
+    /// A keyword is present but doesn't hold the kind of value asked for
+    UnexpectedType(String),
+    /// A scan could not be encoded or decoded with bincode
+    Serialize(String),
+    /// A frame past the end of PixelData, or one the offset tables can't locate
+    NoFrame(usize),
+}
+
+pub type Result<T> = result::Result<T, DicomError>;
+
+fn fmt_tag(tag: u32) -> String {
+    format!("({:04X},{:04X})", tag >> 16, tag & 0xffff)
+}
+
+impl fmt::Display for DicomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DicomError::Io(ref e) => write!(f, "dicom: {}", e),
+            DicomError::BadMagic { offset } => write!(f, "dicom: bad magic in header at offset {}", offset),
+            DicomError::BadVR { tag, offset, ref vr } =>
+                write!(f, "dicom: bad vr {:?} for {} at offset {}", vr, fmt_tag(tag), offset),
+            DicomError::ExpectedItem { tag, offset, found } =>
+                write!(f, "dicom: expected item tag in {} at offset {}, found {}", fmt_tag(tag), offset, fmt_tag(found)),
+            DicomError::BadValue { tag, offset, ref reason } =>
+                write!(f, "dicom: bad value for {} at offset {}: {}", fmt_tag(tag), offset, reason),
+            DicomError::Truncated { tag, offset, needed } =>
+                write!(f, "dicom: {} truncated at offset {}, needed {} more bytes", fmt_tag(tag), offset, needed),
+            DicomError::DuplicateTag { tag, offset } =>
+                write!(f, "dicom: duplicate {} at offset {}", fmt_tag(tag), offset),
+            DicomError::MissingElement(ref keyword) => write!(f, "dicom: no {}", keyword),
+            DicomError::UnexpectedType(ref keyword) => write!(f, "dicom: unexpected value type for {}", keyword),
+            DicomError::Serialize(ref reason) => write!(f, "dicom: serialization failed: {}", reason),
+            DicomError::NoFrame(n) => write!(f, "dicom: no frame {} in pixel data", n),
+        }
+    }
+}
+
+impl error::Error for DicomError {
+    fn description(&self) -> &str {
+        match *self {
+            DicomError::Io(_) => "i/o error",
+            DicomError::BadMagic { .. } => "bad magic in header",
+            DicomError::BadVR { .. } => "bad vr",
+            DicomError::ExpectedItem { .. } => "expected item tag",
+            DicomError::BadValue { .. } => "bad value",
+            DicomError::Truncated { .. } => "truncated data",
+            DicomError::DuplicateTag { .. } => "duplicate tag",
+            DicomError::MissingElement(_) => "missing element",
+            DicomError::UnexpectedType(_) => "unexpected value type",
+            DicomError::Serialize(_) => "serialization failed",
+            DicomError::NoFrame(_) => "no such frame",
+        }
+    }
+}
+
+impl From<io::Error> for DicomError {
+    fn from(err: io::Error) -> DicomError {
+        DicomError::Io(err)
+    }
+}
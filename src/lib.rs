@@ -1,6 +1,10 @@
 extern crate memmap;
+extern crate phf;
 extern crate flate2;
+extern crate encoding;
 extern crate byteorder;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 
 #[macro_use]
 extern crate serde_derive;
@@ -9,42 +13,80 @@ extern crate bincode;
 extern crate serde;
 
 mod dicom_types;
-use dicom_types::{DicomSlice, DicomScan, DicomDict, DcmImg16, DicomElt};
+pub use dicom_types::{DicomSlice, DicomScan, DicomElt, DicomDataElt, Dataset, TransferSyntax};
+pub use dicom_types::{DcmImg, DcmImg16, DcmImgU16, DcmImg8, DcmImgI8, DcmImg32, DcmImgU32, DcmFragments, Frames};
+pub use dicom_types::{DicomDictElt, PrivateDict, DicomTag, ToTag, DicomUid, UidType};
 mod dicom_dict;
-use dicom_dict::dicom_dictionary_init;
+pub use dicom_dict::{lookup_tag, lookup_keyword};
+pub mod tags;
+mod uids;
+pub use uids::lookup_uid;
+mod private_dict;
+pub use private_dict::private_dictionary_init;
+mod charset;
+mod person_name;
+pub use person_name::{PersonName, PersonNameGroup};
+mod datetime;
+pub use datetime::{DicomDate, DicomTime, DicomDateTime, DicomRange};
+mod color;
+pub use color::{ColorSpace, DcmColorImg};
+mod palette;
+pub use palette::{Palette, PaletteLut};
+mod pixels;
+pub use pixels::{PixelFormat, DcmPixels};
 mod dataset;
-use dataset::read_dataset;
+use dataset::{read_dataset, read_dataset_tolerant};
+mod error;
+pub use error::{DicomError, Result};
 
 use std::path::Path;
 use std::fs;
 use memmap::{Mmap, Protection};
-use std::io::{Error, ErrorKind, Result};
-use std::str;
+use std::io::{Error, ErrorKind};
+use std::cmp::Ordering;
 
 use std::fs::File;
 use std::io::prelude::*;
-use bincode::SizeLimit;
 use bincode::{serialize, deserialize};
 
 
 pub struct DicomLib<'a> {
-    dict: DicomDict<'a>,
+    private_dict: PrivateDict<'a>,
 }
 
 impl<'a> DicomLib<'a> {
     pub fn new() -> Self {
-        DicomLib { dict : dicom_dictionary_init() }
+        DicomLib { private_dict : private_dictionary_init() }
+    }
+
+    /// Register a private element so it decodes with the right VR; `elt` is the low byte
+    /// of the element number, e.g. 0x10 for (0029,xx10) under "SIEMENS CSA HEADER".
+    pub fn add_private_entry(&mut self, creator: &str, group: u16, elt: u8, entry: DicomDictElt<'a>) {
+        private_dict::private_dictionary_add(&mut self.private_dict, creator, ((group as u32) << 16) | elt as u32, entry);
+    }
+
+    fn check_magic(data: &[u8]) -> Result<usize> {
+        let off = 0x80;
+        if data.len() < off+4 || &data[off..off+4] != b"DICM" {
+            return Err(DicomError::BadMagic { offset: off });
+        };
+        Ok(off + 4)
     }
 
     pub fn parse<P>(&self, path: P) -> Result<DicomSlice> where P : AsRef<Path> {
-        let mut off = 0x80;
         let file_mmap = Mmap::open_path(path, Protection::Read)?;
         let data: &[u8] = unsafe { file_mmap.as_slice() };
-        let magic = str::from_utf8(&data[off..off+4]).unwrap();
+        let off = Self::check_magic(data)?;
+        read_dataset(&self.private_dict, data, off)
+    }
 
-        if magic != "DICM" { panic!("bad magic in header"); };
-        off += 4;
-        read_dataset(&self.dict, data, off)
+    /// Parse a possibly truncated file (e.g. a partial transfer), keeping every element
+    /// read before the data ran out. The second value is the truncation diagnostic, if any.
+    pub fn parse_tolerant<P>(&self, path: P) -> Result<(DicomSlice, Option<DicomError>)> where P : AsRef<Path> {
+        let file_mmap = Mmap::open_path(path, Protection::Read)?;
+        let data: &[u8] = unsafe { file_mmap.as_slice() };
+        let off = Self::check_magic(data)?;
+        read_dataset_tolerant(&self.private_dict, data, off)
     }
 
     pub fn parse_scan<P>(&self, set: P) -> Result<DicomScan> where P : AsRef<Path> {
@@ -53,57 +95,63 @@ impl<'a> DicomLib<'a> {
         for entry in fs::read_dir(set)? {
             let entry = entry?;
             let path = entry.path();
-            if !path.to_str().unwrap().contains(".dcm") { continue;}
-            v.push(self.parse(path)?);
+            if !path.to_string_lossy().contains(".dcm") { continue;}
+            let slice = self.parse(path)?;
+            v.push((slice.pos()?, slice));
         }
-        v.sort_by(|a, b| a.pos().partial_cmp(&b.pos()).expect("Nan"));
-        let pix_data = v[0].pixel_data().clone();
-        let pix_len = pix_data.data.len();
+        if v.is_empty() { return Err(DicomError::Io(Error::new(ErrorKind::NotFound, "no .dcm files in scan"))); };
+        v.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        let mut v : Vec<DicomSlice> = v.into_iter().map(|(_, slice)| slice).collect();
         let scan_len = v.len();
-        assert_eq!(pix_len, pix_data.xr*pix_data.yr);
-        let mut ivec : Vec<i16> = Vec::with_capacity(scan_len*pix_len);
-        for slice in v.iter_mut() {
-            let pix_data = match slice.keydict.remove("PixelData") {
-                Some(DicomElt::Image16(v)) => v,
-                Some(_) | None => panic!("no PixelData")
+        let (mut xr, mut yr) = (0, 0);
+        let mut ivec : Vec<i16> = Vec::new();
+        for (i, slice) in v.iter_mut().enumerate() {
+            // unsigned CT/MR usually stores at most 15 bits; anything wider is an error, not wrapped
+            let pix_data = match slice.dataset.remove(tags::PIXEL_DATA) {
+                Some(elt) => elt.value.to_image16()?,
+                None => return Err(DicomError::MissingElement(tags::PIXEL_DATA.keyword.to_string())),
             };
+            if i == 0 {
+                xr = pix_data.xr;
+                yr = pix_data.yr;
+                ivec.reserve(scan_len*xr*yr);
+            }
+            if pix_data.data.len() != xr*yr { return Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())); };
             ivec.extend_from_slice(&pix_data.data[0..]);
         };
-        let image = DcmImg16 {xr: pix_data.xr, yr: pix_data.yr, zr: scan_len, data: ivec};
+        let image = DcmImg16 {xr: xr, yr: yr, zr: scan_len, samples: 1, data: ivec};
         Ok(DicomScan {slice_data: v, image: image})
     }
 
-    pub fn get_pixels_hu(ref scan: DicomScan) -> Vec<i16> {
+    pub fn get_pixels_hu(ref scan: DicomScan) -> Result<Vec<i16>> {
         let mut image : Vec<i16> = scan.image.data.clone();
         for v in image.iter_mut().filter(|x| **x == -2000) { *v = 0; };
         let increment = scan.image.xr*scan.image.yr;
         for i in 0..scan.slice_data.len() {
-            let intercept = scan.slice_data[i].intercept();
-            let slope = scan.slice_data[i].slope();
+            let intercept = scan.slice_data[i].intercept()?;
+            let slope = scan.slice_data[i].slope()?;
             let offset = i*increment;
             if slope != 1.0 {
                 for v in image[offset..offset+increment].iter_mut() { *v = ((*v as f64) * slope) as i16;};
             }
             for v in image[offset..offset+increment].iter_mut() { *v += intercept;};
         }
-        image
+        Ok(image)
     }
 
     pub fn serialize_scan<P>(&self, path: P, set: DicomScan) -> Result<usize> where P : AsRef<Path> {
         let mut buffer = File::create(path)?;
-        let limit = SizeLimit::Infinite;
-        let encoded : Vec<u8> = serialize(&set, limit).expect("serialize fail");
-        let size = buffer.write(&encoded)?;
-        Ok(size)
+        let encoded : Vec<u8> = serialize(&set).map_err(|e| DicomError::Serialize(e.to_string()))?;
+        buffer.write_all(&encoded)?;
+        Ok(encoded.len())
     }
 
     pub fn deserialize_scan<P>(&self, path: P) -> Result<DicomScan> where P : AsRef<Path> {
         let mut buffer = File::open(path)?;
         let mut encoded = Vec::new();
         let size = buffer.read_to_end(&mut encoded)?;
-        if size == 0 { return Err(Error::new(ErrorKind::UnexpectedEof, "Empty File")); };
-        let decoded = deserialize(&encoded[..]).expect("deserialize fail");
-        Ok(decoded)
+        if size == 0 { return Err(DicomError::Io(Error::new(ErrorKind::UnexpectedEof, "Empty File"))); };
+        deserialize(&encoded[..]).map_err(|e| DicomError::Serialize(e.to_string()))
     }
 }
 
@@ -115,37 +163,578 @@ mod tests {
     fn parse_works() {
         let dlib = DicomLib::new();
         let result = (dlib.parse("resources/000001.dcm")).unwrap();
-        for (k, v) in result.keydict.iter() {
-            if *k != "PixelData" {
-                println!("{}: {:?}", k, v);
+        for elt in result.dataset.iter() {
+            if elt.tag != 0x7FE00010 {
+                println!("({:04X},{:04X}) {}: {:?}", elt.tag >> 16, elt.tag & 0xffff, elt.vr, elt.value);
             };
         }
     }
 
+    #[test]
+    fn transfer_syntax_works() {
+        let dlib = DicomLib::new();
+        let result = dlib.parse("resources/000001.dcm").unwrap();
+        assert_eq!(*result.transfer_syntax(), TransferSyntax::ExplicitVRLittleEndian);
+        assert!(result.dataset.get_by_keyword("TransferSyntaxUID").is_some());
+    }
+
+    #[test]
+    fn uid_registry_works() {
+        let ct = lookup_uid("1.2.840.10008.5.1.4.1.1.2\0").unwrap();
+        assert_eq!((ct.name, ct.keyword, ct.uid_type), ("CT Image Storage", "CTImageStorage", UidType::SopClass));
+        let be = lookup_uid(TransferSyntax::ExplicitVRBigEndian.uid()).unwrap();
+        assert_eq!((be.name, be.uid_type, be.retired), ("Explicit VR Big Endian", UidType::TransferSyntax, true));
+        assert_eq!(TransferSyntax::from_uid("1.2.840.10008.1.2.4.91").name(), Some("JPEG 2000 Image Compression"));
+        assert!(lookup_uid("1.2.3.4").is_none());
+    }
+
+    #[test]
+    fn bad_magic_is_error() {
+        let dlib = DicomLib::new();
+        match dlib.parse("Cargo.toml") {
+            Err(DicomError::BadMagic { offset }) => assert_eq!(offset, 0x80),
+            other => panic!("expected BadMagic, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn parse_tolerant_salvages_truncated() {
+        let dlib = DicomLib::new();
+        let mut full = Vec::new();
+        File::open("resources/000001.dcm").unwrap().read_to_end(&mut full).unwrap();
+        let path = std::env::temp_dir().join("rudicom_truncated.dcm");
+        File::create(&path).unwrap().write_all(&full[..full.len() / 2]).unwrap();
+        assert!(dlib.parse(&path).is_err());
+        let (slice, diag) = dlib.parse_tolerant(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(slice.dataset.get_by_keyword("PatientID").is_some());
+        assert!(!slice.dataset.contains(tags::PIXEL_DATA));
+        match diag {
+            Some(DicomError::Truncated { tag, .. }) => assert_eq!(tag, 0x7FE00010),
+            other => panic!("expected Truncated, got {:?}", other),
+        }
+    }
+
+    // a Deflated Explicit VR Little Endian file: preamble, meta group, then `body` raw deflated
+    fn deflated_file(body: &[u8]) -> Vec<u8> {
+        use flate2::Compression;
+        use flate2::write::DeflateEncoder;
+        let ts = evr_le(0x00020010, "UI", b"1.2.840.10008.1.2.1.99");
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::Default);
+        encoder.write_all(body).unwrap();
+        let mut file = vec![0; 0x80];
+        file.extend_from_slice(b"DICM");
+        file.extend(evr_le(0x00020000, "UL", &[ts.len() as u8, 0, 0, 0]));
+        file.extend(ts);
+        file.extend(encoder.finish().unwrap());
+        file
+    }
+
+    #[test]
+    fn deflated_works() {
+        let mut body = evr_le(0x00100010, "PN", b"Doe^John");
+        body.extend(evr_le(0x00100020, "LO", b"ID"));
+        body.extend(evr_le(0x00280010, "US", &[1, 0]));
+        body.extend(evr_le(0x00280011, "US", &[2, 0]));
+        body.extend(evr_le(0x00280100, "US", &[8, 0]));
+        body.extend(evr_le(0x7FE00010, "OB", &[7, 9]));
+        let path = std::env::temp_dir().join("rudicom_deflated.dcm");
+        File::create(&path).unwrap().write_all(&deflated_file(&body)).unwrap();
+        let slice = DicomLib::new().parse(&path);
+        fs::remove_file(&path).unwrap();
+        let slice = slice.unwrap();
+        assert_eq!(*slice.transfer_syntax(), TransferSyntax::DeflatedExplicitVRLittleEndian);
+        assert_eq!(*slice.get_by_keyword("PatientName").unwrap(), DicomElt::String("Doe^John".to_string()));
+        assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+        assert_eq!(slice.image().unwrap(), DicomElt::Image8(DcmImg { xr: 2, yr: 1, zr: 1, samples: 1, data: vec![7, 9] }));
+
+        // cut off partway through the compressed pixel data
+        let pixels: Vec<u8> = (0..65536u32).map(|i| (i.wrapping_mul(2654435761) >> 24) as u8).collect();
+        let mut body = evr_le(0x00100020, "LO", b"ID");
+        body.extend(evr_le(0x7FE00010, "OB", &pixels));
+        let file = deflated_file(&body);
+        let path = std::env::temp_dir().join("rudicom_deflated_truncated.dcm");
+        File::create(&path).unwrap().write_all(&file[..file.len() / 2]).unwrap();
+        let dlib = DicomLib::new();
+        assert!(dlib.parse(&path).is_err());
+        let result = dlib.parse_tolerant(&path);
+        fs::remove_file(&path).unwrap();
+        let (slice, diag) = result.unwrap();
+        assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+        match diag {
+            Some(DicomError::Truncated { .. }) => {},
+            other => panic!("expected Truncated, got {:?}", other),
+        }
+    }
+
+    // explicit VR encoding of a single element
+    fn evr(tag: u32, vr: &str, value: &[u8], big: bool) -> Vec<u8> {
+        let u16s = |x: u32| if big { [(x >> 8) as u8, x as u8] } else { [x as u8, (x >> 8) as u8] };
+        let mut v = Vec::new();
+        v.extend_from_slice(&u16s(tag >> 16));
+        v.extend_from_slice(&u16s(tag & 0xffff));
+        v.extend_from_slice(vr.as_bytes());
+        if ["OB", "OD", "OF", "OL", "OV", "OW", "SQ", "SV", "UC", "UN", "UR", "UT", "UV"].contains(&vr) {
+            let len = value.len() as u32;
+            v.extend_from_slice(&[0, 0]);
+            if big { v.extend_from_slice(&u16s(len >> 16)); v.extend_from_slice(&u16s(len)); }
+            else { v.extend_from_slice(&u16s(len)); v.extend_from_slice(&u16s(len >> 16)); }
+        } else {
+            v.extend_from_slice(&u16s(value.len() as u32));
+        }
+        v.extend_from_slice(value);
+        v
+    }
+
+    fn evr_le(tag: u32, vr: &str, value: &[u8]) -> Vec<u8> {
+        evr(tag, vr, value, false)
+    }
+
+    fn item(body: &[u8]) -> Vec<u8> {
+        let len = body.len() as u32;
+        let mut v = vec![0xFE, 0xFF, 0x00, 0xE0, len as u8, (len >> 8) as u8, (len >> 16) as u8, (len >> 24) as u8];
+        v.extend_from_slice(body);
+        v
+    }
+
+    // implicit VR little endian encoding of a single element
+    fn ivr_le(tag: u32, value: &[u8]) -> Vec<u8> {
+        let len = value.len() as u32;
+        let mut v = vec![(tag >> 16) as u8, (tag >> 24) as u8, tag as u8, (tag >> 8) as u8,
+                         len as u8, (len >> 8) as u8, (len >> 16) as u8, (len >> 24) as u8];
+        v.extend_from_slice(value);
+        v
+    }
+
+    #[test]
+    fn unknown_implicit_tags_are_kept() {
+        let mut data = ivr_le(0x00080000, &[24, 0, 0, 0]);
+        data.extend(ivr_le(0x00080060, b"CT"));
+        data.extend(ivr_le(0x00189999, &[1, 2, 3, 4]));
+        data.extend(ivr_le(0x00100020, b"ID"));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        let group_length = slice.dataset.get(0x00080000).unwrap();
+        assert_eq!((&group_length.vr[..], &group_length.value), ("UL", &DicomElt::UInt32s(vec![24])));
+        let unknown = slice.dataset.get(0x00189999).unwrap();
+        assert_eq!((&unknown.vr[..], &unknown.value), ("UN", &DicomElt::Bytes(vec![1, 2, 3, 4])));
+        assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+    }
+
+    #[test]
+    fn repeating_groups_work() {
+        let mut data = ivr_le(0x00280103, &[1, 0]);
+        data.extend(ivr_le(0x00280106, &[0x30, 0xf8]));
+        data.extend(ivr_le(0x60020010, &[0, 2]));
+        data.extend(ivr_le(0x60023000, &[0xff, 0, 0, 0xff]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(*slice.get_by_keyword("SmallestImagePixelValue").unwrap(), DicomElt::Int16s(vec![-2000]));
+        assert_eq!(lookup_tag(0x60020010).unwrap().keyword, "OverlayRows");
+        assert_eq!(lookup_keyword("OverlayRows"), Some(0x60000010));
+        assert_eq!(*slice.get_by_keyword("OverlayRows").unwrap(), DicomElt::UInt16s(vec![512]));
+        assert_eq!(slice.dataset.get(0x60023000).unwrap().value, DicomElt::UInt16s(vec![0xff, 0xff00]));
+    }
+
+    #[test]
+    fn long_vrs_work() {
+        let mut data = evr_le(0x00100020, "LO", b"ID");
+        data.extend(evr_le(0x00081190, "UR", b"http://example.com/wado "));
+        data.extend(evr_le(0x0040A162, "SV", &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]));
+        data.extend(evr_le(0x0040A163, "UV", &[1, 0, 0, 0, 1, 0, 0, 0]));
+        data.extend(evr_le(0x00660129, "OL", &[1, 0, 0, 0, 2, 0, 0, 0]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.dataset.get(tags::RETRIEVE_URL).unwrap().value, DicomElt::String("http://example.com/wado ".to_string()));
+        assert_eq!(slice.dataset.get(0x0040A162).unwrap().value, DicomElt::Int64s(vec![-2]));
+        assert_eq!(slice.dataset.get(0x0040A163).unwrap().value, DicomElt::UInt64s(vec![0x100000001]));
+        assert_eq!(slice.dataset.get(0x00660129).unwrap().value, DicomElt::UInt32s(vec![1, 2]));
+    }
+
+    #[test]
+    fn binary_values_round_trip() {
+        for &big in [false, true].iter() {
+            let ts: &[u8] = if big { b"1.2.840.10008.1.2.2\0" } else { b"1.2.840.10008.1.2.1\0" };
+            let mut data = evr_le(0x00020010, "UI", ts);
+            let mut put = |tag, vr, le: Vec<u8>| {
+                let value: Vec<u8> = if !big { le } else {
+                    let width = match vr { "OW" | "AT" => 2, "FL" | "OF" => 4, _ => 8 };
+                    le.chunks(width).flat_map(|c| c.iter().rev().cloned().collect::<Vec<u8>>()).collect()
+                };
+                data.extend(evr(tag, vr, &value, big));
+            };
+            put(0x00081163, "FD", vec![0, 0, 0, 0, 0, 0, 0xf8, 0x3f, 0, 0, 0, 0, 0, 0, 0x09, 0xc0]);
+            put(0x00109431, "FL", vec![0, 0, 0xc0, 0x3f, 0, 0, 0x10, 0xc0]);
+            put(0x00281201, "OW", vec![0x34, 0x12, 0xdc, 0xfe]);
+            put(0x00640009, "OF", vec![0, 0, 0, 0x3f, 0, 0, 0x80, 0x40]);
+            put(0x00720073, "OD", vec![0, 0, 0, 0, 0, 0, 0xe0, 0x3f]);
+            let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+            assert_eq!(slice.dataset.get(tags::TIME_RANGE).unwrap().value, DicomElt::Float64s(vec![1.5, -3.125]));
+            assert_eq!(slice.dataset.get(tags::EXAMINED_BODY_THICKNESS).unwrap().value, DicomElt::Float32s(vec![1.5, -2.25]));
+            assert_eq!(slice.dataset.get(tags::RED_PALETTE_COLOR_LOOKUP_TABLE_DATA).unwrap().value, DicomElt::UInt16s(vec![0x1234, 0xfedc]));
+            assert_eq!(slice.dataset.get(tags::VECTOR_GRID_DATA).unwrap().value, DicomElt::Float32s(vec![0.5, 4.0]));
+            assert_eq!(slice.dataset.get(tags::SELECTOR_OD_VALUE).unwrap().value, DicomElt::Float64s(vec![0.5]));
+        }
+    }
+
+    #[test]
+    fn big_endian_works() {
+        let mut data = evr_le(0x00020010, "UI", b"1.2.840.10008.1.2.2\0");
+        data.extend(evr(0x00280010, "US", &[0, 2], true));
+        data.extend(evr(0x00280011, "US", &[0, 1], true));
+        data.extend(evr(0x00280100, "US", &[0, 16], true));
+        data.extend(evr(0x00280103, "US", &[0, 1], true));
+        data.extend(evr(0x00081161, "UL", &[0x01, 0x02, 0x03, 0x04, 0, 0, 0, 2], true));
+        data.extend(evr(0x00081163, "FD", &[0x3f, 0xf8, 0, 0, 0, 0, 0, 0], true));
+        data.extend(evr(0x7FE00010, "OW", &[0x12, 0x34, 0xff, 0xfe], true));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(*slice.transfer_syntax(), TransferSyntax::ExplicitVRBigEndian);
+        assert_eq!(slice.dataset.get(tags::ROWS).unwrap().value, DicomElt::UInt16s(vec![2]));
+        assert_eq!(slice.dataset.get(tags::COLUMNS).unwrap().value, DicomElt::UInt16s(vec![1]));
+        assert_eq!(slice.dataset.get(tags::SIMPLE_FRAME_LIST).unwrap().value, DicomElt::UInt32s(vec![0x01020304, 2]));
+        assert_eq!(slice.dataset.get(tags::TIME_RANGE).unwrap().value, DicomElt::Float64s(vec![1.5]));
+        assert_eq!(slice.image().unwrap(), DicomElt::Image16(DcmImg { xr: 1, yr: 2, zr: 1, samples: 1, data: vec![0x1234, -2] }));
+    }
+
+    #[test]
+    fn sequence_items_work() {
+        let mut items = item(&evr_le(0x00081155, "UI", b"1.2.3.4\0"));
+        items.extend(item(&evr_le(0x00081155, "UI", b"1.2.3.5\0")));
+        let mut data = evr_le(0x00081140, "SQ", &items);
+        data.extend(evr_le(0x00100020, "LO", b"ID"));
+        data.extend(evr_le(0x00400275, "SQ", &[]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        let seq = slice.get_by_keyword("ReferencedImageSequence").unwrap();
+        assert_eq!(seq.items().unwrap().len(), 2);
+        // an empty sequence is still a sequence
+        assert_eq!(slice.get_by_keyword("RequestAttributesSequence").unwrap().items(), Some(&[][..]));
+        assert_eq!(seq.item(1).unwrap().get_by_keyword("ReferencedSOPInstanceUID").unwrap().value, DicomElt::String("1.2.3.5\0".to_string()));
+        assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+    }
+
+    #[test]
+    fn undefined_length_sequence_works() {
+        let undefined = [0xff, 0xff, 0xff, 0xff];
+        let item_delim = [0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0];
+        let seq_delim = [0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0];
+        let mut data = evr_le(0x00081140, "SQ", &[]);
+        data.truncate(8);
+        data.extend_from_slice(&undefined);
+        for uid in [&b"1.2.3.4\0"[..], &b"1.2.3.5\0"[..]].iter() {
+            data.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0]);
+            data.extend_from_slice(&undefined);
+            data.extend(evr_le(0x00081155, "UI", uid));
+            data.extend_from_slice(&item_delim);
+        }
+        data.extend_from_slice(&seq_delim);
+        data.extend(evr_le(0x00100020, "LO", b"ID"));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        let seq = slice.get_by_keyword("ReferencedImageSequence").unwrap();
+        assert_eq!(seq.items().unwrap().len(), 2);
+        assert_eq!(seq.item(0).unwrap().get(tags::REFERENCED_SOP_INSTANCE_UID).unwrap().value, DicomElt::String("1.2.3.4\0".to_string()));
+        assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+    }
+
+    #[test]
+    fn undefined_length_un_works() {
+        for &big in [false, true].iter() {
+            let ts: &[u8] = if big { b"1.2.840.10008.1.2.2\0" } else { b"1.2.840.10008.1.2.1\0" };
+            let mut data = evr_le(0x00020010, "UI", ts);
+            // an SQ the writer didn't know, so its items are implicit VR little endian
+            data.extend(evr(0x00081140, "UN", &[], big));
+            data.truncate(data.len() - 4);
+            data.extend_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+            data.extend(item(&ivr_le(0x00081155, b"1.2.3.4\0")));
+            data.extend(item(&[ivr_le(0x00081150, b"1.2.3\0"), ivr_le(0x00081155, b"1.2.3.5\0")].concat()));
+            data.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+            data.extend(evr(0x00100020, "LO", b"ID", big));
+            let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+            let seq = slice.get_by_keyword("ReferencedImageSequence").unwrap();
+            match *seq {
+                DicomElt::Seq(ref items) => assert_eq!(items.len(), 2),
+                ref other => panic!("expected Seq, got {:?}", other),
+            }
+            assert_eq!(seq.item(0).unwrap().get(tags::REFERENCED_SOP_INSTANCE_UID).unwrap().value, DicomElt::String("1.2.3.4\0".to_string()));
+            assert_eq!(seq.item(1).unwrap().get(tags::REFERENCED_SOP_CLASS_UID).unwrap().value, DicomElt::String("1.2.3\0".to_string()));
+            assert_eq!(seq.item(1).unwrap().get(tags::REFERENCED_SOP_INSTANCE_UID).unwrap().value, DicomElt::String("1.2.3.5\0".to_string()));
+            assert_eq!(*slice.get_by_keyword("PatientID").unwrap(), DicomElt::String("ID".to_string()));
+        }
+    }
+
+    #[test]
+    fn private_elements_work() {
+        let mut data = evr_le(0x00100020, "LO", b"ID");
+        data.extend(evr_le(0x00290011, "LO", b"SIEMENS CSA HEADER"));
+        data.extend(evr_le(0x00291110, "UN", b"SV10"));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.dataset.private_creator(0x00291110), Some("SIEMENS CSA HEADER"));
+        let elt = slice.dataset.get_private("SIEMENS CSA HEADER", 0x0029, 0x10).unwrap();
+        assert_eq!(elt.vr, "OB");
+        assert_eq!(elt.value, DicomElt::Bytes(b"SV10".to_vec()));
+    }
+
+    #[test]
+    fn specific_character_set_works() {
+        // PS3.5 H.3.1
+        let name = b"Yamada^Tarou=\x1b$B;3ED\x1b(B^\x1b$BB@O:\x1b(B=\x1b$B$d$^$@\x1b(B^\x1b$B$?$m$&\x1b(B";
+        let mut data = evr_le(0x00080005, "CS", b"\\ISO 2022 IR 87 ");
+        data.extend(evr_le(0x00100010, "PN", name));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.dataset.get(tags::PATIENT_NAME).unwrap().value, DicomElt::String("Yamada^Tarou=山田^太郎=やまだ^たろう".to_string()));
+
+        let mut data = evr_le(0x00080005, "CS", b"ISO_IR 100");
+        data.extend(evr_le(0x00081030, "LO", b"Cr\xe2ne "));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.dataset.get(tags::STUDY_DESCRIPTION).unwrap().value, DicomElt::String("Crâne ".to_string()));
+    }
+
+    #[test]
+    fn person_name_works() {
+        let names = DicomElt::String("Yamada^Tarou=山田^太郎=やまだ^たろう\\Smith^John^^Dr.^ ".to_string()).person_names().unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!((&names[0].alphabetic.family[..], &names[0].ideographic.given[..]), ("Yamada", "太郎"));
+        assert_eq!(names[0].phonetic.family, "やまだ");
+        assert_eq!(names[0].encode(), "Yamada^Tarou=山田^太郎=やまだ^たろう");
+        assert_eq!((&names[1].alphabetic.prefix[..], names[1].ideographic.is_empty()), ("Dr.", true));
+        assert_eq!(names[1].to_string(), "Smith^John^^Dr.");
+        let mut name = PersonName::default();
+        name.ideographic.family = "洪".to_string();
+        assert_eq!(name.encode(), "=洪");
+    }
+
+    #[test]
+    fn date_time_works() {
+        assert_eq!(DicomDate::parse("202002"), Some(DicomDate { year: 2020, month: Some(2), day: None }));
+        assert_eq!(DicomDate::parse("1999.12.31").map(|d| d.day), Some(Some(31)));
+        assert!(DicomDate::parse("20190229").is_none());
+        let tm = DicomTime::parse("103015.25 ").unwrap();
+        assert_eq!((tm.hour, tm.second, tm.fraction), (10, Some(15), Some((250000, 2))));
+        let dt = DicomDateTime::parse("20200131103015.25-0500").unwrap();
+        assert_eq!((dt.date.day, dt.time, dt.offset), (Some(31), Some(tm), Some(-300)));
+        #[cfg(feature = "chrono")]
+        assert_eq!(dt.to_datetime().unwrap().to_rfc3339(), "2020-01-31T10:30:15.250-05:00");
+
+        let range = DicomDate::parse_range("20200101-20201231").unwrap();
+        assert!(range.contains(&DicomDate::parse("202006").unwrap()));
+        assert!(!range.contains(&DicomDate::parse("2021").unwrap()));
+        let open = DicomTime::parse_range("-1200").unwrap();
+        assert!(open.start.is_none() && open.contains(&DicomTime::parse("1159").unwrap()));
+        let range = DicomDateTime::parse_range("20200131-0500-20200131160000+0000").unwrap();
+        assert_eq!(range.start.unwrap().offset, Some(-300));
+        assert!(range.contains(&dt));
+        assert!(!range.contains(&DicomDateTime::parse("20200131120000-0500").unwrap()));
+    }
+
+    #[test]
+    fn number_strings_work() {
+        let mut data = evr_le(0x00200013, "IS", b" 12 ");
+        data.extend(evr_le(0x00201041, "DS", b" 1.0E+2 "));
+        data.extend(evr_le(0x00280034, "IS", b"1\\2.0 "));
+        data.extend(evr_le(0x00281050, "DS", b"  "));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.dataset.get(tags::INSTANCE_NUMBER).unwrap().value, DicomElt::Int32s(vec![12]));
+        assert_eq!(slice.dataset.get(tags::SLICE_LOCATION).unwrap().value, DicomElt::Float64s(vec![100.0]));
+        assert_eq!(slice.dataset.get(tags::PIXEL_ASPECT_RATIO).unwrap().value, DicomElt::Int32s(vec![1, 2]));
+        assert_eq!(slice.dataset.get(tags::WINDOW_CENTER).unwrap().value, DicomElt::Empty);
+
+        match read_dataset(&private_dictionary_init(), &evr_le(0x00200013, "IS", b"1.5 "), 0) {
+            Err(DicomError::BadValue { tag, .. }) => assert_eq!(tag, 0x00200013),
+            other => panic!("expected BadValue, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn pixel_format_works() {
+        let image_pixel = |allocated: u16, stored: u16, high_bit: u16, signed: u16| {
+            let mut data = evr_le(0x00280100, "US", &[allocated as u8, 0]);
+            data.extend(evr_le(0x00280101, "US", &[stored as u8, 0]));
+            data.extend(evr_le(0x00280102, "US", &[high_bit as u8, 0]));
+            data.extend(evr_le(0x00280103, "US", &[signed as u8, 0]));
+            data
+        };
+        // 12 bit signed with garbage above the high bit
+        let mut data = image_pixel(16, 12, 11, 1);
+        data.extend(evr_le(0x7FE00010, "OW", &[0xFF, 0xFF, 0x05, 0xF0, 0x00, 0x08, 0xFF, 0x07]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        match slice.dataset.get(tags::PIXEL_DATA).unwrap().value {
+            DicomElt::Pixels(ref p) => assert_eq!(p.data.len(), 8),
+            ref other => panic!("expected undecoded Pixels, got {:?}", other),
+        }
+        match slice.image().unwrap() {
+            DicomElt::Image16(ref img) => assert_eq!(img.data, vec![-1, 5, -2048, 2047]),
+            ref other => panic!("expected Image16, got {:?}", other),
+        }
+        let mut data = image_pixel(16, 16, 15, 0);
+        data.extend(evr_le(0x7FE00010, "OW", &[0xFF, 0xFF, 0x00, 0x80]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.image().unwrap(), DicomElt::ImageU16(DcmImg { xr: 2, yr: 1, zr: 1, samples: 1, data: vec![65535, 32768] }));
+        // unsigned values past i16::MAX are refused rather than wrapped
+        assert!(slice.pixel_data().is_err());
+        let mut data = image_pixel(16, 12, 11, 0);
+        data.extend(evr_le(0x7FE00010, "OW", &[0xFF, 0x0F, 0x00, 0x00]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.pixel_data().unwrap().data, vec![4095, 0]);
+        // bad bit counts only fail decoding; the rest of the file is still read
+        for &(allocated, stored, high_bit) in &[(0, 0, 0), (16, 0, 15), (8, 12, 11), (16, 12, 16), (16, 12, 10)] {
+            let mut data = image_pixel(allocated, stored, high_bit, 0);
+            data.extend(evr_le(0x7FE00010, "OW", &[0, 0]));
+            let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+            match slice.image() {
+                Err(DicomError::BadValue { .. }) => {},
+                other => panic!("expected BadValue for {:?}, got {:?}", (allocated, stored, high_bit), other),
+            }
+            assert!(slice.frame(0).is_err());
+        }
+        let mut data = image_pixel(8, 6, 6, 1);
+        data.extend(evr_le(0x7FE00010, "OB", &[0x7E, 0x02]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.image().unwrap(), DicomElt::ImageI8(DcmImg { xr: 2, yr: 1, zr: 1, samples: 1, data: vec![-1, 1] }));
+    }
+
+    #[test]
+    fn image_dimensions_work() {
+        let mut data = evr_le(0x00280002, "US", &[1, 0]);
+        data.extend(evr_le(0x00280008, "IS", b"2 "));
+        data.extend(evr_le(0x00280010, "US", &[3, 0]));
+        data.extend(evr_le(0x00280011, "US", &[2, 0]));
+        data.extend(evr_le(0x00280100, "US", &[8, 0]));
+        data.extend(evr_le(0x7FE00010, "OB", &(0..12).collect::<Vec<u8>>()));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        match slice.image().unwrap() {
+            DicomElt::Image8(ref img) => assert_eq!((img.xr, img.yr, img.zr, img.samples, img.data.len()), (2, 3, 2, 1, 12)),
+            ref other => panic!("expected Image8, got {:?}", other),
+        }
+        // the pad byte of odd length pixel data isn't a pixel
+        let mut data = evr_le(0x00280010, "US", &[1, 0]);
+        data.extend(evr_le(0x00280011, "US", &[3, 0]));
+        data.extend(evr_le(0x00280100, "US", &[8, 0]));
+        data.extend(evr_le(0x7FE00010, "OB", &[1, 2, 3, 0]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.image().unwrap(), DicomElt::Image8(DcmImg { xr: 3, yr: 1, zr: 1, samples: 1, data: vec![1, 2, 3] }));
+        for &tag in &[0x00280002, 0x00280010, 0x00280011] {
+            let mut data = evr_le(tag, "US", &[0, 0]);
+            data.extend(evr_le(0x7FE00010, "OB", &[0, 0]));
+            let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+            assert!(slice.frame(0).is_err());
+            match slice.image() {
+                Err(DicomError::BadValue { tag: t, .. }) => assert_eq!(t, tag),
+                other => panic!("expected BadValue for {:08X}, got {:?}", tag, other),
+            }
+        }
+    }
+
+    #[test]
+    fn frames_work() {
+        let mut data = evr_le(0x00280008, "IS", b"2 ");
+        data.extend(evr_le(0x00280010, "US", &[3, 0]));
+        data.extend(evr_le(0x00280011, "US", &[2, 0]));
+        data.extend(evr_le(0x00280100, "US", &[8, 0]));
+        data.extend(evr_le(0x7FE00010, "OB", &(0..12).collect::<Vec<u8>>()));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        let frames: Vec<DicomElt> = slice.frames().unwrap().map(|f| f.unwrap()).collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1], DicomElt::Image8(DcmImg { xr: 2, yr: 3, zr: 1, samples: 1, data: (6..12).collect() }));
+        assert!(slice.frame(2).is_err());
+        // 1 bit frames are packed back to back: frame 1 is bits 6-11
+        let mut data = evr_le(0x00280008, "IS", b"2 ");
+        data.extend(evr_le(0x00280010, "US", &[3, 0]));
+        data.extend(evr_le(0x00280011, "US", &[2, 0]));
+        data.extend(evr_le(0x00280100, "US", &[1, 0]));
+        data.extend(evr_le(0x7FE00010, "OB", &[0b1100_0000, 0b0000_1010]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.frame(1).unwrap(), DicomElt::Image8(DcmImg { xr: 2, yr: 3, zr: 1, samples: 1, data: vec![1, 1, 0, 1, 0, 1] }));
+
+        // frame 0 in two fragments, frame 1 in one, located by the Basic or the Extended Offset Table
+        let encapsulated = |bot: &[u8], eot: bool| {
+            let mut data = evr_le(0x00280008, "IS", b"2 ");
+            if eot { data.extend(evr_le(0x7FE00001, "OV", &[0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0])); }
+            data.extend_from_slice(&[0xE0, 0x7F, 0x10, 0x00, b'O', b'B', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+            data.extend(item(bot));
+            data.extend(item(&[1, 2, 3, 4]));
+            data.extend(item(&[5, 6]));
+            data.extend(item(&[9, 10]));
+            data.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+            read_dataset(&private_dictionary_init(), &data, 0).unwrap()
+        };
+        for slice in [encapsulated(&[0, 0, 0, 0, 22, 0, 0, 0], false), encapsulated(&[], true)].iter() {
+            assert_eq!(slice.number_of_frames().unwrap(), 2);
+            assert_eq!(slice.frame(0).unwrap(), DicomElt::Bytes(vec![1, 2, 3, 4, 5, 6]));
+            assert_eq!(slice.frame(1).unwrap(), DicomElt::Bytes(vec![9, 10]));
+        }
+    }
+
+    #[test]
+    fn color_images_work() {
+        let color = |photometric: &[u8], planar: u8, pixels: &[u8]| {
+            let mut data = evr_le(0x00280002, "US", &[3, 0]);
+            data.extend(evr_le(0x00280004, "CS", photometric));
+            data.extend(evr_le(0x00280006, "US", &[planar, 0]));
+            data.extend(evr_le(0x00280010, "US", &[1, 0]));
+            data.extend(evr_le(0x00280011, "US", &[2, 0]));
+            data.extend(evr_le(0x00280100, "US", &[8, 0]));
+            data.extend(evr_le(0x7FE00010, "OB", pixels));
+            read_dataset(&private_dictionary_init(), &data, 0).unwrap().color_image().unwrap().to_rgb().unwrap().data
+        };
+        let rgb = vec![255, 0, 0, 0, 255, 0];
+        assert_eq!(color(b"RGB ", 0, &rgb), rgb);
+        assert_eq!(color(b"RGB ", 1, &[255, 0, 0, 255, 0, 0]), rgb);
+        assert_eq!(color(b"YBR_FULL", 0, &[76, 85, 255, 29, 255, 107]), vec![254, 0, 0, 0, 0, 254]);
+        // both pixels share Cb and Cr
+        assert_eq!(color(b"YBR_FULL_422", 0, &[76, 150, 85, 255]), vec![254, 0, 0, 255, 74, 74]);
+    }
+
+    #[test]
+    fn palette_works() {
+        let words = |v: &[u16]| v.iter().flat_map(|w| vec![*w as u8, (*w >> 8) as u8]).collect::<Vec<u8>>();
+        let mut data = evr_le(0x00280004, "CS", b"PALETTE COLOR ");
+        data.extend(evr_le(0x00280010, "US", &[1, 0]));
+        data.extend(evr_le(0x00280011, "US", &[4, 0]));
+        data.extend(evr_le(0x00280100, "US", &[8, 0]));
+        // 16 bit red entries for stored values 10-13
+        data.extend(evr_le(0x00281101, "US", &words(&[4, 10, 16])));
+        // 8 bit green entries, packed two to a word
+        data.extend(evr_le(0x00281102, "US", &words(&[4, 10, 8])));
+        data.extend(evr_le(0x00281103, "US", &words(&[4, 10, 16])));
+        data.extend(evr_le(0x00281201, "OW", &words(&[0x0000, 0x1000, 0x2000, 0xFF00])));
+        data.extend(evr_le(0x00281202, "OW", &words(&[0x0201, 0x0403])));
+        // discrete 0, then a linear ramp to 0x9000 over 3 entries
+        data.extend(evr_le(0x00281223, "OW", &words(&[0, 1, 0, 1, 3, 0x9000])));
+        data.extend(evr_le(0x7FE00010, "OB", &[10, 11, 13, 200]));
+        let slice = read_dataset(&private_dictionary_init(), &data, 0).unwrap();
+        assert_eq!(slice.palette().unwrap().blue.data, vec![0, 0x3000, 0x6000, 0x9000]);
+        assert_eq!(slice.rgb().unwrap().data, vec![0, 1, 0, 0x10, 2, 0x30, 0xFF, 4, 0x90, 0xFF, 4, 0x90]);
+    }
+
+    // a scan directory holding a copy of the sample slice
+    fn scan_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy("resources/000001.dcm", dir.join("000001.dcm")).unwrap();
+        dir
+    }
+
     #[test]
     fn parse_set_works() {
         let dlib = DicomLib::new();
-        let result = dlib.parse_scan("resources/LIDC").unwrap();
-        let thickness = result[0].thickness();
+        let dir = scan_dir("rudicom_parse_set");
+        let result = dlib.parse_scan(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        let result = result.unwrap();
+        let thickness = result.slice_data[0].thickness().unwrap();
         println!("thickness: {}", thickness);
+        assert_eq!((result.image.zr, result.image.data.len()), (1, result.image.xr * result.image.yr));
     }
 
     #[test]
     fn parse_scan_serde() {
         let dlib = DicomLib::new();
-        let result = dlib.parse_scan("resources/LIDC").unwrap();
-        let limit = SizeLimit::Infinite;
-        let encoded : Vec<u8> = serialize(&result, limit).unwrap();
-        let decoded : Vec<DicomSlice> = deserialize(&encoded[..]).unwrap();
+        let dir = scan_dir("rudicom_scan_serde");
+        let result = dlib.parse_scan(&dir).unwrap();
+        let encoded : Vec<u8> = serialize(&result).unwrap();
+        let decoded : DicomScan = deserialize(&encoded[..]).unwrap();
         assert_eq!(result, decoded);
-        {
-            let mut buffer = File::create("dicom.rsbin").unwrap();
-            buffer.write(&encoded);
-        }
-        let mut buffer = File::open("dicom.rsbin").unwrap();
-        let mut encoded2 = Vec::new();
-        buffer.read_to_end(&mut encoded2);
-        let decoded2 : Vec<DicomSlice> = deserialize(&encoded2[..]).unwrap();
-        assert_eq!(result, decoded2);
+        let path = dir.join("dicom.rsbin");
+        let size = dlib.serialize_scan(&path, result.clone()).unwrap();
+        assert_eq!(size, encoded.len());
+        let decoded2 = dlib.deserialize_scan(&path);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, decoded2.unwrap());
     }
 }
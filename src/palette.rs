@@ -0,0 +1,129 @@
+// PALETTE COLOR lookup tables, PS3.3 C.7.6.3.1.5-6, including the segmented
+// form of C.7.9.2.
+use dicom_types::{Dataset, DicomElt, DicomTag, DcmImg, DcmImg8};
+use error::{DicomError, Result};
+use tags;
+
+/// One channel of a palette.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PaletteLut {
+    /// The stored pixel value mapped to the first entry
+    pub first: i32,
+    /// Bits per entry, 8 or 16
+    pub bits: u16,
+    pub data: Vec<u16>,
+}
+
+/// The red, green and blue lookup tables of a PALETTE COLOR image.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Palette {
+    pub red: PaletteLut,
+    pub green: PaletteLut,
+    pub blue: PaletteLut,
+}
+
+fn words<'a>(dataset: &'a Dataset, tag: DicomTag) -> Option<&'a [u16]> {
+    match dataset.get(tag).map(|elt| &elt.value) {
+        Some(&DicomElt::UInt16s(ref v)) => Some(v),
+        _ => None,
+    }
+}
+
+/// Expand segments into `out`, starting from the last entry already there (PS3.3 C.7.9.2).
+/// Indirect segments may not refer to other indirect segments.
+fn expand_segments(segments: &[u16], count: Option<usize>, indirect: bool, out: &mut Vec<u16>) -> Option<()> {
+    let (mut pos, mut done) = (0, 0);
+    while pos < segments.len() && count.map_or(true, |count| done < count) {
+        let n = *segments.get(pos + 1)? as usize;
+        match segments[pos] {
+            // discrete
+            0 => {
+                out.extend_from_slice(segments.get(pos + 2..pos + 2 + n)?);
+                pos += 2 + n;
+            },
+            // linear, from the previous entry to y1
+            1 => {
+                let (y0, y1) = (*out.last()? as i64, *segments.get(pos + 2)? as i64);
+                out.extend((1..n as i64 + 1).map(|i| (y0 + (y1 - y0) * i / n as i64) as u16));
+                pos += 3;
+            },
+            // indirect: n segments copied from a byte offset into the data
+            2 if !indirect => {
+                let offset = *segments.get(pos + 2)? as usize | (*segments.get(pos + 3)? as usize) << 16;
+                expand_segments(segments.get(offset / 2..)?, Some(n), true, out)?;
+                pos += 4;
+            },
+            _ => return None,
+        }
+        done += 1;
+    }
+    Some(())
+}
+
+impl PaletteLut {
+    fn new(dataset: &Dataset, descriptor: DicomTag, lut: DicomTag, segmented: DicomTag) -> Result<PaletteLut> {
+        let err = |tag: DicomTag| DicomError::UnexpectedType(tag.keyword.to_string());
+        // US or SS depending on PixelRepresentation; the entry count and bits are unsigned either way
+        let (entries, first, bits) = match dataset.get(descriptor).map(|elt| &elt.value) {
+            Some(&DicomElt::UInt16s(ref v)) if v.len() == 3 => (v[0], v[1] as i32, v[2]),
+            Some(&DicomElt::Int16s(ref v)) if v.len() == 3 => (v[0] as u16, v[1] as i32, v[2] as u16),
+            Some(_) => return Err(err(descriptor)),
+            None => return Err(DicomError::MissingElement(descriptor.keyword.to_string())),
+        };
+        let entries = if entries == 0 { 65536 } else { entries as usize };
+        let mut data = match (words(dataset, lut), words(dataset, segmented)) {
+            (Some(words), _) => words.to_vec(),
+            (None, Some(segments)) => {
+                let mut data = Vec::with_capacity(entries);
+                expand_segments(segments, None, false, &mut data).ok_or_else(|| err(segmented))?;
+                data
+            },
+            (None, None) => return Err(DicomError::MissingElement(lut.keyword.to_string())),
+        };
+        // 8 bit entries are packed two to a word, though some writers use a word each
+        if bits == 8 && data.len() < entries {
+            data = data.iter().flat_map(|&w| vec![w & 0xff, w >> 8]).collect();
+        }
+        if data.len() < entries { return Err(err(lut)); }
+        data.truncate(entries);
+        Ok(PaletteLut { first: first, bits: bits, data: data })
+    }
+
+    /// The entry for a stored value, scaled to 8 bits. Values outside the table
+    /// map to its first or last entry.
+    pub fn lookup(&self, value: i32) -> u8 {
+        let i = (value - self.first).max(0) as usize;
+        let entry = self.data[i.min(self.data.len() - 1)];
+        if self.bits == 8 { entry as u8 } else { (entry >> 8) as u8 }
+    }
+}
+
+impl Palette {
+    /// The palette of a dataset, from the LUT Data elements or their segmented versions.
+    pub fn new(dataset: &Dataset) -> Result<Palette> {
+        Ok(Palette {
+            red: PaletteLut::new(dataset, tags::RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR,
+                                 tags::RED_PALETTE_COLOR_LOOKUP_TABLE_DATA,
+                                 tags::SEGMENTED_RED_PALETTE_COLOR_LOOKUP_TABLE_DATA)?,
+            green: PaletteLut::new(dataset, tags::GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR,
+                                   tags::GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA,
+                                   tags::SEGMENTED_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA)?,
+            blue: PaletteLut::new(dataset, tags::BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR,
+                                  tags::BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA,
+                                  tags::SEGMENTED_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA)?,
+        })
+    }
+
+    pub fn lookup(&self, value: i32) -> [u8; 3] {
+        [self.red.lookup(value), self.green.lookup(value), self.blue.lookup(value)]
+    }
+
+    /// Expand an image of palette indices to interleaved RGB.
+    pub fn apply<T: Copy + Into<i32>>(&self, indices: &DcmImg<T>) -> DcmImg8 {
+        let mut rgb = Vec::with_capacity(indices.data.len() * 3);
+        for &index in &indices.data {
+            rgb.extend_from_slice(&self.lookup(index.into()));
+        }
+        DcmImg { xr: indices.xr, yr: indices.yr, zr: indices.zr, samples: 3, data: rgb }
+    }
+}
@@ -0,0 +1,77 @@
+// Person Name (PN) values, PS3.5 6.2.1: up to three component groups separated by '=',
+// each of up to five components separated by '^'.
+use std::fmt;
+
+/// One component group of a name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PersonNameGroup {
+    pub family: String,
+    pub given: String,
+    pub middle: String,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+/// A name in its alphabetic, ideographic and phonetic representations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PersonName {
+    pub alphabetic: PersonNameGroup,
+    pub ideographic: PersonNameGroup,
+    pub phonetic: PersonNameGroup,
+}
+
+impl PersonNameGroup {
+    fn parse(group: &str) -> PersonNameGroup {
+        let mut components = group.split('^').map(|c| c.trim().to_string());
+        PersonNameGroup {
+            family: components.next().unwrap_or_default(),
+            given: components.next().unwrap_or_default(),
+            middle: components.next().unwrap_or_default(),
+            prefix: components.next().unwrap_or_default(),
+            suffix: components.next().unwrap_or_default(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.family.is_empty() && self.given.is_empty() && self.middle.is_empty()
+            && self.prefix.is_empty() && self.suffix.is_empty()
+    }
+
+    /// Components joined by '^', without trailing empty components.
+    pub fn encode(&self) -> String {
+        let components = [&self.family, &self.given, &self.middle, &self.prefix, &self.suffix];
+        let len = components.iter().rposition(|c| !c.is_empty()).map_or(0, |i| i + 1);
+        components[..len].iter().map(|c| &c[..]).collect::<Vec<&str>>().join("^")
+    }
+}
+
+impl PersonName {
+    /// Parse a single PN value, e.g. "Yamada^Tarou=山田^太郎=やまだ^たろう".
+    /// Padding and whitespace around components is dropped.
+    pub fn parse(value: &str) -> PersonName {
+        let mut groups = value.trim_matches(|c| c == ' ' || c == '\0').split('=').map(PersonNameGroup::parse);
+        PersonName {
+            alphabetic: groups.next().unwrap_or_default(),
+            ideographic: groups.next().unwrap_or_default(),
+            phonetic: groups.next().unwrap_or_default(),
+        }
+    }
+
+    /// Parse a possibly multi-valued PN element, one name per '\'-separated value.
+    pub fn parse_all(value: &str) -> Vec<PersonName> {
+        value.split('\\').map(PersonName::parse).collect()
+    }
+
+    /// The DICOM encoding of the name, without trailing empty groups or components.
+    pub fn encode(&self) -> String {
+        let groups = [self.alphabetic.encode(), self.ideographic.encode(), self.phonetic.encode()];
+        let len = groups.iter().rposition(|g| !g.is_empty()).map_or(0, |i| i + 1);
+        groups[..len].join("=")
+    }
+}
+
+impl fmt::Display for PersonName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
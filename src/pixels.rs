@@ -0,0 +1,125 @@
+// Native (uncompressed) pixel data, PS3.5 8.1.1. The bytes are kept as read and only
+// decoded to samples when an image or a frame is asked for.
+use byteorder::{ByteOrder, BigEndian, LittleEndian};
+use dicom_types::{DicomElt, DcmImg, DicomTag};
+use tags;
+use error::{DicomError, Result};
+
+/// How native pixel samples are laid out, from the Image Pixel module (0028,0100-0103).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PixelFormat {
+    pub allocated: usize,
+    pub stored: usize,
+    pub high_bit: usize,
+    pub signed: bool,
+}
+
+/// Native PixelData as stored, with what is needed to decode it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DcmPixels {
+    pub xr : usize,
+    pub yr : usize,
+    pub zr : usize,
+    pub samples : usize,
+    /// Stored values per frame; fewer than `xr * yr * samples` for subsampled YBR
+    pub frame_values : usize,
+    pub format : PixelFormat,
+    pub big_endian : bool,
+    /// Offset of the value in the file, for errors found when decoding
+    pub offset : usize,
+    pub data : Vec<u8>,
+}
+
+impl PixelFormat {
+    /// Zero or inconsistent bit counts, which make the samples undecodable, are a `BadValue`
+    /// at `offset`.
+    pub fn check(&self, offset: usize) -> Result<()> {
+        let bad = |tag: DicomTag, reason: String| Err(DicomError::BadValue { tag: tag.tag, offset: offset, reason: reason });
+        let (allocated, stored, high_bit) = (self.allocated, self.stored, self.high_bit);
+        if allocated == 0 { return bad(tags::BITS_ALLOCATED, "BitsAllocated is 0".to_string()); }
+        if stored == 0 || stored > allocated {
+            return bad(tags::BITS_STORED, format!("BitsStored {} with BitsAllocated {}", stored, allocated));
+        }
+        if high_bit >= allocated || high_bit + 1 < stored {
+            return bad(tags::HIGH_BIT, format!("HighBit {} with BitsStored {} and BitsAllocated {}",
+                                               high_bit, stored, allocated));
+        }
+        Ok(())
+    }
+
+    /// The stored bits of a raw sample: shifted down to bit 0, masked, and sign extended
+    /// if the representation is signed. The result is the sample's bit pattern.
+    pub fn sample(&self, raw: u32) -> u32 {
+        let mask = if self.stored >= 32 { !0 } else { (1u32 << self.stored) - 1 };
+        let v = (raw >> (self.high_bit + 1 - self.stored)) & mask;
+        if self.signed && v & (1 << (self.stored - 1)) != 0 { v | !mask } else { v }
+    }
+}
+
+impl DcmPixels {
+    fn u16s(&self, bytes: &[u8]) -> Vec<u32> {
+        bytes.chunks(2).filter(|c| c.len() == 2)
+            .map(|c| if self.big_endian { BigEndian::read_u16(c) } else { LittleEndian::read_u16(c) } as u32).collect()
+    }
+
+    fn u32s(&self, bytes: &[u8]) -> Vec<u32> {
+        bytes.chunks(4).filter(|c| c.len() == 4)
+            .map(|c| if self.big_endian { BigEndian::read_u32(c) } else { LittleEndian::read_u32(c) }).collect()
+    }
+
+    fn img<T>(&self, zr: usize, data: Vec<T>) -> DcmImg<T> {
+        DcmImg { xr: self.xr, yr: self.yr, zr: zr, samples: self.samples, data: data }
+    }
+
+    /// Decode values `start..end` as `zr` frames. 1 bit samples are packed across frame
+    /// boundaries, least significant bit first, and come out as one byte each.
+    fn decode_values(&self, start: usize, end: usize, zr: usize) -> Result<DicomElt> {
+        let f = self.format;
+        if f.allocated == 1 {
+            return Ok(DicomElt::Image8(self.img(zr, (start..end).map(|i| self.data[i / 8] >> (i % 8) & 1).collect())));
+        }
+        let bytes = &self.data[start * f.allocated / 8..end * f.allocated / 8];
+        Ok(match (f.allocated, f.signed) {
+            (8, false) => DicomElt::Image8(self.img(zr, bytes.iter().map(|&b| f.sample(b as u32) as u8).collect())),
+            (8, true) => DicomElt::ImageI8(self.img(zr, bytes.iter().map(|&b| f.sample(b as u32) as i8).collect())),
+            (16, false) => DicomElt::ImageU16(self.img(zr, self.u16s(bytes).into_iter().map(|v| f.sample(v) as u16).collect())),
+            (16, true) => DicomElt::Image16(self.img(zr, self.u16s(bytes).into_iter().map(|v| f.sample(v) as i16).collect())),
+            (32, false) => DicomElt::ImageU32(self.img(zr, self.u32s(bytes).into_iter().map(|v| f.sample(v)).collect())),
+            (32, true) => DicomElt::Image32(self.img(zr, self.u32s(bytes).into_iter().map(|v| f.sample(v) as i32).collect())),
+            _ => return Err(DicomError::UnexpectedType(tags::PIXEL_DATA.keyword.to_string())),
+        })
+    }
+
+    /// The format and dimensions are usable; a zero one is a `BadValue`.
+    fn check(&self) -> Result<()> {
+        self.format.check(self.offset)?;
+        for &(tag, n) in &[(tags::NUMBER_OF_FRAMES, self.zr), (tags::SAMPLES_PER_PIXEL, self.samples),
+                           (tags::COLUMNS, self.xr), (tags::ROWS, self.yr)] {
+            if n == 0 {
+                return Err(DicomError::BadValue { tag: tag.tag, offset: self.offset, reason: format!("{} is 0", tag.keyword) });
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of whole values in `data`.
+    fn values(&self) -> usize {
+        self.data.len() * 8 / self.format.allocated
+    }
+
+    /// Every frame, as an image of the type matching BitsAllocated and PixelRepresentation.
+    /// Padding after the last frame is left out. A `BadValue` if the Image Pixel attributes
+    /// are unusable, e.g. a zero Rows, `UnexpectedType` for sample sizes other than 1, 8, 16 and 32 bits.
+    pub fn decode(&self) -> Result<DicomElt> {
+        self.check()?;
+        self.decode_values(0, self.values().min(self.zr * self.frame_values), self.zr)
+    }
+
+    /// Frame `n` alone, decoding only its bytes.
+    pub fn frame(&self, n: usize) -> Result<DicomElt> {
+        self.check()?;
+        let (start, end) = (n * self.frame_values, (n + 1) * self.frame_values);
+        if n >= self.zr || end > self.values() { return Err(DicomError::NoFrame(n)); }
+        self.decode_values(start, end, 1)
+    }
+}
@@ -0,0 +1,36 @@
+// Well-known vendor private data elements, keyed by private creator.
+// Tags are written with the block byte cleared: 0x00290010 is (0029,xx10).
+use std::collections::HashMap;
+use dicom_types::PrivateDict;
+use dicom_types::DicomDictElt;
+
+pub fn private_dictionary_add<'a>(dict: &mut PrivateDict<'a>, creator: &str, tag: u32, elt: DicomDictElt<'a>) {
+	dict.entry(creator.to_string()).or_insert_with(HashMap::new).insert(tag & 0xFFFF00FF, elt);
+}
+
+pub fn private_dictionary_init<'a>() -> PrivateDict<'a> {
+	let mut private_dictionary : PrivateDict = HashMap::new();
+
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290008, DicomDictElt { vr : "CS", vm: "1", name: "CSA Image Header Type", retired: "", keyword: "CSAImageHeaderType" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290009, DicomDictElt { vr : "LO", vm: "1", name: "CSA Image Header Version", retired: "", keyword: "CSAImageHeaderVersion" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290010, DicomDictElt { vr : "OB", vm: "1", name: "CSA Image Header Info", retired: "", keyword: "CSAImageHeaderInfo" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290018, DicomDictElt { vr : "CS", vm: "1", name: "CSA Series Header Type", retired: "", keyword: "CSASeriesHeaderType" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290019, DicomDictElt { vr : "LO", vm: "1", name: "CSA Series Header Version", retired: "", keyword: "CSASeriesHeaderVersion" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS CSA HEADER", 0x00290020, DicomDictElt { vr : "OB", vm: "1", name: "CSA Series Header Info", retired: "", keyword: "CSASeriesHeaderInfo" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x00190008, DicomDictElt { vr : "CS", vm: "1", name: "CSA Image Header Type", retired: "", keyword: "CSAImageHeaderType" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x00190009, DicomDictElt { vr : "LO", vm: "1", name: "CSA Image Header Version", retired: "", keyword: "CSAImageHeaderVersion" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000A, DicomDictElt { vr : "US", vm: "1", name: "Number of Images in Mosaic", retired: "", keyword: "NumberOfImagesInMosaic" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000B, DicomDictElt { vr : "DS", vm: "1", name: "Slice Measurement Duration", retired: "", keyword: "SliceMeasurementDuration" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000C, DicomDictElt { vr : "IS", vm: "1", name: "B Value", retired: "", keyword: "BValue" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000D, DicomDictElt { vr : "CS", vm: "1", name: "Diffusion Directionality", retired: "", keyword: "DiffusionDirectionality" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000E, DicomDictElt { vr : "FD", vm: "3", name: "Diffusion Gradient Direction", retired: "", keyword: "DiffusionGradientDirection" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x0019000F, DicomDictElt { vr : "SH", vm: "1", name: "Gradient Mode", retired: "", keyword: "GradientMode" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x00190027, DicomDictElt { vr : "FD", vm: "6", name: "B Matrix", retired: "", keyword: "BMatrix" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x00190028, DicomDictElt { vr : "FD", vm: "1", name: "Bandwidth per Pixel Phase Encode", retired: "", keyword: "BandwidthPerPixelPhaseEncode" });
+	private_dictionary_add(&mut private_dictionary, "SIEMENS MR HEADER", 0x00190029, DicomDictElt { vr : "FD", vm: "1-n", name: "Mosaic Ref Acq Times", retired: "", keyword: "MosaicRefAcqTimes" });
+	private_dictionary_add(&mut private_dictionary, "GEMS_IDEN_01", 0x00090001, DicomDictElt { vr : "LO", vm: "2", name: "Full Fidelity", retired: "", keyword: "FullFidelity" });
+	private_dictionary_add(&mut private_dictionary, "GEMS_IDEN_01", 0x00090002, DicomDictElt { vr : "SH", vm: "1", name: "Suite ID", retired: "", keyword: "SuiteID" });
+	private_dictionary_add(&mut private_dictionary, "GEMS_IDEN_01", 0x00090004, DicomDictElt { vr : "SH", vm: "1", name: "Product ID", retired: "", keyword: "ProductID" });
+	private_dictionary_add(&mut private_dictionary, "GEMS_SERS_01", 0x00250007, DicomDictElt { vr : "SL", vm: "1", name: "Images in Series", retired: "", keyword: "ImagesInSeries" });
+	private_dictionary
+}
@@ -0,0 +1,5 @@
+//! Constants for every keyword in the data dictionary, e.g. `tags::RESCALE_SLOPE`,
+//! generated by build.rs from resources/dicom_dict.tsv.
+use dicom_types::DicomTag;
+
+include!(concat!(env!("OUT_DIR"), "/tags.rs"));
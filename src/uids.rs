@@ -0,0 +1,10 @@
+// UID registry of PS3.6 Annex A, compiled by build.rs from resources/uid_registry.tsv.
+use dicom_types::{DicomUid, UidType};
+
+include!(concat!(env!("OUT_DIR"), "/uids.rs"));
+
+/// Registry entry for a UID, e.g. from SOPClassUID or TransferSyntaxUID. Trailing
+/// padding is ignored.
+pub fn lookup_uid(uid: &str) -> Option<&'static DicomUid> {
+    UID_REGISTRY.get(uid.trim_matches(|c| c == '\0' || c == ' '))
+}